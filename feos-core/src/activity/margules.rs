@@ -0,0 +1,56 @@
+//! Margules model and propane/butane parameters shared by the tests of
+//! the cubic mixing rules and gamma-phi phase equilibria.
+use crate::cubic::{CubicParameters, CubicRecord};
+use crate::equation_of_state::{Components, ExcessGibbsEnergy};
+use crate::parameter::{Identifier, Parameter, PureRecord};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use std::sync::Arc;
+
+/// Margules model $\frac{g^E}{RT}=\frac{1}{2}\sum_i\sum_jA_{ij}x_ix_j$.
+pub(crate) struct Margules(pub Array2<f64>);
+
+impl Components for Margules {
+    fn components(&self) -> usize {
+        self.0.nrows()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let n = component_list.len();
+        Self(Array2::from_shape_fn([n, n], |(i, j)| {
+            self.0[(component_list[i], component_list[j])]
+        }))
+    }
+}
+
+impl ExcessGibbsEnergy for Margules {
+    fn reduced_molar_excess_gibbs_energy<D: DualNum<f64> + Copy>(
+        &self,
+        _: D,
+        molefracs: &Array1<D>,
+    ) -> D {
+        let mut g = D::zero();
+        for i in 0..self.0.nrows() {
+            for j in 0..self.0.ncols() {
+                g += molefracs[i] * molefracs[j] * (0.5 * self.0[(i, j)]);
+            }
+        }
+        g
+    }
+}
+
+/// Cubic parameters of propane and n-butane.
+pub(crate) fn propane_butane() -> Arc<CubicParameters> {
+    let propane = CubicRecord::new(369.96, 4250000.0, 0.153);
+    let butane = CubicRecord::new(425.2, 3800000.0, 0.199);
+    Arc::new(
+        CubicParameters::from_records(
+            vec![
+                PureRecord::new(Identifier::default(), 44.0962, propane),
+                PureRecord::new(Identifier::default(), 58.123, butane),
+            ],
+            None,
+        )
+        .unwrap(),
+    )
+}
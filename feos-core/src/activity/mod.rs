@@ -0,0 +1,76 @@
+//! Activity coefficient models for liquid mixtures.
+//!
+//! The models implement [ExcessGibbsEnergy] and can therefore be used
+//! either in the mixing rules of a [CubicExcessGibbs](crate::cubic::CubicExcessGibbs)
+//! equation of state or in gamma-phi phase equilibrium calculations, e.g.,
+//! [PhaseEquilibrium::bubble_point_gamma_phi](crate::PhaseEquilibrium::bubble_point_gamma_phi).
+//! Pure component vapor pressures for the latter can be provided by any [Residual](crate::Residual)
+//! model or by empirical correlations ([VaporPressureCorrelation]).
+use crate::equation_of_state::{Components, ExcessGibbsEnergy};
+use ndarray::Array1;
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+#[cfg(test)]
+pub(crate) mod margules;
+mod nrtl;
+mod unifac;
+mod uniquac;
+mod vapor_pressure;
+pub use nrtl::{Nrtl, NrtlBinaryRecord, NrtlParameters, NrtlRecord};
+pub use unifac::{Unifac, UnifacBinaryRecord, UnifacParameters, UnifacRecord};
+pub use uniquac::{Uniquac, UniquacBinaryRecord, UniquacParameters, UniquacRecord};
+pub use vapor_pressure::{VaporPressureCorrelation, VaporPressureRecord};
+
+/// Collection of different [ExcessGibbsEnergy] implementations.
+///
+/// Particularly relevant for situations in which generic types
+/// are undesirable (e.g. FFI).
+pub enum ActivityModel {
+    Nrtl(Arc<Nrtl>),
+    Uniquac(Arc<Uniquac>),
+    Unifac(Arc<Unifac>),
+}
+
+impl Components for ActivityModel {
+    fn components(&self) -> usize {
+        match self {
+            Self::Nrtl(model) => model.components(),
+            Self::Uniquac(model) => model.components(),
+            Self::Unifac(model) => model.components(),
+        }
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        match self {
+            Self::Nrtl(model) => Self::Nrtl(Arc::new(model.subset(component_list))),
+            Self::Uniquac(model) => Self::Uniquac(Arc::new(model.subset(component_list))),
+            Self::Unifac(model) => Self::Unifac(Arc::new(model.subset(component_list))),
+        }
+    }
+}
+
+impl ExcessGibbsEnergy for ActivityModel {
+    fn reduced_molar_excess_gibbs_energy<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        molefracs: &Array1<D>,
+    ) -> D {
+        match self {
+            Self::Nrtl(model) => model.reduced_molar_excess_gibbs_energy(temperature, molefracs),
+            Self::Uniquac(model) => model.reduced_molar_excess_gibbs_energy(temperature, molefracs),
+            Self::Unifac(model) => model.reduced_molar_excess_gibbs_energy(temperature, molefracs),
+        }
+    }
+}
+
+impl fmt::Display for ActivityModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Nrtl(model) => model.fmt(f),
+            Self::Uniquac(model) => model.fmt(f),
+            Self::Unifac(model) => model.fmt(f),
+        }
+    }
+}
@@ -0,0 +1,312 @@
+use crate::equation_of_state::{Components, ExcessGibbsEnergy};
+use crate::parameter::{
+    oriented_binary_matrix, BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord,
+};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// Pure component record of the NRTL model.
+///
+/// The NRTL model does not require pure component parameters.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct NrtlRecord {}
+
+impl fmt::Display for NrtlRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NrtlRecord()")
+    }
+}
+
+/// Binary interaction parameters of the NRTL model.
+///
+/// The parameters of a record for components 1 and 2 are stored as
+/// `[12, 21]`, so that
+/// $$\tau_{12}=a_{12}+\frac{b_{12}}{T}+e_{12}\ln T+f_{12}T$$
+/// $$\alpha_{12}=\alpha_{21}=c+d\left(T-273.15\right)$$
+/// with $\[T\]=\text{K}$.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct NrtlBinaryRecord {
+    /// constant part of the interaction parameters
+    #[serde(default)]
+    pub a: [f64; 2],
+    /// interaction parameters in units of K
+    #[serde(default)]
+    pub b: [f64; 2],
+    /// non-randomness parameter
+    #[serde(default)]
+    pub c: f64,
+    /// temperature dependence of the non-randomness parameter in units of 1/K
+    #[serde(default)]
+    pub d: f64,
+    /// logarithmic temperature dependence of the interaction parameters
+    #[serde(default)]
+    pub e: [f64; 2],
+    /// linear temperature dependence of the interaction parameters in units of 1/K
+    #[serde(default)]
+    pub f: [f64; 2],
+}
+
+impl NrtlBinaryRecord {
+    /// Create a new binary record with temperature independent
+    /// non-randomness parameter and $\tau_{ij}=a_{ij}+\frac{b_{ij}}{T}$.
+    pub fn new(a: [f64; 2], b: [f64; 2], c: f64) -> Self {
+        Self {
+            a,
+            b,
+            c,
+            ..Default::default()
+        }
+    }
+
+    /// The same interactions for the components in reversed order.
+    fn reversed(&self) -> Self {
+        let rev = |[x12, x21]: [f64; 2]| [x21, x12];
+        Self {
+            a: rev(self.a),
+            b: rev(self.b),
+            c: self.c,
+            d: self.d,
+            e: rev(self.e),
+            f: rev(self.f),
+        }
+    }
+}
+
+impl fmt::Display for NrtlBinaryRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NrtlBinaryRecord(a={:?}, b={:?}", self.a, self.b)?;
+        write!(f, ", c={}, d={}", self.c, self.d)?;
+        write!(f, ", e={:?}, f={:?})", self.e, self.f)
+    }
+}
+
+/// Parameters of the NRTL model for one or more substances.
+pub struct NrtlParameters {
+    pub a: Array2<f64>,
+    pub b: Array2<f64>,
+    pub c: Array2<f64>,
+    pub d: Array2<f64>,
+    pub e: Array2<f64>,
+    pub f: Array2<f64>,
+    pure_records: Vec<PureRecord<NrtlRecord>>,
+    binary_records: Array2<NrtlBinaryRecord>,
+}
+
+impl Parameter for NrtlParameters {
+    type Pure = NrtlRecord;
+    type Binary = NrtlBinaryRecord;
+
+    /// Creates parameters from records.
+    ///
+    /// The binary records above the diagonal determine the interactions;
+    /// the records below the diagonal are replaced by their reversed counterparts.
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        let n = pure_records.len();
+        let mut binary_records = binary_records.unwrap_or_else(|| Array2::default([n, n]));
+        for i in 0..n {
+            binary_records[(i, i)] = NrtlBinaryRecord::default();
+            for j in i + 1..n {
+                binary_records[(j, i)] = binary_records[(i, j)].reversed();
+            }
+        }
+
+        let component = |x: fn(&NrtlBinaryRecord) -> [f64; 2]| {
+            Array2::from_shape_fn([n, n], |(i, j)| x(&binary_records[(i, j)])[0])
+        };
+        let a = component(|br| br.a);
+        let b = component(|br| br.b);
+        let e = component(|br| br.e);
+        let f = component(|br| br.f);
+        let c = binary_records.map(|br| br.c);
+        let d = binary_records.map(|br| br.d);
+
+        Ok(Self {
+            a,
+            b,
+            c,
+            d,
+            e,
+            f,
+            pure_records,
+            binary_records,
+        })
+    }
+
+    fn records(&self) -> (&[PureRecord<NrtlRecord>], Option<&Array2<NrtlBinaryRecord>>) {
+        (&self.pure_records, Some(&self.binary_records))
+    }
+
+    /// Binary records are oriented: if a record is found with reversed
+    /// identifiers, the reversed interactions are used.
+    fn binary_matrix_from_records(
+        pure_records: &[PureRecord<Self::Pure>],
+        binary_records: &[BinaryRecord<Identifier, Self::Binary>],
+        identifier_option: IdentifierOption,
+    ) -> Option<Array2<Self::Binary>> {
+        oriented_binary_matrix(
+            pure_records,
+            binary_records,
+            identifier_option,
+            NrtlBinaryRecord::reversed,
+        )
+    }
+}
+
+impl fmt::Display for NrtlParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pure_records
+            .iter()
+            .try_for_each(|pr| writeln!(f, "{}", pr))?;
+        writeln!(f, "\na_ij:\n{}", self.a)?;
+        writeln!(f, "\nb_ij:\n{}", self.b)?;
+        writeln!(f, "\nalpha_ij:\n{}", self.c)
+    }
+}
+
+/// Non-random two-liquid (NRTL) model.
+///
+/// [Renon and Prausnitz (1968)](https://doi.org/10.1002/aic.690140124)
+/// $$\frac{g^E}{RT}=\sum_ix_i\frac{\sum_jx_j\tau_{ji}G_{ji}}{\sum_kx_kG_{ki}}\qquad G_{ij}=\exp\left(-\alpha_{ij}\tau_{ij}\right)$$
+pub struct Nrtl {
+    pub parameters: Arc<NrtlParameters>,
+}
+
+impl Nrtl {
+    pub fn new(parameters: Arc<NrtlParameters>) -> Self {
+        Self { parameters }
+    }
+
+    fn tau_alpha<D: DualNum<f64> + Copy>(&self, temperature: D) -> (Array2<D>, Array2<D>) {
+        let p = &self.parameters;
+        let n = p.a.nrows();
+        let ln_t = temperature.ln();
+        let tau = Array2::from_shape_fn([n, n], |ij| {
+            temperature.recip() * p.b[ij] + ln_t * p.e[ij] + temperature * p.f[ij] + p.a[ij]
+        });
+        let alpha = Array2::from_shape_fn([n, n], |ij| (temperature - 273.15) * p.d[ij] + p.c[ij]);
+        (tau, alpha)
+    }
+}
+
+impl Components for Nrtl {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(Arc::new(self.parameters.subset(component_list)))
+    }
+}
+
+impl ExcessGibbsEnergy for Nrtl {
+    fn reduced_molar_excess_gibbs_energy<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        molefracs: &Array1<D>,
+    ) -> D {
+        let (tau, alpha) = self.tau_alpha(temperature);
+        let g = (&alpha * &tau).mapv(|x| (-x).exp());
+        let n = molefracs.len();
+        let mut res = D::zero();
+        for i in 0..n {
+            let mut num = D::zero();
+            let mut den = D::zero();
+            for j in 0..n {
+                num += molefracs[j] * tau[(j, i)] * g[(j, i)];
+                den += molefracs[j] * g[(j, i)];
+            }
+            res += molefracs[i] * num / den;
+        }
+        res
+    }
+}
+
+impl fmt::Display for Nrtl {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "NRTL")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    fn parameters() -> NrtlParameters {
+        // ethanol/water from Gmehling et al.
+        let records = vec![
+            PureRecord::new(
+                Identifier::new(None, Some("ethanol"), None, None, None, None),
+                46.069,
+                NrtlRecord {},
+            ),
+            PureRecord::new(
+                Identifier::new(None, Some("water"), None, None, None, None),
+                18.015,
+                NrtlRecord {},
+            ),
+        ];
+        let binary = NrtlBinaryRecord::new([0.0, 0.0], [-55.17, 670.44], 0.3031);
+        NrtlParameters::new_binary(records, Some(binary)).unwrap()
+    }
+
+    #[test]
+    fn orientation() {
+        let p = parameters();
+        assert_relative_eq!(p.b[(0, 1)], -55.17);
+        assert_relative_eq!(p.b[(1, 0)], 670.44);
+        let p = p.subset(&[1, 0]);
+        assert_relative_eq!(p.b[(0, 1)], 670.44);
+        assert_relative_eq!(p.b[(1, 0)], -55.17);
+
+        let parameters = parameters();
+        let (pure_records, _) = parameters.records();
+        let binary_records = vec![BinaryRecord::new(
+            Identifier::new(None, Some("water"), None, None, None, None),
+            Identifier::new(None, Some("ethanol"), None, None, None, None),
+            NrtlBinaryRecord::new([0.0, 0.0], [670.44, -55.17], 0.3031),
+        )];
+        let br = NrtlParameters::binary_matrix_from_records(
+            pure_records,
+            &binary_records,
+            IdentifierOption::Name,
+        )
+        .unwrap();
+        let p = NrtlParameters::from_records(pure_records.to_vec(), Some(br)).unwrap();
+        assert_relative_eq!(p.b[(0, 1)], -55.17);
+        assert_relative_eq!(p.b[(1, 0)], 670.44);
+    }
+
+    #[test]
+    fn activity_coefficients() {
+        let nrtl = Nrtl::new(Arc::new(parameters()));
+        let t = 350.0;
+        let x = arr1(&[0.3, 0.7]);
+        let ln_gamma = nrtl.ln_activity_coefficients(t, &x);
+
+        // analytic expressions for binary mixtures
+        let tau12 = -55.17 / t;
+        let tau21 = 670.44 / t;
+        let g12 = (-0.3031 * tau12).exp();
+        let g21 = (-0.3031 * tau21).exp();
+        let (x1, x2) = (x[0], x[1]);
+        let ln_gamma1 = x2.powi(2)
+            * (tau21 * (g21 / (x1 + x2 * g21)).powi(2) + tau12 * g12 / (x2 + x1 * g12).powi(2));
+        let ln_gamma2 = x1.powi(2)
+            * (tau12 * (g12 / (x2 + x1 * g12)).powi(2) + tau21 * g21 / (x1 + x2 * g21).powi(2));
+        assert_relative_eq!(ln_gamma[0], ln_gamma1, max_relative = 1e-12);
+        assert_relative_eq!(ln_gamma[1], ln_gamma2, max_relative = 1e-12);
+
+        // pure components
+        let ln_gamma = nrtl.ln_activity_coefficients(t, &arr1(&[1.0, 0.0]));
+        assert_relative_eq!(ln_gamma[0], 0.0, epsilon = 1e-14);
+    }
+}
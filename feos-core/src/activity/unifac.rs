@@ -0,0 +1,447 @@
+use crate::equation_of_state::{Components, ExcessGibbsEnergy};
+use crate::parameter::{
+    BinaryRecord, ChemicalRecord, ParameterError, ParameterHetero, SegmentCount, SegmentRecord,
+};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fmt;
+use std::sync::Arc;
+
+/// Subgroup parameters of the modified UNIFAC (Dortmund) model.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UnifacRecord {
+    /// relative van der Waals volume of the subgroup
+    pub rk: f64,
+    /// relative van der Waals surface area of the subgroup
+    pub qk: f64,
+    /// main group that determines the group interactions
+    pub main_group: String,
+}
+
+impl UnifacRecord {
+    /// Create a new subgroup record for the modified UNIFAC model.
+    pub fn new(rk: f64, qk: f64, main_group: String) -> Self {
+        Self { rk, qk, main_group }
+    }
+}
+
+impl fmt::Display for UnifacRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UnifacRecord(rk={}, qk={}, main_group={})",
+            self.rk, self.qk, self.main_group
+        )
+    }
+}
+
+/// Interaction parameters between two main groups $n$ and $m$ of the
+/// modified UNIFAC model.
+///
+/// The parameters are not symmetric: a binary record with `id1`$=n$ and
+/// `id2`$=m$ determines
+/// $$\Psi_{nm}=\exp\left(-\frac{a_{nm}+b_{nm}T+c_{nm}T^2}{T}\right)$$
+/// with $\[T\]=\text{K}$.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UnifacBinaryRecord {
+    /// interaction parameter in units of K
+    pub a: f64,
+    /// linear temperature dependence
+    #[serde(default)]
+    pub b: f64,
+    /// quadratic temperature dependence in units of 1/K
+    #[serde(default)]
+    pub c: f64,
+}
+
+impl UnifacBinaryRecord {
+    /// Create a new set of main group interaction parameters.
+    pub fn new(a: f64, b: f64, c: f64) -> Self {
+        Self { a, b, c }
+    }
+}
+
+impl fmt::Display for UnifacBinaryRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "UnifacBinaryRecord(a={}, b={}, c={})",
+            self.a, self.b, self.c
+        )
+    }
+}
+
+/// Parameters of the modified UNIFAC model for one or more substances.
+///
+/// The group parameters are stored for every combination of component
+/// and subgroup present in the system.
+pub struct UnifacParameters {
+    pub component_index: Array1<usize>,
+    pub identifiers: Vec<String>,
+    pub counts: Array1<f64>,
+    pub rk: Array1<f64>,
+    pub qk: Array1<f64>,
+    pub a: Array2<f64>,
+    pub b: Array2<f64>,
+    pub c: Array2<f64>,
+    pub r: Array1<f64>,
+    pub q: Array1<f64>,
+    chemical_records: Vec<ChemicalRecord>,
+    segment_records: Vec<SegmentRecord<UnifacRecord>>,
+    binary_segment_records: Option<Vec<BinaryRecord<String, UnifacBinaryRecord>>>,
+}
+
+impl ParameterHetero for UnifacParameters {
+    type Chemical = ChemicalRecord;
+    type Pure = UnifacRecord;
+    type Binary = UnifacBinaryRecord;
+
+    fn from_segments<C: Clone + Into<ChemicalRecord>>(
+        chemical_records: Vec<C>,
+        segment_records: Vec<SegmentRecord<UnifacRecord>>,
+        binary_segment_records: Option<Vec<BinaryRecord<String, UnifacBinaryRecord>>>,
+    ) -> Result<Self, ParameterError> {
+        let chemical_records: Vec<ChemicalRecord> =
+            chemical_records.into_iter().map(|c| c.into()).collect();
+
+        let mut component_index = Vec::new();
+        let mut identifiers = Vec::new();
+        let mut main_groups = Vec::new();
+        let mut counts = Vec::new();
+        let mut rk = Vec::new();
+        let mut qk = Vec::new();
+        let mut r = Array1::zeros(chemical_records.len());
+        let mut q = Array1::zeros(chemical_records.len());
+
+        for (i, chemical_record) in chemical_records.iter().enumerate() {
+            let segment_map = chemical_record.segment_map(&segment_records)?;
+            for (segment, &count) in segment_map.iter() {
+                let count = count as f64;
+                component_index.push(i);
+                identifiers.push(segment.identifier.clone());
+                main_groups.push(segment.model_record.main_group.clone());
+                counts.push(count);
+                rk.push(segment.model_record.rk);
+                qk.push(segment.model_record.qk);
+                r[i] += segment.model_record.rk * count;
+                q[i] += segment.model_record.qk * count;
+            }
+        }
+
+        let binary_map: HashMap<_, _> = binary_segment_records
+            .iter()
+            .flatten()
+            .map(|br| ((br.id1.clone(), br.id2.clone()), br.model_record.clone()))
+            .collect();
+        let n = main_groups.len();
+        let interactions = Array2::from_shape_fn([n, n], |(k, l)| {
+            binary_map
+                .get(&(main_groups[k].clone(), main_groups[l].clone()))
+                .cloned()
+                .unwrap_or_default()
+        });
+
+        Ok(Self {
+            component_index: Array1::from_vec(component_index),
+            identifiers,
+            counts: Array1::from_vec(counts),
+            rk: Array1::from_vec(rk),
+            qk: Array1::from_vec(qk),
+            a: interactions.map(|br| br.a),
+            b: interactions.map(|br| br.b),
+            c: interactions.map(|br| br.c),
+            r,
+            q,
+            chemical_records,
+            segment_records,
+            binary_segment_records,
+        })
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[ChemicalRecord],
+        &[SegmentRecord<UnifacRecord>],
+        &Option<Vec<BinaryRecord<String, UnifacBinaryRecord>>>,
+    ) {
+        (
+            &self.chemical_records,
+            &self.segment_records,
+            &self.binary_segment_records,
+        )
+    }
+}
+
+impl fmt::Display for UnifacParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UnifacParameters(")?;
+        write!(f, "\n\tcomponent_index={}", self.component_index)?;
+        write!(f, "\n\tidentifiers={:?}", self.identifiers)?;
+        write!(f, "\n\tcounts={}", self.counts)?;
+        write!(f, "\n\trk={}", self.rk)?;
+        write!(f, "\n\tqk={}", self.qk)?;
+        write!(f, "\n)")
+    }
+}
+
+/// Modified UNIFAC (Dortmund) group contribution model.
+///
+/// [Weidlich and Gmehling (1987)](https://doi.org/10.1021/ie00058a017)
+///
+/// The combinatorial part
+/// $$\frac{g^{E,C}}{RT}=\sum_ix_i\ln V_i'+5\sum_iq_ix_i\ln\frac{F_i}{V_i}$$
+/// with $V_i'=\frac{r_i^{3/4}}{\sum_jx_jr_j^{3/4}}$, $V_i=\frac{r_i}{\sum_jx_jr_j}$
+/// and $F_i=\frac{q_i}{\sum_jx_jq_j}$ is combined with the residual part
+/// $$\frac{g^{E,R}}{RT}=-\sum_kN_kQ_k\ln\left(\sum_m\Theta_m\Psi_{mk}\right)+\sum_ix_i\sum_k\nu_k^{(i)}Q_k\ln\left(\sum_m\Theta_m^{(i)}\Psi_{mk}\right)$$
+/// where $N_k=\sum_ix_i\nu_k^{(i)}$ and $\Theta_m=\frac{Q_mN_m}{\sum_nQ_nN_n}$.
+pub struct Unifac {
+    pub parameters: Arc<UnifacParameters>,
+}
+
+impl Unifac {
+    pub fn new(parameters: Arc<UnifacParameters>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl Components for Unifac {
+    fn components(&self) -> usize {
+        self.parameters.chemical_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(Arc::new(self.parameters.subset(component_list)))
+    }
+}
+
+impl ExcessGibbsEnergy for Unifac {
+    fn reduced_molar_excess_gibbs_energy<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        molefracs: &Array1<D>,
+    ) -> D {
+        let p = &self.parameters;
+        let n = molefracs.len();
+
+        // combinatorial part
+        let r34 = p.r.mapv(|r| r.powf(0.75));
+        let rx = (molefracs * &p.r).sum();
+        let qx = (molefracs * &p.q).sum();
+        let r34x = (molefracs * &r34).sum();
+        let mut g_c = D::zero();
+        for i in 0..n {
+            let ln_v_prime = (r34x.recip() * r34[i]).ln();
+            let ln_f_v = (rx / qx * (p.q[i] / p.r[i])).ln();
+            g_c += molefracs[i] * (ln_v_prime + ln_f_v * (5.0 * p.q[i]));
+        }
+
+        // residual part
+        let t_inv = temperature.recip();
+        let psi = Array2::from_shape_fn(p.a.raw_dim(), |kl| {
+            (-(t_inv * p.a[kl] + p.b[kl] + temperature * p.c[kl])).exp()
+        });
+        let n_groups = p.counts.len();
+        let mut g_r = D::zero();
+
+        // mixture
+        let qn: Array1<D> = (0..n_groups)
+            .map(|k| molefracs[p.component_index[k]] * p.counts[k] * p.qk[k])
+            .collect();
+        let qn_sum = qn.sum();
+        for k in 0..n_groups {
+            let s = (0..n_groups).fold(D::zero(), |acc, m| acc + qn[m] * psi[(m, k)]);
+            g_r -= qn[k] * (s / qn_sum).ln();
+        }
+
+        // pure components
+        for i in 0..n {
+            let groups: Vec<_> = (0..n_groups)
+                .filter(|&k| p.component_index[k] == i)
+                .collect();
+            let qn_i: f64 = groups.iter().map(|&k| p.counts[k] * p.qk[k]).sum();
+            let mut g_i = D::zero();
+            for &k in &groups {
+                let s = groups.iter().fold(D::zero(), |acc, &m| {
+                    acc + psi[(m, k)] * (p.counts[m] * p.qk[m] / qn_i)
+                });
+                g_i += s.ln() * (p.counts[k] * p.qk[k]);
+            }
+            g_r += molefracs[i] * g_i;
+        }
+
+        g_c + g_r
+    }
+}
+
+impl fmt::Display for Unifac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "modified UNIFAC (Dortmund)")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameter::Identifier;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    fn unifac() -> Unifac {
+        let segment_records = vec![
+            SegmentRecord::new(
+                "CH3".into(),
+                15.035,
+                UnifacRecord::new(0.6325, 1.0608, "CH2".into()),
+            ),
+            SegmentRecord::new(
+                "CH2".into(),
+                14.027,
+                UnifacRecord::new(0.6325, 0.7081, "CH2".into()),
+            ),
+            SegmentRecord::new(
+                "OH".into(),
+                17.007,
+                UnifacRecord::new(1.2302, 0.8927, "OH".into()),
+            ),
+            SegmentRecord::new(
+                "H2O".into(),
+                18.015,
+                UnifacRecord::new(1.7334, 2.4561, "H2O".into()),
+            ),
+        ];
+        let binary = |id1: &str, id2: &str, a, b, c| {
+            BinaryRecord::new(id1.into(), id2.into(), UnifacBinaryRecord::new(a, b, c))
+        };
+        let binary_records = vec![
+            binary("CH2", "OH", 2777.0, -4.674, 1.551e-3),
+            binary("OH", "CH2", 1606.0, -4.746, 9.181e-4),
+            binary("CH2", "H2O", 1391.3, -3.6156, 1.144e-3),
+            binary("H2O", "CH2", -17.253, 0.8389, 9.021e-4),
+            binary("OH", "H2O", -801.9, 3.824, -7.514e-3),
+            binary("H2O", "OH", 1460.0, -8.673, 1.641e-2),
+        ];
+        let ethanol = ChemicalRecord::new(
+            Identifier::new(None, Some("ethanol"), None, None, None, None),
+            vec!["CH3".into(), "CH2".into(), "OH".into()],
+            None,
+        );
+        let water = ChemicalRecord::new(
+            Identifier::new(None, Some("water"), None, None, None, None),
+            vec!["H2O".into()],
+            None,
+        );
+        let parameters = UnifacParameters::from_segments(
+            vec![ethanol, water],
+            segment_records,
+            Some(binary_records),
+        )
+        .unwrap();
+        Unifac::new(Arc::new(parameters))
+    }
+
+    /// Group activity coefficients $\ln\Gamma_k$ in a mixture of groups.
+    fn ln_group_activity(groups: &[(f64, f64, &str)], psi: impl Fn(&str, &str) -> f64) -> Vec<f64> {
+        let qn: f64 = groups.iter().map(|(n, q, _)| n * q).sum();
+        let theta: Vec<_> = groups.iter().map(|(n, q, _)| n * q / qn).collect();
+        groups
+            .iter()
+            .map(|&(_, q_k, g_k)| {
+                let s1: f64 = groups
+                    .iter()
+                    .zip(&theta)
+                    .map(|(&(_, _, g_m), t_m)| t_m * psi(g_m, g_k))
+                    .sum();
+                let s2: f64 = groups
+                    .iter()
+                    .zip(&theta)
+                    .map(|(&(_, _, g_m), t_m)| {
+                        let den: f64 = groups
+                            .iter()
+                            .zip(&theta)
+                            .map(|(&(_, _, g_n), t_n)| t_n * psi(g_n, g_m))
+                            .sum();
+                        t_m * psi(g_k, g_m) / den
+                    })
+                    .sum();
+                q_k * (1.0 - s1.ln() - s2)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn activity_coefficients() {
+        let unifac = unifac();
+        let t = 340.0;
+        let x = arr1(&[0.25, 0.75]);
+        let ln_gamma = unifac.ln_activity_coefficients(t, &x);
+
+        // combinatorial part
+        let r = [2.4952, 1.7334];
+        let q = [2.6616, 2.4561];
+        let r34x = x[0] * r[0].powf(0.75) + x[1] * r[1].powf(0.75);
+        let rx = x[0] * r[0] + x[1] * r[1];
+        let qx = x[0] * q[0] + x[1] * q[1];
+        let ln_gamma_c: Vec<_> = (0..2)
+            .map(|i| {
+                let vp = r[i].powf(0.75) / r34x;
+                let v = r[i] / rx;
+                let f = q[i] / qx;
+                1.0 - vp + vp.ln() - 5.0 * q[i] * (1.0 - v / f + (v / f).ln())
+            })
+            .collect();
+
+        // residual part
+        let psi = |g1: &str, g2: &str| {
+            let (a, b, c) = match (g1, g2) {
+                ("CH2", "OH") => (2777.0, -4.674, 1.551e-3),
+                ("OH", "CH2") => (1606.0, -4.746, 9.181e-4),
+                ("CH2", "H2O") => (1391.3, -3.6156, 1.144e-3),
+                ("H2O", "CH2") => (-17.253, 0.8389, 9.021e-4),
+                ("OH", "H2O") => (-801.9, 3.824, -7.514e-3),
+                ("H2O", "OH") => (1460.0, -8.673, 1.641e-2),
+                _ => (0.0, 0.0, 0.0),
+            };
+            f64::exp(-(a + b * t + c * t * t) / t)
+        };
+        let ethanol = [
+            (1.0, 1.0608, "CH2"),
+            (1.0, 0.7081, "CH2"),
+            (1.0, 0.8927, "OH"),
+        ];
+        let mixture = [
+            (x[0], 1.0608, "CH2"),
+            (x[0], 0.7081, "CH2"),
+            (x[0], 0.8927, "OH"),
+            (x[1], 2.4561, "H2O"),
+        ];
+        let ln_gamma_mix = ln_group_activity(&mixture, psi);
+        let ln_gamma_pure = ln_group_activity(&ethanol, psi);
+        let ln_gamma_r = [
+            (0..3)
+                .map(|k| ln_gamma_mix[k] - ln_gamma_pure[k])
+                .sum::<f64>(),
+            ln_gamma_mix[3],
+        ];
+
+        for i in 0..2 {
+            assert_relative_eq!(
+                ln_gamma[i],
+                ln_gamma_c[i] + ln_gamma_r[i],
+                max_relative = 1e-12
+            );
+        }
+    }
+
+    #[test]
+    fn subset() {
+        let unifac = unifac();
+        let water = unifac.subset(&[1]);
+        assert_eq!(water.components(), 1);
+        assert_eq!(water.parameters.identifiers, vec!["H2O".to_string()]);
+        let ln_gamma = unifac.ln_activity_coefficients(300.0, &arr1(&[1.0, 0.0]));
+        assert_relative_eq!(ln_gamma[0], 0.0, epsilon = 1e-14);
+    }
+}
@@ -0,0 +1,285 @@
+use crate::equation_of_state::{Components, ExcessGibbsEnergy};
+use crate::parameter::{
+    oriented_binary_matrix, BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord,
+};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+use std::sync::Arc;
+
+/// Coordination number of the UNIQUAC model.
+const Z: f64 = 10.0;
+
+/// Pure component parameters of the UNIQUAC model.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct UniquacRecord {
+    /// relative van der Waals volume
+    pub r: f64,
+    /// relative van der Waals surface area
+    pub q: f64,
+}
+
+impl UniquacRecord {
+    /// Create a new pure substance record for the UNIQUAC model.
+    pub fn new(r: f64, q: f64) -> Self {
+        Self { r, q }
+    }
+}
+
+impl fmt::Display for UniquacRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UniquacRecord(r={}, q={})", self.r, self.q)
+    }
+}
+
+/// Binary interaction parameters of the UNIQUAC model.
+///
+/// The parameters of a record for components 1 and 2 are stored as
+/// `[12, 21]`, so that
+/// $$\tau_{12}=\exp\left(a_{12}+\frac{b_{12}}{T}+c_{12}\ln T+d_{12}T\right)$$
+/// with $\[T\]=\text{K}$.
+#[derive(Serialize, Deserialize, Debug, Clone, Default, PartialEq)]
+pub struct UniquacBinaryRecord {
+    /// constant part of the interaction parameters
+    #[serde(default)]
+    pub a: [f64; 2],
+    /// interaction parameters in units of K
+    #[serde(default)]
+    pub b: [f64; 2],
+    /// logarithmic temperature dependence of the interaction parameters
+    #[serde(default)]
+    pub c: [f64; 2],
+    /// linear temperature dependence of the interaction parameters in units of 1/K
+    #[serde(default)]
+    pub d: [f64; 2],
+}
+
+impl UniquacBinaryRecord {
+    /// Create a new binary record with $\tau_{ij}=\exp\left(a_{ij}+\frac{b_{ij}}{T}\right)$.
+    pub fn new(a: [f64; 2], b: [f64; 2]) -> Self {
+        Self {
+            a,
+            b,
+            ..Default::default()
+        }
+    }
+
+    /// The same interactions for the components in reversed order.
+    fn reversed(&self) -> Self {
+        let rev = |[x12, x21]: [f64; 2]| [x21, x12];
+        Self {
+            a: rev(self.a),
+            b: rev(self.b),
+            c: rev(self.c),
+            d: rev(self.d),
+        }
+    }
+}
+
+impl fmt::Display for UniquacBinaryRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UniquacBinaryRecord(a={:?}, b={:?}", self.a, self.b)?;
+        write!(f, ", c={:?}, d={:?})", self.c, self.d)
+    }
+}
+
+/// Parameters of the UNIQUAC model for one or more substances.
+pub struct UniquacParameters {
+    pub r: Array1<f64>,
+    pub q: Array1<f64>,
+    pub a: Array2<f64>,
+    pub b: Array2<f64>,
+    pub c: Array2<f64>,
+    pub d: Array2<f64>,
+    pure_records: Vec<PureRecord<UniquacRecord>>,
+    binary_records: Array2<UniquacBinaryRecord>,
+}
+
+impl Parameter for UniquacParameters {
+    type Pure = UniquacRecord;
+    type Binary = UniquacBinaryRecord;
+
+    /// Creates parameters from records.
+    ///
+    /// The binary records above the diagonal determine the interactions;
+    /// the records below the diagonal are replaced by their reversed counterparts.
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        let n = pure_records.len();
+        let mut binary_records = binary_records.unwrap_or_else(|| Array2::default([n, n]));
+        for i in 0..n {
+            binary_records[(i, i)] = UniquacBinaryRecord::default();
+            for j in i + 1..n {
+                binary_records[(j, i)] = binary_records[(i, j)].reversed();
+            }
+        }
+
+        let r = pure_records.iter().map(|pr| pr.model_record.r).collect();
+        let q = pure_records.iter().map(|pr| pr.model_record.q).collect();
+        let component = |x: fn(&UniquacBinaryRecord) -> [f64; 2]| {
+            Array2::from_shape_fn([n, n], |(i, j)| x(&binary_records[(i, j)])[0])
+        };
+        let a = component(|br| br.a);
+        let b = component(|br| br.b);
+        let c = component(|br| br.c);
+        let d = component(|br| br.d);
+
+        Ok(Self {
+            r,
+            q,
+            a,
+            b,
+            c,
+            d,
+            pure_records,
+            binary_records,
+        })
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<UniquacRecord>],
+        Option<&Array2<UniquacBinaryRecord>>,
+    ) {
+        (&self.pure_records, Some(&self.binary_records))
+    }
+
+    /// Binary records are oriented: if a record is found with reversed
+    /// identifiers, the reversed interactions are used.
+    fn binary_matrix_from_records(
+        pure_records: &[PureRecord<Self::Pure>],
+        binary_records: &[BinaryRecord<Identifier, Self::Binary>],
+        identifier_option: IdentifierOption,
+    ) -> Option<Array2<Self::Binary>> {
+        oriented_binary_matrix(
+            pure_records,
+            binary_records,
+            identifier_option,
+            UniquacBinaryRecord::reversed,
+        )
+    }
+}
+
+impl fmt::Display for UniquacParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pure_records
+            .iter()
+            .try_for_each(|pr| writeln!(f, "{}", pr))?;
+        writeln!(f, "\na_ij:\n{}", self.a)?;
+        writeln!(f, "\nb_ij:\n{}", self.b)
+    }
+}
+
+/// Universal quasichemical (UNIQUAC) model.
+///
+/// [Abrams and Prausnitz (1975)](https://doi.org/10.1002/aic.690210115)
+/// $$\frac{g^E}{RT}=\sum_ix_i\ln\frac{\Phi_i}{x_i}+\frac{z}{2}\sum_iq_ix_i\ln\frac{\theta_i}{\Phi_i}-\sum_iq_ix_i\ln\left(\sum_j\theta_j\tau_{ji}\right)$$
+/// with $\Phi_i=\frac{r_ix_i}{\sum_jr_jx_j}$, $\theta_i=\frac{q_ix_i}{\sum_jq_jx_j}$ and $z=10$.
+pub struct Uniquac {
+    pub parameters: Arc<UniquacParameters>,
+}
+
+impl Uniquac {
+    pub fn new(parameters: Arc<UniquacParameters>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl Components for Uniquac {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(Arc::new(self.parameters.subset(component_list)))
+    }
+}
+
+impl ExcessGibbsEnergy for Uniquac {
+    fn reduced_molar_excess_gibbs_energy<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        molefracs: &Array1<D>,
+    ) -> D {
+        let p = &self.parameters;
+        let n = molefracs.len();
+        let ln_t = temperature.ln();
+        let tau = Array2::from_shape_fn([n, n], |ij| {
+            (temperature.recip() * p.b[ij] + ln_t * p.c[ij] + temperature * p.d[ij] + p.a[ij]).exp()
+        });
+
+        let rx = (molefracs * &p.r).sum();
+        let qx = (molefracs * &p.q).sum();
+        let mut res = D::zero();
+        for i in 0..n {
+            // ln(Phi_i/x_i) and ln(theta_i/Phi_i) without singularities for x_i=0
+            let ln_phi_x = (rx.recip() * p.r[i]).ln();
+            let ln_theta_phi = (rx / qx * (p.q[i] / p.r[i])).ln();
+            let theta_tau = (0..n).fold(D::zero(), |acc, j| {
+                acc + molefracs[j] * p.q[j] * tau[(j, i)]
+            }) / qx;
+            res += molefracs[i] * (ln_phi_x + ln_theta_phi * (0.5 * Z * p.q[i]))
+                - molefracs[i] * p.q[i] * theta_tau.ln();
+        }
+        res
+    }
+}
+
+impl fmt::Display for Uniquac {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "UNIQUAC")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    #[test]
+    fn activity_coefficients() {
+        // acetone/water
+        let records = vec![
+            PureRecord::new(
+                Identifier::default(),
+                58.08,
+                UniquacRecord::new(2.5735, 2.336),
+            ),
+            PureRecord::new(Identifier::default(), 18.015, UniquacRecord::new(0.92, 1.4)),
+        ];
+        let binary = UniquacBinaryRecord::new([0.0, 0.0], [-30.63, -195.3]);
+        let parameters = UniquacParameters::new_binary(records, Some(binary)).unwrap();
+        let uniquac = Uniquac::new(Arc::new(parameters));
+        let t = 330.0;
+        let x = arr1(&[0.4, 0.6]);
+        let ln_gamma = uniquac.ln_activity_coefficients(t, &x);
+
+        // analytic expressions for binary mixtures
+        let (r1, r2, q1, q2) = (2.5735, 0.92, 2.336, 1.4);
+        let (x1, x2) = (x[0], x[1]);
+        let tau12 = (-30.63 / t).exp();
+        let tau21 = (-195.3 / t).exp();
+        let phi1 = r1 * x1 / (r1 * x1 + r2 * x2);
+        let phi2 = 1.0 - phi1;
+        let theta1 = q1 * x1 / (q1 * x1 + q2 * x2);
+        let theta2 = 1.0 - theta1;
+        let l1 = 0.5 * Z * (r1 - q1) - (r1 - 1.0);
+        let l2 = 0.5 * Z * (r2 - q2) - (r2 - 1.0);
+        let ln_gamma1 = (phi1 / x1).ln()
+            + 0.5 * Z * q1 * (theta1 / phi1).ln()
+            + phi2 * (l1 - r1 / r2 * l2)
+            - q1 * (theta1 + theta2 * tau21).ln()
+            + theta2 * q1 * (tau21 / (theta1 + theta2 * tau21) - tau12 / (theta2 + theta1 * tau12));
+        assert_relative_eq!(ln_gamma[0], ln_gamma1, max_relative = 1e-12);
+
+        // pure components
+        let ln_gamma = uniquac.ln_activity_coefficients(t, &arr1(&[0.0, 1.0]));
+        assert_relative_eq!(ln_gamma[1], 0.0, epsilon = 1e-14);
+    }
+}
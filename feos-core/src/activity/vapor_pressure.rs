@@ -0,0 +1,122 @@
+use crate::errors::EosResult;
+use crate::parameter::{NoBinaryModelRecord, Parameter, ParameterError, PureRecord};
+use crate::phase_equilibria::VaporPressureModel;
+use crate::si::{Pressure, Temperature, KELVIN, PASCAL};
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Empirical correlations for pure component vapor pressures.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub enum VaporPressureRecord {
+    /// Antoine equation in the form used by the NIST Chemistry WebBook
+    /// $$\log_{10}\frac{p^\mathrm{sat}}{\text{bar}}=A-\frac{B}{T/\text{K}+C}$$
+    Antoine([f64; 3]),
+    /// DIPPR eq. # 101
+    /// $$\ln\frac{p^\mathrm{sat}}{\text{Pa}}=A+\frac{B}{T}+C\ln T+DT^E$$
+    /// with $\[T\]=\text{K}$.
+    DIPPR101([f64; 5]),
+}
+
+impl VaporPressureRecord {
+    /// Create parameters for the Antoine equation.
+    pub fn antoine(a: f64, b: f64, c: f64) -> Self {
+        Self::Antoine([a, b, c])
+    }
+
+    /// Create parameters for DIPPR eq. # 101.
+    pub fn dippr101(a: f64, b: f64, c: f64, d: f64, e: f64) -> Self {
+        Self::DIPPR101([a, b, c, d, e])
+    }
+
+    /// Vapor pressure in Pa at the temperature in K.
+    fn vapor_pressure(&self, t: f64) -> f64 {
+        match *self {
+            Self::Antoine([a, b, c]) => 1e5 * 10f64.powf(a - b / (t + c)),
+            Self::DIPPR101([a, b, c, d, e]) => (a + b / t + c * t.ln() + d * t.powf(e)).exp(),
+        }
+    }
+}
+
+impl fmt::Display for VaporPressureRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Antoine([a, b, c]) => {
+                write!(f, "VaporPressureRecord(Antoine, a={a}, b={b}, c={c})")
+            }
+            Self::DIPPR101([a, b, c, d, e]) => write!(
+                f,
+                "VaporPressureRecord(EQ101, a={a}, b={b}, c={c}, d={d}, e={e})"
+            ),
+        }
+    }
+}
+
+/// Vapor pressures of one or more substances from empirical correlations.
+pub struct VaporPressureCorrelation(Vec<PureRecord<VaporPressureRecord>>);
+
+impl Parameter for VaporPressureCorrelation {
+    type Pure = VaporPressureRecord;
+    type Binary = NoBinaryModelRecord;
+
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        _binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        Ok(Self(pure_records))
+    }
+
+    fn records(&self) -> (&[PureRecord<Self::Pure>], Option<&Array2<Self::Binary>>) {
+        (&self.0, None)
+    }
+}
+
+impl VaporPressureModel for VaporPressureCorrelation {
+    fn vapor_pressures(&self, temperature: Temperature) -> EosResult<Pressure<Array1<f64>>> {
+        let t = temperature.convert_into(KELVIN);
+        let p_sat: Array1<f64> = self
+            .0
+            .iter()
+            .map(|r| r.model_record.vapor_pressure(t))
+            .collect();
+        Ok(p_sat * PASCAL)
+    }
+}
+
+impl fmt::Display for VaporPressureCorrelation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.0.iter().try_for_each(|pr| writeln!(f, "{}", pr))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameter::Identifier;
+    use crate::si::BAR;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn vapor_pressures() -> EosResult<()> {
+        // water from the NIST Chemistry WebBook (Bridgeman and Aldrich) and DIPPR
+        let records = vec![
+            PureRecord::new(
+                Identifier::default(),
+                18.015,
+                VaporPressureRecord::antoine(5.0768, 1659.793, -45.854),
+            ),
+            PureRecord::new(
+                Identifier::default(),
+                18.015,
+                VaporPressureRecord::dippr101(73.649, -7258.2, -7.3037, 4.1653e-6, 2.0),
+            ),
+        ];
+        let correlation = VaporPressureCorrelation::from_records(records, None).unwrap();
+        let p_sat = correlation.vapor_pressures(373.15 * KELVIN)?;
+        assert_relative_eq!(p_sat.get(0), 1.01325 * BAR, max_relative = 1e-2);
+        assert_relative_eq!(p_sat.get(1), 1.01325 * BAR, max_relative = 1e-2);
+        let p_sat = correlation.subset(&[1]).vapor_pressures(373.15 * KELVIN)?;
+        assert_eq!(p_sat.len(), 1);
+        Ok(())
+    }
+}
@@ -0,0 +1,54 @@
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Temperature dependence of the attractive parameter of a cubic
+/// equation of state.
+///
+/// If no alpha function is specified for a component, the Soave
+/// alpha function with the generalized correlation of the
+/// respective cubic model for $m(\omega)$ is used.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum AlphaFunction {
+    /// Soave (1972): $\alpha=\left(1+m\left(1-\sqrt{T_r}\right)\right)^2$
+    Soave { m: f64 },
+    /// Twu et al. (1991): $\alpha=T_r^{N(M-1)}\exp\left(L\left(1-T_r^{NM}\right)\right)$
+    Twu91 { l: f64, m: f64, n: f64 },
+    /// Mathias and Copeman (1983):
+    /// $\alpha=\left(1+c_1\left(1-\sqrt{T_r}\right)+c_2\left(1-\sqrt{T_r}\right)^2+c_3\left(1-\sqrt{T_r}\right)^3\right)^2$
+    ///
+    /// Above the critical temperature, only the first coefficient is used.
+    MathiasCopeman { c1: f64, c2: f64, c3: f64 },
+}
+
+impl AlphaFunction {
+    /// Evaluate the alpha function at the reduced temperature $T_r=\frac{T}{T_c}$.
+    pub fn alpha<D: DualNum<f64> + Copy>(&self, reduced_temperature: D) -> D {
+        let tr = reduced_temperature;
+        match *self {
+            Self::Soave { m } => ((-tr.sqrt() + 1.0) * m + 1.0).powi(2),
+            Self::Twu91 { l, m, n } => tr.powf(n * (m - 1.0)) * ((-tr.powf(n * m) + 1.0) * l).exp(),
+            Self::MathiasCopeman { c1, c2, c3 } => {
+                let s = -tr.sqrt() + 1.0;
+                if tr.re() < 1.0 {
+                    (s * c1 + s.powi(2) * c2 + s.powi(3) * c3 + 1.0).powi(2)
+                } else {
+                    (s * c1 + 1.0).powi(2)
+                }
+            }
+        }
+    }
+}
+
+impl fmt::Display for AlphaFunction {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Soave { m } => write!(f, "Soave(m={m})"),
+            Self::Twu91 { l, m, n } => write!(f, "Twu91(l={l}, m={m}, n={n})"),
+            Self::MathiasCopeman { c1, c2, c3 } => {
+                write!(f, "MathiasCopeman(c1={c1}, c2={c2}, c3={c3})")
+            }
+        }
+    }
+}
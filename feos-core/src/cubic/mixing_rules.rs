@@ -0,0 +1,459 @@
+use super::{Cubic, CubicModel, CubicOptions, CubicParameters};
+use crate::equation_of_state::{Components, ExcessGibbsEnergy, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::si::MolarWeight;
+use crate::state::StateHD;
+use ndarray::{Array1, ScalarOperand};
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// Range of the reduced attraction parameter used to fit
+/// the MHV parameters of generic cubic equations of state.
+const ALPHA_FIT: (f64, f64) = (10.0, 13.0);
+const N_FIT: usize = 31;
+
+/// Mixing rules that combine a cubic equation of state with a
+/// model for the excess Gibbs energy.
+///
+/// In the following, $\alpha=\frac{a}{bRT}$ denotes the reduced attraction
+/// parameter and $g^E$ the molar excess Gibbs energy of the [ExcessGibbsEnergy] model.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum MixingRule {
+    /// Huron and Vidal (1979): $\alpha=\sum_ix_i\alpha_i-\frac{g^E}{\Lambda RT}$
+    /// with $\Lambda=\frac{1}{\delta_1-\delta_2}\ln\frac{1+\delta_1}{1+\delta_2}$
+    /// and $b=\sum_ix_ib_i$.
+    HuronVidal,
+    /// Michelsen (1990): $q_1\left(\alpha-\sum_ix_i\alpha_i\right)=\frac{g^E}{RT}+\sum_ix_i\ln\frac{b}{b_i}$
+    /// and $b=\sum_ix_ib_i$.
+    ModifiedHuronVidal1,
+    /// Dahl and Michelsen (1990):
+    /// $q_1\left(\alpha-\sum_ix_i\alpha_i\right)+q_2\left(\alpha^2-\sum_ix_i\alpha_i^2\right)=\frac{g^E}{RT}+\sum_ix_i\ln\frac{b}{b_i}$
+    /// and $b=\sum_ix_ib_i$.
+    ModifiedHuronVidal2,
+    /// Wong and Sandler (1992): $b=\frac{Q}{1-D}$ and $\alpha=D$ with
+    /// $Q=\sum_i\sum_jx_ix_j\frac{\left(b_i-\frac{a_i}{RT}\right)+\left(b_j-\frac{a_j}{RT}\right)}{2}\left(1-k_{ij}\right)$
+    /// and $D=\sum_ix_i\alpha_i-\frac{g^E}{\Lambda RT}$.
+    ///
+    /// The binary interaction parameters $k_{ij}$ of the [CubicParameters]
+    /// are used in the combining rule for the second virial coefficient.
+    WongSandler,
+}
+
+impl fmt::Display for MixingRule {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::HuronVidal => write!(f, "Huron-Vidal"),
+            Self::ModifiedHuronVidal1 => write!(f, "MHV1"),
+            Self::ModifiedHuronVidal2 => write!(f, "MHV2"),
+            Self::WongSandler => write!(f, "Wong-Sandler"),
+        }
+    }
+}
+
+impl CubicModel {
+    /// The constant $\Lambda$ of the infinite pressure limit
+    /// used in the Huron-Vidal and Wong-Sandler mixing rules.
+    pub fn huron_vidal_parameter(&self) -> f64 {
+        let (delta1, delta2) = self.delta();
+        if (delta1 - delta2).abs() < f64::EPSILON {
+            1.0 / (1.0 + delta1)
+        } else {
+            ((1.0 + delta1) / (1.0 + delta2)).ln() / (delta1 - delta2)
+        }
+    }
+
+    /// The parameter $q_1$ of the MHV1 mixing rule.
+    ///
+    /// For generic cubic equations of state, $q_1$ is obtained from a
+    /// linear fit of the exact zero-pressure function $q(\alpha)$ for $10\leq\alpha\leq13$.
+    pub fn mhv1_parameter(&self) -> f64 {
+        match self {
+            Self::PengRobinson | Self::PengRobinson78 => -0.53,
+            Self::SoaveRedlichKwong => -0.593,
+            Self::Generic { .. } => self.fit_zero_pressure_function().0,
+        }
+    }
+
+    /// The parameters $q_1$ and $q_2$ of the MHV2 mixing rule.
+    ///
+    /// For generic cubic equations of state, $q_1$ and $q_2$ are obtained from a
+    /// quadratic fit of the exact zero-pressure function $q(\alpha)$ for $10\leq\alpha\leq13$.
+    pub fn mhv2_parameters(&self) -> (f64, f64) {
+        match self {
+            Self::PengRobinson | Self::PengRobinson78 => (-0.4347, -0.003654),
+            Self::SoaveRedlichKwong => (-0.478, -0.0047),
+            Self::Generic { .. } => {
+                let (_, q1, q2) = self.fit_zero_pressure_function();
+                (q1, q2)
+            }
+        }
+    }
+
+    /// The zero-pressure function $q(\alpha)=\frac{g^\mathrm{res}}{RT}-\ln\frac{bp}{RT}$
+    /// evaluated at the liquid root of the equation of state at $p=0$.
+    fn zero_pressure_function(&self, alpha: f64) -> f64 {
+        let (delta1, delta2) = self.delta();
+        let (s, w) = (delta1 + delta2, delta1 * delta2);
+        let u = 0.5 * (alpha - s - ((alpha - s).powi(2) - 4.0 * (w + alpha)).sqrt());
+        let attraction = if (delta1 - delta2).abs() < f64::EPSILON {
+            1.0 / (u + delta1)
+        } else {
+            ((u + delta1) / (u + delta2)).ln() / (delta1 - delta2)
+        };
+        -1.0 - (u - 1.0).ln() - alpha * attraction
+    }
+
+    /// Least-squares fits of $q(\alpha)$. Returns the slope of the linear fit and
+    /// the linear and quadratic coefficients of the quadratic fit.
+    fn fit_zero_pressure_function(&self) -> (f64, f64, f64) {
+        let (alpha_min, alpha_max) = ALPHA_FIT;
+        let alpha_mean = 0.5 * (alpha_min + alpha_max);
+        let t = Array1::linspace(alpha_min - alpha_mean, alpha_max - alpha_mean, N_FIT);
+        let q = t.mapv(|t| self.zero_pressure_function(t + alpha_mean));
+
+        // Symmetric sampling points decouple the odd and even powers in the normal equations.
+        let n = N_FIT as f64;
+        let t2 = t.mapv(|t| t * t).sum();
+        let t4 = t.mapv(|t| t.powi(4)).sum();
+        let c1 = (&t * &q).sum() / t2;
+        let c2 = (n * (&t * &t * &q).sum() - t2 * q.sum()) / (n * t4 - t2 * t2);
+        (c1, c1 - 2.0 * c2 * alpha_mean, c2)
+    }
+}
+
+/// A cubic equation of state with a mixing rule based on an excess Gibbs energy model.
+pub struct CubicExcessGibbs<G> {
+    cubic: Cubic,
+    excess_gibbs: Arc<G>,
+    mixing_rule: MixingRule,
+    huron_vidal_parameter: f64,
+    mhv_parameters: (f64, f64),
+}
+
+impl<G: ExcessGibbsEnergy> CubicExcessGibbs<G> {
+    /// Create a new Peng-Robinson equation of state with the given mixing rule.
+    pub fn new(
+        parameters: Arc<CubicParameters>,
+        excess_gibbs: Arc<G>,
+        mixing_rule: MixingRule,
+    ) -> EosResult<Self> {
+        Self::with_options(
+            parameters,
+            excess_gibbs,
+            mixing_rule,
+            CubicOptions::default(),
+        )
+    }
+
+    /// Create a new cubic equation of state with the given mixing rule and options.
+    pub fn with_options(
+        parameters: Arc<CubicParameters>,
+        excess_gibbs: Arc<G>,
+        mixing_rule: MixingRule,
+        options: CubicOptions,
+    ) -> EosResult<Self> {
+        let cubic = Cubic::with_options(parameters, options);
+        if cubic.components() != excess_gibbs.components() {
+            return Err(EosError::IncompatibleComponents(
+                cubic.components(),
+                excess_gibbs.components(),
+            ));
+        }
+        let model = options.model;
+        let mhv_parameters = match mixing_rule {
+            MixingRule::ModifiedHuronVidal1 => (model.mhv1_parameter(), 0.0),
+            MixingRule::ModifiedHuronVidal2 => model.mhv2_parameters(),
+            _ => (0.0, 0.0),
+        };
+        Ok(Self {
+            cubic,
+            excess_gibbs,
+            mixing_rule,
+            huron_vidal_parameter: model.huron_vidal_parameter(),
+            mhv_parameters,
+        })
+    }
+
+    /// The mixing rule.
+    pub fn mixing_rule(&self) -> MixingRule {
+        self.mixing_rule
+    }
+
+    /// The mixture parameters $a$ and $b$ for given temperature and composition.
+    fn mixture_parameters<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        molefracs: &Array1<D>,
+    ) -> (D, D) {
+        let x = molefracs;
+        let b_i = &self.cubic.b;
+        let alpha_i: Vec<_> = self
+            .cubic
+            .attraction(temperature)
+            .into_iter()
+            .zip(b_i.iter())
+            .map(|(a, &b)| a / (temperature * b))
+            .collect();
+        let g_e = self
+            .excess_gibbs
+            .reduced_molar_excess_gibbs_energy(temperature, x);
+
+        let mut alpha_lin = D::zero();
+        for i in 0..alpha_i.len() {
+            alpha_lin += alpha_i[i] * x[i];
+        }
+        let b_lin = (x * b_i).sum();
+        let mut ln_b = D::zero();
+        for i in 0..alpha_i.len() {
+            ln_b += x[i] * (b_lin / b_i[i]).ln();
+        }
+
+        let (alpha, b) = match self.mixing_rule {
+            MixingRule::HuronVidal => (alpha_lin - g_e / self.huron_vidal_parameter, b_lin),
+            MixingRule::ModifiedHuronVidal1 => {
+                let (q1, _) = self.mhv_parameters;
+                (alpha_lin + (g_e + ln_b) / q1, b_lin)
+            }
+            MixingRule::ModifiedHuronVidal2 => {
+                let (q1, q2) = self.mhv_parameters;
+                let mut alpha2_lin = D::zero();
+                for i in 0..alpha_i.len() {
+                    alpha2_lin += alpha_i[i].powi(2) * x[i];
+                }
+                let c = alpha_lin * q1 + alpha2_lin * q2 + g_e + ln_b;
+                let alpha = -((c * (4.0 * q2) + q1 * q1).sqrt() + q1) / (2.0 * q2);
+                (alpha, b_lin)
+            }
+            MixingRule::WongSandler => {
+                let k_ij = &self.cubic.parameters.k_ij;
+                let mut q = D::zero();
+                for i in 0..alpha_i.len() {
+                    for j in 0..alpha_i.len() {
+                        let b_a_i = (-alpha_i[i] + 1.0) * b_i[i];
+                        let b_a_j = (-alpha_i[j] + 1.0) * b_i[j];
+                        q += (b_a_i + b_a_j) * x[i] * x[j] * (0.5 * (1.0 - k_ij[(i, j)]));
+                    }
+                }
+                let d = alpha_lin - g_e / self.huron_vidal_parameter;
+                (d, q / (-d + 1.0))
+            }
+        };
+        (alpha * b * temperature, b)
+    }
+}
+
+impl<G: fmt::Display> fmt::Display for CubicExcessGibbs<G> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{} ({}, {})",
+            self.cubic, self.mixing_rule, self.excess_gibbs
+        )
+    }
+}
+
+impl<G: ExcessGibbsEnergy> Components for CubicExcessGibbs<G> {
+    fn components(&self) -> usize {
+        self.cubic.components()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self {
+            cubic: self.cubic.subset(component_list),
+            excess_gibbs: Arc::new(self.excess_gibbs.subset(component_list)),
+            mixing_rule: self.mixing_rule,
+            huron_vidal_parameter: self.huron_vidal_parameter,
+            mhv_parameters: self.mhv_parameters,
+        }
+    }
+}
+
+impl<G: ExcessGibbsEnergy> Residual for CubicExcessGibbs<G> {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.cubic.compute_max_density(moles)
+    }
+
+    fn residual_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        let (a, b) = self.mixture_parameters(state.temperature, &state.molefracs);
+        self.cubic.helmholtz_energy(state, a, b)
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        vec![(
+            format!("{} ({})", self.cubic.model(), self.mixing_rule),
+            self.residual_helmholtz_energy(state),
+        )]
+    }
+
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.cubic.molar_weight()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activity::margules::{propane_butane as parameters, Margules};
+    use crate::si::{KELVIN, METER, MOL};
+    use crate::state::{Contributions, State};
+    use crate::{EosResult, PhaseEquilibrium, SolverOptions};
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, arr2};
+    use typenum::P3;
+
+    const MIXING_RULES: [MixingRule; 4] = [
+        MixingRule::HuronVidal,
+        MixingRule::ModifiedHuronVidal1,
+        MixingRule::ModifiedHuronVidal2,
+        MixingRule::WongSandler,
+    ];
+
+    #[test]
+    fn activity_coefficients() {
+        let margules = Margules(arr2(&[[0.0, 0.8], [0.8, 0.0]]));
+        let x = arr1(&[0.3, 0.7]);
+        let ln_gamma = margules.ln_activity_coefficients(300.0, &x);
+        assert_relative_eq!(ln_gamma[0], 0.8 * x[1] * x[1], max_relative = 1e-14);
+        assert_relative_eq!(ln_gamma[1], 0.8 * x[0] * x[0], max_relative = 1e-14);
+    }
+
+    #[test]
+    fn mhv_parameters() {
+        let (delta1, delta2) = CubicModel::PengRobinson.delta();
+        let generic = CubicModel::Generic { delta1, delta2 };
+        assert_relative_eq!(
+            generic.huron_vidal_parameter(),
+            0.62323,
+            max_relative = 1e-4
+        );
+        assert_relative_eq!(
+            generic.mhv1_parameter(),
+            CubicModel::PengRobinson.mhv1_parameter(),
+            max_relative = 0.05
+        );
+
+        let generic = CubicModel::Generic {
+            delta1: 1.0,
+            delta2: 0.0,
+        };
+        let (q1, q2) = generic.mhv2_parameters();
+        let (q1_srk, q2_srk) = CubicModel::SoaveRedlichKwong.mhv2_parameters();
+        assert_relative_eq!(q1, q1_srk, max_relative = 0.05);
+        assert_relative_eq!(q2, q2_srk, max_relative = 0.2);
+    }
+
+    #[test]
+    fn pure_components() -> EosResult<()> {
+        // For pure components, all mixing rules reduce to the cubic equation of state.
+        let cubic = Arc::new(Cubic::new(parameters()));
+        let margules = Arc::new(Margules(arr2(&[[0.0, 0.8], [0.8, 0.0]])));
+        let moles = arr1(&[1.0, 0.0]) * MOL;
+        let t = 300.0 * KELVIN;
+        let density = 10000.0 * MOL / METER.powi::<P3>();
+        let p = State::new_nvt(&cubic, t, moles.sum() / density, &moles)?
+            .pressure(Contributions::Total);
+        for mixing_rule in MIXING_RULES {
+            let eos = Arc::new(CubicExcessGibbs::new(
+                parameters(),
+                margules.clone(),
+                mixing_rule,
+            )?);
+            let state = State::new_nvt(&eos, t, moles.sum() / density, &moles)?;
+            assert_relative_eq!(
+                state.pressure(Contributions::Total),
+                p,
+                max_relative = 1e-12
+            );
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn incompatible_components() {
+        let margules = Arc::new(Margules(arr2(&[[0.0]])));
+        let eos = CubicExcessGibbs::new(parameters(), margules, MixingRule::HuronVidal);
+        assert!(matches!(eos, Err(EosError::IncompatibleComponents(2, 1))));
+    }
+
+    #[test]
+    fn mixture_parameters() -> EosResult<()> {
+        let margules = Arc::new(Margules(arr2(&[[0.0, 0.8], [0.8, 0.0]])));
+        let x = arr1(&[0.3, 0.7]);
+        let t = 300.0;
+        let g_e = margules.reduced_molar_excess_gibbs_energy(t, &x);
+        for mixing_rule in MIXING_RULES {
+            let eos = CubicExcessGibbs::new(parameters(), margules.clone(), mixing_rule)?;
+            let (a, b) = eos.mixture_parameters(t, &x);
+            let b_i = &eos.cubic.b;
+            let alpha_i: Vec<_> = eos
+                .cubic
+                .attraction(t)
+                .iter()
+                .zip(b_i)
+                .map(|(a, b)| a / (b * t))
+                .collect();
+            let alpha = a / (b * t);
+            let alpha_lin = x[0] * alpha_i[0] + x[1] * alpha_i[1];
+            let b_lin = (&x * b_i).sum();
+            let ln_b = x[0] * (b_lin / b_i[0]).ln() + x[1] * (b_lin / b_i[1]).ln();
+            match mixing_rule {
+                MixingRule::HuronVidal => {
+                    assert_relative_eq!(
+                        -(alpha - alpha_lin) * eos.huron_vidal_parameter,
+                        g_e,
+                        max_relative = 1e-12
+                    )
+                }
+                MixingRule::ModifiedHuronVidal1 => {
+                    let (q1, _) = eos.mhv_parameters;
+                    assert_relative_eq!(q1 * (alpha - alpha_lin), g_e + ln_b, max_relative = 1e-12)
+                }
+                MixingRule::ModifiedHuronVidal2 => {
+                    let (q1, q2) = eos.mhv_parameters;
+                    let alpha2_lin = x[0] * alpha_i[0].powi(2) + x[1] * alpha_i[1].powi(2);
+                    assert_relative_eq!(
+                        q1 * (alpha - alpha_lin) + q2 * (alpha * alpha - alpha2_lin),
+                        g_e + ln_b,
+                        max_relative = 1e-12
+                    )
+                }
+                MixingRule::WongSandler => {
+                    // quadratic mixing rule for the second virial coefficient b - a/RT
+                    let b2_i: Vec<_> = (0..2).map(|i| b_i[i] * (1.0 - alpha_i[i])).collect();
+                    let b2 = x[0] * x[0] * b2_i[0]
+                        + x[1] * x[1] * b2_i[1]
+                        + x[0] * x[1] * (b2_i[0] + b2_i[1]);
+                    assert_relative_eq!(b - a / t, b2, max_relative = 1e-12)
+                }
+            }
+        }
+        Ok(())
+    }
+
+    #[test]
+    fn bubble_point() -> EosResult<()> {
+        let margules = Arc::new(Margules(arr2(&[[0.0, 0.3], [0.3, 0.0]])));
+        let cubic = Arc::new(Cubic::new(parameters()));
+        let t = 300.0 * KELVIN;
+        let x = arr1(&[0.5, 0.5]);
+        let options = (SolverOptions::default(), SolverOptions::default());
+        let vle_vdw = PhaseEquilibrium::bubble_point(&cubic, t, &x, None, None, options)?;
+        let p_vdw = vle_vdw.vapor().pressure(Contributions::Total);
+        for mixing_rule in MIXING_RULES {
+            let eos = Arc::new(CubicExcessGibbs::new(
+                parameters(),
+                margules.clone(),
+                mixing_rule,
+            )?);
+            let vle = PhaseEquilibrium::bubble_point(&eos, t, &x, None, None, options)?;
+            // positive deviations from Raoult's law increase the bubble point pressure
+            assert!(vle.vapor().pressure(Contributions::Total) > p_vdw);
+        }
+        Ok(())
+    }
+}
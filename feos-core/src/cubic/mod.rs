@@ -0,0 +1,500 @@
+//! Implementation of cubic equations of state.
+//!
+//! This module acts as a reference on how a simple equation
+//! of state - with a single contribution to the Helmholtz energy - can be implemented.
+//! The implementation closely follows the form of the equations given in
+//! [this wikipedia article](https://en.wikipedia.org/wiki/Cubic_equations_of_state#Peng%E2%80%93Robinson_equation_of_state).
+//!
+//! All cubic equations of state are written in the generic form
+//! $$p=\frac{RT}{v-b}-\frac{a\alpha(T)}{(v+\delta_1b)(v+\delta_2b)}$$
+//! that covers the Peng-Robinson (1976 and 1978), the Soave-Redlich-Kwong
+//! and arbitrary two-parameter cubic equations of state. The temperature
+//! dependence of the attractive parameter is given by an [AlphaFunction]
+//! and the molar volume can be corrected with a [VolumeTranslation].
+use crate::equation_of_state::{Components, Residual};
+use crate::parameter::Parameter;
+use crate::si::{MolarWeight, GRAM, MOL};
+use crate::state::StateHD;
+use ndarray::{Array1, ScalarOperand};
+use num_dual::DualNum;
+use std::f64::consts::SQRT_2;
+use std::fmt;
+use std::sync::Arc;
+
+mod alpha;
+mod mixing_rules;
+mod parameters;
+pub use alpha::AlphaFunction;
+pub use mixing_rules::{CubicExcessGibbs, MixingRule};
+pub use parameters::{CubicParameters, CubicRecord, VolumeTranslation};
+
+const KB_A3: f64 = 13806490.0;
+const MAX_ITER_OMEGA: usize = 50;
+const TOL_OMEGA: f64 = 1e-14;
+
+/// Peng-Robinson parameters for a single substance.
+pub type PengRobinsonRecord = CubicRecord;
+/// Peng-Robinson parameters for one ore more substances.
+pub type PengRobinsonParameters = CubicParameters;
+/// The Peng-Robinson equation of state.
+///
+/// [Cubic::new] uses the Peng-Robinson (1976) model by default.
+pub type PengRobinson = Cubic;
+
+/// The specific cubic equation of state.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum CubicModel {
+    /// Peng-Robinson (1976)
+    PengRobinson,
+    /// Peng-Robinson (1978) with the modified correlation for $m(\omega)$
+    /// for heavy components ($\omega>0.491$).
+    PengRobinson78,
+    /// Soave-Redlich-Kwong (1972)
+    SoaveRedlichKwong,
+    /// Generic cubic equation of state with arbitrary $\delta_1$ and $\delta_2$.
+    ///
+    /// The universal constants $\Omega_a$ and $\Omega_b$ are determined
+    /// from the critical conditions. The generalized Soave alpha function
+    /// uses the correlation of the Peng-Robinson equation of state.
+    Generic { delta1: f64, delta2: f64 },
+}
+
+impl CubicModel {
+    /// The parameters $\delta_1$ and $\delta_2$ of the generic cubic equation of state.
+    pub fn delta(&self) -> (f64, f64) {
+        match *self {
+            Self::PengRobinson | Self::PengRobinson78 => (1.0 + SQRT_2, 1.0 - SQRT_2),
+            Self::SoaveRedlichKwong => (1.0, 0.0),
+            Self::Generic { delta1, delta2 } => (delta1, delta2),
+        }
+    }
+
+    /// The universal constants $\Omega_a$ and $\Omega_b$.
+    pub fn omega(&self) -> (f64, f64) {
+        match self {
+            Self::PengRobinson | Self::PengRobinson78 => (0.45724, 0.07780),
+            Self::SoaveRedlichKwong => (0.42748, 0.08664),
+            Self::Generic { .. } => {
+                let (delta1, delta2) = self.delta();
+                let u = delta1 + delta2;
+                let w = delta1 * delta2;
+
+                // At the critical point, the cubic equation in Z has a triple root,
+                // which results in a single equation for Omega_b.
+                let dzc = (1.0 - u) / 3.0;
+                let mut b = 0.1;
+                for _ in 0..MAX_ITER_OMEGA {
+                    let zc = (1.0 + b - u * b) / 3.0;
+                    let f = zc.powi(3) - 3.0 * zc * zc * b - (u + w) * b * b - u * b.powi(3);
+                    let df = 3.0 * zc * zc * dzc
+                        - 6.0 * zc * dzc * b
+                        - 3.0 * zc * zc
+                        - 2.0 * (u + w) * b
+                        - 3.0 * u * b * b;
+                    let delta = f / df;
+                    b -= delta;
+                    if delta.abs() < TOL_OMEGA {
+                        break;
+                    }
+                }
+                let zc = (1.0 + b - u * b) / 3.0;
+                (3.0 * zc * zc - w * b * b + u * b + u * b * b, b)
+            }
+        }
+    }
+
+    /// The generalized correlation for the parameter $m$ of the Soave alpha function.
+    pub fn soave_m(&self, acentric_factor: f64) -> f64 {
+        let w = acentric_factor;
+        match self {
+            Self::SoaveRedlichKwong => 0.480 + (1.574 - 0.176 * w) * w,
+            Self::PengRobinson78 if w > 0.491 => {
+                0.379642 + (1.48503 + (-0.164423 + 0.016666 * w) * w) * w
+            }
+            _ => 0.37464 + (1.54226 - 0.26992 * w) * w,
+        }
+    }
+}
+
+impl fmt::Display for CubicModel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::PengRobinson => write!(f, "Peng Robinson"),
+            Self::PengRobinson78 => write!(f, "Peng Robinson (1978)"),
+            Self::SoaveRedlichKwong => write!(f, "Soave Redlich Kwong"),
+            Self::Generic { delta1, delta2 } => {
+                write!(f, "Cubic (delta1={delta1}, delta2={delta2})")
+            }
+        }
+    }
+}
+
+/// Customization options for cubic equations of state.
+#[derive(Clone, Copy)]
+pub struct CubicOptions {
+    pub model: CubicModel,
+}
+
+impl Default for CubicOptions {
+    fn default() -> Self {
+        Self {
+            model: CubicModel::PengRobinson,
+        }
+    }
+}
+
+/// A cubic equation of state.
+pub struct Cubic {
+    /// Parameters
+    parameters: Arc<CubicParameters>,
+    options: CubicOptions,
+    delta: (f64, f64),
+    a: Array1<f64>,
+    b: Array1<f64>,
+    alpha: Vec<AlphaFunction>,
+}
+
+impl Cubic {
+    /// Create a new Peng-Robinson equation of state from a set of parameters.
+    pub fn new(parameters: Arc<CubicParameters>) -> Self {
+        Self::with_options(parameters, CubicOptions::default())
+    }
+
+    /// Create a new cubic equation of state with the given options.
+    pub fn with_options(parameters: Arc<CubicParameters>, options: CubicOptions) -> Self {
+        let model = options.model;
+        let (omega_a, omega_b) = model.omega();
+        let p = &parameters;
+        let a = (&p.tc * &p.tc / &p.pc) * (omega_a * KB_A3);
+        let b = (&p.tc / &p.pc) * (omega_b * KB_A3);
+        let alpha = p
+            .alpha
+            .iter()
+            .zip(p.acentric_factor.iter())
+            .map(|(&alpha, &w)| {
+                alpha.unwrap_or(AlphaFunction::Soave {
+                    m: model.soave_m(w),
+                })
+            })
+            .collect();
+        Self {
+            parameters,
+            options,
+            delta: model.delta(),
+            a,
+            b,
+            alpha,
+        }
+    }
+
+    /// The specific cubic equation of state.
+    pub fn model(&self) -> CubicModel {
+        self.options.model
+    }
+
+    /// Temperature-dependent attraction parameters $a_i\alpha_i(T)$ of the pure components.
+    fn attraction<D: DualNum<f64> + Copy>(&self, temperature: D) -> Vec<D> {
+        let tc = &self.parameters.tc;
+        (0..self.a.len())
+            .map(|i| self.alpha[i].alpha(temperature / tc[i]) * self.a[i])
+            .collect()
+    }
+
+    /// Residual Helmholtz energy for given mixture parameters $a$ and $b$
+    /// including the volume translation.
+    fn helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>, a: D, b: D) -> D {
+        let t = state.temperature;
+        let x = &state.molefracs;
+
+        // Volume translation
+        let mut c = D::zero();
+        for (i, vt) in self.parameters.volume_translation.iter().enumerate() {
+            if let Some(vt) = vt {
+                c += vt.shift(t) * x[i];
+            }
+        }
+
+        // Helmholtz energy
+        let n = state.moles.sum();
+        let v = state.volume;
+        let v_cubic = v + c * n;
+        let (delta1, delta2) = self.delta;
+        let attraction = if (delta1 - delta2).abs() < f64::EPSILON {
+            a * n / (v_cubic + b * n * delta1)
+        } else {
+            a / (b * (delta1 - delta2))
+                * ((v_cubic + b * n * delta1) / (v_cubic + b * n * delta2)).ln()
+        };
+        n * ((v / (v_cubic - b * n)).ln() - attraction / t)
+    }
+}
+
+impl fmt::Display for Cubic {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.options.model)
+    }
+}
+
+impl Components for Cubic {
+    fn components(&self) -> usize {
+        self.b.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.options,
+        )
+    }
+}
+
+impl Residual for Cubic {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        let p = &self.parameters;
+        let c = p
+            .volume_translation
+            .iter()
+            .zip(p.tc.iter())
+            .map(|(c, &tc)| c.map_or(0.0, |c| c.shift(tc)));
+        let b = moles
+            .iter()
+            .zip(self.b.iter())
+            .zip(c)
+            .map(|((&n, &b), c)| n * (b - c))
+            .sum::<f64>()
+            / moles.sum();
+        0.9 / b
+    }
+
+    fn residual_helmholtz_energy<D: DualNum<f64> + Copy>(&self, state: &StateHD<D>) -> D {
+        let p = &self.parameters;
+        let x = &state.molefracs;
+        let ak = self.attraction(state.temperature);
+
+        // Mixing rules
+        let mut ak_mix = D::zero();
+        for i in 0..ak.len() {
+            for j in 0..ak.len() {
+                ak_mix += (ak[i] * ak[j]).sqrt() * (x[i] * x[j] * (1.0 - p.k_ij[(i, j)]));
+            }
+        }
+        let b = (x * &self.b).sum();
+
+        self.helmholtz_energy(state, ak_mix, b)
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        vec![(
+            self.options.model.to_string(),
+            self.residual_helmholtz_energy(state),
+        )]
+    }
+
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        &self.parameters.molarweight * (GRAM / MOL)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::parameter::PureRecord;
+    use crate::si::{BAR, KELVIN, METER, MOL, PASCAL};
+    use crate::state::{Contributions, DensityInitialization, State};
+    use crate::{EosResult, SolverOptions, Verbosity};
+    use approx::*;
+    use ndarray::arr1;
+    use std::sync::Arc;
+    use typenum::P3;
+
+    fn pure_record_vec() -> Vec<PureRecord<PengRobinsonRecord>> {
+        let records = r#"[
+            {
+                "identifier": {
+                    "cas": "74-98-6",
+                    "name": "propane",
+                    "iupac_name": "propane",
+                    "smiles": "CCC",
+                    "inchi": "InChI=1/C3H8/c1-3-2/h3H2,1-2H3",
+                    "formula": "C3H8"
+                },
+                "model_record": {
+                    "tc": 369.96,
+                    "pc": 4250000.0,
+                    "acentric_factor": 0.153
+                },
+                "molarweight": 44.0962
+            },
+            {
+                "identifier": {
+                    "cas": "106-97-8",
+                    "name": "butane",
+                    "iupac_name": "butane",
+                    "smiles": "CCCC",
+                    "inchi": "InChI=1/C4H10/c1-3-4-2/h3-4H2,1-2H3",
+                    "formula": "C4H10"
+                },
+                "model_record": {
+                    "tc": 425.2,
+                    "pc": 3800000.0,
+                    "acentric_factor": 0.199
+                },
+                "molarweight": 58.123
+            }
+        ]"#;
+        serde_json::from_str(records).expect("Unable to parse json.")
+    }
+
+    #[test]
+    fn peng_robinson() -> EosResult<()> {
+        let mixture = pure_record_vec();
+        let propane = mixture[0].clone();
+        let tc = propane.model_record.tc;
+        let pc = propane.model_record.pc;
+        let parameters = PengRobinsonParameters::new_pure(propane)?;
+        let pr = Arc::new(PengRobinson::new(Arc::new(parameters)));
+        let options = SolverOptions::new().verbosity(Verbosity::Iter);
+        let cp = State::critical_point(&pr, None, None, options)?;
+        println!("{} {}", cp.temperature, cp.pressure(Contributions::Total));
+        assert_relative_eq!(cp.temperature, tc * KELVIN, max_relative = 1e-4);
+        assert_relative_eq!(
+            cp.pressure(Contributions::Total),
+            pc * PASCAL,
+            max_relative = 1e-4
+        );
+        Ok(())
+    }
+
+    fn critical_point(model: CubicModel) -> EosResult<()> {
+        let propane = pure_record_vec()[0].clone();
+        let tc = propane.model_record.tc;
+        let pc = propane.model_record.pc;
+        let parameters = Arc::new(CubicParameters::new_pure(propane)?);
+        let eos = Arc::new(Cubic::with_options(parameters, CubicOptions { model }));
+        let cp = State::critical_point(&eos, None, None, SolverOptions::default())?;
+        assert_relative_eq!(cp.temperature, tc * KELVIN, max_relative = 1e-4);
+        assert_relative_eq!(
+            cp.pressure(Contributions::Total),
+            pc * PASCAL,
+            max_relative = 1e-4
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn critical_points() -> EosResult<()> {
+        critical_point(CubicModel::SoaveRedlichKwong)?;
+        critical_point(CubicModel::PengRobinson78)?;
+        critical_point(CubicModel::Generic {
+            delta1: 2.0,
+            delta2: 0.5,
+        })
+    }
+
+    #[test]
+    fn generic_omega() {
+        let (delta1, delta2) = CubicModel::PengRobinson.delta();
+        let (omega_a, omega_b) = CubicModel::Generic { delta1, delta2 }.omega();
+        assert_relative_eq!(omega_a, 0.45724, max_relative = 1e-4);
+        assert_relative_eq!(omega_b, 0.07780, max_relative = 1e-4);
+
+        let (omega_a, omega_b) = CubicModel::Generic {
+            delta1: 1.0,
+            delta2: 0.0,
+        }
+        .omega();
+        assert_relative_eq!(omega_a, 0.42748, max_relative = 1e-4);
+        assert_relative_eq!(omega_b, 0.08664, max_relative = 1e-4);
+
+        let (omega_a, omega_b) = CubicModel::Generic {
+            delta1: 0.0,
+            delta2: 0.0,
+        }
+        .omega();
+        assert_relative_eq!(omega_a, 27.0 / 64.0, max_relative = 1e-10);
+        assert_relative_eq!(omega_b, 1.0 / 8.0, max_relative = 1e-10);
+    }
+
+    #[test]
+    fn alpha_functions() {
+        let alphas = [
+            AlphaFunction::Soave { m: 0.6 },
+            AlphaFunction::Twu91 {
+                l: 0.3,
+                m: 0.9,
+                n: 1.8,
+            },
+            AlphaFunction::MathiasCopeman {
+                c1: 0.6,
+                c2: -0.2,
+                c3: 0.3,
+            },
+        ];
+        for alpha in alphas {
+            assert_relative_eq!(alpha.alpha(1.0), 1.0);
+            assert!(alpha.alpha(0.7) > 1.0);
+            assert!(alpha.alpha(1.3) < 1.0);
+        }
+    }
+
+    #[test]
+    fn volume_translation() -> EosResult<()> {
+        let records = pure_record_vec();
+        let c = 5e-6;
+        let translated: Vec<_> = records
+            .iter()
+            .map(|r| {
+                let record = r
+                    .model_record
+                    .clone()
+                    .volume_translation(VolumeTranslation::Constant(c));
+                PureRecord::new(r.identifier.clone(), r.molarweight, record)
+            })
+            .collect();
+        let eos = Arc::new(Cubic::new(Arc::new(CubicParameters::from_records(
+            records, None,
+        )?)));
+        let eos_translated = Arc::new(Cubic::new(Arc::new(CubicParameters::from_records(
+            translated, None,
+        )?)));
+
+        let t = 250.0 * KELVIN;
+        let p = 10.0 * BAR;
+        let moles = arr1(&[0.3, 0.7]) * MOL;
+        let liquid = State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid)?;
+        let liquid_translated =
+            State::new_npt(&eos_translated, t, p, &moles, DensityInitialization::Liquid)?;
+        assert_relative_eq!(
+            1.0 / liquid.density - 1.0 / liquid_translated.density,
+            c * METER.powi::<P3>() / MOL,
+            max_relative = 1e-8
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn json_records() {
+        let record: CubicRecord = serde_json::from_str(
+            r#"{
+                "tc": 369.96,
+                "pc": 4250000.0,
+                "acentric_factor": 0.153,
+                "alpha": {"twu91": {"l": 0.3, "m": 0.9, "n": 1.8}},
+                "volume_translation": {"linear": {"c0": 1e-6, "c1": 1e-9}}
+            }"#,
+        )
+        .expect("Unable to parse json.");
+        assert_eq!(
+            record.to_string(),
+            "CubicRecord(tc=369.96 K, pc=4250000 Pa, acentric factor=0.153, alpha=Twu91(l=0.3, m=0.9, n=1.8), volume translation=Linear(c0=0.000001 m³/mol, c1=0.000000001 m³/mol/K))"
+        );
+        let json = serde_json::to_string(&CubicRecord::new(369.96, 4250000.0, 0.153)).unwrap();
+        assert_eq!(
+            json,
+            r#"{"tc":369.96,"pc":4250000.0,"acentric_factor":0.153}"#
+        );
+    }
+}
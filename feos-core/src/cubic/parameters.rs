@@ -0,0 +1,201 @@
+use super::AlphaFunction;
+use crate::parameter::{Identifier, Parameter, ParameterError, PureRecord};
+use ndarray::{Array1, Array2};
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+/// Conversion of a molar volume in m³/mol to Å³ per molecule.
+const M3_PER_MOL_TO_A3: f64 = 1e30 / 6.02214076e23;
+
+/// Péneloux-type volume translation $v=v^\mathrm{cubic}-c$.
+///
+/// The volume shift is given in m³/mol.
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq)]
+#[serde(rename_all = "snake_case")]
+pub enum VolumeTranslation {
+    /// Constant volume shift $c$.
+    Constant(f64),
+    /// Linear temperature dependence $c=c_0+c_1T$ with $c_1$ in m³/mol/K.
+    Linear { c0: f64, c1: f64 },
+}
+
+impl VolumeTranslation {
+    /// Volume shift per molecule in units of Å³ at the given temperature in K.
+    pub(super) fn shift<D: DualNum<f64> + Copy>(&self, temperature: D) -> D {
+        match *self {
+            Self::Constant(c) => D::from(c * M3_PER_MOL_TO_A3),
+            Self::Linear { c0, c1 } => (temperature * c1 + c0) * M3_PER_MOL_TO_A3,
+        }
+    }
+}
+
+impl fmt::Display for VolumeTranslation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Constant(c) => write!(f, "Constant(c={c} m³/mol)"),
+            Self::Linear { c0, c1 } => write!(f, "Linear(c0={c0} m³/mol, c1={c1} m³/mol/K)"),
+        }
+    }
+}
+
+/// Parameters of a cubic equation of state for a single substance.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct CubicRecord {
+    /// critical temperature in Kelvin
+    pub(super) tc: f64,
+    /// critical pressure in Pascal
+    pub(super) pc: f64,
+    /// acentric factor
+    pub(super) acentric_factor: f64,
+    /// alpha function
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) alpha: Option<AlphaFunction>,
+    /// volume translation
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub(super) volume_translation: Option<VolumeTranslation>,
+}
+
+impl CubicRecord {
+    /// Create a new pure substance record for a cubic equation of state
+    /// with the generalized Soave alpha function and without volume translation.
+    pub fn new(tc: f64, pc: f64, acentric_factor: f64) -> Self {
+        Self {
+            tc,
+            pc,
+            acentric_factor,
+            alpha: None,
+            volume_translation: None,
+        }
+    }
+
+    /// Use a specific alpha function for this substance.
+    pub fn alpha(mut self, alpha: AlphaFunction) -> Self {
+        self.alpha = Some(alpha);
+        self
+    }
+
+    /// Use a volume translation for this substance.
+    pub fn volume_translation(mut self, volume_translation: VolumeTranslation) -> Self {
+        self.volume_translation = Some(volume_translation);
+        self
+    }
+}
+
+impl fmt::Display for CubicRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "CubicRecord(tc={} K", self.tc)?;
+        write!(f, ", pc={} Pa", self.pc)?;
+        write!(f, ", acentric factor={}", self.acentric_factor)?;
+        if let Some(alpha) = &self.alpha {
+            write!(f, ", alpha={alpha}")?;
+        }
+        if let Some(volume_translation) = &self.volume_translation {
+            write!(f, ", volume translation={volume_translation}")?;
+        }
+        write!(f, ")")
+    }
+}
+
+/// Parameters of a cubic equation of state for one or more substances.
+pub struct CubicParameters {
+    /// Critical temperature in Kelvin
+    pub(super) tc: Array1<f64>,
+    /// Critical pressure in Pascal
+    pub(super) pc: Array1<f64>,
+    /// Acentric factor
+    pub(super) acentric_factor: Array1<f64>,
+    /// Alpha functions
+    pub(super) alpha: Vec<Option<AlphaFunction>>,
+    /// Volume translations
+    pub(super) volume_translation: Vec<Option<VolumeTranslation>>,
+    /// Binary interaction parameter
+    pub(super) k_ij: Array2<f64>,
+    /// Molar weight in units of g/mol
+    pub(super) molarweight: Array1<f64>,
+    /// List of pure component records
+    pure_records: Vec<PureRecord<CubicRecord>>,
+}
+
+impl fmt::Display for CubicParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pure_records
+            .iter()
+            .try_for_each(|pr| writeln!(f, "{}", pr))?;
+        writeln!(f, "\nk_ij:\n{}", self.k_ij)
+    }
+}
+
+impl CubicParameters {
+    /// Build a simple parameter set without binary interaction parameters.
+    pub fn new_simple(
+        tc: &[f64],
+        pc: &[f64],
+        acentric_factor: &[f64],
+        molarweight: &[f64],
+    ) -> Result<Self, ParameterError> {
+        if [pc.len(), acentric_factor.len(), molarweight.len()]
+            .iter()
+            .any(|&l| l != tc.len())
+        {
+            return Err(ParameterError::IncompatibleParameters(String::from(
+                "each component has to have parameters.",
+            )));
+        }
+        let records = (0..tc.len())
+            .map(|i| {
+                let record = CubicRecord::new(tc[i], pc[i], acentric_factor[i]);
+                let id = Identifier::default();
+                PureRecord::new(id, molarweight[i], record)
+            })
+            .collect();
+        CubicParameters::from_records(records, None)
+    }
+}
+
+impl Parameter for CubicParameters {
+    type Pure = CubicRecord;
+    type Binary = f64;
+
+    /// Creates parameters from pure component records.
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        let n = pure_records.len();
+
+        let mut tc = Array1::zeros(n);
+        let mut pc = Array1::zeros(n);
+        let mut acentric_factor = Array1::zeros(n);
+        let mut alpha = Vec::with_capacity(n);
+        let mut volume_translation = Vec::with_capacity(n);
+        let mut molarweight = Array1::zeros(n);
+
+        for (i, record) in pure_records.iter().enumerate() {
+            molarweight[i] = record.molarweight;
+            let r = &record.model_record;
+            tc[i] = r.tc;
+            pc[i] = r.pc;
+            acentric_factor[i] = r.acentric_factor;
+            alpha.push(r.alpha);
+            volume_translation.push(r.volume_translation);
+        }
+
+        let k_ij = binary_records.unwrap_or_else(|| Array2::zeros([n; 2]));
+
+        Ok(Self {
+            tc,
+            pc,
+            acentric_factor,
+            alpha,
+            volume_translation,
+            k_ij,
+            molarweight,
+            pure_records,
+        })
+    }
+
+    fn records(&self) -> (&[PureRecord<CubicRecord>], Option<&Array2<f64>>) {
+        (&self.pure_records, Some(&self.k_ij))
+    }
+}
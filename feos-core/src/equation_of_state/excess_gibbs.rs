@@ -0,0 +1,32 @@
+use super::Components;
+use ndarray::Array1;
+use num_dual::{Dual64, DualNum};
+
+/// A model for the molar excess Gibbs energy of a liquid mixture.
+///
+/// Implementations only need to provide the reduced molar excess
+/// Gibbs energy as function of temperature and composition. The
+/// function has to be generic over [DualNum] so that models can be
+/// used in the mixing rules of cubic equations of state without
+/// losing access to (higher order) derivatives.
+pub trait ExcessGibbsEnergy: Components + Send + Sync {
+    /// The reduced molar excess Gibbs energy $\frac{g^E}{RT}$.
+    fn reduced_molar_excess_gibbs_energy<D: DualNum<f64> + Copy>(
+        &self,
+        temperature: D,
+        molefracs: &Array1<D>,
+    ) -> D;
+
+    /// The logarithms of the activity coefficients
+    /// $\ln\gamma_i=\left(\frac{\partial nG^E/RT}{\partial n_i}\right)_{T,p,n_j}$.
+    fn ln_activity_coefficients(&self, temperature: f64, molefracs: &Array1<f64>) -> Array1<f64> {
+        let t = Dual64::from(temperature);
+        Array1::from_shape_fn(molefracs.len(), |i| {
+            let mut moles = molefracs.mapv(Dual64::from);
+            moles[i].eps = 1.0;
+            let n = moles.sum();
+            let x = moles.mapv(|m| m / n);
+            (self.reduced_molar_excess_gibbs_energy(t, &x) * n).eps
+        })
+    }
+}
@@ -5,9 +5,11 @@ use crate::{
 use ndarray::{Array1, ScalarOperand};
 use std::sync::Arc;
 
+mod excess_gibbs;
 mod ideal_gas;
 mod residual;
 
+pub use excess_gibbs::ExcessGibbsEnergy;
 pub use ideal_gas::IdealGas;
 pub use residual::{EntropyScaling, NoResidual, Residual};
 
@@ -23,20 +23,23 @@ macro_rules! log_result {
     }
 }
 
+pub mod activity;
 pub mod cubic;
 mod density_iteration;
 mod equation_of_state;
 mod errors;
 pub mod parameter;
 mod phase_equilibria;
+pub mod sensitivity;
 pub mod si;
 mod state;
 pub use equation_of_state::{
-    Components, EntropyScaling, EquationOfState, IdealGas, NoResidual, Residual,
+    Components, EntropyScaling, EquationOfState, ExcessGibbsEnergy, IdealGas, NoResidual, Residual,
 };
 pub use errors::{EosError, EosResult};
 pub use phase_equilibria::{
-    PhaseDiagram, PhaseDiagramHetero, PhaseEquilibrium, TemperatureOrPressure,
+    ChemicalReactions, FlashResult, MultiPhaseEquilibrium, PhaseDiagram, PhaseDiagramHetero,
+    PhaseEnvelope, PhaseEquilibrium, TemperatureOrPressure, VaporPressureModel,
 };
 pub use state::{
     Contributions, DensityInitialization, Derivative, State, StateBuilder, StateHD, StateVec,
@@ -0,0 +1,612 @@
+use super::{
+    BinaryRecord, FromSegments, FromSegmentsBinary, Parameter, ParameterError, ParameterHetero,
+    PureRecord, SegmentCount, SegmentRecord,
+};
+use ndarray::{Array1, Array2};
+
+/// Bounds and scaling of an adjustable model parameter.
+///
+/// Within a [ParameterVector] every parameter is divided by its scaling
+/// factor so that all entries of the vector are of similar magnitude.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct FitBounds {
+    pub lower: f64,
+    pub upper: f64,
+    pub scale: f64,
+}
+
+impl FitBounds {
+    pub fn new(lower: f64, upper: f64, scale: f64) -> Self {
+        Self {
+            lower,
+            upper,
+            scale,
+        }
+    }
+
+    /// Bounds for parameters that are strictly positive.
+    pub fn positive(scale: f64) -> Self {
+        Self::new(0.0, f64::INFINITY, scale)
+    }
+}
+
+impl Default for FitBounds {
+    fn default() -> Self {
+        Self::new(f64::NEG_INFINITY, f64::INFINITY, 1.0)
+    }
+}
+
+/// Read and write access to the adjustable parameters of a model record.
+pub trait FitRecord {
+    /// Names of the parameters that can be adjusted in this record.
+    fn names(&self) -> Vec<&'static str>;
+
+    /// Value of the parameter `name`.
+    fn get(&self, name: &str) -> Result<f64, ParameterError>;
+
+    /// Set the value of the parameter `name`.
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError>;
+
+    /// Default bounds and scaling of the parameter `name`.
+    fn bounds(&self, _name: &str) -> FitBounds {
+        FitBounds::default()
+    }
+}
+
+/// Binary interaction parameters that only consist of $k_{ij}$.
+impl FitRecord for f64 {
+    fn names(&self) -> Vec<&'static str> {
+        vec!["k_ij"]
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        match name {
+            "k_ij" => Ok(*self),
+            _ => Err(ParameterError::UnknownParameter(name.into())),
+        }
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        match name {
+            "k_ij" => *self = value,
+            _ => return Err(ParameterError::UnknownParameter(name.into())),
+        }
+        Ok(())
+    }
+
+    fn bounds(&self, _name: &str) -> FitBounds {
+        FitBounds::new(-1.0, 1.0, 0.01)
+    }
+}
+
+/// Location of an adjustable parameter within the records.
+#[derive(Clone, Debug, PartialEq)]
+pub enum FitTarget {
+    /// Parameter of a pure component record.
+    Pure { component: usize, name: String },
+    /// Binary parameter of the components `i` and `j`.
+    Binary { i: usize, j: usize, name: String },
+}
+
+impl std::fmt::Display for FitTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Pure { component, name } => write!(f, "{name}[{component}]"),
+            Self::Binary { i, j, name } => write!(f, "{name}[{i},{j}]"),
+        }
+    }
+}
+
+/// Mapping between a flat vector of adjustable parameters and
+/// the records that are used to construct model parameters.
+///
+/// Parameters that are not added to the vector keep the values
+/// of the records the `ParameterVector` was created from.
+pub struct ParameterVector<P: Parameter> {
+    pure_records: Vec<PureRecord<P::Pure>>,
+    binary_records: Option<Array2<P::Binary>>,
+    targets: Vec<FitTarget>,
+    bounds: Vec<FitBounds>,
+}
+
+impl<P: Parameter> ParameterVector<P>
+where
+    P::Pure: FitRecord,
+    P::Binary: FitRecord,
+{
+    /// Create a `ParameterVector` without adjustable parameters from records.
+    pub fn new(
+        pure_records: Vec<PureRecord<P::Pure>>,
+        binary_records: Option<Array2<P::Binary>>,
+    ) -> Self {
+        Self {
+            pure_records,
+            binary_records,
+            targets: Vec::new(),
+            bounds: Vec::new(),
+        }
+    }
+
+    /// Create a `ParameterVector` without adjustable parameters from the
+    /// records of existing parameters.
+    pub fn from_parameters(parameters: &P) -> Self {
+        let (pure_records, binary_records) = parameters.records();
+        Self::new(pure_records.to_vec(), binary_records.cloned())
+    }
+
+    /// Add the parameter `name` of component `component` to the vector.
+    ///
+    /// If no `bounds` are provided, the defaults of the record are used.
+    pub fn add_pure(
+        &mut self,
+        component: usize,
+        name: &str,
+        bounds: Option<FitBounds>,
+    ) -> Result<(), ParameterError> {
+        let record = &self
+            .pure_records
+            .get(component)
+            .ok_or_else(|| {
+                ParameterError::IncompatibleParameters(format!(
+                    "There is no component with index {component}."
+                ))
+            })?
+            .model_record;
+        record.get(name)?;
+        self.push(
+            FitTarget::Pure {
+                component,
+                name: name.into(),
+            },
+            bounds.unwrap_or_else(|| record.bounds(name)),
+        )
+    }
+
+    /// Add the binary parameter `name` of components `i` and `j` to the vector.
+    ///
+    /// The binary records are kept symmetric, i.e., the entries for $ij$ and $ji$
+    /// are both set to the value from the vector.
+    pub fn add_binary(
+        &mut self,
+        i: usize,
+        j: usize,
+        name: &str,
+        bounds: Option<FitBounds>,
+    ) -> Result<(), ParameterError> {
+        let n = self.pure_records.len();
+        if i == j || i >= n || j >= n {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "Invalid component indices ({i}, {j}) for a binary parameter."
+            )));
+        }
+        let record = &self
+            .binary_records
+            .get_or_insert_with(|| Array2::default([n, n]))[(i, j)];
+        record.get(name)?;
+        let bounds = bounds.unwrap_or_else(|| record.bounds(name));
+        let (i, j) = (i.min(j), i.max(j));
+        self.push(
+            FitTarget::Binary {
+                i,
+                j,
+                name: name.into(),
+            },
+            bounds,
+        )
+    }
+
+    /// Replace the binary record of components `i` and `j`.
+    ///
+    /// Can be used to add binary parameters that are not part of the default
+    /// record, e.g., a temperature dependence of $k_{ij}$, before their
+    /// coefficients are added to the vector.
+    pub fn set_binary_record(
+        &mut self,
+        i: usize,
+        j: usize,
+        record: P::Binary,
+    ) -> Result<(), ParameterError> {
+        let n = self.pure_records.len();
+        if i == j || i >= n || j >= n {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "Invalid component indices ({i}, {j}) for a binary parameter."
+            )));
+        }
+        let (i, j) = (i.min(j), i.max(j));
+        if self
+            .targets
+            .iter()
+            .any(|t| matches!(t, FitTarget::Binary { i: ti, j: tj, .. } if (*ti, *tj) == (i, j)))
+        {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "The binary record of ({i}, {j}) contains adjustable parameters."
+            )));
+        }
+        let br = self
+            .binary_records
+            .get_or_insert_with(|| Array2::default([n, n]));
+        br[(j, i)] = record.clone();
+        br[(i, j)] = record;
+        Ok(())
+    }
+
+    fn push(&mut self, target: FitTarget, bounds: FitBounds) -> Result<(), ParameterError> {
+        if self.targets.contains(&target) {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "The parameter {target} is already part of the parameter vector."
+            )));
+        }
+        self.targets.push(target);
+        self.bounds.push(bounds);
+        Ok(())
+    }
+
+    /// Number of adjustable parameters.
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Return `true` if there are no adjustable parameters.
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// The locations of the adjustable parameters.
+    pub fn targets(&self) -> &[FitTarget] {
+        &self.targets
+    }
+
+    /// Names of the adjustable parameters, e.g., `m[0]` or `k_ij[0,1]`.
+    pub fn names(&self) -> Vec<String> {
+        self.targets.iter().map(|t| t.to_string()).collect()
+    }
+
+    /// Scaling factors of the adjustable parameters.
+    pub fn scaling(&self) -> Array1<f64> {
+        self.bounds.iter().map(|b| b.scale).collect()
+    }
+
+    /// Lower and upper bounds of the scaled parameter vector.
+    pub fn bounds(&self) -> (Array1<f64>, Array1<f64>) {
+        let lower = self.bounds.iter().map(|b| b.lower / b.scale).collect();
+        let upper = self.bounds.iter().map(|b| b.upper / b.scale).collect();
+        (lower, upper)
+    }
+
+    /// Values of the adjustable parameters in the records.
+    pub fn values(&self) -> Array1<f64> {
+        self.targets
+            .iter()
+            .map(|target| match target {
+                FitTarget::Pure { component, name } => {
+                    self.pure_records[*component].model_record.get(name)
+                }
+                FitTarget::Binary { i, j, name } => {
+                    self.binary_records.as_ref().unwrap()[(*i, *j)].get(name)
+                }
+            })
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// The scaled parameter vector of the current records.
+    pub fn to_vector(&self) -> Array1<f64> {
+        self.values() / self.scaling()
+    }
+
+    /// Pure and binary records with the adjustable parameters taken from
+    /// the scaled parameter vector `x`.
+    #[allow(clippy::type_complexity)]
+    pub fn records(
+        &self,
+        x: &Array1<f64>,
+    ) -> Result<(Vec<PureRecord<P::Pure>>, Option<Array2<P::Binary>>), ParameterError> {
+        if x.len() != self.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "Expected {} parameters, got {}.",
+                self.len(),
+                x.len()
+            )));
+        }
+        let mut pure_records = self.pure_records.clone();
+        let mut binary_records = self.binary_records.clone();
+        for ((target, bounds), &x) in self.targets.iter().zip(&self.bounds).zip(x) {
+            let value = x * bounds.scale;
+            match target {
+                FitTarget::Pure { component, name } => {
+                    pure_records[*component].model_record.set(name, value)?
+                }
+                FitTarget::Binary { i, j, name } => {
+                    let br = binary_records.as_mut().unwrap();
+                    br[(*i, *j)].set(name, value)?;
+                    br[(*j, *i)].set(name, value)?;
+                }
+            }
+        }
+        Ok((pure_records, binary_records))
+    }
+
+    /// Model parameters from the scaled parameter vector `x`.
+    pub fn parameters(&self, x: &Array1<f64>) -> Result<P, ParameterError> {
+        let (pure_records, binary_records) = self.records(x)?;
+        P::from_records(pure_records, binary_records)
+    }
+
+    /// Replace the values in the stored records by those of the scaled parameter vector `x`.
+    pub fn update(&mut self, x: &Array1<f64>) -> Result<(), ParameterError> {
+        (self.pure_records, self.binary_records) = self.records(x)?;
+        Ok(())
+    }
+}
+
+/// Location of an adjustable parameter within the segment records.
+#[derive(Clone, Debug, PartialEq)]
+pub enum SegmentTarget {
+    /// Parameter of the segment `identifier`.
+    Segment { identifier: String, name: String },
+    /// Binary parameter of the segments `id1` and `id2`.
+    Binary {
+        id1: String,
+        id2: String,
+        name: String,
+    },
+}
+
+impl std::fmt::Display for SegmentTarget {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Segment { identifier, name } => write!(f, "{name}[{identifier}]"),
+            Self::Binary { id1, id2, name } => write!(f, "{name}[{id1},{id2}]"),
+        }
+    }
+}
+
+/// Mapping between a flat vector of adjustable parameters and the
+/// segment records of a group contribution method.
+///
+/// The same segment records are used to construct the parameters of
+/// every molecule, so that the segment parameters can be regressed
+/// simultaneously to data of many molecules. Segments that are not
+/// added to the vector, e.g., established groups, keep their values.
+pub struct SegmentVector<M, B = f64> {
+    segment_records: Vec<SegmentRecord<M>>,
+    binary_segment_records: Option<Vec<BinaryRecord<String, B>>>,
+    targets: Vec<SegmentTarget>,
+    bounds: Vec<FitBounds>,
+}
+
+impl<M: FitRecord + Clone, B: FitRecord + Clone + Default> SegmentVector<M, B> {
+    /// Create a `SegmentVector` without adjustable parameters from segment records.
+    pub fn new(
+        segment_records: Vec<SegmentRecord<M>>,
+        binary_segment_records: Option<Vec<BinaryRecord<String, B>>>,
+    ) -> Self {
+        Self {
+            segment_records,
+            binary_segment_records,
+            targets: Vec::new(),
+            bounds: Vec::new(),
+        }
+    }
+
+    fn segment_index(&self, identifier: &str) -> Result<usize, ParameterError> {
+        self.segment_records
+            .iter()
+            .position(|s| s.identifier == identifier)
+            .ok_or_else(|| {
+                ParameterError::IncompatibleParameters(format!(
+                    "There is no segment with identifier '{identifier}'."
+                ))
+            })
+    }
+
+    fn binary_index(&self, id1: &str, id2: &str) -> Option<usize> {
+        self.binary_segment_records
+            .as_ref()?
+            .iter()
+            .position(|br| (br.id1 == id1 && br.id2 == id2) || (br.id1 == id2 && br.id2 == id1))
+    }
+
+    /// Add the parameter `name` of the segment `identifier` to the vector.
+    ///
+    /// If no `bounds` are provided, the defaults of the record are used.
+    pub fn add_segment(
+        &mut self,
+        identifier: &str,
+        name: &str,
+        bounds: Option<FitBounds>,
+    ) -> Result<(), ParameterError> {
+        let record = &self.segment_records[self.segment_index(identifier)?].model_record;
+        record.get(name)?;
+        let bounds = bounds.unwrap_or_else(|| record.bounds(name));
+        self.push(
+            SegmentTarget::Segment {
+                identifier: identifier.into(),
+                name: name.into(),
+            },
+            bounds,
+        )
+    }
+
+    /// Add the binary parameter `name` of the segments `id1` and `id2` to the vector.
+    ///
+    /// If there is no binary record for the two segments, a record with
+    /// default values is added.
+    pub fn add_binary(
+        &mut self,
+        id1: &str,
+        id2: &str,
+        name: &str,
+        bounds: Option<FitBounds>,
+    ) -> Result<(), ParameterError> {
+        self.segment_index(id1)?;
+        self.segment_index(id2)?;
+        if id1 == id2 {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "Invalid segments ({id1}, {id2}) for a binary parameter."
+            )));
+        }
+        let index = match self.binary_index(id1, id2) {
+            Some(index) => index,
+            None => {
+                let records = self.binary_segment_records.get_or_insert_with(Vec::new);
+                records.push(BinaryRecord::new(id1.into(), id2.into(), B::default()));
+                records.len() - 1
+            }
+        };
+        let record = &self.binary_segment_records.as_ref().unwrap()[index].model_record;
+        record.get(name)?;
+        let bounds = bounds.unwrap_or_else(|| record.bounds(name));
+        let (id1, id2) = if id1 < id2 { (id1, id2) } else { (id2, id1) };
+        self.push(
+            SegmentTarget::Binary {
+                id1: id1.into(),
+                id2: id2.into(),
+                name: name.into(),
+            },
+            bounds,
+        )
+    }
+
+    fn push(&mut self, target: SegmentTarget, bounds: FitBounds) -> Result<(), ParameterError> {
+        if self.targets.contains(&target) {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "The parameter {target} is already part of the parameter vector."
+            )));
+        }
+        self.targets.push(target);
+        self.bounds.push(bounds);
+        Ok(())
+    }
+
+    /// Number of adjustable parameters.
+    pub fn len(&self) -> usize {
+        self.targets.len()
+    }
+
+    /// Return `true` if there are no adjustable parameters.
+    pub fn is_empty(&self) -> bool {
+        self.targets.is_empty()
+    }
+
+    /// The locations of the adjustable parameters.
+    pub fn targets(&self) -> &[SegmentTarget] {
+        &self.targets
+    }
+
+    /// Names of the adjustable parameters, e.g., `m[CH3]` or `k_ij[CH3,OH]`.
+    pub fn names(&self) -> Vec<String> {
+        self.targets.iter().map(|t| t.to_string()).collect()
+    }
+
+    /// Scaling factors of the adjustable parameters.
+    pub fn scaling(&self) -> Array1<f64> {
+        self.bounds.iter().map(|b| b.scale).collect()
+    }
+
+    /// Lower and upper bounds of the scaled parameter vector.
+    pub fn bounds(&self) -> (Array1<f64>, Array1<f64>) {
+        let lower = self.bounds.iter().map(|b| b.lower / b.scale).collect();
+        let upper = self.bounds.iter().map(|b| b.upper / b.scale).collect();
+        (lower, upper)
+    }
+
+    /// Values of the adjustable parameters in the records.
+    pub fn values(&self) -> Array1<f64> {
+        self.targets
+            .iter()
+            .map(|target| match target {
+                SegmentTarget::Segment { identifier, name } => self.segment_records
+                    [self.segment_index(identifier)?]
+                .model_record
+                .get(name),
+                SegmentTarget::Binary { id1, id2, name } => {
+                    let index = self.binary_index(id1, id2).unwrap();
+                    self.binary_segment_records.as_ref().unwrap()[index]
+                        .model_record
+                        .get(name)
+                }
+            })
+            .collect::<Result<_, _>>()
+            .unwrap()
+    }
+
+    /// The scaled parameter vector of the current records.
+    pub fn to_vector(&self) -> Array1<f64> {
+        self.values() / self.scaling()
+    }
+
+    /// Segment and binary segment records with the adjustable parameters
+    /// taken from the scaled parameter vector `x`.
+    #[allow(clippy::type_complexity)]
+    pub fn records(
+        &self,
+        x: &Array1<f64>,
+    ) -> Result<(Vec<SegmentRecord<M>>, Option<Vec<BinaryRecord<String, B>>>), ParameterError> {
+        if x.len() != self.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "Expected {} parameters, got {}.",
+                self.len(),
+                x.len()
+            )));
+        }
+        let mut segment_records = self.segment_records.clone();
+        let mut binary_segment_records = self.binary_segment_records.clone();
+        for ((target, bounds), &x) in self.targets.iter().zip(&self.bounds).zip(x) {
+            let value = x * bounds.scale;
+            match target {
+                SegmentTarget::Segment { identifier, name } => segment_records
+                    [self.segment_index(identifier)?]
+                .model_record
+                .set(name, value)?,
+                SegmentTarget::Binary { id1, id2, name } => {
+                    let index = self.binary_index(id1, id2).unwrap();
+                    binary_segment_records.as_mut().unwrap()[index]
+                        .model_record
+                        .set(name, value)?
+                }
+            }
+        }
+        Ok((segment_records, binary_segment_records))
+    }
+
+    /// Replace the values in the stored records by those of the scaled parameter vector `x`.
+    pub fn update(&mut self, x: &Array1<f64>) -> Result<(), ParameterError> {
+        (self.segment_records, self.binary_segment_records) = self.records(x)?;
+        Ok(())
+    }
+
+    /// Parameters of a heterosegmented model for the molecules in
+    /// `chemical_records` from the scaled parameter vector `x`.
+    pub fn parameters_hetero<P, C>(
+        &self,
+        chemical_records: Vec<C>,
+        x: &Array1<f64>,
+    ) -> Result<P, ParameterError>
+    where
+        P: ParameterHetero<Pure = M, Binary = B>,
+        C: Clone + Into<P::Chemical>,
+    {
+        let (segment_records, binary_segment_records) = self.records(x)?;
+        P::from_segments(chemical_records, segment_records, binary_segment_records)
+    }
+}
+
+impl<M: FitRecord + Clone> SegmentVector<M> {
+    /// Parameters of a homosegmented model for the molecules in
+    /// `chemical_records` from the scaled parameter vector `x`.
+    pub fn parameters<P, C>(
+        &self,
+        chemical_records: Vec<C>,
+        x: &Array1<f64>,
+    ) -> Result<P, ParameterError>
+    where
+        P: Parameter<Pure = M>,
+        C: SegmentCount,
+        M: FromSegments<C::Count>,
+        P::Binary: FromSegmentsBinary<C::Count>,
+    {
+        let (segment_records, binary_segment_records) = self.records(x)?;
+        P::from_segments(chemical_records, segment_records, binary_segment_records)
+    }
+}
@@ -14,11 +14,13 @@ use std::path::Path;
 use thiserror::Error;
 
 mod chemical_record;
+mod fit;
 mod identifier;
 mod model_record;
 mod segment;
 
 pub use chemical_record::{ChemicalRecord, SegmentCount};
+pub use fit::{FitBounds, FitRecord, FitTarget, ParameterVector, SegmentTarget, SegmentVector};
 pub use identifier::{Identifier, IdentifierOption};
 pub use model_record::{BinaryRecord, FromSegments, FromSegmentsBinary, PureRecord};
 pub use segment::SegmentRecord;
@@ -348,6 +350,51 @@ where
     }
 }
 
+/// Build a matrix of binary records for models in which the binary
+/// interactions depend on the order of the components.
+///
+/// Records that are found with reversed identifiers are converted
+/// with the function `reversed`. Can be used to override
+/// [Parameter::binary_matrix_from_records].
+pub fn oriented_binary_matrix<P, B: Clone + Default>(
+    pure_records: &[PureRecord<P>],
+    binary_records: &[BinaryRecord<Identifier, B>],
+    identifier_option: IdentifierOption,
+    reversed: fn(&B) -> B,
+) -> Option<Array2<B>> {
+    if binary_records.is_empty() {
+        return None;
+    }
+    let binary_map: HashMap<_, _> = binary_records
+        .iter()
+        .filter_map(|br| {
+            let id1 = br.id1.as_string(identifier_option)?;
+            let id2 = br.id2.as_string(identifier_option)?;
+            Some(((id1, id2), &br.model_record))
+        })
+        .collect();
+    let identifiers: Vec<_> = pure_records
+        .iter()
+        .enumerate()
+        .map(|(i, pr)| {
+            pr.identifier
+                .as_string(identifier_option)
+                .unwrap_or_else(|| {
+                    panic!("No identifier for given identifier_option for pure record {i}.")
+                })
+        })
+        .collect();
+    let n = pure_records.len();
+    Some(Array2::from_shape_fn([n, n], |(i, j)| {
+        let (id1, id2) = (identifiers[i].clone(), identifiers[j].clone());
+        binary_map
+            .get(&(id1.clone(), id2.clone()))
+            .map(|&br| br.clone())
+            .or_else(|| binary_map.get(&(id2, id1)).map(|&br| reversed(br)))
+            .unwrap_or_default()
+    }))
+}
+
 /// Dummy struct used for models that do not use binary interaction parameters.
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct NoBinaryModelRecord;
@@ -494,4 +541,6 @@ pub enum ParameterError {
     InsufficientInformation,
     #[error("Incompatible parameters: {0}")]
     IncompatibleParameters(String),
+    #[error("The parameter '{0}' is not available in this record.")]
+    UnknownParameter(String),
 }
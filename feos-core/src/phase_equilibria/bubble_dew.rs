@@ -24,6 +24,9 @@ const MAX_TSTEP: f64 = 20.0;
 const MAX_LNPSTEP: f64 = 0.1;
 const NEWTON_TOL: f64 = 1e-3;
 
+const TOL_VAPOR_FRACTION: f64 = 1e-8;
+const INCIPIENT_PHASE_FRACTION: f64 = 1e-12;
+
 /// Trait that enables functions to be generic over their input unit.
 pub trait TemperatureOrPressure: Copy + Into<TPSpec> {
     type Other: fmt::Display + TemperatureOrPressure;
@@ -212,11 +215,10 @@ impl TemperatureOrPressure for Temperature {
             * (&state1.partial_density / &state2.partial_density)
                 .into_value()
                 .mapv(f64::ln);
-        let res = concatenate![Axis(0), mu_1_res - mu_2_res + dmu_ig, arr1(&[p_1 - p_2])];
-        let error = norm(&res);
+        let mut res = concatenate![Axis(0), mu_1_res - mu_2_res + dmu_ig, arr1(&[p_1 - p_2])];
 
         // calculate Jacobian
-        let jacobian = concatenate![
+        let mut jacobian = concatenate![
             Axis(1),
             concatenate![Axis(0), -dmu_drho_2, -dp_drho_2.insert_axis(Axis(0))],
             concatenate![
@@ -225,6 +227,8 @@ impl TemperatureOrPressure for Temperature {
                 arr2(&[[dp_drho_1]])
             ]
         ];
+        fix_absent_components(&state1.molefracs, &mut res, &mut jacobian);
+        let error = norm(&res);
 
         // calculate Newton step
         let dx = LU::new(jacobian)?.solve(&res);
@@ -382,16 +386,15 @@ impl TemperatureOrPressure for Quantity<f64, SIUnit<N2, N1, P1, Z0, Z0, Z0, Z0>>
             .into_value()
             .mapv(f64::ln);
         let delta_mu_ig = (RGAS * state1.temperature).to_reduced() * &delta_dmu_ig_dt;
-        let res = concatenate![
+        let mut res = concatenate![
             Axis(0),
             mu_1_res - mu_2_res + delta_mu_ig,
             arr1(&[p_1 - p]),
             arr1(&[p_2 - p])
         ];
-        let error = norm(&res);
 
         // calculate Jacobian
-        let jacobian = concatenate![
+        let mut jacobian = concatenate![
             Axis(1),
             concatenate![
                 Axis(0),
@@ -410,6 +413,8 @@ impl TemperatureOrPressure for Quantity<f64, SIUnit<N2, N1, P1, Z0, Z0, Z0, Z0>>
                 arr2(&[[dp_dt_1], [dp_dt_2]])
             ]
         ];
+        fix_absent_components(&state1.molefracs, &mut res, &mut jacobian);
+        let error = norm(&res);
 
         // calculate Newton step
         let dx = LU::new(jacobian)?.solve(&res);
@@ -487,6 +492,108 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
         )
     }
 
+    /// Calculate a phase equilibrium for a given temperature
+    /// or pressure, feed composition and vapor phase fraction.
+    ///
+    /// The vapor phase fraction $\beta$ has to be within $[0, 1]$, where
+    /// $\beta=0$ and $\beta=1$ correspond to the bubble and dew point of
+    /// the feed, respectively. In all cases, the phases are scaled to the
+    /// amount of substance in the feed. The calculation is initialized by
+    /// interpolating between the bubble and the dew point, which requires an
+    /// initial temperature if the pressure is specified.
+    pub fn vapor_fraction_flash<TP: TemperatureOrPressure>(
+        eos: &Arc<E>,
+        temperature_or_pressure: TP,
+        vapor_fraction: f64,
+        feed: &Moles<Array1<f64>>,
+        tp_init: Option<TP::Other>,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        if !(0.0..=1.0).contains(&vapor_fraction) {
+            return Err(EosError::InvalidState(
+                String::from("vapor fraction flash"),
+                String::from("vapor fraction"),
+                vapor_fraction,
+            ));
+        }
+        let z = (feed / feed.sum()).into_value();
+        let bubble = Self::bubble_point(eos, temperature_or_pressure, &z, tp_init, None, options)?;
+        if vapor_fraction == 0.0 {
+            return bubble.scale_to_feed(feed, vapor_fraction);
+        }
+        let dew = Self::dew_point(eos, temperature_or_pressure, &z, tp_init, None, options)?;
+        if vapor_fraction == 1.0 {
+            return dew.scale_to_feed(feed, vapor_fraction);
+        }
+
+        // interpolate temperature, pressure and K values between bubble and dew point
+        let beta = vapor_fraction;
+        let interpolate = |b: f64, d: f64| (1.0 - beta) * b + beta * d;
+        let temperature = Temperature::from_reduced(interpolate(
+            bubble.vapor().temperature.to_reduced(),
+            dew.vapor().temperature.to_reduced(),
+        ));
+        let pressure = Pressure::from_reduced(
+            interpolate(
+                bubble
+                    .vapor()
+                    .pressure(Contributions::Total)
+                    .to_reduced()
+                    .ln(),
+                dew.vapor().pressure(Contributions::Total).to_reduced().ln(),
+            )
+            .exp(),
+        );
+        // components that are absent from the feed are absent in both phases
+        let ln_k = |state: &Self| {
+            Zip::from(&state.vapor().molefracs)
+                .and(&state.liquid().molefracs)
+                .and(&z)
+                .map_collect(|&y, &x, &z| if z > 0.0 { (y / x).ln() } else { 0.0 })
+        };
+        let ln_k_bubble = ln_k(&bubble);
+        let ln_k_dew = ln_k(&dew);
+        let k = ((1.0 - beta) * ln_k_bubble + beta * ln_k_dew).mapv(f64::exp);
+        let x = &z / (1.0 + beta * (&k - 1.0));
+        let y = &k * &x;
+        let liquid = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            &Moles::from_reduced(&x / x.sum()),
+            Liquid,
+        )?;
+        let vapor = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            &Moles::from_reduced(&y / y.sum()),
+            Vapor,
+        )?;
+        let [vapor, liquid] =
+            vapor_fraction_iteration(temperature_or_pressure, beta, &z, vapor, liquid, options)?;
+        Self([vapor, liquid]).scale_to_feed(feed, beta)
+    }
+
+    /// Scale the phases to the amount of substance in the feed.
+    ///
+    /// At the bubble and dew point, the incipient phase is assigned
+    /// a vanishing amount of substance, because states without any
+    /// particles can not be represented.
+    fn scale_to_feed(mut self, feed: &Moles<Array1<f64>>, vapor_fraction: f64) -> EosResult<Self> {
+        let beta = vapor_fraction.clamp(INCIPIENT_PHASE_FRACTION, 1.0 - INCIPIENT_PHASE_FRACTION);
+        let n = feed.sum();
+        let moles_vapor =
+            Moles::from_reduced(self.vapor().molefracs.clone()) * (n * beta).to_reduced();
+        let moles_liquid =
+            Moles::from_reduced(self.liquid().molefracs.clone()) * (n * (1.0 - beta)).to_reduced();
+        self.update_moles(
+            self.vapor().pressure(Contributions::Total),
+            [&moles_vapor, &moles_liquid],
+        )?;
+        Ok(self)
+    }
+
     pub(super) fn bubble_dew_point<TP: TemperatureOrPressure>(
         eos: &Arc<E>,
         tp_spec: TP,
@@ -745,6 +852,113 @@ fn bubble_dew<E: Residual, TP: TemperatureOrPressure>(
     }
 }
 
+fn vapor_fraction_iteration<E: Residual, TP: TemperatureOrPressure>(
+    tp_spec: TP,
+    beta: f64,
+    feed: &Array1<f64>,
+    mut vapor: State<E>,
+    mut liquid: State<E>,
+    options: (SolverOptions, SolverOptions),
+) -> EosResult<[State<E>; 2]> {
+    let (options_inner, options_outer) = options;
+    let (max_iter_inner, tol_inner, _) =
+        options_inner.unwrap_or(MAX_ITER_INNER, TOL_VAPOR_FRACTION);
+    let (max_iter_outer, tol_outer, verbosity) =
+        options_outer.unwrap_or(MAX_ITER_OUTER, TOL_VAPOR_FRACTION);
+
+    log_iter!(
+        verbosity,
+        "res outer loop | res inner loop | {:^16} | molefracs vapor phase",
+        TP::IDENTIFIER
+    );
+    log_iter!(verbosity, "{:-<85}", "");
+
+    for ko in 0..max_iter_outer {
+        // Inner loop for finding T or p from the Rachford-Rice equation
+        let mut err_in = 1.0;
+        for _ in 0..max_iter_inner {
+            let k = (liquid.ln_phi() - vapor.ln_phi()).mapv(f64::exp);
+            let denominator = 1.0 + beta * (&k - 1.0);
+            let f = (feed * (&k - 1.0) / &denominator).sum();
+            err_in = f.abs();
+            if err_in < tol_inner {
+                break;
+            }
+            let dk = feed * &k / (&denominator * &denominator);
+            let mut temperature = vapor.temperature;
+            let mut pressure = vapor.pressure(Contributions::Total);
+            match tp_spec.into() {
+                TPSpec::Temperature(_) => {
+                    // derivative w.r.t. ln(pressure)
+                    let dln_k =
+                        ((liquid.dln_phi_dp() - vapor.dln_phi_dp()) * pressure).into_value();
+                    let lnpstep = (-f / (&dk * &dln_k).sum()).clamp(-MAX_LNPSTEP, MAX_LNPSTEP);
+                    pressure *= lnpstep.exp();
+                }
+                TPSpec::Pressure(_) => {
+                    // derivative w.r.t. temperature
+                    let dln_k = (liquid.dln_phi_dt() - vapor.dln_phi_dt()).to_reduced();
+                    let tstep = (-f / (&dk * &dln_k).sum()).clamp(-MAX_TSTEP, MAX_TSTEP);
+                    temperature += Temperature::from_reduced(tstep);
+                }
+            }
+            adjust_states(temperature, pressure, &mut vapor, &mut liquid, None)?;
+        }
+
+        // Outer loop: update compositions by successive substitution
+        let ln_k = liquid.ln_phi() - vapor.ln_phi();
+        let err_out = norm(
+            &Zip::from(&ln_k)
+                .and(&vapor.molefracs)
+                .and(&liquid.molefracs)
+                .and(feed)
+                .map_collect(|&ln_k, &y, &x, &z| if z > 0.0 { ln_k - (y / x).ln() } else { 0.0 }),
+        );
+        log_iter!(
+            verbosity,
+            "{:<14.8e} | {:<14.8e} | {:12.8} | {:.8}",
+            err_out,
+            err_in,
+            TP::from_state(&vapor),
+            vapor.molefracs
+        );
+        if err_out < tol_outer && err_in < tol_inner {
+            log_result!(
+                verbosity,
+                "Vapor fraction flash: calculation converged in {} step(s)\n",
+                ko + 1
+            );
+            return Ok([vapor, liquid]);
+        }
+
+        let k = ln_k.mapv(f64::exp);
+        let x = feed / (1.0 + beta * (&k - 1.0));
+        let y = &k * &x;
+        let temperature = vapor.temperature;
+        let pressure = vapor.pressure(Contributions::Total);
+        vapor = State::new_npt(
+            &vapor.eos,
+            temperature,
+            pressure,
+            &Moles::from_reduced(&y / y.sum()),
+            InitialDensity(vapor.density),
+        )?;
+        liquid = State::new_npt(
+            &liquid.eos,
+            temperature,
+            pressure,
+            &Moles::from_reduced(&x / x.sum()),
+            InitialDensity(liquid.density),
+        )?;
+
+        if PhaseEquilibrium::is_trivial_solution(&vapor, &liquid) {
+            log_iter!(verbosity, "Trivial solution encountered!");
+            return Err(EosError::TrivialSolution);
+        }
+    }
+    Err(EosError::NotConverged(String::from("vapor fraction flash")))
+}
+
 fn adjust_states<E: Residual>(
     temperature: Temperature,
     pressure: Pressure,
@@ -778,7 +992,16 @@ fn adjust_x2<E: Residual>(
     let ln_phi_1 = state1.ln_phi();
     let ln_phi_2 = state2.ln_phi();
     let k = (ln_phi_1 - ln_phi_2).mapv(f64::exp);
-    let err_out = (&k * x1 / &state2.molefracs - 1.0).mapv(f64::abs).sum();
+    let err_out = Zip::from(&k)
+        .and(x1)
+        .and(&state2.molefracs)
+        .fold(0.0, |acc, &k, &x1, &x2| {
+            if x1 > 0.0 {
+                acc + (k * x1 / x2 - 1.0).abs()
+            } else {
+                acc
+            }
+        });
     let x2 = (x1 * &k) / (&k * x1).sum();
     log_iter!(verbosity, "{:<14.8e} | {:14} | {:16} |", err_out, "", "");
     *state2 = State::new_npt(
@@ -790,3 +1013,22 @@ fn adjust_x2<E: Residual>(
     )?;
     Ok(err_out)
 }
+
+/// Keep the partial densities of components that are absent from the
+/// specified phase at zero during a Newton step.
+///
+/// The ideal gas chemical potential of an absent component diverges, so the
+/// corresponding residual, row and column of the Jacobian are replaced by
+/// the trivial equation for an unchanged partial density.
+fn fix_absent_components(
+    molefracs: &Array1<f64>,
+    res: &mut Array1<f64>,
+    jacobian: &mut Array2<f64>,
+) {
+    for (i, _) in molefracs.iter().enumerate().filter(|(_, &x)| x == 0.0) {
+        res[i] = 0.0;
+        jacobian.row_mut(i).fill(0.0);
+        jacobian.column_mut(i).fill(0.0);
+        jacobian[(i, i)] = 1.0;
+    }
+}
@@ -0,0 +1,385 @@
+use super::{FlashResult, PhaseEquilibrium};
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::si::{MolarEnergy, Moles, Pressure, Temperature, BAR, KELVIN, RGAS};
+use crate::state::{DensityInitialization, State};
+use crate::{Contributions, SolverOptions, Verbosity};
+use ndarray::{Array1, Array2, Axis};
+use num_dual::linalg::{norm, LU};
+use num_dual::{Dual64, DualNum};
+use std::sync::Arc;
+
+const MAX_ITER_REACTION: usize = 50;
+const TOL_REACTION: f64 = 1e-8;
+const STEP_TO_BOUNDARY: f64 = 0.9;
+const REFERENCE_TEMPERATURE: f64 = 298.15;
+
+/// A set of independent chemical reactions together with the standard
+/// formation properties of all components.
+///
+/// The standard state of every component is the ideal gas at the
+/// reference temperature of 298.15 K and a pressure of 1 bar. The
+/// temperature dependence of the standard chemical potentials follows
+/// from the ideal gas model of the equation of state.
+#[derive(Clone, Debug)]
+pub struct ChemicalReactions {
+    stoichiometric_matrix: Array2<f64>,
+    gibbs_energy_of_formation: MolarEnergy<Array1<f64>>,
+    enthalpy_of_formation: MolarEnergy<Array1<f64>>,
+}
+
+impl ChemicalReactions {
+    /// Create a set of reactions from the stoichiometric coefficients
+    /// (reactions × components, positive for products) and the standard
+    /// molar Gibbs energies and enthalpies of formation of all components.
+    pub fn new(
+        stoichiometric_matrix: Array2<f64>,
+        gibbs_energy_of_formation: MolarEnergy<Array1<f64>>,
+        enthalpy_of_formation: MolarEnergy<Array1<f64>>,
+    ) -> EosResult<Self> {
+        let n = stoichiometric_matrix.ncols();
+        for len in [gibbs_energy_of_formation.len(), enthalpy_of_formation.len()] {
+            if len != n {
+                return Err(EosError::IncompatibleComponents(n, len));
+            }
+        }
+        if stoichiometric_matrix.nrows() == 0 {
+            return Err(EosError::Error(
+                "At least one chemical reaction has to be specified.".into(),
+            ));
+        }
+        Ok(Self {
+            stoichiometric_matrix,
+            gibbs_energy_of_formation,
+            enthalpy_of_formation,
+        })
+    }
+
+    /// Create a set of independent reactions from the element balance.
+    ///
+    /// The `element_matrix` (elements × components) contains the number of
+    /// atoms of each element in the components. The stoichiometric matrix is
+    /// determined as a basis of its null space, i.e., every reaction that
+    /// conserves all elements is a linear combination of these reactions.
+    pub fn from_element_matrix(
+        element_matrix: &Array2<f64>,
+        gibbs_energy_of_formation: MolarEnergy<Array1<f64>>,
+        enthalpy_of_formation: MolarEnergy<Array1<f64>>,
+    ) -> EosResult<Self> {
+        Self::new(
+            null_space(element_matrix),
+            gibbs_energy_of_formation,
+            enthalpy_of_formation,
+        )
+    }
+
+    /// Return the number of independent reactions.
+    pub fn reactions(&self) -> usize {
+        self.stoichiometric_matrix.nrows()
+    }
+
+    /// Return the stoichiometric matrix (reactions × components).
+    pub fn stoichiometric_matrix(&self) -> &Array2<f64> {
+        &self.stoichiometric_matrix
+    }
+
+    /// Standard chemical potentials $\frac{\mu_i^0(T)}{RT}$ of all components.
+    ///
+    /// The chemical potentials of the ideal gas model are shifted by a
+    /// linear function of temperature for each component, so that the
+    /// Gibbs energy and enthalpy of formation are matched at the reference
+    /// temperature.
+    fn standard_chemical_potential<E: IdealGas>(
+        &self,
+        eos: &E,
+        temperature: Temperature,
+    ) -> Array1<f64> {
+        let p0 = BAR.to_reduced();
+        let t0 = (REFERENCE_TEMPERATURE * KELVIN).to_reduced();
+        let t = temperature.to_reduced();
+
+        // chemical potential of the ideal gas model at standard pressure
+        let mu_ig = |t: Dual64| eos.ln_lambda3(t).mapv(|l| l + (t.recip() * p0).ln());
+        let mu_ig_0 = mu_ig(Dual64::from(t0).derivative());
+        let g0 = mu_ig_0.mapv(|m| m.re);
+        let h0 = mu_ig_0.mapv(|m| -m.eps * t0);
+        let g = mu_ig(Dual64::from(t)).mapv(|m| m.re);
+
+        let rt0 = RGAS * REFERENCE_TEMPERATURE * KELVIN;
+        let g_f = (&self.gibbs_energy_of_formation / rt0).into_value();
+        let h_f = (&self.enthalpy_of_formation / rt0).into_value();
+        g + (&h_f - &h0) * (t0 / t) + g_f - g0 - h_f + h0
+    }
+
+    /// Logarithm of the equilibrium constants $\ln K_k=-\sum_i\nu_{ki}\frac{\mu_i^0(T)}{RT}$
+    /// of all reactions at the given temperature.
+    pub fn ln_equilibrium_constant<E: IdealGas>(
+        &self,
+        eos: &E,
+        temperature: Temperature,
+    ) -> Array1<f64> {
+        -self
+            .stoichiometric_matrix
+            .dot(&self.standard_chemical_potential(eos, temperature))
+    }
+
+    /// Newton iteration in the extents of reaction.
+    ///
+    /// The `equilibrate` closure determines the (phase) equilibrium for the given
+    /// overall composition and returns the logarithms of the fugacities in units
+    /// of the standard pressure together with their derivatives w.r.t. the
+    /// overall moles.
+    fn reaction_iteration<R, F>(
+        &self,
+        ln_k: &Array1<f64>,
+        feed: &Array1<f64>,
+        options: SolverOptions,
+        mut equilibrate: F,
+    ) -> EosResult<R>
+    where
+        F: FnMut(&Array1<f64>) -> EosResult<(R, Array1<f64>, Array2<f64>)>,
+    {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_REACTION, TOL_REACTION);
+        let nu = &self.stoichiometric_matrix;
+        let mut moles = self.initial_moles(feed)?;
+
+        log_iter!(verbosity, " iter |    residual    |    step   ");
+        log_iter!(verbosity, "{:-<33}", "");
+        for iter in 1..=max_iter {
+            let (result, ln_f, dln_f_dn) = equilibrate(&moles)?;
+            let f = nu.dot(&ln_f) - ln_k;
+            let res = norm(&f);
+            if res < tol {
+                log_result!(
+                    verbosity,
+                    "Reaction equilibrium: calculation converged in {} step(s)\n",
+                    iter
+                );
+                return Ok(result);
+            }
+
+            // Newton step for the extents of reaction
+            let jacobian = nu.dot(&dln_f_dn).dot(&nu.t());
+            let dxi = LU::new(jacobian)?.solve(&f);
+            let dn = -nu.t().dot(&dxi);
+
+            // keep all mole numbers positive
+            let alpha = dn
+                .iter()
+                .zip(moles.iter())
+                .filter(|(&dn, _)| dn < 0.0)
+                .map(|(&dn, &n)| -STEP_TO_BOUNDARY * n / dn)
+                .fold(1.0, f64::min);
+            log_iter!(verbosity, " {:4} | {:14.8e} | {:9.6}", iter, res, alpha);
+            moles += &(dn * alpha);
+        }
+        Err(EosError::NotConverged("reaction equilibrium".to_owned()))
+    }
+
+    /// Determine initial mole numbers in which all components that participate
+    /// in any reaction are present.
+    fn initial_moles(&self, feed: &Array1<f64>) -> EosResult<Array1<f64>> {
+        let nu = &self.stoichiometric_matrix;
+        let mut moles = feed.clone();
+        // Every reaction is moved to the center of its feasible interval. A
+        // second sweep is required if a reactant is only produced by a later
+        // reaction.
+        for _ in 0..2 {
+            for nu_k in nu.outer_iter() {
+                let (mut lower, mut upper) = (f64::NEG_INFINITY, f64::INFINITY);
+                for (&nu_ki, &n) in nu_k.iter().zip(moles.iter()) {
+                    if nu_ki > 0.0 {
+                        lower = lower.max(-n / nu_ki);
+                    } else if nu_ki < 0.0 {
+                        upper = upper.min(-n / nu_ki);
+                    }
+                }
+                if lower.is_finite() && upper.is_finite() && lower < upper {
+                    moles.scaled_add(0.5 * (lower + upper), &nu_k);
+                }
+            }
+        }
+        let participating = nu.map_axis(Axis(0), |nu_i| nu_i.iter().any(|&nu| nu != 0.0));
+        if moles
+            .iter()
+            .zip(participating.iter())
+            .any(|(&n, &p)| p && n <= 0.0)
+        {
+            return Err(EosError::Error(
+                "The reactions can not proceed for the given feed.".into(),
+            ));
+        }
+        Ok(moles)
+    }
+}
+
+impl std::fmt::Display for ChemicalReactions {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "ChemicalReactions(reactions={}, components={})",
+            self.reactions(),
+            self.stoichiometric_matrix.ncols()
+        )
+    }
+}
+
+/// Logarithms of the fugacities in units of the standard pressure and
+/// their derivatives w.r.t. the mole numbers at constant temperature and pressure.
+fn ln_fugacity<E: Residual>(state: &State<E>) -> (Array1<f64>, Array2<f64>) {
+    let moles = state.moles.to_reduced();
+    let ln_p = (state.pressure(Contributions::Total) / BAR)
+        .into_value()
+        .ln();
+    let ln_phi = state.ln_phi();
+    let ln_f = Array1::from_shape_fn(moles.len(), |i| {
+        if moles[i] > 0.0 {
+            (moles[i] / moles.sum()).ln() + ln_phi[i] + ln_p
+        } else {
+            0.0
+        }
+    });
+    let mut dln_f_dn =
+        (state.dln_phi_dnj() * Moles::from_reduced(1.0)).into_value() - moles.sum().recip();
+    for (i, &n) in moles.iter().enumerate() {
+        if n > 0.0 {
+            dln_f_dn[(i, i)] += n.recip();
+        }
+    }
+    (ln_f, dln_f_dn)
+}
+
+/// Basis of the null space of a matrix calculated from its
+/// reduced row echelon form.
+fn null_space(matrix: &Array2<f64>) -> Array2<f64> {
+    let (rows, cols) = matrix.dim();
+    let mut a = matrix.clone();
+    let mut pivots = Vec::new();
+    let mut r = 0;
+    for c in 0..cols {
+        if r == rows {
+            break;
+        }
+        let p = (r..rows)
+            .max_by(|&i, &j| a[(i, c)].abs().total_cmp(&a[(j, c)].abs()))
+            .unwrap();
+        if a[(p, c)].abs() < 1e-12 {
+            continue;
+        }
+        for k in 0..cols {
+            a.swap((r, k), (p, k));
+        }
+        let pivot = a[(r, c)];
+        a.row_mut(r).mapv_inplace(|x| x / pivot);
+        let row = a.row(r).to_owned();
+        for i in (0..rows).filter(|&i| i != r) {
+            let factor = a[(i, c)];
+            a.row_mut(i).scaled_add(-factor, &row);
+        }
+        pivots.push(c);
+        r += 1;
+    }
+
+    let free: Vec<_> = (0..cols).filter(|c| !pivots.contains(c)).collect();
+    let mut basis = Array2::zeros((free.len(), cols));
+    for (k, &f) in free.iter().enumerate() {
+        basis[(k, f)] = 1.0;
+        for (i, &p) in pivots.iter().enumerate() {
+            basis[(k, p)] = -a[(i, f)];
+        }
+    }
+    basis
+}
+
+/// # Chemical equilibria
+impl<E: Residual + IdealGas> State<E> {
+    /// Calculate the chemical equilibrium of a single phase at given
+    /// temperature and pressure.
+    ///
+    /// The density of the phase is determined from `density_initialization`
+    /// in every iteration, which can be used to specify a liquid or vapor phase.
+    pub fn reaction_equilibrium(
+        eos: &Arc<E>,
+        reactions: &ChemicalReactions,
+        temperature: Temperature,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        density_initialization: DensityInitialization,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let ln_k = reactions.ln_equilibrium_constant(&**eos, temperature);
+        reactions.reaction_iteration(&ln_k, &feed.to_reduced(), options, |moles| {
+            let state = State::new_npt(
+                eos,
+                temperature,
+                pressure,
+                &Moles::from_reduced(moles.clone()),
+                density_initialization,
+            )?;
+            let (ln_f, dln_f_dn) = ln_fugacity(&state);
+            Ok((state, ln_f, dln_f_dn))
+        })
+    }
+}
+
+/// # Chemical equilibria
+impl<E: Residual + IdealGas> FlashResult<E> {
+    /// Calculate the simultaneous phase and chemical equilibrium at given
+    /// temperature and pressure.
+    ///
+    /// For every composition in the iteration of the extents of reaction, the
+    /// phase split is determined with a [Tp-flash](State::tp_flash).
+    /// The `options` are used for the (inner) Tp-flash and the (outer)
+    /// iteration of the extents of reaction, respectively.
+    pub fn reactive_flash(
+        eos: &Arc<E>,
+        reactions: &ChemicalReactions,
+        temperature: Temperature,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        options: (SolverOptions, SolverOptions),
+    ) -> EosResult<Self> {
+        let (options_inner, options_outer) = options;
+        let ln_k = reactions.ln_equilibrium_constant(&**eos, temperature);
+        let mut previous: Option<PhaseEquilibrium<E, 2>> = None;
+        reactions.reaction_iteration(&ln_k, &feed.to_reduced(), options_outer, |moles| {
+            let feed_state = State::new_npt(
+                eos,
+                temperature,
+                pressure,
+                &Moles::from_reduced(moles.clone()),
+                DensityInitialization::None,
+            )?;
+
+            // the phase split of the previous iteration is adapted
+            // to the new overall composition
+            let initial_state = previous.take().and_then(|mut vle| {
+                let k = (vle.liquid().ln_phi() - vle.vapor().ln_phi()).mapv(f64::exp);
+                vle.update_states(&feed_state, &k).ok().map(|_| vle)
+            });
+            let result = match feed_state.tp_flash(initial_state.as_ref(), options_inner, None) {
+                Ok(vle) => Self::TwoPhase(vle),
+                Err(EosError::NoPhaseSplit) => Self::SinglePhase(feed_state),
+                Err(e) => return Err(e),
+            };
+            let (ln_f, dln_f_dn) = match &result {
+                Self::SinglePhase(state) => ln_fugacity(state),
+                Self::TwoPhase(vle) => {
+                    // The overall composition is distributed among both phases,
+                    // such that the chemical potentials stay equal:
+                    // dmu = H_l (H_l + H_v)^-1 H_v dn
+                    let (ln_f, h_v) = ln_fugacity(vle.vapor());
+                    let (_, h_l) = ln_fugacity(vle.liquid());
+                    let lu = LU::new(&h_l + &h_v)?;
+                    let mut x = Array2::zeros(h_v.raw_dim());
+                    for (mut x, h) in x.columns_mut().into_iter().zip(h_v.columns()) {
+                        x.assign(&lu.solve(&h.to_owned()));
+                    }
+                    previous = Some(vle.clone());
+                    (ln_f, h_l.dot(&x))
+                }
+            };
+            Ok((result, ln_f, dln_f_dn))
+        })
+    }
+}
@@ -0,0 +1,569 @@
+use super::tp_flash::rachford_rice;
+use super::{PhaseEquilibrium, TemperatureOrPressure};
+use crate::equation_of_state::{ExcessGibbsEnergy, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::si::{Moles, Pressure, Temperature};
+use crate::state::{DensityInitialization, State, TPSpec};
+use crate::{SolverOptions, Verbosity};
+use ndarray::{arr1, Array1, Zip};
+use std::sync::Arc;
+
+const MAX_ITER_GAMMA_PHI: usize = 100;
+const TOL_GAMMA_PHI: f64 = 1e-10;
+const MAX_INVERSE_TEMPERATURE_STEP: f64 = 0.1;
+const DEFAULT_TEMPERATURE: f64 = 300.0;
+
+/// Pure component vapor pressures used in gamma-phi phase equilibrium calculations.
+pub trait VaporPressureModel {
+    /// Return the vapor pressures of all components at the given temperature.
+    fn vapor_pressures(&self, temperature: Temperature) -> EosResult<Pressure<Array1<f64>>>;
+}
+
+/// Vapor pressures calculated from pure component phase equilibria of a [Residual] model.
+impl<E: Residual> VaporPressureModel for Arc<E> {
+    fn vapor_pressures(&self, temperature: Temperature) -> EosResult<Pressure<Array1<f64>>> {
+        let p_sat = PhaseEquilibrium::vapor_pressure(self, temperature)
+            .into_iter()
+            .map(|p| p.map(|p| p.to_reduced()).ok_or(EosError::SuperCritical))
+            .collect::<EosResult<Array1<f64>>>()?;
+        Ok(Pressure::from_reduced(p_sat))
+    }
+}
+
+/// Ingredients of the K-values $K_i=\frac{\gamma_i p_i^\mathrm{sat}\varphi_i^\mathrm{sat}}{\varphi_i^Vp}$.
+struct GammaPhi<'a, E, G, P: ?Sized> {
+    eos: &'a Arc<E>,
+    pure_eos: Vec<Arc<E>>,
+    activity_model: &'a Arc<G>,
+    vapor_pressure: &'a P,
+}
+
+impl<'a, E: Residual, G: ExcessGibbsEnergy, P: VaporPressureModel + ?Sized> GammaPhi<'a, E, G, P> {
+    fn new(eos: &'a Arc<E>, activity_model: &'a Arc<G>, vapor_pressure: &'a P) -> EosResult<Self> {
+        let n = eos.components();
+        if activity_model.components() != n {
+            return Err(EosError::IncompatibleComponents(
+                n,
+                activity_model.components(),
+            ));
+        }
+        let pure_eos = (0..n).map(|i| Arc::new(eos.subset(&[i]))).collect();
+        Ok(Self {
+            eos,
+            pure_eos,
+            activity_model,
+            vapor_pressure,
+        })
+    }
+
+    /// Vapor pressures in reduced units and the logarithms of the
+    /// fugacity coefficients of the saturated vapors.
+    fn saturation(&self, temperature: f64) -> EosResult<(Array1<f64>, Array1<f64>)> {
+        let t = Temperature::from_reduced(temperature);
+        let p_sat = self.vapor_pressure.vapor_pressures(t)?;
+        let ln_phi_sat = self
+            .pure_eos
+            .iter()
+            .zip(p_sat.to_reduced().iter())
+            .map(|(eos, &p)| {
+                let moles = Moles::from_reduced(arr1(&[1.0]));
+                let p = Pressure::from_reduced(p);
+                State::new_npt(eos, t, p, &moles, DensityInitialization::Vapor)
+                    .map(|s| s.ln_phi()[0])
+            })
+            .collect::<EosResult<_>>()?;
+        Ok((p_sat.to_reduced(), ln_phi_sat))
+    }
+
+    /// Logarithms of the fugacity coefficients of the vapor phase.
+    fn ln_phi_vapor(
+        &self,
+        temperature: f64,
+        pressure: f64,
+        y: &Array1<f64>,
+    ) -> EosResult<Array1<f64>> {
+        let state = State::new_npt(
+            self.eos,
+            Temperature::from_reduced(temperature),
+            Pressure::from_reduced(pressure),
+            &Moles::from_reduced(y.clone()),
+            DensityInitialization::Vapor,
+        )?;
+        Ok(state.ln_phi())
+    }
+
+    /// The products $K_ip$ in reduced units.
+    fn k_times_p(
+        &self,
+        temperature: f64,
+        pressure: Option<f64>,
+        saturation: &(Array1<f64>, Array1<f64>),
+        x: &Array1<f64>,
+        y: &Array1<f64>,
+    ) -> EosResult<Array1<f64>> {
+        let (p_sat, ln_phi_sat) = saturation;
+        let ln_gamma = self.activity_model.ln_activity_coefficients(temperature, x);
+        let ln_phi_v = match pressure {
+            Some(p) => self.ln_phi_vapor(temperature, p, y)?,
+            None => Array1::zeros(x.len()),
+        };
+        Ok((ln_gamma + ln_phi_sat - ln_phi_v).mapv(f64::exp) * p_sat)
+    }
+
+    /// The temperature derivatives $-\frac{\partial\ln p_i^\mathrm{sat}}{\partial(1/T)}$.
+    fn clausius_clapeyron(&self, temperature: f64, p_sat: &Array1<f64>) -> EosResult<Array1<f64>> {
+        let t2 = temperature * (1.0 + 1e-5);
+        let p_sat2 = self
+            .vapor_pressure
+            .vapor_pressures(Temperature::from_reduced(t2))?
+            .to_reduced();
+        Ok((p_sat2 / p_sat).mapv(f64::ln) / (1.0 / temperature - 1.0 / t2))
+    }
+}
+
+/// # Gamma-phi phase equilibria
+impl<E: Residual> PhaseEquilibrium<E, 2> {
+    /// Calculate a bubble point with the gamma-phi approach for given
+    /// temperature or pressure and composition of the liquid phase.
+    ///
+    /// The liquid phase is described by the `activity_model` and the vapor
+    /// phase by the equation of state `eos`:
+    /// $$y_i\varphi_i^Vp=x_i\gamma_ip_i^\mathrm{sat}\varphi_i^\mathrm{sat}$$
+    /// The Poynting correction is neglected. The states of the resulting
+    /// [PhaseEquilibrium] are evaluated with `eos` at the equilibrium temperature,
+    /// pressure and compositions, i.e., only the vapor phase properties are
+    /// consistent with the gamma-phi model.
+    pub fn bubble_point_gamma_phi<TP, G, P>(
+        eos: &Arc<E>,
+        activity_model: &Arc<G>,
+        vapor_pressure: &P,
+        temperature_or_pressure: TP,
+        liquid_molefracs: &Array1<f64>,
+        tp_init: Option<TP::Other>,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        TP: TemperatureOrPressure,
+        G: ExcessGibbsEnergy,
+        P: VaporPressureModel + ?Sized,
+    {
+        Self::bubble_dew_point_gamma_phi(
+            eos,
+            activity_model,
+            vapor_pressure,
+            temperature_or_pressure.into(),
+            tp_init.map(Into::into),
+            liquid_molefracs,
+            true,
+            options,
+        )
+    }
+
+    /// Calculate a dew point with the gamma-phi approach for given
+    /// temperature or pressure and composition of the vapor phase.
+    ///
+    /// See [PhaseEquilibrium::bubble_point_gamma_phi] for details on the model.
+    pub fn dew_point_gamma_phi<TP, G, P>(
+        eos: &Arc<E>,
+        activity_model: &Arc<G>,
+        vapor_pressure: &P,
+        temperature_or_pressure: TP,
+        vapor_molefracs: &Array1<f64>,
+        tp_init: Option<TP::Other>,
+        options: SolverOptions,
+    ) -> EosResult<Self>
+    where
+        TP: TemperatureOrPressure,
+        G: ExcessGibbsEnergy,
+        P: VaporPressureModel + ?Sized,
+    {
+        Self::bubble_dew_point_gamma_phi(
+            eos,
+            activity_model,
+            vapor_pressure,
+            temperature_or_pressure.into(),
+            tp_init.map(Into::into),
+            vapor_molefracs,
+            false,
+            options,
+        )
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn bubble_dew_point_gamma_phi<G: ExcessGibbsEnergy, P: VaporPressureModel + ?Sized>(
+        eos: &Arc<E>,
+        activity_model: &Arc<G>,
+        vapor_pressure: &P,
+        tp_spec: TPSpec,
+        tp_init: Option<TPSpec>,
+        molefracs_spec: &Array1<f64>,
+        bubble: bool,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_GAMMA_PHI, TOL_GAMMA_PHI);
+        let model = GammaPhi::new(eos, activity_model, vapor_pressure)?;
+        if molefracs_spec.len() != eos.components() {
+            return Err(EosError::IncompatibleComponents(
+                eos.components(),
+                molefracs_spec.len(),
+            ));
+        }
+
+        let (mut t, mut p) = match (tp_spec, tp_init) {
+            (TPSpec::Temperature(t), Some(TPSpec::Pressure(p))) => {
+                (t.to_reduced(), Some(p.to_reduced()))
+            }
+            (TPSpec::Temperature(t), _) => (t.to_reduced(), None),
+            (TPSpec::Pressure(p), Some(TPSpec::Temperature(t))) => {
+                (t.to_reduced(), Some(p.to_reduced()))
+            }
+            (TPSpec::Pressure(p), _) => (DEFAULT_TEMPERATURE, Some(p.to_reduced())),
+        };
+        let mut molefracs = molefracs_spec.clone();
+
+        log_iter!(
+            verbosity,
+            " iter |    residual    |  temperature  |    pressure    "
+        );
+        log_iter!(verbosity, "{:-<60}", "");
+        for iter in 1..=max_iter {
+            let saturation = model.saturation(t)?;
+            let (x, y) = if bubble {
+                (molefracs_spec, &molefracs)
+            } else {
+                (&molefracs, molefracs_spec)
+            };
+            let kp = model.k_times_p(t, p, &saturation, x, y)?;
+
+            // Bubble point: sum(x*K) = 1, dew point: sum(y/K) = 1
+            let (s, weights) = match p {
+                Some(p) if bubble => {
+                    let w = x * &kp / p;
+                    (w.sum(), w)
+                }
+                Some(p) => {
+                    let w = y / &kp * p;
+                    (w.sum(), w)
+                }
+                None if bubble => (1.0, x * &kp),
+                None => (1.0, y / &kp),
+            };
+            let mut res = if bubble { s.ln() } else { -s.ln() };
+            let molefracs_new = &weights / weights.sum();
+            let dx = (&molefracs_new - &molefracs)
+                .mapv(f64::abs)
+                .fold(0.0, |a: f64, &b| a.max(b));
+            molefracs = molefracs_new;
+
+            match tp_spec {
+                TPSpec::Temperature(_) => {
+                    // K is inversely proportional to the pressure
+                    let p_new = match p {
+                        None if bubble => weights.sum(),
+                        None => 1.0 / weights.sum(),
+                        Some(p) => p * res.exp(),
+                    };
+                    if p.is_none() {
+                        res = 1.0;
+                    }
+                    p = Some(p_new);
+                }
+                TPSpec::Pressure(_) => {
+                    // ln K is approximately linear in 1/T
+                    let b = model.clausius_clapeyron(t, &saturation.0)?;
+                    let b_mean = (&b * &weights).sum() / weights.sum();
+                    let step = (res / b_mean).clamp(
+                        -MAX_INVERSE_TEMPERATURE_STEP / t,
+                        MAX_INVERSE_TEMPERATURE_STEP / t,
+                    );
+                    t = 1.0 / (1.0 / t + step);
+                }
+            }
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:13.5} | {:14.8}",
+                iter,
+                res.abs(),
+                Temperature::from_reduced(t),
+                Pressure::from_reduced(p.unwrap())
+            );
+
+            if res.abs() < tol && dx < tol {
+                log_result!(
+                    verbosity,
+                    "Gamma-phi {} point: calculation converged in {} step(s)\n",
+                    if bubble { "bubble" } else { "dew" },
+                    iter
+                );
+                let (x, y) = if bubble {
+                    (molefracs_spec, &molefracs)
+                } else {
+                    (&molefracs, molefracs_spec)
+                };
+                return Self::new_npt(
+                    eos,
+                    Temperature::from_reduced(t),
+                    Pressure::from_reduced(p.unwrap()),
+                    &Moles::from_reduced(y.clone()),
+                    &Moles::from_reduced(x.clone()),
+                );
+            }
+        }
+        Err(EosError::NotConverged(String::from(if bubble {
+            "bubble_point_gamma_phi"
+        } else {
+            "dew_point_gamma_phi"
+        })))
+    }
+
+    /// Perform a Tp-flash calculation with the gamma-phi approach.
+    ///
+    /// See [PhaseEquilibrium::bubble_point_gamma_phi] for details on the model.
+    /// If the feed does not split into a vapor and a liquid phase,
+    /// [EosError::NoPhaseSplit] is returned.
+    pub fn tp_flash_gamma_phi<G: ExcessGibbsEnergy, P: VaporPressureModel + ?Sized>(
+        eos: &Arc<E>,
+        activity_model: &Arc<G>,
+        vapor_pressure: &P,
+        temperature: Temperature,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_GAMMA_PHI, TOL_GAMMA_PHI);
+        let model = GammaPhi::new(eos, activity_model, vapor_pressure)?;
+        let t = temperature.to_reduced();
+        let p = pressure.to_reduced();
+        let n = feed.to_reduced();
+        let z = &n / n.sum();
+
+        let saturation = model.saturation(t)?;
+        let mut k = model.k_times_p(t, None, &saturation, &z, &z)? / p;
+        let mut beta = None;
+
+        log_iter!(verbosity, " iter |    residual    | vapor fraction");
+        log_iter!(verbosity, "{:-<38}", "");
+        for iter in 1..=max_iter {
+            let b = rachford_rice(&z, &k, beta).map_err(|_| EosError::NoPhaseSplit)?;
+            beta = Some(b);
+            let x = &z / &(1.0 + b * (&k - 1.0));
+            let x = &x / x.sum();
+            let y = &x * &k;
+            let y = &y / y.sum();
+
+            let k_new = model.k_times_p(t, Some(p), &saturation, &x, &y)? / p;
+            let res = Zip::from(&k_new)
+                .and(&k)
+                .fold(0.0, |acc: f64, &k_new, &k| acc.max((k_new / k).ln().abs()));
+            k = k_new;
+            log_iter!(verbosity, " {:4} | {:14.8e} | {:14.8}", iter, res, b);
+
+            if res < tol {
+                log_result!(
+                    verbosity,
+                    "Gamma-phi Tp-flash: calculation converged in {} step(s)\n",
+                    iter
+                );
+                let n_total = n.sum();
+                return Self::new_npt(
+                    eos,
+                    temperature,
+                    pressure,
+                    &Moles::from_reduced(y * b * n_total),
+                    &Moles::from_reduced(x * (1.0 - b) * n_total),
+                );
+            }
+        }
+        Err(EosError::NotConverged(String::from("tp_flash_gamma_phi")))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::activity::margules::{propane_butane, Margules};
+    use crate::activity::{VaporPressureCorrelation, VaporPressureRecord};
+    use crate::cubic::PengRobinson;
+    use crate::parameter::{Identifier, Parameter, PureRecord};
+    use crate::si::{BAR, KELVIN, MOL};
+    use crate::{Components, Contributions};
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, arr2, Array2};
+
+    fn eos() -> Arc<PengRobinson> {
+        Arc::new(PengRobinson::new(propane_butane()))
+    }
+
+    fn margules() -> Arc<Margules> {
+        Arc::new(Margules(arr2(&[[0.0, 0.8], [0.8, 0.0]])))
+    }
+
+    #[test]
+    fn vapor_pressures() -> EosResult<()> {
+        let eos = eos();
+        let p_sat = eos.vapor_pressures(300.0 * KELVIN)?;
+        let p_propane = PhaseEquilibrium::pure(
+            &eos.subset(&[0]).into(),
+            300.0 * KELVIN,
+            None,
+            Default::default(),
+        )?
+        .vapor()
+        .pressure(Contributions::Total);
+        assert_relative_eq!(p_sat.get(0), p_propane, max_relative = 1e-8);
+        assert!(eos.vapor_pressures(400.0 * KELVIN).is_err());
+        Ok(())
+    }
+
+    #[test]
+    fn ideal_solution() -> EosResult<()> {
+        // Without excess Gibbs energy, K-values follow from the fugacity
+        // coefficients of the equation of state only.
+        let eos = eos();
+        let ideal = Arc::new(Margules(Array2::zeros([2, 2])));
+        let x = arr1(&[0.3, 0.7]);
+        let vle = PhaseEquilibrium::bubble_point_gamma_phi(
+            &eos,
+            &ideal,
+            &eos,
+            300.0 * KELVIN,
+            &x,
+            None,
+            Default::default(),
+        )?;
+        let p_sat = eos.vapor_pressures(300.0 * KELVIN)?;
+        let p_raoult = p_sat.get(0) * x[0] + p_sat.get(1) * x[1];
+        assert_relative_eq!(
+            vle.vapor().pressure(Contributions::Total),
+            p_raoult,
+            max_relative = 0.05
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn vapor_pressure_correlation() -> EosResult<()> {
+        // propane and n-butane from the NIST Chemistry WebBook
+        let records = vec![
+            PureRecord::new(
+                Identifier::default(),
+                44.0962,
+                VaporPressureRecord::antoine(4.53678, 1149.36, 24.906),
+            ),
+            PureRecord::new(
+                Identifier::default(),
+                58.123,
+                VaporPressureRecord::antoine(4.35576, 1175.581, -2.071),
+            ),
+        ];
+        let correlation = VaporPressureCorrelation::from_records(records, None).unwrap();
+        let eos = eos();
+        let ideal = Arc::new(Margules(Array2::zeros([2, 2])));
+        let x = arr1(&[0.3, 0.7]);
+        let t = 260.0 * KELVIN;
+        let vle = PhaseEquilibrium::bubble_point_gamma_phi(
+            &eos,
+            &ideal,
+            &correlation,
+            t,
+            &x,
+            None,
+            Default::default(),
+        )?;
+        let p_sat = correlation.vapor_pressures(t)?;
+        let p_raoult = p_sat.get(0) * x[0] + p_sat.get(1) * x[1];
+        let p = vle.vapor().pressure(Contributions::Total);
+        assert_relative_eq!(p, p_raoult, max_relative = 0.05);
+
+        let vle = PhaseEquilibrium::bubble_point_gamma_phi(
+            &eos,
+            &ideal,
+            &correlation,
+            p,
+            &x,
+            None,
+            Default::default(),
+        )?;
+        assert_relative_eq!(vle.vapor().temperature, t, max_relative = 1e-8);
+        Ok(())
+    }
+
+    #[test]
+    fn bubble_dew_point() -> EosResult<()> {
+        let eos = eos();
+        let activity = margules();
+        let options = SolverOptions::default();
+        let x = arr1(&[0.4, 0.6]);
+        let t = 300.0 * KELVIN;
+        let bubble =
+            PhaseEquilibrium::bubble_point_gamma_phi(&eos, &activity, &eos, t, &x, None, options)?;
+        let p = bubble.vapor().pressure(Contributions::Total);
+        let y = bubble.vapor().molefracs.clone();
+        assert_relative_eq!(bubble.liquid().molefracs, x, epsilon = 1e-12);
+        assert!(y[0] > x[0]);
+
+        // Bubble point for given pressure reproduces the temperature
+        let bubble_p =
+            PhaseEquilibrium::bubble_point_gamma_phi(&eos, &activity, &eos, p, &x, None, options)?;
+        assert_relative_eq!(bubble_p.vapor().temperature, t, max_relative = 1e-8);
+        assert_relative_eq!(bubble_p.vapor().molefracs, y, epsilon = 1e-8);
+
+        // Dew points of the incipient vapor phase reproduce the bubble point
+        let dew =
+            PhaseEquilibrium::dew_point_gamma_phi(&eos, &activity, &eos, t, &y, None, options)?;
+        assert_relative_eq!(
+            dew.vapor().pressure(Contributions::Total),
+            p,
+            max_relative = 1e-8
+        );
+        assert_relative_eq!(dew.liquid().molefracs, x, epsilon = 1e-8);
+        let dew = PhaseEquilibrium::dew_point_gamma_phi(
+            &eos,
+            &activity,
+            &eos,
+            p,
+            &y,
+            Some(290.0 * KELVIN),
+            options,
+        )?;
+        assert_relative_eq!(dew.vapor().temperature, t, max_relative = 1e-8);
+        Ok(())
+    }
+
+    #[test]
+    fn tp_flash() -> EosResult<()> {
+        let eos = eos();
+        let activity = margules();
+        let options = SolverOptions::default();
+        let t = 300.0 * KELVIN;
+        let z = arr1(&[0.4, 0.6]);
+        let bubble =
+            PhaseEquilibrium::bubble_point_gamma_phi(&eos, &activity, &eos, t, &z, None, options)?;
+        let dew =
+            PhaseEquilibrium::dew_point_gamma_phi(&eos, &activity, &eos, t, &z, None, options)?;
+        let p_bubble = bubble.vapor().pressure(Contributions::Total);
+        let p_dew = dew.vapor().pressure(Contributions::Total);
+        let p = 0.5 * (p_bubble + p_dew);
+
+        let feed = &z * MOL;
+        let vle =
+            PhaseEquilibrium::tp_flash_gamma_phi(&eos, &activity, &eos, t, p, &feed, options)?;
+        let moles = &vle.vapor().moles + &vle.liquid().moles;
+        assert_relative_eq!(moles, feed, max_relative = 1e-8);
+        assert!(vle.vapor().molefracs[0] > z[0]);
+        assert!(vle.liquid().molefracs[0] < z[0]);
+
+        // No phase split above the bubble point pressure
+        let vle = PhaseEquilibrium::tp_flash_gamma_phi(
+            &eos,
+            &activity,
+            &eos,
+            t,
+            p_bubble + 1.0 * BAR,
+            &feed,
+            options,
+        );
+        assert!(vle.is_err());
+        Ok(())
+    }
+}
@@ -9,6 +9,10 @@ use std::fmt::Write;
 use std::sync::Arc;
 
 mod bubble_dew;
+mod chemical_equilibrium;
+mod gamma_phi;
+mod multiphase_flash;
+mod ph_ps_flash;
 mod phase_diagram_binary;
 mod phase_diagram_pure;
 mod phase_envelope;
@@ -16,8 +20,13 @@ mod stability_analysis;
 mod tp_flash;
 mod vle_pure;
 pub use bubble_dew::TemperatureOrPressure;
+pub use chemical_equilibrium::ChemicalReactions;
+pub use gamma_phi::VaporPressureModel;
+pub use multiphase_flash::MultiPhaseEquilibrium;
+pub use ph_ps_flash::FlashResult;
 pub use phase_diagram_binary::PhaseDiagramHetero;
 pub use phase_diagram_pure::PhaseDiagram;
+pub use phase_envelope::PhaseEnvelope;
 
 /// A thermodynamic equilibrium state.
 ///
@@ -146,7 +155,8 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
         Ok(Self([vapor, liquid]))
     }
 
-    pub(super) fn vapor_phase_fraction(&self) -> f64 {
+    /// The molar vapor phase fraction $\beta=\frac{N^V}{N^V+N^L}$.
+    pub fn vapor_phase_fraction(&self) -> f64 {
         (self.vapor().total_moles / (self.vapor().total_moles + self.liquid().total_moles))
             .into_value()
     }
@@ -0,0 +1,459 @@
+use super::PhaseEquilibrium;
+use crate::equation_of_state::Residual;
+use crate::errors::{EosError, EosResult};
+use crate::si::{Moles, Pressure, Temperature};
+use crate::state::{Contributions, DensityInitialization, State, StateVec};
+use crate::{SolverOptions, Verbosity};
+use ndarray::*;
+use num_dual::linalg::{norm, LU};
+use std::fmt;
+use std::fmt::Write;
+use std::sync::Arc;
+
+const MAX_ITER_MULTIPHASE: usize = 1000;
+const TOL_MULTIPHASE: f64 = 1e-8;
+const MAX_ITER_RR: usize = 100;
+const TOL_RR: f64 = 1e-10;
+const MAX_LINE_SEARCH: usize = 30;
+const INITIAL_PHASE_FRACTION: f64 = 1e-2;
+
+/// A thermodynamic equilibrium state with an arbitrary number of phases.
+///
+/// In contrast to [PhaseEquilibrium], the number of phases is only known
+/// at runtime. The phases are sorted by increasing density, i.e., a vapor
+/// phase, if present, is always the first phase.
+#[derive(Debug)]
+pub struct MultiPhaseEquilibrium<E>(Vec<State<E>>);
+
+impl<E> Clone for MultiPhaseEquilibrium<E> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<E: Residual> fmt::Display for MultiPhaseEquilibrium<E> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let beta = self.phase_fractions();
+        for (i, s) in self.0.iter().enumerate() {
+            writeln!(f, "phase {} (β = {:.5}): {}", i, beta[i], s)?;
+        }
+        Ok(())
+    }
+}
+
+impl<E: Residual> MultiPhaseEquilibrium<E> {
+    fn new(mut states: Vec<State<E>>) -> Self {
+        states.sort_by(|s1, s2| s1.density.to_reduced().total_cmp(&s2.density.to_reduced()));
+        Self(states)
+    }
+
+    /// The individual phases sorted by increasing density.
+    pub fn phases(&self) -> StateVec<'_, E> {
+        self.0.iter().collect()
+    }
+
+    /// The number of phases in equilibrium.
+    pub fn number_of_phases(&self) -> usize {
+        self.0.len()
+    }
+
+    /// The temperature of the system.
+    pub fn temperature(&self) -> Temperature {
+        self.0[0].temperature
+    }
+
+    /// The pressure of the system.
+    pub fn pressure(&self) -> Pressure {
+        self.0[0].pressure(Contributions::Total)
+    }
+
+    /// The molar phase fractions $\beta_k=\frac{N^{(k)}}{N}$ of all phases.
+    pub fn phase_fractions(&self) -> Array1<f64> {
+        let n = Array1::from_iter(self.0.iter().map(|s| s.total_moles.to_reduced()));
+        &n / n.sum()
+    }
+
+    /// The total mole numbers summed over all phases.
+    pub fn total_moles(&self) -> Moles<Array1<f64>> {
+        self.0
+            .iter()
+            .skip(1)
+            .fold(self.0[0].moles.clone(), |acc, s| acc + &s.moles)
+    }
+
+    pub fn _repr_markdown_(&self) -> String {
+        let beta = self.phase_fractions();
+        let mut res = "||phase fraction|temperature|density|molefracs|\n|-|-|-|-|-|\n".to_string();
+        for (i, s) in self.0.iter().enumerate() {
+            writeln!(
+                res,
+                "|phase {}|{:.5}|{:.5}|{:.5}|{:.5}|",
+                i + 1,
+                beta[i],
+                s.temperature,
+                s.density,
+                s.molefracs
+            )
+            .unwrap();
+        }
+        res
+    }
+}
+
+impl<E, const N: usize> From<PhaseEquilibrium<E, N>> for MultiPhaseEquilibrium<E> {
+    fn from(vle: PhaseEquilibrium<E, N>) -> Self {
+        Self(vle.0.into())
+    }
+}
+
+impl<E, const N: usize> TryFrom<MultiPhaseEquilibrium<E>> for PhaseEquilibrium<E, N> {
+    type Error = EosError;
+
+    fn try_from(equilibrium: MultiPhaseEquilibrium<E>) -> EosResult<Self> {
+        let n = equilibrium.0.len();
+        let states = equilibrium.0.try_into().map_err(|_| {
+            EosError::Error(format!("Expected {N} phases in equilibrium, found {n}."))
+        })?;
+        Ok(Self(states))
+    }
+}
+
+/// # Flash calculations
+impl<E: Residual> MultiPhaseEquilibrium<E> {
+    /// Perform a multiphase Tp-flash calculation for an arbitrary
+    /// number of phases.
+    ///
+    /// See [State::multiphase_flash] for details on the algorithm.
+    pub fn tp_flash(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        options: SolverOptions,
+        non_volatile_components: Option<Vec<usize>>,
+    ) -> EosResult<Self> {
+        State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            feed,
+            DensityInitialization::None,
+        )?
+        .multiphase_flash(options, non_volatile_components)
+    }
+}
+
+/// # Flash calculations
+impl<E: Residual> State<E> {
+    /// Perform a multiphase Tp-flash calculation using the [State] as feed.
+    ///
+    /// Starting from the homogeneous feed, the number of phases is increased
+    /// step by step. In every stage, a stability analysis of the current
+    /// solution is performed and an unstable trial phase is added. The
+    /// resulting phase equilibrium is solved by successive substitution with
+    /// a multiphase Rachford-Rice (Michelsen, 1994) algorithm, in which phases
+    /// that vanish are removed. The calculation stops once the stability
+    /// analysis of the current solution finds no further phase split. If an
+    /// added phase vanishes again without changing the solution, the
+    /// calculation fails with [EosError::NotConverged].
+    ///
+    /// Non-volatile components (e.g. ions) are excluded from the phase
+    /// with the lowest density, analogous to [State::tp_flash].
+    pub fn multiphase_flash(
+        &self,
+        options: SolverOptions,
+        non_volatile_components: Option<Vec<usize>>,
+    ) -> EosResult<MultiPhaseEquilibrium<E>> {
+        let (max_iter, tol, verbosity) = options.unwrap_or(MAX_ITER_MULTIPHASE, TOL_MULTIPHASE);
+        let nvc = non_volatile_components.unwrap_or_default();
+
+        let mut phases = vec![self.clone()];
+        let mut beta = arr1(&[1.0]);
+        let mut iter = 0;
+
+        // The Gibbs phase rule limits the number of phases at given T and p.
+        while phases.len() < self.eos.components() {
+            let Some(trial) = unstable_trial_phase(&phases)? else {
+                break;
+            };
+            log_result!(
+                verbosity,
+                "Multiphase flash: adding phase {} with x = {:.5}\n",
+                phases.len() + 1,
+                trial.molefracs
+            );
+            let previous = phases.clone();
+            beta *= 1.0 - INITIAL_PHASE_FRACTION;
+            beta = concatenate![Axis(0), beta, arr1(&[INITIAL_PHASE_FRACTION])];
+            phases.push(trial);
+            self.multiphase_successive_substitution(
+                &mut phases,
+                &mut beta,
+                &mut iter,
+                max_iter,
+                tol,
+                verbosity,
+                &nvc,
+            )?;
+
+            // the phases did not change: the instability of the previous
+            // solution cannot be resolved by adding the trial phase
+            if same_phases(&phases, &previous) {
+                return Err(EosError::NotConverged(String::from(
+                    "multiphase flash: the added phase vanished again",
+                )));
+            }
+        }
+        log_result!(
+            verbosity,
+            "Multiphase flash: calculation converged in {} step(s) with {} phase(s)\n",
+            iter,
+            phases.len()
+        );
+        Ok(MultiPhaseEquilibrium::new(phases))
+    }
+
+    fn multiphase_successive_substitution(
+        &self,
+        phases: &mut Vec<State<E>>,
+        beta: &mut Array1<f64>,
+        iter: &mut usize,
+        max_iter: usize,
+        tol: f64,
+        verbosity: Verbosity,
+        non_volatile_components: &[usize],
+    ) -> EosResult<()> {
+        let z = &self.molefracs;
+        let n = self.eos.components();
+
+        log_iter!(verbosity, " iter |    residual    | phase fractions");
+        log_iter!(verbosity, "{:-<60}", "");
+
+        while *iter < max_iter {
+            *iter += 1;
+
+            // merge phases that converged to the same solution
+            let mut k = 1;
+            while k < phases.len() {
+                if let Some(j) =
+                    (0..k).find(|&j| PhaseEquilibrium::is_trivial_solution(&phases[j], &phases[k]))
+                {
+                    beta[j] += beta[k];
+                    beta.remove_index(Axis(0), k);
+                    phases.remove(k);
+                } else {
+                    k += 1;
+                }
+            }
+
+            // inverse fugacity coefficients; non-volatile components are
+            // excluded from the phase with the lowest density
+            let mut phi_inv = Array2::zeros((phases.len(), n));
+            for (mut p, s) in phi_inv.outer_iter_mut().zip(phases.iter()) {
+                p.assign(&s.ln_phi().mapv(|l| (-l).exp()));
+            }
+            if phases.len() > 1 {
+                let vapor = (0..phases.len())
+                    .min_by(|&i, &j| {
+                        phases[i]
+                            .density
+                            .to_reduced()
+                            .total_cmp(&phases[j].density.to_reduced())
+                    })
+                    .unwrap();
+                non_volatile_components
+                    .iter()
+                    .for_each(|&i| phi_inv[(vapor, i)] = 0.0);
+            }
+
+            // check for convergence: x_i^(k) phi_i^(k) has to be equal in all phases
+            let mut res = Vec::new();
+            for i in 0..n {
+                let ln_f = |k: usize| phases[k].molefracs[i].ln() - phi_inv[(k, i)].ln();
+                let mut present = (0..phases.len())
+                    .filter(|&k| phi_inv[(k, i)] > 0.0 && phases[k].molefracs[i] > 0.0);
+                if let Some(r) = present.next() {
+                    res.extend(present.map(|k| ln_f(k) - ln_f(r)));
+                }
+            }
+            let res = norm(&Array1::from_vec(res));
+            log_iter!(verbosity, " {:4} | {:14.8e} | {:.8}", iter, res, beta);
+            if res < tol && beta.iter().all(|&b| b > 0.0) {
+                return Ok(());
+            }
+
+            // update phase fractions and remove vanishing phases
+            multiphase_rachford_rice(z, &phi_inv, beta)?;
+            let e = phi_inv.t().dot(beta);
+            let mut k = 0;
+            while k < phases.len() {
+                if beta[k] > 0.0 {
+                    k += 1;
+                } else {
+                    beta.remove_index(Axis(0), k);
+                    phi_inv.remove_index(Axis(0), k);
+                    phases.remove(k);
+                }
+            }
+            if phases.len() == 1 {
+                // the solution collapsed to the homogeneous feed
+                phases[0] = self.clone();
+                return Ok(());
+            }
+
+            // update compositions and states
+            for ((s, p), &b) in phases.iter_mut().zip(phi_inv.outer_iter()).zip(beta.iter()) {
+                let x =
+                    Zip::from(z)
+                        .and(&p)
+                        .and(&e)
+                        .map_collect(|&z, &p, &e| if z > 0.0 { z * p / e } else { 0.0 });
+                let moles = &x * (self.total_moles * b / x.sum());
+                *s = State::new_npt(
+                    &s.eos,
+                    s.temperature,
+                    self.pressure(Contributions::Total),
+                    &moles,
+                    DensityInitialization::InitialDensity(s.density),
+                )?;
+            }
+        }
+        Err(EosError::NotConverged(String::from("multiphase flash")))
+    }
+}
+
+/// Return a trial phase with negative tangent plane distance with respect
+/// to any of the given phases that does not coincide with an existing phase.
+fn unstable_trial_phase<E: Residual>(phases: &[State<E>]) -> EosResult<Option<State<E>>> {
+    for phase in phases {
+        // stability analysis is not defined for phases that lack components
+        if phase.molefracs.iter().any(|&x| x <= 0.0) {
+            continue;
+        }
+        for trial in phase.stability_analysis(SolverOptions::default())? {
+            if !phases
+                .iter()
+                .any(|p| PhaseEquilibrium::is_trivial_solution(p, &trial))
+            {
+                return Ok(Some(trial));
+            }
+        }
+    }
+    Ok(None)
+}
+
+/// Check whether both sets of phases consist of the same phases.
+fn same_phases<E: Residual>(phases1: &[State<E>], phases2: &[State<E>]) -> bool {
+    phases1.len() == phases2.len()
+        && phases1.iter().all(|p1| {
+            phases2
+                .iter()
+                .any(|p2| PhaseEquilibrium::is_trivial_solution(p1, p2))
+        })
+}
+
+/// Multiphase Rachford-Rice algorithm following Michelsen (1994).
+///
+/// The phase fractions are obtained by minimizing the convex function
+/// $Q(\beta)=\sum_k\beta_k-\sum_iz_i\ln E_i$ with $E_i=\sum_k\beta_k/\varphi_i^{(k)}$
+/// subject to $\beta_k\geq 0$. Phases with vanishing phase fraction are
+/// returned with $\beta_k=0$.
+fn multiphase_rachford_rice(
+    feed: &Array1<f64>,
+    phi_inv: &Array2<f64>,
+    beta: &mut Array1<f64>,
+) -> EosResult<()> {
+    let q = |beta: &Array1<f64>| {
+        let e = phi_inv.t().dot(beta);
+        beta.sum()
+            - Zip::from(feed).and(&e).fold(
+                0.0,
+                |acc, &z, &e| if z > 0.0 { acc + z * e.ln() } else { acc },
+            )
+    };
+
+    for _ in 0..MAX_ITER_RR {
+        // gradient
+        let e = phi_inv.t().dot(beta);
+        let w = Zip::from(feed)
+            .and(&e)
+            .map_collect(|&z, &e| if z > 0.0 { z / e } else { 0.0 });
+        let g = 1.0 - phi_inv.dot(&w);
+
+        // phases with vanishing phase fraction and positive gradient are inactive
+        let active: Vec<_> = (0..beta.len())
+            .filter(|&k| beta[k] > 0.0 || g[k] < 0.0)
+            .collect();
+        if active.iter().all(|&k| g[k].abs() < TOL_RR) {
+            return Ok(());
+        }
+
+        // Newton step on the active phases
+        let ww = &w * &w / feed.mapv(|z| if z > 0.0 { z } else { 1.0 });
+        let hessian = Array2::from_shape_fn((active.len(), active.len()), |(a, b)| {
+            (&phi_inv.row(active[a]) * &phi_inv.row(active[b]) * &ww).sum()
+        });
+        let g_active = Array1::from_shape_fn(active.len(), |a| g[active[a]]);
+        let dx = LU::new(hessian)?.solve(&g_active);
+
+        // restrict the step size to non-negative phase fractions
+        let mut alpha: f64 = 1.0;
+        for (&k, &d) in active.iter().zip(dx.iter()) {
+            if d > 0.0 && beta[k] - alpha * d < 0.0 {
+                alpha = beta[k] / d;
+            }
+        }
+
+        // line search (close to the solution, the expected decrease of Q is
+        // below its rounding error and the Newton step is accepted directly)
+        let q0 = q(beta);
+        let line_search = g_active.dot(&dx) > 1e3 * f64::EPSILON * q0.abs().max(1.0);
+        let mut beta_new = beta.clone();
+        for _ in 0..MAX_LINE_SEARCH {
+            beta_new.assign(beta);
+            for (&k, &d) in active.iter().zip(dx.iter()) {
+                beta_new[k] = (beta[k] - alpha * d).max(0.0);
+            }
+            let q_new = q(&beta_new);
+            if q_new.is_finite() && (!line_search || q_new <= q0) {
+                break;
+            }
+            alpha *= 0.5;
+        }
+        *beta = beta_new;
+    }
+    Err(EosError::NotConverged(String::from(
+        "multiphase Rachford-Rice",
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+
+    #[test]
+    fn rachford_rice_two_phases() {
+        // two-phase solution has to agree with the classical Rachford-Rice equation
+        let z = arr1(&[0.3, 0.3, 0.4]);
+        let k = arr1(&[3.0, 1.2, 0.1]);
+        let phi_inv = stack![Axis(0), k, Array1::ones(3)];
+        let mut beta = arr1(&[0.5, 0.5]);
+        multiphase_rachford_rice(&z, &phi_inv, &mut beta).unwrap();
+        let b = beta[0];
+        let g = (&z * &(&k - 1.0) / (1.0 + b * (&k - 1.0))).sum();
+        assert_relative_eq!(g, 0.0, epsilon = 1e-10);
+        assert_relative_eq!(beta.sum(), 1.0, epsilon = 1e-10);
+    }
+
+    #[test]
+    fn rachford_rice_vanishing_phase() {
+        // all K values below one: the vapor phase has to vanish
+        let z = arr1(&[0.5, 0.5]);
+        let phi_inv = arr2(&[[0.5, 0.2], [1.0, 1.0]]);
+        let mut beta = arr1(&[0.5, 0.5]);
+        multiphase_rachford_rice(&z, &phi_inv, &mut beta).unwrap();
+        assert_eq!(beta[0], 0.0);
+        assert_relative_eq!(beta[1], 1.0, epsilon = 1e-10);
+    }
+}
@@ -0,0 +1,323 @@
+use super::PhaseEquilibrium;
+use crate::equation_of_state::{IdealGas, Residual};
+use crate::errors::{EosError, EosResult};
+use crate::si::{MolarEnergy, MolarEntropy, Moles, Pressure, Temperature};
+use crate::state::{Contributions, DensityInitialization, State};
+use crate::{SolverOptions, Verbosity};
+use ndarray::Array1;
+use std::sync::Arc;
+
+const MAX_ITER_PH: usize = 50;
+const TOL_PH: f64 = 1e-10;
+/// The residual is limited by the accuracy of the inner tp-flash.
+const TOL_PH_RESIDUAL: f64 = 1e-8;
+const MAX_TEMPERATURE_STEP: f64 = 0.2;
+
+/// The result of a flash calculation for which the number of
+/// phases is not known in advance.
+#[derive(Debug)]
+#[allow(clippy::large_enum_variant)]
+pub enum FlashResult<E> {
+    /// The feed is stable as a single homogeneous phase.
+    SinglePhase(State<E>),
+    /// The feed splits into two phases.
+    TwoPhase(PhaseEquilibrium<E, 2>),
+}
+
+impl<E> Clone for FlashResult<E> {
+    fn clone(&self) -> Self {
+        match self {
+            Self::SinglePhase(s) => Self::SinglePhase(s.clone()),
+            Self::TwoPhase(vle) => Self::TwoPhase(vle.clone()),
+        }
+    }
+}
+
+impl<E: Residual> FlashResult<E> {
+    /// The temperature of the system.
+    pub fn temperature(&self) -> Temperature {
+        match self {
+            Self::SinglePhase(s) => s.temperature,
+            Self::TwoPhase(vle) => vle.vapor().temperature,
+        }
+    }
+
+    /// The pressure of the system.
+    pub fn pressure(&self) -> Pressure {
+        match self {
+            Self::SinglePhase(s) => s.pressure(Contributions::Total),
+            Self::TwoPhase(vle) => vle.vapor().pressure(Contributions::Total),
+        }
+    }
+
+    /// The molar vapor phase fraction, or `None` for a single phase.
+    pub fn vapor_phase_fraction(&self) -> Option<f64> {
+        match self {
+            Self::SinglePhase(_) => None,
+            Self::TwoPhase(vle) => Some(vle.vapor_phase_fraction()),
+        }
+    }
+
+    fn states(&self) -> &[State<E>] {
+        match self {
+            Self::SinglePhase(s) => std::slice::from_ref(s),
+            Self::TwoPhase(vle) => &vle.0,
+        }
+    }
+
+    /// Mole weighted average of a molar property over all phases in reduced units.
+    fn molar_average<F: Fn(&State<E>) -> f64>(&self, property: F) -> f64 {
+        let states = self.states();
+        let n: f64 = states.iter().map(|s| s.total_moles.to_reduced()).sum();
+        states
+            .iter()
+            .map(|s| s.total_moles.to_reduced() * property(s))
+            .sum::<f64>()
+            / n
+    }
+}
+
+impl<E: Residual + IdealGas> FlashResult<E> {
+    /// Molar enthalpy of the (possibly heterogeneous) system.
+    pub fn molar_enthalpy(&self, contributions: Contributions) -> MolarEnergy {
+        MolarEnergy::from_reduced(
+            self.molar_average(|s| s.molar_enthalpy(contributions).to_reduced()),
+        )
+    }
+
+    /// Molar entropy of the (possibly heterogeneous) system.
+    pub fn molar_entropy(&self, contributions: Contributions) -> MolarEntropy {
+        MolarEntropy::from_reduced(
+            self.molar_average(|s| s.molar_entropy(contributions).to_reduced()),
+        )
+    }
+}
+
+impl<E: Residual> From<State<E>> for FlashResult<E> {
+    fn from(state: State<E>) -> Self {
+        Self::SinglePhase(state)
+    }
+}
+
+impl<E> From<PhaseEquilibrium<E, 2>> for FlashResult<E> {
+    fn from(vle: PhaseEquilibrium<E, 2>) -> Self {
+        Self::TwoPhase(vle)
+    }
+}
+
+/// # Flash calculations
+impl<E: Residual + IdealGas> FlashResult<E> {
+    /// Perform a flash calculation at given pressure and molar enthalpy.
+    ///
+    /// The temperature is iterated in an outer loop, while the phase split
+    /// at each temperature is determined with a [Tp-flash](State::tp_flash).
+    /// The `options` are used for the (inner) Tp-flash and the (outer)
+    /// temperature iteration, respectively.
+    pub fn ph_flash(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        molar_enthalpy: MolarEnergy,
+        feed: &Moles<Array1<f64>>,
+        initial_temperature: Option<Temperature>,
+        options: (SolverOptions, SolverOptions),
+        non_volatile_components: Option<Vec<usize>>,
+    ) -> EosResult<Self> {
+        Self::flash_temperature_iteration(
+            eos,
+            pressure,
+            feed,
+            initial_temperature,
+            options,
+            non_volatile_components,
+            molar_enthalpy.to_reduced(),
+            |s| s.molar_enthalpy(Contributions::Total).to_reduced(),
+            |s| {
+                s.molar_isobaric_heat_capacity(Contributions::Total)
+                    .to_reduced()
+            },
+            |eos, t0, density| {
+                State::new_nph(eos, pressure, molar_enthalpy, feed, density, Some(t0))
+            },
+        )
+    }
+
+    /// Perform a flash calculation at given pressure and molar entropy.
+    ///
+    /// The temperature is iterated in an outer loop, while the phase split
+    /// at each temperature is determined with a [Tp-flash](State::tp_flash).
+    /// The `options` are used for the (inner) Tp-flash and the (outer)
+    /// temperature iteration, respectively.
+    pub fn ps_flash(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        molar_entropy: MolarEntropy,
+        feed: &Moles<Array1<f64>>,
+        initial_temperature: Option<Temperature>,
+        options: (SolverOptions, SolverOptions),
+        non_volatile_components: Option<Vec<usize>>,
+    ) -> EosResult<Self> {
+        Self::flash_temperature_iteration(
+            eos,
+            pressure,
+            feed,
+            initial_temperature,
+            options,
+            non_volatile_components,
+            molar_entropy.to_reduced(),
+            |s| s.molar_entropy(Contributions::Total).to_reduced(),
+            |s| (s.molar_isobaric_heat_capacity(Contributions::Total) / s.temperature).to_reduced(),
+            |eos, t0, density| {
+                State::new_nps(eos, pressure, molar_entropy, feed, density, Some(t0))
+            },
+        )
+    }
+
+    fn flash_temperature_iteration<F, G, H>(
+        eos: &Arc<E>,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        initial_temperature: Option<Temperature>,
+        options: (SolverOptions, SolverOptions),
+        non_volatile_components: Option<Vec<usize>>,
+        spec: f64,
+        property: F,
+        derivative: G,
+        single_phase: H,
+    ) -> EosResult<Self>
+    where
+        F: Fn(&State<E>) -> f64,
+        G: Fn(&State<E>) -> f64,
+        H: Fn(&Arc<E>, Temperature, DensityInitialization) -> EosResult<State<E>>,
+    {
+        let (options_inner, options_outer) = options;
+        let (max_iter, tol, verbosity) = options_outer.unwrap_or(MAX_ITER_PH, TOL_PH);
+        let t0 = initial_temperature.unwrap_or(Temperature::from_reduced(298.15));
+
+        // For pure components, the two-phase region degenerates to the
+        // saturation temperature and the phase fractions follow from the
+        // lever rule.
+        if eos.components() == 1 {
+            if let Ok(mut vle) = PhaseEquilibrium::pure(eos, pressure, None, options_inner) {
+                let (f_v, f_l) = (property(vle.vapor()), property(vle.liquid()));
+                let t_sat = vle.vapor().temperature;
+                return if spec > f_v {
+                    Ok(single_phase(eos, t_sat, DensityInitialization::Vapor)?.into())
+                } else if spec < f_l {
+                    Ok(single_phase(eos, t_sat, DensityInitialization::Liquid)?.into())
+                } else {
+                    let beta = (spec - f_l) / (f_v - f_l);
+                    let v = feed * beta;
+                    let l = feed * (1.0 - beta);
+                    vle.update_moles(pressure, [&v, &l])?;
+                    log_result!(
+                        verbosity,
+                        "Flash: pure component at saturation with vapor fraction {:.8}",
+                        beta
+                    );
+                    Ok(vle.into())
+                };
+            }
+        }
+
+        log_iter!(verbosity, " iter |    residual    |  temperature  | phases");
+        log_iter!(verbosity, "{:-<51}", "");
+
+        let mut t = t0.to_reduced();
+        let (mut t_min, mut t_max) = (0.0, f64::INFINITY);
+        let mut initial_state = None;
+        let mut previous: Option<(f64, f64, usize)> = None;
+        for iter in 1..=max_iter {
+            let result = Self::tp_flash_or_single_phase(
+                eos,
+                Temperature::from_reduced(t),
+                pressure,
+                feed,
+                initial_state.as_ref(),
+                options_inner,
+                non_volatile_components.clone(),
+            )?;
+            let phases = result.states().len();
+            let f = result.molar_average(&property) - spec;
+            log_iter!(
+                verbosity,
+                " {:4} | {:14.8e} | {:13.5} | {}",
+                iter,
+                f,
+                Temperature::from_reduced(t),
+                phases
+            );
+
+            // The specified property increases monotonically with temperature,
+            // which is used to bracket the solution.
+            if f < 0.0 {
+                t_min = t;
+            } else {
+                t_max = t;
+            }
+
+            // Secant steps are only used within the same phase region,
+            // otherwise a Newton step with the heat capacity at constant
+            // phase compositions is performed.
+            let df_newton = result.molar_average(&derivative);
+            let df = match previous {
+                Some((t_prev, f_prev, phases_prev)) if phases_prev == phases && t_prev != t => {
+                    (f - f_prev) / (t - t_prev)
+                }
+                _ => df_newton,
+            };
+            let mut t_new = t - f / df;
+            if !t_new.is_finite() || df <= 0.0 {
+                t_new = t * (1.0 - f.signum() * MAX_TEMPERATURE_STEP);
+            }
+            t_new = t_new.clamp(
+                t * (1.0 - MAX_TEMPERATURE_STEP),
+                t * (1.0 + MAX_TEMPERATURE_STEP),
+            );
+            if (t_new <= t_min || t_new >= t_max) && t_max.is_finite() {
+                t_new = 0.5 * (t_min + t_max);
+            }
+
+            // Besides a vanishing temperature step, the residual itself has to
+            // be small, scaled with the derivative to obtain a relative
+            // temperature deviation.
+            if (t_new - t).abs() < tol * t && f.abs() < TOL_PH_RESIDUAL * t * df_newton.abs() {
+                log_result!(
+                    verbosity,
+                    "Flash: calculation converged in {} step(s)\n",
+                    iter
+                );
+                return Ok(result);
+            }
+
+            previous = Some((t, f, phases));
+            if let Self::TwoPhase(vle) = result {
+                initial_state = Some(vle);
+            }
+            t = t_new;
+        }
+        Err(EosError::NotConverged("flash".to_owned()))
+    }
+
+    fn tp_flash_or_single_phase(
+        eos: &Arc<E>,
+        temperature: Temperature,
+        pressure: Pressure,
+        feed: &Moles<Array1<f64>>,
+        initial_state: Option<&PhaseEquilibrium<E, 2>>,
+        options: SolverOptions,
+        non_volatile_components: Option<Vec<usize>>,
+    ) -> EosResult<Self> {
+        let state = State::new_npt(
+            eos,
+            temperature,
+            pressure,
+            feed,
+            DensityInitialization::None,
+        )?;
+        match state.tp_flash(initial_state, options, non_volatile_components) {
+            Ok(vle) => Ok(vle.into()),
+            Err(EosError::NoPhaseSplit) => Ok(state.into()),
+            Err(e) => Err(e),
+        }
+    }
+}
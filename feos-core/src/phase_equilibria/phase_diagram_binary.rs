@@ -46,7 +46,7 @@ impl<E: Residual> PhaseDiagram<E, 2> {
                 .into_iter()
                 .chain(states2.into_iter().rev())
                 .collect();
-            return Ok(Self { states });
+            return Ok(Self::new(states));
         }
 
         // use dew point when calculating a supercritical tx diagram
@@ -96,7 +96,7 @@ impl<E: Residual> PhaseDiagram<E, 2> {
         if !bubble {
             states = states.into_iter().rev().collect();
         }
-        Ok(Self { states })
+        Ok(Self::new(states))
     }
 
     #[allow(clippy::type_complexity)]
@@ -171,7 +171,7 @@ impl<E: Residual> PhaseDiagram<E, 2> {
                 states.push(vle.clone());
             }
         }
-        Ok(Self { states })
+        Ok(Self::new(states))
     }
 }
 
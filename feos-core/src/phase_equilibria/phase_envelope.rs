@@ -1,12 +1,22 @@
 use super::{PhaseDiagram, PhaseEquilibrium};
 use crate::equation_of_state::Residual;
-use crate::errors::EosResult;
-use crate::si::{Moles, Pressure, Temperature};
-use crate::state::{Contributions, State};
-use crate::SolverOptions;
-use ndarray::Array1;
+use crate::errors::{EosError, EosResult};
+use crate::si::{Density, Moles, Pressure, Temperature};
+use crate::state::{Contributions, DensityInitialization, State};
+use crate::{SolverOptions, Verbosity};
+use ndarray::{concatenate, s, Array1, Array2, Axis};
+use num_dual::linalg::{norm, LU};
 use std::sync::Arc;
 
+const MAX_ITER_ENVELOPE: usize = 20;
+const TOL_ENVELOPE: f64 = 1e-10;
+const INITIAL_STEP_ENVELOPE: f64 = 0.02;
+const MAX_STEP_ENVELOPE: f64 = 0.1;
+const MIN_STEP_ENVELOPE: f64 = 1e-6;
+const MAX_ITER_EXTREMUM: usize = 20;
+const TOL_EXTREMUM: f64 = 1e-8;
+const TRIVIAL_LN_K: f64 = 1e-6;
+
 impl<E: Residual> PhaseDiagram<E, 2> {
     /// Calculate the bubble point line of a mixture with given composition.
     pub fn bubble_point_line(
@@ -155,3 +165,426 @@ impl<E: Residual> PhaseDiagram<E, 2> {
         Ok(PhaseDiagram::new(states))
     }
 }
+
+/// Phase envelope of a mixture with fixed composition.
+///
+/// The critical point, the cricondenbar and the cricondentherm are only
+/// defined for a phase envelope at fixed composition, but [PhaseDiagram] is
+/// also used for pure components and for binary phase diagrams at constant
+/// temperature or pressure. Therefore, they are stored alongside the
+/// [PhaseDiagram] of the traced points instead of in it.
+pub struct PhaseEnvelope<E> {
+    /// Points on the phase envelope ordered from the bubble point branch to
+    /// the dew point branch. Each point contains the feed and the incipient
+    /// phase, of which the phase with the lower density is the vapor.
+    pub diagram: PhaseDiagram<E, 2>,
+    /// The critical point. `None` if the envelope was not traced through the
+    /// critical point, e.g., because `npoints` was reached before, or if the
+    /// critical point could not be converged.
+    pub critical_point: Option<State<E>>,
+    /// The point of maximum pressure. `None` if the maximum was not bracketed
+    /// by the traced points or if it lies so close to the critical point
+    /// that the envelope equations cannot be solved.
+    pub cricondenbar: Option<PhaseEquilibrium<E, 2>>,
+    /// The point of maximum temperature. `None` under the same conditions as
+    /// the cricondenbar.
+    pub cricondentherm: Option<PhaseEquilibrium<E, 2>>,
+}
+
+impl<E> Clone for PhaseEnvelope<E> {
+    fn clone(&self) -> Self {
+        Self {
+            diagram: self.diagram.clone(),
+            critical_point: self.critical_point.clone(),
+            cricondenbar: self.cricondenbar.clone(),
+            cricondentherm: self.cricondentherm.clone(),
+        }
+    }
+}
+
+/// A converged point on the phase envelope.
+///
+/// The vector of variables is $X=\left(\ln K_1,\ldots,\ln K_n,\ln T,\ln p\right)$
+/// with the incipient phase $y_i=K_iz_i$ in equilibrium with the feed $z_i$.
+struct EnvelopePoint<E> {
+    x: Array1<f64>,
+    spec: usize,
+    incipient: State<E>,
+    feed: State<E>,
+    jacobian: Array2<f64>,
+    iterations: usize,
+}
+
+impl<E: Residual> EnvelopePoint<E> {
+    /// Newton iteration for a point on the phase envelope with
+    /// the variable with index `spec` fixed to its initial value.
+    fn solve(
+        eos: &Arc<E>,
+        molefracs: &Array1<f64>,
+        mut x: Array1<f64>,
+        spec: usize,
+        densities: [Density; 2],
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        let n = molefracs.len();
+        let (max_iter, tol, _) = options.unwrap_or(MAX_ITER_ENVELOPE, TOL_ENVELOPE);
+        let [mut rho_incipient, mut rho_feed] = densities;
+
+        for iterations in 1..=max_iter {
+            if x.slice(s![..n])
+                .iter()
+                .all(|ln_k| ln_k.abs() < TRIVIAL_LN_K)
+            {
+                return Err(EosError::TrivialSolution);
+            }
+            let t = Temperature::from_reduced(x[n].exp());
+            let p = Pressure::from_reduced(x[n + 1].exp());
+            let y = x.slice(s![..n]).mapv(f64::exp) * molefracs;
+            let incipient = State::new_npt(
+                eos,
+                t,
+                p,
+                &Moles::from_reduced(y.clone()),
+                DensityInitialization::InitialDensity(rho_incipient),
+            )?;
+            let feed = State::new_npt(
+                eos,
+                t,
+                p,
+                &Moles::from_reduced(molefracs.clone()),
+                DensityInitialization::InitialDensity(rho_feed),
+            )?;
+            rho_incipient = incipient.density;
+            rho_feed = feed.density;
+
+            // residuals: iso-fugacity and sum of mole fractions of the incipient phase
+            let f = concatenate![
+                Axis(0),
+                &x.slice(s![..n]) + &incipient.ln_phi() - feed.ln_phi(),
+                Array1::from_elem(1, y.sum() - 1.0),
+                Array1::zeros(1)
+            ];
+
+            // Jacobian w.r.t. ln K, ln T and ln p
+            let dln_phi_dn = (incipient.dln_phi_dnj() * Moles::from_reduced(1.0)).into_value();
+            let mut jacobian = Array2::zeros((n + 2, n + 2));
+            for i in 0..n {
+                for j in 0..n {
+                    jacobian[(i, j)] = dln_phi_dn[(i, j)] * y[j] + if i == j { 1.0 } else { 0.0 };
+                }
+            }
+            jacobian
+                .slice_mut(s![..n, n])
+                .assign(&((incipient.dln_phi_dt() - feed.dln_phi_dt()) * t).into_value());
+            jacobian
+                .slice_mut(s![..n, n + 1])
+                .assign(&((incipient.dln_phi_dp() - feed.dln_phi_dp()) * p).into_value());
+            jacobian.slice_mut(s![n, ..n]).assign(&y);
+            jacobian[(n + 1, spec)] = 1.0;
+
+            if norm(&f) < tol {
+                return Ok(Self {
+                    x,
+                    spec,
+                    incipient,
+                    feed,
+                    jacobian,
+                    iterations,
+                });
+            }
+
+            x -= &LU::new(jacobian)?.solve(&f);
+            if x.iter().any(|x| !x.is_finite()) {
+                return Err(EosError::IterationFailed(String::from("phase envelope")));
+            }
+        }
+        Err(EosError::NotConverged(String::from("phase envelope")))
+    }
+
+    /// Derivatives of all variables w.r.t. the variable with index `spec`
+    /// along the phase envelope.
+    fn sensitivity(&self, spec: usize) -> EosResult<Array1<f64>> {
+        let n = self.x.len();
+        let mut jacobian = self.jacobian.clone();
+        jacobian.row_mut(n - 1).fill(0.0);
+        jacobian[(n - 1, spec)] = 1.0;
+        let mut rhs = Array1::zeros(n);
+        rhs[n - 1] = 1.0;
+        Ok(LU::new(jacobian)?.solve(&rhs))
+    }
+
+    fn densities(&self) -> [Density; 2] {
+        [self.incipient.density, self.feed.density]
+    }
+
+    fn phase_equilibrium(&self) -> PhaseEquilibrium<E, 2> {
+        PhaseEquilibrium::from_states(self.incipient.clone(), self.feed.clone())
+    }
+}
+
+impl<E: Residual> PhaseDiagram<E, 2> {
+    /// Calculate the phase envelope of a mixture with given composition
+    /// by continuation.
+    ///
+    /// Starting from the bubble point at `min_temperature`, the phase envelope
+    /// is traced using the method of Michelsen (1980). The specified variable
+    /// ($\ln K_i$, $\ln T$ or $\ln p$) is chosen in every step as the
+    /// variable that changes the most along the envelope, which allows passing
+    /// through the critical point, from the bubble point branch to the dew
+    /// point branch, and through retrograde regions. The calculation stops at
+    /// the dew point with the pressure of the initial bubble point or after
+    /// `npoints` points.
+    ///
+    /// Apart from the points on the envelope, the returned [PhaseEnvelope]
+    /// contains the critical point, the cricondenbar and the cricondentherm
+    /// if they were found.
+    pub fn phase_envelope(
+        eos: &Arc<E>,
+        moles: &Moles<Array1<f64>>,
+        min_temperature: Temperature,
+        npoints: usize,
+        options: SolverOptions,
+    ) -> EosResult<PhaseEnvelope<E>> {
+        let molefracs = (moles / moles.sum()).into_value();
+        let n = molefracs.len();
+        let verbosity = options.verbosity;
+
+        // initialize with the bubble point at the minimum temperature
+        let vle = PhaseEquilibrium::bubble_point(
+            eos,
+            min_temperature,
+            &molefracs,
+            None,
+            None,
+            (SolverOptions::default(), SolverOptions::default()),
+        )?;
+        let x = concatenate![
+            Axis(0),
+            (&vle.vapor().molefracs / &molefracs).mapv(f64::ln),
+            Array1::from_vec(vec![
+                min_temperature.to_reduced().ln(),
+                vle.vapor().pressure(Contributions::Total).to_reduced().ln(),
+            ])
+        ];
+        let densities = [vle.vapor().density, vle.liquid().density];
+        let mut point = EnvelopePoint::solve(eos, &molefracs, x, n, densities, options)?;
+        let ln_p_min = point.x[n + 1];
+
+        log_iter!(
+            verbosity,
+            " point | iter |  temperature  |    pressure    | spec"
+        );
+        log_iter!(verbosity, "{:-<58}", "");
+
+        let mut points = Vec::with_capacity(npoints);
+        let mut states = Vec::with_capacity(npoints);
+        let mut critical_point = None;
+        let mut step = INITIAL_STEP_ENVELOPE;
+        let mut direction: Option<Array1<f64>> = None;
+        while points.len() < npoints {
+            // the variable that changes the most is used as specification
+            let sensitivity = point.sensitivity(point.spec)?;
+            let spec = (0..n + 2)
+                .max_by(|&i, &j| sensitivity[i].abs().total_cmp(&sensitivity[j].abs()))
+                .unwrap();
+            let mut sensitivity = &sensitivity / sensitivity[spec];
+
+            // continue in the same direction as in the previous step and start
+            // the bubble point branch towards increasing temperatures
+            let reverse = match &direction {
+                Some(dx) => sensitivity.dot(dx) < 0.0,
+                None => sensitivity[n] < 0.0,
+            };
+            if reverse {
+                sensitivity *= -1.0;
+            }
+            let mut ds = step;
+
+            // jump across the critical point
+            let ln_k = point.x[spec];
+            if spec < n && ln_k * (ln_k + ds * sensitivity[spec]) < 0.5 * ds * ds {
+                ds = -2.0 * ln_k / sensitivity[spec];
+            }
+
+            log_iter!(
+                verbosity,
+                " {:5} | {:4} | {:13.5} | {:14.5} | {}",
+                points.len(),
+                point.iterations,
+                Temperature::from_reduced(point.x[n].exp()),
+                Pressure::from_reduced(point.x[n + 1].exp()),
+                spec
+            );
+
+            // predictor-corrector step with step size control
+            let new_point = loop {
+                let x = &point.x + &(&sensitivity * ds);
+                match EnvelopePoint::solve(eos, &molefracs, x, spec, point.densities(), options) {
+                    Ok(p) => break Some(p),
+                    Err(_) if ds.abs() * 0.5 > MIN_STEP_ENVELOPE => ds *= 0.5,
+                    Err(_) => break None,
+                }
+            };
+            states.push(point.phase_equilibrium());
+            let Some(new_point) = new_point else {
+                points.push(point);
+                break;
+            };
+
+            // step size control
+            step = ds.abs()
+                * match new_point.iterations {
+                    0..=3 => 1.5,
+                    4..=6 => 1.0,
+                    _ => 0.5,
+                };
+            step = step.min(MAX_STEP_ENVELOPE);
+
+            // locate the critical point from the change of sign of ln K
+            let i = (0..n)
+                .max_by(|&i, &j| point.x[i].abs().total_cmp(&point.x[j].abs()))
+                .unwrap();
+            if critical_point.is_none() && point.x[i] * new_point.x[i] < 0.0 {
+                let w = point.x[i] / (point.x[i] - new_point.x[i]);
+                let ln_t = point.x[n] + w * (new_point.x[n] - point.x[n]);
+                let t = Temperature::from_reduced(ln_t.exp());
+                critical_point =
+                    State::critical_point(eos, Some(moles), Some(t), SolverOptions::default()).ok();
+                if let Some(cp) = &critical_point {
+                    log_result!(
+                        verbosity,
+                        "Phase envelope: critical point at T = {:.5}, p = {:.5}",
+                        cp.temperature,
+                        cp.pressure(Contributions::Total)
+                    );
+                }
+            }
+
+            direction = Some(&new_point.x - &point.x);
+            points.push(point);
+            point = new_point;
+
+            // stop on the dew point branch below the initial pressure
+            if point.x[n + 1] < ln_p_min {
+                states.push(point.phase_equilibrium());
+                points.push(point);
+                break;
+            }
+        }
+
+        Ok(PhaseEnvelope {
+            diagram: PhaseDiagram::new(states),
+            critical_point,
+            cricondenbar: locate_extremum(eos, &molefracs, &points, n + 1, options, verbosity),
+            cricondentherm: locate_extremum(eos, &molefracs, &points, n, options, verbosity),
+        })
+    }
+}
+
+/// Locate the maximum of the variable with index `target` (ln p for the
+/// cricondenbar, ln T for the cricondentherm) along the phase envelope.
+///
+/// The extremum is bracketed by the traced points and the root of the
+/// derivative of the target variable along the envelope is found with the
+/// Illinois algorithm. If the extremum lies so close to the critical point
+/// that the envelope equations cannot be solved, no extremum is returned.
+fn locate_extremum<E: Residual>(
+    eos: &Arc<E>,
+    molefracs: &Array1<f64>,
+    points: &[EnvelopePoint<E>],
+    target: usize,
+    options: SolverOptions,
+    verbosity: Verbosity,
+) -> Option<PhaseEquilibrium<E, 2>> {
+    let n = molefracs.len();
+    let name = if target == n {
+        "temperature"
+    } else {
+        "pressure"
+    };
+
+    // the largest value has to be in the interior of the traced envelope
+    let i =
+        (0..points.len()).max_by(|&i, &j| points[i].x[target].total_cmp(&points[j].x[target]))?;
+    if i == 0 || i == points.len() - 1 {
+        return None;
+    }
+
+    for (a, b) in [(&points[i - 1], &points[i]), (&points[i], &points[i + 1])] {
+        // the variable that changes the most between the two points
+        // is used as specification
+        let spec = (0..n + 2)
+            .max_by(|&k, &l| (b.x[k] - a.x[k]).abs().total_cmp(&(b.x[l] - a.x[l]).abs()))
+            .unwrap();
+        let g_a = a.sensitivity(spec).ok()?[target];
+        let g_b = b.sensitivity(spec).ok()?[target];
+        if g_a * g_b > 0.0 {
+            continue;
+        }
+
+        let point = refine_extremum(eos, molefracs, [a, b], [g_a, g_b], spec, target, options)?;
+        log_result!(
+            verbosity,
+            "Phase envelope: maximum {} at T = {:.5}, p = {:.5}",
+            name,
+            Temperature::from_reduced(point.x[n].exp()),
+            Pressure::from_reduced(point.x[n + 1].exp())
+        );
+        return Some(point.phase_equilibrium());
+    }
+    None
+}
+
+/// Find the root of the derivative of the target variable between the
+/// two envelope points `a` and `b`.
+fn refine_extremum<E: Residual>(
+    eos: &Arc<E>,
+    molefracs: &Array1<f64>,
+    [a, b]: [&EnvelopePoint<E>; 2],
+    [mut g_a, mut g_b]: [f64; 2],
+    spec: usize,
+    target: usize,
+    options: SolverOptions,
+) -> Option<EnvelopePoint<E>> {
+    let (mut theta_a, mut theta_b) = (a.x[spec], b.x[spec]);
+    let mut side = 0;
+    for _ in 0..MAX_ITER_EXTREMUM {
+        let theta = (theta_a * g_b - theta_b * g_a) / (g_b - g_a);
+        let w = (theta - a.x[spec]) / (b.x[spec] - a.x[spec]);
+        let x = &a.x + &((&b.x - &a.x) * w);
+
+        // across the critical point, the densities of the incipient
+        // phase and the feed swap and have to be taken from the same side
+        let close_to_a = if a.x[0] * b.x[0] < 0.0 {
+            x[0] * a.x[0] > 0.0
+        } else {
+            w < 0.5
+        };
+        let densities = if close_to_a {
+            a.densities()
+        } else {
+            b.densities()
+        };
+        let point = EnvelopePoint::solve(eos, molefracs, x, spec, densities, options).ok()?;
+        let g = point.sensitivity(spec).ok()?[target];
+        if g.abs() < TOL_EXTREMUM {
+            return Some(point);
+        }
+        if g * g_b > 0.0 {
+            theta_b = theta;
+            g_b = g;
+            if side == -1 {
+                g_a *= 0.5;
+            }
+            side = -1;
+        } else {
+            theta_a = theta;
+            g_a = g;
+            if side == 1 {
+                g_b *= 0.5;
+            }
+            side = 1;
+        }
+    }
+    None
+}
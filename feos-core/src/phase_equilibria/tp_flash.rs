@@ -308,7 +308,11 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
         Ok(false)
     }
 
-    fn update_states(&mut self, feed_state: &State<E>, k: &Array1<f64>) -> EosResult<()> {
+    pub(super) fn update_states(
+        &mut self,
+        feed_state: &State<E>,
+        k: &Array1<f64>,
+    ) -> EosResult<()> {
         // calculate vapor phase fraction using Rachford-Rice algorithm
         let mut beta = self.vapor_phase_fraction();
         beta = rachford_rice(&feed_state.molefracs, k, Some(beta))?;
@@ -338,7 +342,11 @@ impl<E: Residual> PhaseEquilibrium<E, 2> {
     }
 }
 
-fn rachford_rice(feed: &Array1<f64>, k: &Array1<f64>, beta_in: Option<f64>) -> EosResult<f64> {
+pub(super) fn rachford_rice(
+    feed: &Array1<f64>,
+    k: &Array1<f64>,
+    beta_in: Option<f64>,
+) -> EosResult<f64> {
     const MAX_ITER: usize = 10;
     const ABS_TOL: f64 = 1e-6;
 
@@ -0,0 +1,209 @@
+use crate::activity::*;
+use crate::parameter::{IdentifierOption, Parameter, ParameterHetero};
+use crate::si::Temperature;
+use crate::ExcessGibbsEnergy;
+use numpy::{PyArray1, PyReadonlyArray1, ToPyArray};
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use quantity::python::PySINumber;
+use std::convert::TryInto;
+use std::sync::Arc;
+
+/// An activity coefficient model for liquid mixtures.
+///
+/// Use the static methods to read parameters of the NRTL,
+/// UNIQUAC or modified UNIFAC (Dortmund) models from json files.
+#[pyclass(name = "ActivityModel")]
+#[derive(Clone)]
+pub struct PyActivityModel(pub Arc<ActivityModel>);
+
+#[pymethods]
+impl PyActivityModel {
+    /// NRTL model from json files.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The substances to search.
+    /// pure_path : str
+    ///     Path to file containing pure substance records.
+    /// binary_path : str
+    ///     Path to file containing binary interaction parameters.
+    /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search substance.
+    ///
+    /// Returns
+    /// -------
+    /// ActivityModel
+    #[staticmethod]
+    #[pyo3(
+        signature = (substances, pure_path, binary_path, identifier_option=IdentifierOption::Name),
+        text_signature = "(substances, pure_path, binary_path, identifier_option)"
+    )]
+    fn nrtl(
+        substances: Vec<PyBackedStr>,
+        pure_path: String,
+        binary_path: String,
+        identifier_option: IdentifierOption,
+    ) -> PyResult<Self> {
+        let substances = substances.iter().map(|s| &**s).collect();
+        let parameters =
+            NrtlParameters::from_json(substances, pure_path, Some(binary_path), identifier_option)?;
+        let model = Nrtl::new(Arc::new(parameters));
+        Ok(Self(Arc::new(ActivityModel::Nrtl(Arc::new(model)))))
+    }
+
+    /// UNIQUAC model from json files.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The substances to search.
+    /// pure_path : str
+    ///     Path to file containing pure substance parameters.
+    /// binary_path : str
+    ///     Path to file containing binary interaction parameters.
+    /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search substance.
+    ///
+    /// Returns
+    /// -------
+    /// ActivityModel
+    #[staticmethod]
+    #[pyo3(
+        signature = (substances, pure_path, binary_path, identifier_option=IdentifierOption::Name),
+        text_signature = "(substances, pure_path, binary_path, identifier_option)"
+    )]
+    fn uniquac(
+        substances: Vec<PyBackedStr>,
+        pure_path: String,
+        binary_path: String,
+        identifier_option: IdentifierOption,
+    ) -> PyResult<Self> {
+        let substances = substances.iter().map(|s| &**s).collect();
+        let parameters = UniquacParameters::from_json(
+            substances,
+            pure_path,
+            Some(binary_path),
+            identifier_option,
+        )?;
+        let model = Uniquac::new(Arc::new(parameters));
+        Ok(Self(Arc::new(ActivityModel::Uniquac(Arc::new(model)))))
+    }
+
+    /// Modified UNIFAC (Dortmund) model from json files.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The substances to search.
+    /// pure_path : str
+    ///     Path to file containing the chemical records.
+    /// segments_path : str
+    ///     Path to file containing subgroup parameters.
+    /// binary_path : str
+    ///     Path to file containing main group interaction parameters.
+    /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search substance.
+    ///
+    /// Returns
+    /// -------
+    /// ActivityModel
+    #[staticmethod]
+    #[pyo3(
+        signature = (substances, pure_path, segments_path, binary_path, identifier_option=IdentifierOption::Name),
+        text_signature = "(substances, pure_path, segments_path, binary_path, identifier_option)"
+    )]
+    fn unifac(
+        substances: Vec<PyBackedStr>,
+        pure_path: String,
+        segments_path: String,
+        binary_path: String,
+        identifier_option: IdentifierOption,
+    ) -> PyResult<Self> {
+        let substances: Vec<_> = substances.iter().map(|s| &**s).collect();
+        let parameters = UnifacParameters::from_json_segments(
+            &substances,
+            pure_path,
+            segments_path,
+            Some(binary_path),
+            identifier_option,
+        )?;
+        let model = Unifac::new(Arc::new(parameters));
+        Ok(Self(Arc::new(ActivityModel::Unifac(Arc::new(model)))))
+    }
+
+    /// Calculate the logarithms of the activity coefficients.
+    ///
+    /// Parameters
+    /// ----------
+    /// temperature : SINumber
+    ///     The temperature.
+    /// molefracs : numpy.ndarray[float]
+    ///     The composition of the liquid phase.
+    ///
+    /// Returns
+    /// -------
+    /// numpy.ndarray[float]
+    fn ln_activity_coefficients<'py>(
+        &self,
+        py: Python<'py>,
+        temperature: PySINumber,
+        molefracs: PyReadonlyArray1<f64>,
+    ) -> PyResult<Bound<'py, PyArray1<f64>>> {
+        let t: Temperature = temperature.try_into()?;
+        Ok(self
+            .0
+            .ln_activity_coefficients(t.to_reduced(), &molefracs.as_array().to_owned())
+            .to_pyarray_bound(py))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Pure component vapor pressures from Antoine or DIPPR correlations.
+#[pyclass(name = "VaporPressureCorrelation")]
+#[derive(Clone)]
+pub struct PyVaporPressureCorrelation(pub Arc<VaporPressureCorrelation>);
+
+#[pymethods]
+impl PyVaporPressureCorrelation {
+    /// Read vapor pressure correlations from a json file.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The substances to search.
+    /// path : str
+    ///     Path to file containing the correlations.
+    /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search substance.
+    ///
+    /// Returns
+    /// -------
+    /// VaporPressureCorrelation
+    #[staticmethod]
+    #[pyo3(
+        signature = (substances, path, identifier_option=IdentifierOption::Name),
+        text_signature = "(substances, path, identifier_option)"
+    )]
+    fn from_json(
+        substances: Vec<PyBackedStr>,
+        path: String,
+        identifier_option: IdentifierOption,
+    ) -> PyResult<Self> {
+        let substances = substances.iter().map(|s| &**s).collect();
+        Ok(Self(Arc::new(VaporPressureCorrelation::from_json(
+            substances,
+            path,
+            None,
+            identifier_option,
+        )?)))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
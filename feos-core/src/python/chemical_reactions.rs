@@ -0,0 +1,88 @@
+use crate::ChemicalReactions;
+use numpy::{PyArray2, PyReadonlyArray2, ToPyArray};
+use pyo3::prelude::*;
+use quantity::python::PySIArray1;
+use std::convert::TryInto;
+
+/// A set of independent chemical reactions.
+///
+/// Parameters
+/// ----------
+/// stoichiometric_matrix : numpy.ndarray[float]
+///     The stoichiometric coefficients (reactions x components).
+///     Positive values correspond to products.
+/// gibbs_energy_of_formation : SIArray1
+///     The standard molar Gibbs energy of formation of every component
+///     in the ideal gas state at 298.15 K and 1 bar.
+/// enthalpy_of_formation : SIArray1
+///     The standard molar enthalpy of formation of every component
+///     in the ideal gas state at 298.15 K.
+///
+/// Returns
+/// -------
+/// ChemicalReactions
+#[pyclass(name = "ChemicalReactions")]
+#[derive(Clone)]
+pub struct PyChemicalReactions(pub ChemicalReactions);
+
+#[pymethods]
+impl PyChemicalReactions {
+    #[new]
+    #[pyo3(
+        text_signature = "(stoichiometric_matrix, gibbs_energy_of_formation, enthalpy_of_formation)"
+    )]
+    fn new(
+        stoichiometric_matrix: PyReadonlyArray2<f64>,
+        gibbs_energy_of_formation: PySIArray1,
+        enthalpy_of_formation: PySIArray1,
+    ) -> PyResult<Self> {
+        Ok(Self(ChemicalReactions::new(
+            stoichiometric_matrix.as_array().to_owned(),
+            gibbs_energy_of_formation.try_into()?,
+            enthalpy_of_formation.try_into()?,
+        )?))
+    }
+
+    /// Create a set of independent reactions from the element balance.
+    ///
+    /// Parameters
+    /// ----------
+    /// element_matrix : numpy.ndarray[float]
+    ///     The number of atoms of each element (rows) in
+    ///     every component (columns).
+    /// gibbs_energy_of_formation : SIArray1
+    ///     The standard molar Gibbs energy of formation of every component.
+    /// enthalpy_of_formation : SIArray1
+    ///     The standard molar enthalpy of formation of every component.
+    ///
+    /// Returns
+    /// -------
+    /// ChemicalReactions
+    #[staticmethod]
+    #[pyo3(text_signature = "(element_matrix, gibbs_energy_of_formation, enthalpy_of_formation)")]
+    fn from_element_matrix(
+        element_matrix: PyReadonlyArray2<f64>,
+        gibbs_energy_of_formation: PySIArray1,
+        enthalpy_of_formation: PySIArray1,
+    ) -> PyResult<Self> {
+        Ok(Self(ChemicalReactions::from_element_matrix(
+            &element_matrix.as_array().to_owned(),
+            gibbs_energy_of_formation.try_into()?,
+            enthalpy_of_formation.try_into()?,
+        )?))
+    }
+
+    #[getter]
+    fn get_reactions(&self) -> usize {
+        self.0.reactions()
+    }
+
+    #[getter]
+    fn get_stoichiometric_matrix<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray2<f64>> {
+        self.0.stoichiometric_matrix().to_pyarray_bound(py)
+    }
+
+    fn __repr__(&self) -> String {
+        self.0.to_string()
+    }
+}
@@ -1,4 +1,4 @@
-use crate::cubic::{PengRobinsonParameters, PengRobinsonRecord};
+use crate::cubic::{AlphaFunction, CubicParameters, CubicRecord, VolumeTranslation};
 use crate::parameter::{
     BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError, PureRecord,
 };
@@ -10,16 +10,171 @@ use pyo3::prelude::*;
 use std::convert::{TryFrom, TryInto};
 use std::sync::Arc;
 
-/// A pure substance parameter for the Peng-Robinson equation of state.
-#[pyclass(name = "PengRobinsonRecord")]
+/// Temperature dependence of the attractive parameter
+/// of a cubic equation of state.
+#[pyclass(name = "AlphaFunction")]
+#[derive(Clone, Copy)]
+pub struct PyAlphaFunction(pub AlphaFunction);
+
+#[pymethods]
+impl PyAlphaFunction {
+    /// Soave alpha function.
+    ///
+    /// Parameters
+    /// ----------
+    /// m : float
+    ///     The parameter m.
+    ///
+    /// Returns
+    /// -------
+    /// AlphaFunction
+    #[staticmethod]
+    fn soave(m: f64) -> Self {
+        Self(AlphaFunction::Soave { m })
+    }
+
+    /// Alpha function of Twu et al. (1991).
+    ///
+    /// Parameters
+    /// ----------
+    /// l : float
+    ///     The parameter L.
+    /// m : float
+    ///     The parameter M.
+    /// n : float
+    ///     The parameter N.
+    ///
+    /// Returns
+    /// -------
+    /// AlphaFunction
+    #[staticmethod]
+    fn twu91(l: f64, m: f64, n: f64) -> Self {
+        Self(AlphaFunction::Twu91 { l, m, n })
+    }
+
+    /// Alpha function of Mathias and Copeman (1983).
+    ///
+    /// Parameters
+    /// ----------
+    /// c1 : float
+    ///     The parameter c1.
+    /// c2 : float
+    ///     The parameter c2.
+    /// c3 : float
+    ///     The parameter c3.
+    ///
+    /// Returns
+    /// -------
+    /// AlphaFunction
+    #[staticmethod]
+    fn mathias_copeman(c1: f64, c2: f64, c3: f64) -> Self {
+        Self(AlphaFunction::MathiasCopeman { c1, c2, c3 })
+    }
+
+    /// Evaluate the alpha function at a reduced temperature.
+    ///
+    /// Parameters
+    /// ----------
+    /// reduced_temperature : float
+    ///     The reduced temperature T/Tc.
+    ///
+    /// Returns
+    /// -------
+    /// float
+    fn alpha(&self, reduced_temperature: f64) -> f64 {
+        self.0.alpha(reduced_temperature)
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// Volume translation of a cubic equation of state.
+#[pyclass(name = "VolumeTranslation")]
+#[derive(Clone, Copy)]
+pub struct PyVolumeTranslation(pub VolumeTranslation);
+
+#[pymethods]
+impl PyVolumeTranslation {
+    /// Constant volume translation.
+    ///
+    /// Parameters
+    /// ----------
+    /// c : float
+    ///     The volume shift in m³/mol.
+    ///
+    /// Returns
+    /// -------
+    /// VolumeTranslation
+    #[staticmethod]
+    fn constant(c: f64) -> Self {
+        Self(VolumeTranslation::Constant(c))
+    }
+
+    /// Volume translation that depends linearly on temperature.
+    ///
+    /// Parameters
+    /// ----------
+    /// c0 : float
+    ///     The constant volume shift in m³/mol.
+    /// c1 : float
+    ///     The temperature coefficient in m³/mol/K.
+    ///
+    /// Returns
+    /// -------
+    /// VolumeTranslation
+    #[staticmethod]
+    fn linear(c0: f64, c1: f64) -> Self {
+        Self(VolumeTranslation::Linear { c0, c1 })
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+/// A pure substance parameter for cubic equations of state.
+///
+/// Parameters
+/// ----------
+/// tc : float
+///     critical temperature in units of Kelvin
+/// pc : float
+///     critical pressure in units of Pascal
+/// acentric_factor : float
+///     acentric factor
+/// alpha : AlphaFunction, optional
+///     alpha function; defaults to the generalized Soave alpha
+///     function of the respective cubic model.
+/// volume_translation : VolumeTranslation, optional
+///     volume translation
+#[pyclass(name = "CubicRecord")]
 #[derive(Clone)]
-pub struct PyPengRobinsonRecord(PengRobinsonRecord);
+pub struct PyCubicRecord(CubicRecord);
 
 #[pymethods]
-impl PyPengRobinsonRecord {
+impl PyCubicRecord {
     #[new]
-    fn new(tc: f64, pc: f64, acentric_factor: f64) -> Self {
-        Self(PengRobinsonRecord::new(tc, pc, acentric_factor))
+    #[pyo3(
+        text_signature = "(tc, pc, acentric_factor, alpha=None, volume_translation=None)",
+        signature = (tc, pc, acentric_factor, alpha=None, volume_translation=None)
+    )]
+    fn new(
+        tc: f64,
+        pc: f64,
+        acentric_factor: f64,
+        alpha: Option<PyAlphaFunction>,
+        volume_translation: Option<PyVolumeTranslation>,
+    ) -> Self {
+        let mut record = CubicRecord::new(tc, pc, acentric_factor);
+        if let Some(alpha) = alpha {
+            record = record.alpha(alpha.0);
+        }
+        if let Some(volume_translation) = volume_translation {
+            record = record.volume_translation(volume_translation.0);
+        }
+        Self(record)
     }
 
     fn __repr__(&self) -> PyResult<String> {
@@ -27,25 +182,20 @@ impl PyPengRobinsonRecord {
     }
 }
 
-impl_json_handling!(PyPengRobinsonRecord);
+impl_json_handling!(PyCubicRecord);
 
-impl_pure_record!(PengRobinsonRecord, PyPengRobinsonRecord);
+impl_pure_record!(CubicRecord, PyCubicRecord);
 
 impl_binary_record!();
 
-#[pyclass(name = "PengRobinsonParameters")]
+#[pyclass(name = "CubicParameters")]
 #[derive(Clone)]
-pub struct PyPengRobinsonParameters(pub Arc<PengRobinsonParameters>);
+pub struct PyCubicParameters(pub Arc<CubicParameters>);
 
-impl_parameter!(
-    PengRobinsonParameters,
-    PyPengRobinsonParameters,
-    PyPengRobinsonRecord,
-    f64
-);
+impl_parameter!(CubicParameters, PyCubicParameters, PyCubicRecord, f64);
 
 #[pymethods]
-impl PyPengRobinsonParameters {
+impl PyCubicParameters {
     fn __repr__(&self) -> PyResult<String> {
         Ok(self.0.to_string())
     }
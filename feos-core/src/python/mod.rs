@@ -2,6 +2,8 @@ use crate::EosError;
 use pyo3::exceptions::PyRuntimeError;
 use pyo3::PyErr;
 
+pub mod activity;
+pub mod chemical_reactions;
 pub mod cubic;
 mod equation_of_state;
 pub mod parameter;
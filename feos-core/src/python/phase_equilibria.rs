@@ -276,6 +276,80 @@ macro_rules! impl_phase_equilibrium {
                 }
             }
 
+            /// Compute a phase equilibrium for given temperature
+            /// or pressure, feed and vapor phase fraction.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature_or_pressure : SINumber
+            ///     The system temperature or pressure.
+            /// vapor_fraction : float
+            ///     The molar vapor phase fraction between 0 and 1.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// tp_init : SINumber, optional
+            ///     The system pressure/temperature used as starting
+            ///     condition for the iteration. Required if the
+            ///     pressure is specified.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of inner iterations.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of outer iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the inner loop.
+            /// tol_outer : float, optional
+            ///     The solution tolerance in the outer loop.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature_or_pressure, vapor_fraction, feed, tp_init=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn vapor_fraction_flash(
+                eos: $py_eos,
+                temperature_or_pressure: PySINumber,
+                vapor_fraction: f64,
+                feed: PySIArray1,
+                tp_init: Option<PySINumber>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let options = (
+                    (max_iter_inner, tol_inner, verbosity).into(),
+                    (max_iter_outer, tol_outer, verbosity).into()
+                );
+                if let Ok(t) = Temperature::<f64>::try_from(temperature_or_pressure) {
+                    Ok(Self(PhaseEquilibrium::vapor_fraction_flash(
+                        &eos.0,
+                        t,
+                        vapor_fraction,
+                        &feed.try_into()?,
+                        tp_init.map(|p| p.try_into()).transpose()?,
+                        options,
+                    )?))
+                } else if let Ok(p) = Pressure::<f64>::try_from(temperature_or_pressure) {
+                    Ok(Self(PhaseEquilibrium::vapor_fraction_flash(
+                        &eos.0,
+                        p,
+                        vapor_fraction,
+                        &feed.try_into()?,
+                        tp_init.map(|t| t.try_into()).transpose()?,
+                        options,
+                    )?))
+                } else {
+                    Ok(Err(EosError::WrongUnits("temperature or pressure".into(),
+                        quantity::si::SINumber::from(temperature_or_pressure).to_string()
+                    ))?)
+                }
+            }
+
             /// Creates a new PhaseEquilibrium that contains two states at the
             /// specified temperature, pressure and moles.
             ///
@@ -326,6 +400,422 @@ macro_rules! impl_phase_equilibrium {
                 PyState(self.0.liquid().clone())
             }
 
+            /// The molar vapor phase fraction.
+            #[getter]
+            fn get_vapor_phase_fraction(&self) -> f64 {
+                self.0.vapor_phase_fraction()
+            }
+
+            /// Perform a flash calculation at given pressure and molar enthalpy.
+            ///
+            /// The temperature is iterated in an outer loop with a Tp-flash
+            /// in each step.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// molar_enthalpy : SINumber
+            ///     The molar enthalpy of the feed.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// initial_temperature : SINumber, optional
+            ///     The initial guess for the temperature.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations in the Tp-flash.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of temperature iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the Tp-flash.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the temperature iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            /// non_volatile_components : [int], optional
+            ///     Indices of components that are not present in the vapor phase.
+            ///
+            /// Returns
+            /// -------
+            /// State | PhaseEquilibrium
+            ///     A single state if the feed is stable,
+            ///     otherwise the two phases in equilibrium.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, molar_enthalpy, feed, initial_temperature=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None, non_volatile_components=None)")]
+            pub fn ph_flash(
+                py: Python,
+                eos: $py_eos,
+                pressure: PySINumber,
+                molar_enthalpy: PySINumber,
+                feed: PySIArray1,
+                initial_temperature: Option<PySINumber>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+                non_volatile_components: Option<Vec<usize>>,
+            ) -> PyResult<PyObject> {
+                let result = FlashResult::ph_flash(
+                    &eos.0,
+                    pressure.try_into()?,
+                    molar_enthalpy.try_into()?,
+                    &feed.try_into()?,
+                    initial_temperature.map(|t| t.try_into()).transpose()?,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    ),
+                    non_volatile_components,
+                )?;
+                Ok(match result {
+                    FlashResult::SinglePhase(s) => PyState(s).into_py(py),
+                    FlashResult::TwoPhase(vle) => Self(vle).into_py(py),
+                })
+            }
+
+            /// Perform a flash calculation at given pressure and molar entropy.
+            ///
+            /// The temperature is iterated in an outer loop with a Tp-flash
+            /// in each step.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// molar_entropy : SINumber
+            ///     The molar entropy of the feed.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// initial_temperature : SINumber, optional
+            ///     The initial guess for the temperature.
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations in the Tp-flash.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of temperature iterations.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the Tp-flash.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the temperature iteration.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            /// non_volatile_components : [int], optional
+            ///     Indices of components that are not present in the vapor phase.
+            ///
+            /// Returns
+            /// -------
+            /// State | PhaseEquilibrium
+            ///     A single state if the feed is stable,
+            ///     otherwise the two phases in equilibrium.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, pressure, molar_entropy, feed, initial_temperature=None, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None, non_volatile_components=None)")]
+            pub fn ps_flash(
+                py: Python,
+                eos: $py_eos,
+                pressure: PySINumber,
+                molar_entropy: PySINumber,
+                feed: PySIArray1,
+                initial_temperature: Option<PySINumber>,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+                non_volatile_components: Option<Vec<usize>>,
+            ) -> PyResult<PyObject> {
+                let result = FlashResult::ps_flash(
+                    &eos.0,
+                    pressure.try_into()?,
+                    molar_entropy.try_into()?,
+                    &feed.try_into()?,
+                    initial_temperature.map(|t| t.try_into()).transpose()?,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    ),
+                    non_volatile_components,
+                )?;
+                Ok(match result {
+                    FlashResult::SinglePhase(s) => PyState(s).into_py(py),
+                    FlashResult::TwoPhase(vle) => Self(vle).into_py(py),
+                })
+            }
+
+            /// Calculate the simultaneous phase and chemical equilibrium
+            /// at given temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// reactions : ChemicalReactions
+            ///     The chemical reactions.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// max_iter_inner : int, optional
+            ///     The maximum number of iterations in the Tp-flash.
+            /// max_iter_outer : int, optional
+            ///     The maximum number of iterations of the extents of reaction.
+            /// tol_inner : float, optional
+            ///     The solution tolerance in the Tp-flash.
+            /// tol_outer : float, optional
+            ///     The solution tolerance of the extents of reaction.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State | PhaseEquilibrium
+            ///     A single state if the reacting mixture is stable,
+            ///     otherwise the two phases in equilibrium.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, reactions, temperature, pressure, feed, max_iter_inner=None, max_iter_outer=None, tol_inner=None, tol_outer=None, verbosity=None)")]
+            pub fn reactive_flash(
+                py: Python,
+                eos: $py_eos,
+                reactions: PyChemicalReactions,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                feed: PySIArray1,
+                max_iter_inner: Option<usize>,
+                max_iter_outer: Option<usize>,
+                tol_inner: Option<f64>,
+                tol_outer: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyObject> {
+                let result = FlashResult::reactive_flash(
+                    &eos.0,
+                    &reactions.0,
+                    temperature.try_into()?,
+                    pressure.try_into()?,
+                    &feed.try_into()?,
+                    (
+                        (max_iter_inner, tol_inner, verbosity).into(),
+                        (max_iter_outer, tol_outer, verbosity).into(),
+                    ),
+                )?;
+                Ok(match result {
+                    FlashResult::SinglePhase(s) => PyState(s).into_py(py),
+                    FlashResult::TwoPhase(vle) => Self(vle).into_py(py),
+                })
+            }
+
+            /// Compute a bubble point with the gamma-phi approach for given
+            /// temperature or pressure and liquid mole fractions.
+            ///
+            /// The liquid phase is described by the activity coefficient model
+            /// and the vapor phase by the equation of state. The Poynting
+            /// correction is neglected.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state used for the vapor phase.
+            /// activity_model : ActivityModel
+            ///     The activity coefficient model used for the liquid phase.
+            /// temperature_or_pressure : SINumber
+            ///     The system temperature or pressure.
+            /// liquid_molefracs : numpy.ndarray
+            ///     The mole fraction of the liquid phase.
+            /// vapor_pressure : VaporPressureCorrelation, optional
+            ///     Correlations for the pure component vapor pressures.
+            ///     Defaults to the vapor pressures of the equation of state.
+            /// tp_init : SINumber, optional
+            ///     The system pressure/temperature used as starting
+            ///     condition for the iteration.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, activity_model, temperature_or_pressure, liquid_molefracs, vapor_pressure=None, tp_init=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn bubble_point_gamma_phi(
+                eos: $py_eos,
+                activity_model: PyActivityModel,
+                temperature_or_pressure: PySINumber,
+                liquid_molefracs: &Bound<'_, PyArray1<f64>>,
+                vapor_pressure: Option<PyVaporPressureCorrelation>,
+                tp_init: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let vp: &dyn VaporPressureModel = match &vapor_pressure {
+                    Some(vp) => vp.0.as_ref(),
+                    None => &eos.0,
+                };
+                let x = liquid_molefracs.to_owned_array();
+                let options = (max_iter, tol, verbosity).into();
+                if let Ok(t) = Temperature::<f64>::try_from(temperature_or_pressure) {
+                    Ok(Self(PhaseEquilibrium::bubble_point_gamma_phi(
+                        &eos.0,
+                        &activity_model.0,
+                        vp,
+                        t,
+                        &x,
+                        tp_init.map(|p| p.try_into()).transpose()?,
+                        options,
+                    )?))
+                } else if let Ok(p) = Pressure::<f64>::try_from(temperature_or_pressure) {
+                    Ok(Self(PhaseEquilibrium::bubble_point_gamma_phi(
+                        &eos.0,
+                        &activity_model.0,
+                        vp,
+                        p,
+                        &x,
+                        tp_init.map(|t| t.try_into()).transpose()?,
+                        options,
+                    )?))
+                } else {
+                    Ok(Err(EosError::WrongUnits("temperature or pressure".into(),
+                        quantity::si::SINumber::from(temperature_or_pressure).to_string()
+                    ))?)
+                }
+            }
+
+            /// Compute a dew point with the gamma-phi approach for given
+            /// temperature or pressure and vapor mole fractions.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state used for the vapor phase.
+            /// activity_model : ActivityModel
+            ///     The activity coefficient model used for the liquid phase.
+            /// temperature_or_pressure : SINumber
+            ///     The system temperature or pressure.
+            /// vapor_molefracs : numpy.ndarray
+            ///     The mole fraction of the vapor phase.
+            /// vapor_pressure : VaporPressureCorrelation, optional
+            ///     Correlations for the pure component vapor pressures.
+            ///     Defaults to the vapor pressures of the equation of state.
+            /// tp_init : SINumber, optional
+            ///     The system pressure/temperature used as starting
+            ///     condition for the iteration.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, activity_model, temperature_or_pressure, vapor_molefracs, vapor_pressure=None, tp_init=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn dew_point_gamma_phi(
+                eos: $py_eos,
+                activity_model: PyActivityModel,
+                temperature_or_pressure: PySINumber,
+                vapor_molefracs: &Bound<'_, PyArray1<f64>>,
+                vapor_pressure: Option<PyVaporPressureCorrelation>,
+                tp_init: Option<PySINumber>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let vp: &dyn VaporPressureModel = match &vapor_pressure {
+                    Some(vp) => vp.0.as_ref(),
+                    None => &eos.0,
+                };
+                let y = vapor_molefracs.to_owned_array();
+                let options = (max_iter, tol, verbosity).into();
+                if let Ok(t) = Temperature::<f64>::try_from(temperature_or_pressure) {
+                    Ok(Self(PhaseEquilibrium::dew_point_gamma_phi(
+                        &eos.0,
+                        &activity_model.0,
+                        vp,
+                        t,
+                        &y,
+                        tp_init.map(|p| p.try_into()).transpose()?,
+                        options,
+                    )?))
+                } else if let Ok(p) = Pressure::<f64>::try_from(temperature_or_pressure) {
+                    Ok(Self(PhaseEquilibrium::dew_point_gamma_phi(
+                        &eos.0,
+                        &activity_model.0,
+                        vp,
+                        p,
+                        &y,
+                        tp_init.map(|t| t.try_into()).transpose()?,
+                        options,
+                    )?))
+                } else {
+                    Ok(Err(EosError::WrongUnits("temperature or pressure".into(),
+                        quantity::si::SINumber::from(temperature_or_pressure).to_string()
+                    ))?)
+                }
+            }
+
+            /// Perform a Tp-flash calculation with the gamma-phi approach.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state used for the vapor phase.
+            /// activity_model : ActivityModel
+            ///     The activity coefficient model used for the liquid phase.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// vapor_pressure : VaporPressureCorrelation, optional
+            ///     Correlations for the pure component vapor pressures.
+            ///     Defaults to the vapor pressures of the equation of state.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, activity_model, temperature, pressure, feed, vapor_pressure=None, max_iter=None, tol=None, verbosity=None)")]
+            pub fn tp_flash_gamma_phi(
+                eos: $py_eos,
+                activity_model: PyActivityModel,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                feed: PySIArray1,
+                vapor_pressure: Option<PyVaporPressureCorrelation>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let vp: &dyn VaporPressureModel = match &vapor_pressure {
+                    Some(vp) => vp.0.as_ref(),
+                    None => &eos.0,
+                };
+                Ok(Self(PhaseEquilibrium::tp_flash_gamma_phi(
+                    &eos.0,
+                    &activity_model.0,
+                    vp,
+                    temperature.try_into()?,
+                    pressure.try_into()?,
+                    &feed.try_into()?,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
             /// Calculate the pure component vapor-liquid equilibria for all
             /// components in the system.
             ///
@@ -560,6 +1050,143 @@ macro_rules! impl_phase_equilibrium {
                     non_volatile_components
                 )?))
             }
+
+            /// Calculates a Tp-flash with an arbitrary number of phases
+            /// with the state as feed.
+            ///
+            /// Parameters
+            /// ----------
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            /// non_volatile_components : [int], optional
+            ///     Indices of components that are not present in the vapor phase.
+            ///
+            /// Returns
+            /// -------
+            /// MultiPhaseEquilibrium
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When pressure iteration fails or the phase equilibrium does not converge.
+            #[pyo3(text_signature = "($self, max_iter=None, tol=None, verbosity=None, non_volatile_components=None)")]
+            pub fn multiphase_flash(
+                &self,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                non_volatile_components: Option<Vec<usize>>,
+            ) -> PyResult<PyMultiPhaseEquilibrium> {
+                Ok(PyMultiPhaseEquilibrium(self.0.multiphase_flash(
+                    (max_iter, tol, verbosity).into(),
+                    non_volatile_components
+                )?))
+            }
+        }
+
+        /// A thermodynamic equilibrium state with an arbitrary number of phases.
+        #[pyclass(name = "MultiPhaseEquilibrium")]
+        #[derive(Clone)]
+        pub struct PyMultiPhaseEquilibrium(MultiPhaseEquilibrium<$eos>);
+
+        #[pymethods]
+        impl PyMultiPhaseEquilibrium {
+            /// Perform a Tp-flash calculation with an arbitrary number of phases.
+            ///
+            /// Phases are added successively based on a stability analysis
+            /// of the current solution until the system is stable.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos : EquationOfState
+            ///     The equation of state.
+            /// temperature : SINumber
+            ///     The system temperature.
+            /// pressure : SINumber
+            ///     The system pressure.
+            /// feed : SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            /// non_volatile_components : [int], optional
+            ///     Indices of components that are not present in the vapor phase.
+            ///
+            /// Returns
+            /// -------
+            /// MultiPhaseEquilibrium
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     When pressure iteration fails or the phase equilibrium does not converge.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, temperature, pressure, feed, max_iter=None, tol=None, verbosity=None, non_volatile_components=None)")]
+            pub fn tp_flash(
+                eos: $py_eos,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                feed: PySIArray1,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+                non_volatile_components: Option<Vec<usize>>,
+            ) -> PyResult<Self> {
+                Ok(Self(MultiPhaseEquilibrium::tp_flash(
+                    &eos.0,
+                    temperature.try_into()?,
+                    pressure.try_into()?,
+                    &feed.try_into()?,
+                    (max_iter, tol, verbosity).into(),
+                    non_volatile_components
+                )?))
+            }
+
+            /// The coexisting phases in the order of increasing density.
+            #[getter]
+            fn get_phases(&self) -> PyStateVec {
+                self.0.phases().into()
+            }
+
+            /// The phase fractions (amount of substance in each phase
+            /// relative to the total amount of substance).
+            #[getter]
+            fn get_phase_fractions<'py>(&self, py: Python<'py>) -> Bound<'py, PyArray1<f64>> {
+                self.0.phase_fractions().into_pyarray_bound(py)
+            }
+
+            /// Converts the multiphase equilibrium into a two-phase equilibrium.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEquilibrium
+            ///
+            /// Raises
+            /// ------
+            /// RuntimeError
+            ///     If the number of phases is not two.
+            fn to_phase_equilibrium(&self) -> PyResult<PyPhaseEquilibrium> {
+                Ok(PyPhaseEquilibrium(self.0.clone().try_into()?))
+            }
+
+            fn __len__(&self) -> usize {
+                self.0.number_of_phases()
+            }
+
+            fn _repr_markdown_(&self) -> String {
+                self.0._repr_markdown_()
+            }
+
+            fn __repr__(&self) -> PyResult<String> {
+                Ok(self.0.to_string())
+            }
         }
 
         /// Phase diagram for a pure component or a binary mixture.
@@ -858,6 +1485,52 @@ macro_rules! impl_phase_equilibrium {
                 Ok(Self(dia))
             }
 
+            /// Calculate the phase envelope of a mixture with fixed composition
+            /// using a continuation method.
+            ///
+            /// The envelope is traced from the bubble point at the minimum
+            /// temperature across the critical point to the dew point branch.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: Eos
+            ///     The equation of state.
+            /// moles: SIArray1
+            ///     The moles of the individual components
+            /// min_temperature: SINumber
+            ///     The temperature of the first bubble point.
+            /// npoints: int
+            ///     The maximum number of points.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for each point.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// PhaseEnvelope
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, moles, min_temperature, npoints, max_iter=None, tol=None, verbosity=None)")]
+            pub fn phase_envelope(
+                eos: &$py_eos,
+                moles: PySIArray1,
+                min_temperature: PySINumber,
+                npoints: usize,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyPhaseEnvelope> {
+                Ok(PyPhaseEnvelope(PhaseDiagram::phase_envelope(
+                    &eos.0,
+                    &moles.try_into()?,
+                    min_temperature.try_into()?,
+                    npoints,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
             #[getter]
             pub fn get_states(&self) -> Vec<PyPhaseEquilibrium> {
                 self.0
@@ -1190,5 +1863,43 @@ macro_rules! impl_phase_equilibrium {
                     .map(|d| PyPhaseDiagram(d.clone()))
             }
         }
+
+        /// Phase envelope of a mixture with fixed composition.
+        ///
+        /// Attributes
+        /// ----------
+        /// diagram : PhaseDiagram
+        ///     The points on the phase envelope.
+        /// critical_point : State | None
+        ///     The critical point, if the envelope was traced through it.
+        /// cricondenbar : PhaseEquilibrium | None
+        ///     The point of maximum pressure, if it was found.
+        /// cricondentherm : PhaseEquilibrium | None
+        ///     The point of maximum temperature, if it was found.
+        #[pyclass(name = "PhaseEnvelope")]
+        pub struct PyPhaseEnvelope(PhaseEnvelope<$eos>);
+
+        #[pymethods]
+        impl PyPhaseEnvelope {
+            #[getter]
+            pub fn get_diagram(&self) -> PyPhaseDiagram {
+                PyPhaseDiagram(self.0.diagram.clone())
+            }
+
+            #[getter]
+            pub fn get_critical_point(&self) -> Option<PyState> {
+                self.0.critical_point.clone().map(PyState)
+            }
+
+            #[getter]
+            pub fn get_cricondenbar(&self) -> Option<PyPhaseEquilibrium> {
+                self.0.cricondenbar.clone().map(PyPhaseEquilibrium)
+            }
+
+            #[getter]
+            pub fn get_cricondentherm(&self) -> Option<PyPhaseEquilibrium> {
+                self.0.cricondentherm.clone().map(PyPhaseEquilibrium)
+            }
+        }
     }
 }
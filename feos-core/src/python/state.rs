@@ -237,6 +237,67 @@ macro_rules! impl_state {
                 }
             }
 
+            /// Calculate the chemical equilibrium of a single phase
+            /// at given temperature and pressure.
+            ///
+            /// Parameters
+            /// ----------
+            /// eos: EquationOfState
+            ///     The equation of state to use.
+            /// reactions: ChemicalReactions
+            ///     The chemical reactions.
+            /// temperature: SINumber
+            ///     The temperature.
+            /// pressure: SINumber
+            ///     The pressure.
+            /// feed: SIArray1
+            ///     Feed composition (units of amount of substance).
+            /// density_initialization : {'vapor', 'liquid', None}, optional
+            ///     Method used to initialize the density of the phase.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations.
+            /// tol: float, optional
+            ///     The solution tolerance.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// State : State in chemical equilibrium.
+            #[staticmethod]
+            #[pyo3(text_signature = "(eos, reactions, temperature, pressure, feed, density_initialization=None, max_iter=None, tol=None, verbosity=None)")]
+            fn reaction_equilibrium(
+                eos: $py_eos,
+                reactions: PyChemicalReactions,
+                temperature: PySINumber,
+                pressure: PySINumber,
+                feed: PySIArray1,
+                density_initialization: Option<&str>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                let density_initialization = match density_initialization {
+                    Some("vapor") => DensityInitialization::Vapor,
+                    Some("liquid") => DensityInitialization::Liquid,
+                    None => DensityInitialization::None,
+                    Some(_) => {
+                        return Err(PyErr::new::<PyValueError, _>(
+                            "`density_initialization` must be 'vapor' or 'liquid'.".to_string(),
+                        ))
+                    }
+                };
+                Ok(PyState(State::reaction_equilibrium(
+                    &eos.0,
+                    &reactions.0,
+                    temperature.try_into()?,
+                    pressure.try_into()?,
+                    &feed.try_into()?,
+                    density_initialization,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
             /// Calculate spinodal states for a given temperature and composition.
             ///
             /// Parameters
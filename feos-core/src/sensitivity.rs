@@ -0,0 +1,401 @@
+//! Exact derivatives of thermodynamic properties with respect to model parameters.
+//!
+//! Models that implement [ParametersAD] can evaluate their residual Helmholtz energy
+//! with dual-valued parameters. The functions in this module use this capability to
+//! calculate properties together with their derivatives with respect to the model
+//! parameters. Properties that are defined implicitly (e.g., a vapor pressure or the
+//! density at given temperature and pressure) are first converged with the regular
+//! solvers. The derivatives are then obtained from the implicit function theorem by
+//! applying Newton steps with dual-valued parameters to the converged solution.
+//!
+//! The generic dual number `D` determines which derivatives are calculated. With
+//! [Dual64] the directional derivative along the dual part of the parameters is
+//! obtained, [gradient] provides the full gradient with respect to all parameters.
+use crate::si::*;
+use crate::{
+    Contributions, DensityInitialization, EosResult, EquationOfState, IdealGas, PhaseEquilibrium,
+    Residual, SolverOptions, State, StateHD,
+};
+use ndarray::{s, Array1, Array2, ScalarOperand};
+use num_dual::linalg::LU;
+use num_dual::*;
+use std::sync::Arc;
+
+/// A residual Helmholtz energy model that can be evaluated with dual-valued parameters.
+pub trait ParametersAD: Residual {
+    /// Return the names of all parameters in the order used in [ParametersAD::parameters].
+    fn parameter_names(&self) -> Vec<String>;
+
+    /// Return the current values of all parameters.
+    fn parameters(&self) -> Array1<f64>;
+
+    /// Evaluate the residual reduced Helmholtz energy $\beta A^\mathrm{res}$ for the given parameters.
+    ///
+    /// For parameters equal to [ParametersAD::parameters], the result has to be
+    /// identical to [Residual::residual_helmholtz_energy].
+    fn residual_helmholtz_energy_ad<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        state: &StateHD<D>,
+    ) -> D;
+}
+
+impl<I: IdealGas, R: ParametersAD> ParametersAD for EquationOfState<I, R> {
+    fn parameter_names(&self) -> Vec<String> {
+        self.residual.parameter_names()
+    }
+
+    fn parameters(&self) -> Array1<f64> {
+        self.residual.parameters()
+    }
+
+    fn residual_helmholtz_energy_ad<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        state: &StateHD<D>,
+    ) -> D {
+        self.residual
+            .residual_helmholtz_energy_ad(parameters, state)
+    }
+}
+
+/// Calculate a property and its gradient with respect to all model parameters.
+///
+/// The property is evaluated once for every parameter with the corresponding
+/// entry in the dual part of the parameters set to 1.
+#[allow(clippy::type_complexity)]
+pub fn gradient<E: ParametersAD, U: Copy, F>(
+    eos: &E,
+    property: F,
+) -> EosResult<(Quantity<f64, U>, Quantity<Array1<f64>, U>)>
+where
+    F: Fn(&Array1<Dual64>) -> EosResult<Quantity<Dual64, U>>,
+{
+    let parameters = eos.parameters().mapv(Dual64::from);
+    let mut value = None;
+    let gradient = (0..parameters.len())
+        .map(|i| {
+            let mut parameters = parameters.clone();
+            parameters[i].eps = 1.0;
+            let res = property(&parameters)?;
+            value = Some(res.re());
+            Ok(res.eps())
+        })
+        .collect::<EosResult<_>>()?;
+    let value = match value {
+        Some(value) => value,
+        None => property(&parameters)?.re(),
+    };
+    Ok((value, gradient))
+}
+
+/// Vapor pressure of a pure component.
+pub fn vapor_pressure<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &Arc<E>,
+    parameters: &Array1<D>,
+    temperature: Temperature,
+) -> EosResult<Pressure<D>> {
+    let t = temperature.to_reduced();
+    let [_, rho_v] = pure_vle(eos, parameters, temperature)?;
+    let (p, _) =
+        pressure_chemical_potential(&**eos, parameters, D::from(t), &Array1::from_elem(1, rho_v));
+    Ok(Pressure::from_reduced(p * t))
+}
+
+/// Density of the liquid phase in equilibrium with its vapor for a pure component.
+pub fn equilibrium_liquid_density<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &Arc<E>,
+    parameters: &Array1<D>,
+    temperature: Temperature,
+) -> EosResult<Density<D>> {
+    let [rho_l, _] = pure_vle(eos, parameters, temperature)?;
+    Ok(Density::from_reduced(rho_l))
+}
+
+/// Density at given temperature, pressure and composition.
+pub fn density<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &Arc<E>,
+    parameters: &Array1<D>,
+    temperature: Temperature,
+    pressure: Pressure,
+    moles: Option<&Moles<Array1<f64>>>,
+    density_initialization: DensityInitialization,
+) -> EosResult<Density<D>> {
+    let moles = eos.validate_moles(moles)?;
+    let state = State::new_npt(eos, temperature, pressure, &moles, density_initialization)?;
+    let rho = density_tp(&state, parameters, pressure)?;
+    Ok(Density::from_reduced(rho))
+}
+
+/// Bubble point pressure for given temperature and liquid composition.
+///
+/// All components have to be present in the liquid phase.
+pub fn bubble_point_pressure<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &Arc<E>,
+    parameters: &Array1<D>,
+    temperature: Temperature,
+    liquid_molefracs: &Array1<f64>,
+) -> EosResult<Pressure<D>> {
+    let vle = PhaseEquilibrium::bubble_point(
+        eos,
+        temperature,
+        liquid_molefracs,
+        None,
+        None,
+        Default::default(),
+    )?;
+    let t = temperature.to_reduced();
+    let mut x0 = Array1::zeros(eos.components() + 1);
+    x0[0] = vle.liquid().density.to_reduced();
+    x0.slice_mut(s![1..])
+        .assign(&vle.vapor().partial_density.to_reduced());
+    let equations = BubblePoint {
+        eos: &**eos,
+        temperature: t,
+        liquid_molefracs,
+    };
+    let x = equations.solve(parameters, x0)?;
+    let rho_v = x.slice(s![1..]).to_owned();
+    let (p, _) = pressure_chemical_potential(&**eos, parameters, D::from(t), &rho_v);
+    Ok(Pressure::from_reduced(p * t))
+}
+
+/// Speed of sound at given temperature, pressure and composition.
+///
+/// The ideal gas contribution to the heat capacity does not depend on the
+/// parameters of the residual model and is evaluated with the ideal gas model
+/// of the equation of state.
+pub fn speed_of_sound<E: ParametersAD + IdealGas, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &Arc<E>,
+    parameters: &Array1<D>,
+    temperature: Temperature,
+    pressure: Pressure,
+    moles: Option<&Moles<Array1<f64>>>,
+    density_initialization: DensityInitialization,
+) -> EosResult<Velocity<D>> {
+    let moles = eos.validate_moles(moles)?;
+    let state = State::new_npt(eos, temperature, pressure, &moles, density_initialization)?;
+    let rho = density_tp(&state, parameters, pressure)?;
+    let x = &state.molefracs;
+    let t = D::from(temperature.to_reduced());
+
+    // Helmholtz energy density and its derivatives w.r.t. temperature and density
+    let (_, a_t, _, a_trho) = second_partial_derivative(
+        |t, rho| {
+            let parameters = parameters.mapv(HyperDual::from_re);
+            helmholtz_energy_density_molefracs(&**eos, &parameters, t, rho, x)
+        },
+        t,
+        rho,
+    );
+    let parameters = parameters.mapv(Dual2::from_re);
+    let (_, _, a_tt) = second_derivative(
+        |t| helmholtz_energy_density_molefracs(&**eos, &parameters, t, Dual2::from_re(rho), x),
+        t,
+    );
+    let (_, _, a_rhorho) = second_derivative(
+        |rho| helmholtz_energy_density_molefracs(&**eos, &parameters, Dual2::from_re(t), rho, x),
+        rho,
+    );
+
+    let cv_ig = (state.molar_isochoric_heat_capacity(Contributions::IdealGas) / RGAS).into_value();
+    let cv = -t * a_tt / rho + cv_ig;
+    let dp_drho = t + rho * a_rhorho;
+    let dp_dt = rho + rho * a_trho - a_t;
+    let cp = cv + t * dp_dt * dp_dt / (rho * rho * dp_drho);
+    let molar_weight = (eos.molar_weight().to_reduced() * x).sum();
+    Ok(Velocity::from_reduced(
+        (cp / cv * dp_drho / molar_weight).sqrt(),
+    ))
+}
+
+/// The phase densities of a pure component in vapor-liquid equilibrium.
+fn pure_vle<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &Arc<E>,
+    parameters: &Array1<D>,
+    temperature: Temperature,
+) -> EosResult<[D; 2]> {
+    let vle = PhaseEquilibrium::pure(eos, temperature, None, SolverOptions::default())?;
+    let x0 = Array1::from_vec(vec![
+        vle.liquid().density.to_reduced(),
+        vle.vapor().density.to_reduced(),
+    ]);
+    let equations = PureVle {
+        eos: &**eos,
+        temperature: temperature.to_reduced(),
+    };
+    let x = equations.solve(parameters, x0)?;
+    Ok([x[0], x[1]])
+}
+
+/// The density of a converged state at the given pressure.
+fn density_tp<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    state: &State<E>,
+    parameters: &Array1<D>,
+    pressure: Pressure,
+) -> EosResult<D> {
+    let t = state.temperature.to_reduced();
+    let equations = DensityTP {
+        eos: &*state.eos,
+        temperature: t,
+        pressure: pressure.to_reduced() / t,
+        molefracs: &state.molefracs,
+    };
+    let x = equations.solve(parameters, Array1::from_elem(1, state.density.to_reduced()))?;
+    Ok(x[0])
+}
+
+/// Residual Helmholtz energy density $\beta A^\mathrm{res}/V$.
+fn helmholtz_energy_density<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &E,
+    parameters: &Array1<D>,
+    temperature: D,
+    partial_density: &Array1<D>,
+) -> D {
+    let state = StateHD::new(temperature, D::one(), partial_density.clone());
+    eos.residual_helmholtz_energy_ad(parameters, &state)
+}
+
+/// Residual Helmholtz energy density $A^\mathrm{res}/V$ for given total density and composition.
+fn helmholtz_energy_density_molefracs<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &E,
+    parameters: &Array1<D>,
+    temperature: D,
+    density: D,
+    molefracs: &Array1<f64>,
+) -> D {
+    let partial_density = molefracs.mapv(|x| density * x);
+    helmholtz_energy_density(eos, parameters, temperature, &partial_density) * temperature
+}
+
+/// Reduced pressure $\beta p$ and reduced residual chemical potentials $\beta\mu_i^\mathrm{res}$.
+fn pressure_chemical_potential<E: ParametersAD, D: DualNum<f64> + Copy + ScalarOperand>(
+    eos: &E,
+    parameters: &Array1<D>,
+    temperature: D,
+    partial_density: &Array1<D>,
+) -> (D, Array1<D>) {
+    let parameters = parameters.mapv(Dual::from_re);
+    let temperature = Dual::from_re(temperature);
+    let mut phi = D::zero();
+    let mut mu_res = Array1::zeros(partial_density.len());
+    for i in 0..partial_density.len() {
+        let mut rho = partial_density.mapv(Dual::from_re);
+        rho[i].eps = D::one();
+        let a = helmholtz_energy_density(eos, &parameters, temperature, &rho);
+        phi = a.re;
+        mu_res[i] = a.eps;
+    }
+    let p = partial_density.sum() - phi + (partial_density * &mu_res).sum();
+    (p, mu_res)
+}
+
+/// A system of equations that implicitly defines a property.
+trait ImplicitEquations {
+    /// Evaluate the residuals of the equations.
+    fn residuals<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        x: &Array1<D>,
+    ) -> Array1<D>;
+
+    /// Propagate the derivatives w.r.t. the parameters to the converged solution `x0`.
+    ///
+    /// Every Newton step adds one order of derivatives, so the number of steps
+    /// equals the order of the dual number.
+    fn solve<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        x0: Array1<f64>,
+    ) -> EosResult<Array1<D>> {
+        let n = x0.len();
+        let parameters_dual = parameters.mapv(Dual::from_re);
+        let mut x = x0.mapv(D::from);
+        for _ in 0..D::NDERIV {
+            let mut f = Array1::zeros(n);
+            let mut jacobian = Array2::zeros((n, n));
+            for k in 0..n {
+                let mut x_dual = x.mapv(Dual::from_re);
+                x_dual[k].eps = D::one();
+                let res = self.residuals(&parameters_dual, &x_dual);
+                f = res.mapv(|r| r.re);
+                jacobian.column_mut(k).assign(&res.mapv(|r| r.eps));
+            }
+            x -= &LU::new(jacobian)?.solve(&f);
+        }
+        Ok(x)
+    }
+}
+
+/// Phase equilibrium of a pure component in terms of the liquid and vapor density.
+struct PureVle<'a, E> {
+    eos: &'a E,
+    temperature: f64,
+}
+
+impl<E: ParametersAD> ImplicitEquations for PureVle<'_, E> {
+    fn residuals<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        x: &Array1<D>,
+    ) -> Array1<D> {
+        let t = D::from(self.temperature);
+        let (p_l, mu_l) =
+            pressure_chemical_potential(self.eos, parameters, t, &Array1::from_elem(1, x[0]));
+        let (p_v, mu_v) =
+            pressure_chemical_potential(self.eos, parameters, t, &Array1::from_elem(1, x[1]));
+        Array1::from_vec(vec![p_l - p_v, x[0].ln() + mu_l[0] - x[1].ln() - mu_v[0]])
+    }
+}
+
+/// Total density at given temperature, pressure and composition.
+struct DensityTP<'a, E> {
+    eos: &'a E,
+    temperature: f64,
+    pressure: f64,
+    molefracs: &'a Array1<f64>,
+}
+
+impl<E: ParametersAD> ImplicitEquations for DensityTP<'_, E> {
+    fn residuals<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        x: &Array1<D>,
+    ) -> Array1<D> {
+        let partial_density = self.molefracs.mapv(|xi| x[0] * xi);
+        let (p, _) = pressure_chemical_potential(
+            self.eos,
+            parameters,
+            D::from(self.temperature),
+            &partial_density,
+        );
+        Array1::from_elem(1, p - self.pressure)
+    }
+}
+
+/// Bubble point in terms of the total liquid density and the partial densities of the vapor.
+struct BubblePoint<'a, E> {
+    eos: &'a E,
+    temperature: f64,
+    liquid_molefracs: &'a Array1<f64>,
+}
+
+impl<E: ParametersAD> ImplicitEquations for BubblePoint<'_, E> {
+    fn residuals<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        x: &Array1<D>,
+    ) -> Array1<D> {
+        let t = D::from(self.temperature);
+        let rho_l = self.liquid_molefracs.mapv(|xi| x[0] * xi);
+        let rho_v = x.slice(s![1..]).to_owned();
+        let (p_l, mu_l) = pressure_chemical_potential(self.eos, parameters, t, &rho_l);
+        let (p_v, mu_v) = pressure_chemical_potential(self.eos, parameters, t, &rho_v);
+        let mut res = Array1::zeros(x.len());
+        res[0] = p_l - p_v;
+        for i in 0..rho_v.len() {
+            res[i + 1] = rho_l[i].ln() + mu_l[i] - rho_v[i].ln() - mu_v[i];
+        }
+        res
+    }
+}
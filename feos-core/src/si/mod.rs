@@ -3,6 +3,7 @@
 #![allow(clippy::type_complexity)]
 use ang::{Angle, Degrees, Radians};
 use ndarray::{Array, ArrayBase, Data, Dimension};
+use num_dual::Dual64;
 use num_traits::Zero;
 use std::marker::PhantomData;
 use std::ops::{Div, Mul, Sub};
@@ -93,6 +94,10 @@ pub type _Density = Diff<_Moles, _Volume>;
 pub type Density<T = f64> = Quantity<T, _Density>;
 pub type _MassDensity = Diff<_Mass, _Volume>;
 pub type MassDensity<T = f64> = Quantity<T, _MassDensity>;
+pub type _SpecificVolume = Diff<_Volume, _Mass>;
+pub type SpecificVolume<T = f64> = Quantity<T, _SpecificVolume>;
+pub type _SpecificAmount = Diff<_Moles, _Mass>;
+pub type SpecificAmount<T = f64> = Quantity<T, _SpecificAmount>;
 pub type _PressurePerVolume = Diff<_Pressure, _Volume>;
 pub type PressurePerVolume<T = f64> = Quantity<T, _PressurePerVolume>;
 pub type _PressurePerTemperature = Diff<_Pressure, _Temperature>;
@@ -311,6 +316,18 @@ impl<T, U> Quantity<T, U> {
     }
 }
 
+impl<U> Quantity<Dual64, U> {
+    /// Return the real part of a dual-valued quantity.
+    pub fn re(&self) -> Quantity<f64, U> {
+        Quantity(self.0.re, PhantomData)
+    }
+
+    /// Return the derivative (dual part) of a dual-valued quantity.
+    pub fn eps(&self) -> Quantity<f64, U> {
+        Quantity(self.0.eps, PhantomData)
+    }
+}
+
 impl<T> From<T> for Dimensionless<T> {
     fn from(value: T) -> Self {
         Quantity(value, PhantomData)
@@ -3,11 +3,12 @@ use syn::DeriveInput;
 
 use crate::implement;
 
-const OPT_IMPLS: [&str; 4] = [
+const OPT_IMPLS: [&str; 5] = [
     "bond_lengths",
     "molar_weight",
     "fluid_parameters",
     "pair_potential",
+    "electrostatics",
 ];
 
 pub(crate) fn expand_helmholtz_energy_functional(
@@ -119,6 +120,16 @@ fn impl_helmholtz_energy_functional(
         }
     }
 
+    let mut electrostatics = Vec::new();
+    for v in variants.iter() {
+        if implement("electrostatics", v, &OPT_IMPLS)? {
+            let name = &v.ident;
+            electrostatics.push(quote! {
+                Self::#name(functional) => functional.electrostatics(temperature, density)
+            });
+        }
+    }
+
     Ok(quote! {
         impl HelmholtzEnergyFunctional for FunctionalVariant {
             type Contribution = FunctionalContributionVariant;
@@ -149,6 +160,12 @@ fn impl_helmholtz_energy_functional(
                     _ => Graph::with_capacity(0, 0),
                 }
             }
+            fn electrostatics(&self, temperature: f64, density: &Array1<f64>) -> Option<Electrostatics> {
+                match self {
+                    #(#electrostatics,)*
+                    _ => None,
+                }
+            }
         }
     })
 }
@@ -64,6 +64,16 @@ pub enum ExternalPotential {
 
     /// Custom potential
     Custom(Array2<f64>),
+
+    /// Wall with a uniform surface charge density `surface_charge` (in units of $e/\AA^2$)
+    /// in addition to the (non-electrostatic) potential `potential`.
+    ///
+    /// The charge of the wall is compensated by the fluid and enters the
+    /// calculation via the electrostatic potential.
+    ChargedWall {
+        potential: Box<ExternalPotential>,
+        surface_charge: f64,
+    },
 }
 
 /// Parameters of the fluid required to evaluate the external potential.
@@ -72,8 +82,16 @@ pub trait FluidParameters: HelmholtzEnergyFunctional {
     fn sigma_ff(&self) -> &Array1<f64>;
 }
 
-#[allow(unused_variables)]
+#[allow(unused_variables, clippy::only_used_in_recursion)]
 impl ExternalPotential {
+    /// Surface charge density of the wall in units of $e/\AA^2$.
+    pub fn surface_charge(&self) -> f64 {
+        match self {
+            Self::ChargedWall { surface_charge, .. } => *surface_charge,
+            _ => 0.0,
+        }
+    }
+
     // Evaluate the external potential in cartesian coordinates for a given grid and fluid parameters.
     pub fn calculate_cartesian_potential<P: FluidParameters>(
         &self,
@@ -84,6 +102,9 @@ impl ExternalPotential {
         if let ExternalPotential::Custom(potential) = self {
             return potential.clone();
         }
+        if let ExternalPotential::ChargedWall { potential, .. } = self {
+            return potential.calculate_cartesian_potential(z_grid, fluid_parameters, temperature);
+        }
 
         // Allocate external potential
         let m = fluid_parameters.m();
@@ -233,6 +254,14 @@ impl ExternalPotential {
         if let ExternalPotential::Custom(potential) = self {
             return potential.clone();
         }
+        if let ExternalPotential::ChargedWall { potential, .. } = self {
+            return potential.calculate_cylindrical_potential(
+                r_grid,
+                pore_size,
+                fluid_parameters,
+                temperature,
+            );
+        }
 
         // Allocate external potential
         let m = fluid_parameters.m();
@@ -399,6 +428,14 @@ impl ExternalPotential {
         if let ExternalPotential::Custom(potential) = self {
             return potential.clone();
         }
+        if let ExternalPotential::ChargedWall { potential, .. } = self {
+            return potential.calculate_spherical_potential(
+                r_grid,
+                pore_size,
+                fluid_parameters,
+                temperature,
+            );
+        }
 
         // Allocate external potential
         let m = fluid_parameters.m();
@@ -15,9 +15,11 @@ mod external_potential;
 mod fea_potential;
 mod pore;
 mod pore2d;
+mod wetting;
 pub use external_potential::{ExternalPotential, FluidParameters};
 pub use pore::{Pore1D, PoreProfile, PoreProfile1D, PoreSpecification};
 pub use pore2d::{Pore2D, PoreProfile2D};
+pub use wetting::{Wetting, WettingDiagram};
 
 #[cfg(feature = "rayon")]
 mod pore3d;
@@ -276,8 +278,34 @@ where
             .vapor()
             .build()?;
 
-        let mut vapor = pore.initialize(&vapor_bulk, None, None)?.solve(solver)?;
-        let mut liquid = pore.initialize(&bulk_init, None, None)?.solve(solver)?;
+        let vapor = pore.initialize(&vapor_bulk, None, None)?.solve(solver)?;
+        let liquid = pore.initialize(&bulk_init, None, None)?.solve(solver)?;
+
+        Self::equilibrate(
+            functional,
+            vapor,
+            liquid,
+            &vapor_bulk,
+            &liquid_bulk,
+            solver,
+            options,
+        )
+    }
+
+    /// Determine the bulk density at which the grand potentials of two
+    /// pore profiles, solved for the bulk phases `vapor_bulk` and
+    /// `liquid_bulk` respectively, are equal.
+    fn equilibrate(
+        functional: &Arc<DFT<F>>,
+        mut vapor: PoreProfile<D, F>,
+        mut liquid: PoreProfile<D, F>,
+        vapor_bulk: &State<DFT<F>>,
+        liquid_bulk: &State<DFT<F>>,
+        solver: Option<&DFTSolver>,
+        options: SolverOptions,
+    ) -> EosResult<Adsorption<D, F>> {
+        let temperature = liquid_bulk.temperature;
+        let moles = &liquid_bulk.moles;
 
         // calculate initial value for bulk density
         let n_dp_drho_v = (vapor.profile.moles() * vapor_bulk.dp_drho(Contributions::Total)).sum();
@@ -288,18 +316,13 @@ where
             / (n_dp_drho_v / vapor_bulk.density - n_dp_drho_l / liquid_bulk.density);
 
         // update filled pore with limited step size
-        let mut bulk = StateBuilder::new(functional)
-            .temperature(temperature)
-            .pressure(p_max)
-            .moles(&moles)
-            .vapor()
-            .build()?;
+        let mut bulk = liquid_bulk.clone();
         let rho0 = liquid_bulk.density;
         let steps = (10.0 * (rho - rho0) / rho0).into_value().abs().ceil() as usize;
         let delta_rho = (rho - rho0) / steps as f64;
         for i in 1..=steps {
             let rho_i = rho0 + i as f64 * delta_rho;
-            bulk = State::new_nvt(functional, temperature, moles.sum() / rho_i, &moles)?;
+            bulk = State::new_nvt(functional, temperature, moles.sum() / rho_i, moles)?;
             liquid = liquid.update_bulk(&bulk).solve(solver)?;
         }
 
@@ -325,7 +348,7 @@ where
             rho += delta_rho;
 
             // update bulk phase
-            bulk = State::new_nvt(functional, temperature, moles.sum() / rho, &moles)?;
+            bulk = State::new_nvt(functional, temperature, moles.sum() / rho, moles)?;
         }
         Err(EosError::NotConverged(
             "Adsorption::phase_equilibrium".into(),
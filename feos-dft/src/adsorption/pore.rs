@@ -15,6 +15,7 @@ use ndarray::Axis as Axis_nd;
 use ndarray::RemoveAxis;
 use num_dual::linalg::LU;
 use num_dual::DualNum;
+use std::f64::consts::PI;
 use std::fmt::Display;
 use std::sync::Arc;
 
@@ -194,8 +195,18 @@ impl PoreSpecification<Ix1> for Pore1D {
         let weight_functions = dft.weight_functions(t);
         let convolver = ConvolverFFT::plan(&grid, &weight_functions, Some(1));
 
+        // the fluid in the pore compensates the charge of the walls
+        let r = self.pore_size.to_reduced();
+        let area = match self.geometry {
+            Geometry::Cartesian => 1.0,
+            Geometry::Cylindrical => 2.0 * PI * r,
+            Geometry::Spherical => 4.0 * PI * r * r,
+        };
+        let mut profile = DFTProfile::new(grid, convolver, bulk, Some(external_potential), density);
+        profile.wall_charge = Some(self.potential.surface_charge() * area);
+
         Ok(PoreProfile {
-            profile: DFTProfile::new(grid, convolver, bulk, Some(external_potential), density),
+            profile,
             grand_potential: None,
             interfacial_tension: None,
         })
@@ -0,0 +1,346 @@
+use super::{Adsorption1D, FluidParameters, Pore1D, PoreProfile1D, PoreSpecification};
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::geometry::Geometry;
+use crate::interface::PlanarInterface;
+use crate::profile::DFTProfile;
+use crate::solver::DFTSolver;
+use ang::Angle;
+use feos_core::si::{Area, Density, Length, Pressure, SurfaceTension, Temperature};
+use feos_core::{
+    EosError, EosResult, PhaseEquilibrium, Residual, SolverOptions, State, StateBuilder,
+};
+use ndarray::{Array1, Array2, Ix1};
+use std::sync::Arc;
+
+/// Wall-fluid interfacial tensions and contact angle of a fluid at vapor-liquid coexistence.
+///
+/// The wall-vapor and wall-liquid interfaces are calculated in a wide slit pore
+/// ([Pore1D] with cartesian geometry), the vapor-liquid interface is calculated
+/// as a [PlanarInterface] on a grid with the same number of points and width as
+/// the pore.
+pub struct Wetting<F: HelmholtzEnergyFunctional> {
+    pub vle: PhaseEquilibrium<DFT<F>, 2>,
+    pub wall_vapor: PoreProfile1D<F>,
+    pub wall_liquid: PoreProfile1D<F>,
+    pub vapor_liquid: PlanarInterface<F>,
+    pub wall_vapor_tension: Option<SurfaceTension>,
+    pub wall_liquid_tension: Option<SurfaceTension>,
+    pub surface_tension: Option<SurfaceTension>,
+    pub contact_angle: Option<Angle>,
+}
+
+impl<F: HelmholtzEnergyFunctional> Clone for Wetting<F> {
+    fn clone(&self) -> Self {
+        Self {
+            vle: self.vle.clone(),
+            wall_vapor: self.wall_vapor.clone(),
+            wall_liquid: self.wall_liquid.clone(),
+            vapor_liquid: self.vapor_liquid.clone(),
+            wall_vapor_tension: self.wall_vapor_tension,
+            wall_liquid_tension: self.wall_liquid_tension,
+            surface_tension: self.surface_tension,
+            contact_angle: self.contact_angle,
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> Wetting<F> {
+    pub fn new(
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        pore: &Pore1D,
+        critical_temperature: Temperature,
+    ) -> EosResult<Self> {
+        if !matches!(pore.geometry, Geometry::Cartesian) {
+            return Err(EosError::Error(
+                "Wetting properties can only be calculated in cartesian geometry".into(),
+            ));
+        }
+        let wall_liquid = pore.initialize(vle.liquid(), None, None)?;
+        let density = film_density(&wall_liquid.profile, vle.vapor(), None);
+        let external_potential = &wall_liquid.profile.external_potential;
+        let wall_vapor = pore.initialize(vle.vapor(), Some(&density), Some(external_potential))?;
+        let vapor_liquid = PlanarInterface::from_tanh(
+            vle,
+            wall_vapor.profile.grid.grids()[0].len(),
+            pore.pore_size,
+            critical_temperature,
+            false,
+        );
+        Ok(Self {
+            vle: vle.clone(),
+            wall_vapor,
+            wall_liquid,
+            vapor_liquid,
+            wall_vapor_tension: None,
+            wall_liquid_tension: None,
+            surface_tension: None,
+            contact_angle: None,
+        })
+    }
+
+    pub fn solve_inplace(&mut self, solver: Option<&DFTSolver>, debug: bool) -> EosResult<()> {
+        // Solve the profiles
+        self.wall_vapor.solve_inplace(solver, debug)?;
+        self.wall_liquid.solve_inplace(solver, debug)?;
+        self.vapor_liquid.solve_inplace(solver, debug)?;
+
+        // postprocess
+        let area = Area::from_reduced(1.0);
+        let gamma_wv = self.wall_vapor.interfacial_tension.unwrap() / area;
+        let gamma_wl = self.wall_liquid.interfacial_tension.unwrap() / area;
+        let gamma_lv = self.vapor_liquid.surface_tension.unwrap();
+        self.wall_vapor_tension = Some(gamma_wv);
+        self.wall_liquid_tension = Some(gamma_wl);
+        self.surface_tension = Some(gamma_lv);
+
+        // Young's equation
+        let cos_theta = ((gamma_wv - gamma_wl) / gamma_lv).into_value();
+        self.contact_angle = Some(Angle::Radians(cos_theta.clamp(-1.0, 1.0).acos()));
+
+        Ok(())
+    }
+
+    pub fn solve(mut self, solver: Option<&DFTSolver>) -> EosResult<Self> {
+        self.solve_inplace(solver, false)?;
+        Ok(self)
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> Wetting<F> {
+    /// The spreading coefficient $S=\gamma_\mathrm{wv}-\gamma_\mathrm{wl}-\gamma_\mathrm{lv}$.
+    ///
+    /// The spreading coefficient is negative for partial wetting and zero
+    /// if the wall is completely wet by the liquid.
+    pub fn spreading_coefficient(&self) -> Option<SurfaceTension> {
+        Some(self.wall_vapor_tension? - self.wall_liquid_tension? - self.surface_tension?)
+    }
+
+    /// The thickness of the liquid film adsorbed at the wall-vapor interface.
+    ///
+    /// The film thickness is the excess adsorption at the wall-vapor interface
+    /// relative to the wall-liquid interface divided by the density difference
+    /// of the coexisting phases.
+    pub fn film_thickness(&self) -> Length {
+        let excess = |p: &PoreProfile1D<F>| {
+            p.profile.total_moles() - p.profile.bulk.density * p.profile.volume()
+        };
+        let delta_rho = self.vle.liquid().density - self.vle.vapor().density;
+        (excess(&self.wall_vapor) - excess(&self.wall_liquid)) / delta_rho / Area::from_reduced(1.0)
+    }
+}
+
+/// Container structure for the calculation of wetting properties along the
+/// vapor-liquid coexistence curve.
+pub struct WettingDiagram<F: HelmholtzEnergyFunctional> {
+    pub profiles: Vec<Wetting<F>>,
+}
+
+#[allow(clippy::ptr_arg)]
+impl<F: HelmholtzEnergyFunctional + FluidParameters> WettingDiagram<F> {
+    /// Calculate wetting properties for all phase equilibria in `dia`.
+    ///
+    /// The wall-fluid profiles are initialized with the results of the
+    /// previous phase equilibrium, so that a thin film at the wall-vapor
+    /// interface is continued as long as it is (meta)stable. Phase
+    /// equilibria for which the calculation fails are skipped.
+    pub fn new(
+        dia: &Vec<PhaseEquilibrium<DFT<F>, 2>>,
+        pore: &Pore1D,
+        critical_temperature: Temperature,
+        solver: Option<&DFTSolver>,
+    ) -> EosResult<Self> {
+        let mut profiles: Vec<Wetting<F>> = Vec::with_capacity(dia.len());
+        for vle in dia.iter() {
+            let mut wetting = Wetting::new(vle, pore, critical_temperature)?;
+            if let Some(init) = profiles.last() {
+                wetting.wall_vapor.profile.density = init.wall_vapor.profile.density.clone();
+                wetting.wall_liquid.profile.density = init.wall_liquid.profile.density.clone();
+            }
+            if let Ok(wetting) = wetting.solve(solver) {
+                profiles.push(wetting);
+            }
+        }
+        Ok(Self { profiles })
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> WettingDiagram<F> {
+    pub fn temperature(&self) -> Temperature<Array1<f64>> {
+        Temperature::from_shape_fn(self.profiles.len(), |i| {
+            self.profiles[i].vle.vapor().temperature
+        })
+    }
+
+    pub fn wall_vapor_tension(&self) -> SurfaceTension<Array1<f64>> {
+        SurfaceTension::from_shape_fn(self.profiles.len(), |i| {
+            self.profiles[i].wall_vapor_tension.unwrap()
+        })
+    }
+
+    pub fn wall_liquid_tension(&self) -> SurfaceTension<Array1<f64>> {
+        SurfaceTension::from_shape_fn(self.profiles.len(), |i| {
+            self.profiles[i].wall_liquid_tension.unwrap()
+        })
+    }
+
+    pub fn surface_tension(&self) -> SurfaceTension<Array1<f64>> {
+        SurfaceTension::from_shape_fn(self.profiles.len(), |i| {
+            self.profiles[i].surface_tension.unwrap()
+        })
+    }
+
+    pub fn spreading_coefficient(&self) -> SurfaceTension<Array1<f64>> {
+        SurfaceTension::from_shape_fn(self.profiles.len(), |i| {
+            self.profiles[i].spreading_coefficient().unwrap()
+        })
+    }
+
+    pub fn contact_angle(&self) -> Vec<Angle> {
+        self.profiles
+            .iter()
+            .map(|p| p.contact_angle.unwrap())
+            .collect()
+    }
+
+    pub fn film_thickness(&self) -> Length<Array1<f64>> {
+        Length::from_shape_fn(self.profiles.len(), |i| self.profiles[i].film_thickness())
+    }
+
+    /// Estimate the wetting temperature by linear interpolation of the
+    /// spreading coefficient between the two phase equilibria at which
+    /// it changes its sign.
+    ///
+    /// Returns `None` if the wall is partially wet along the entire diagram.
+    pub fn wetting_temperature(&self) -> Option<Temperature> {
+        let t = self.temperature();
+        let s = self.spreading_coefficient();
+        (1..self.profiles.len()).find_map(|i| {
+            let (s0, s1) = (s.get(i - 1), s.get(i));
+            (s0.to_reduced() * s1.to_reduced() <= 0.0 && s0 != s1).then(|| {
+                let (t0, t1) = (t.get(i - 1), t.get(i));
+                t0 - s0 * (t1 - t0) / (s1 - s0)
+            })
+        })
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> Adsorption1D<F> {
+    /// Calculate the prewetting transition between a thin and a thick
+    /// liquid film adsorbed in a wide slit pore.
+    ///
+    /// The thin film is initialized in equilibrium with the vapor at
+    /// `p_min`, the thick film is initialized as a liquid film with the
+    /// given thickness in equilibrium with the vapor at `p_max`. Both
+    /// pressures have to be below the saturation pressure and the pore
+    /// has to be wide enough to accommodate the thick film.
+    ///
+    /// The thickness of the films relaxes slowly, therefore, by default,
+    /// the profiles are determined with a few damped Picard iterations
+    /// followed by a Newton solver.
+    #[allow(clippy::too_many_arguments)]
+    pub fn prewetting_transition(
+        functional: &Arc<DFT<F>>,
+        temperature: Temperature,
+        p_min: Pressure,
+        p_max: Pressure,
+        pore: &Pore1D,
+        film_thickness: Length,
+        solver: Option<&DFTSolver>,
+        options: SolverOptions,
+    ) -> EosResult<Self> {
+        if !matches!(pore.geometry, Geometry::Cartesian) {
+            return Err(EosError::Error(
+                "Prewetting transitions can only be calculated in cartesian geometry".into(),
+            ));
+        }
+        let moles = functional.validate_moles(None)?;
+        let solver = solver.cloned().unwrap_or_else(|| {
+            DFTSolver::new(None)
+                .picard_iteration(None, Some(200), None, Some(0.1))
+                .newton(None, None, None, None)
+        });
+        let solver = Some(&solver);
+
+        // bulk phases in equilibrium with the thin and the thick film
+        let thin_bulk = StateBuilder::new(functional)
+            .temperature(temperature)
+            .pressure(p_min)
+            .moles(&moles)
+            .vapor()
+            .build()?;
+        let thick_bulk = StateBuilder::new(functional)
+            .temperature(temperature)
+            .pressure(p_max)
+            .moles(&moles)
+            .vapor()
+            .build()?;
+
+        // density profile of the metastable liquid at the wall
+        let liquid_bulk = StateBuilder::new(functional)
+            .temperature(temperature)
+            .pressure(p_max)
+            .moles(&moles)
+            .liquid()
+            .build()?;
+        let liquid = pore.initialize(&liquid_bulk, None, None)?.solve(solver)?;
+        let external_potential = &liquid.profile.external_potential;
+
+        // solve both films
+        let density = film_density(&liquid.profile, &thin_bulk, None);
+        let thin = pore
+            .initialize(&thin_bulk, Some(&density), Some(external_potential))?
+            .solve(solver)?;
+        let z_film = 0.5 * pore.pore_size - film_thickness;
+        let density = film_density(&liquid.profile, &thick_bulk, Some(z_film));
+        let thick = pore
+            .initialize(&thick_bulk, Some(&density), Some(external_potential))?
+            .solve(solver)?;
+
+        Self::equilibrate(
+            functional,
+            thin,
+            thick,
+            &thin_bulk,
+            &thick_bulk,
+            solver,
+            options,
+        )
+    }
+}
+
+/// Initial density profile of a liquid film at the wall of a slit pore.
+///
+/// The film is obtained from the density profile of the liquid at the wall
+/// by a smooth transition to the density of the vapor at `z_film` (measured
+/// from the center of the pore). Without a film, the density of the vapor is
+/// weighted with the Boltzmann factor of the external potential and limited
+/// by the density of the liquid.
+fn film_density<F: HelmholtzEnergyFunctional + FluidParameters>(
+    liquid: &DFTProfile<Ix1, F>,
+    vapor: &State<DFT<F>>,
+    z_film: Option<Length>,
+) -> Density<Array2<f64>> {
+    let rho_v = vapor.partial_density.to_reduced();
+    let rho_l = liquid.density.to_reduced();
+    let z = &liquid.grid.grids()[0];
+    let index = liquid.dft.component_index();
+    let width = liquid
+        .dft
+        .sigma_ff()
+        .iter()
+        .fold(0.0, |w: f64, &s| w.max(s));
+    Density::from_reduced(Array2::from_shape_fn(rho_l.raw_dim(), |(i, j)| {
+        let rho_v = rho_v[index[i]];
+        let rho_l = rho_l[(i, j)];
+        match z_film {
+            Some(z_film) => {
+                let f = 0.5 * (1.0 + ((z[j] - z_film.to_reduced()) / width).tanh());
+                f * rho_l + (1.0 - f) * rho_v
+            }
+            None => {
+                let boltzmann = (-liquid.external_potential[(i, j)]).exp();
+                (rho_v * boltzmann).min(rho_l)
+            }
+        }
+    }))
+}
@@ -0,0 +1,483 @@
+//! Dynamic density functional theory (DDFT) for the relaxation of density profiles.
+//!
+//! The time integration is only implemented for one-dimensional grids, i.e.,
+//! for planar, cylindrical and spherical geometries, and for functionals of
+//! spherical or homosegmented molecules.
+use crate::convolver::BulkConvolver;
+use crate::functional::{HelmholtzEnergyFunctional, MoleculeShape};
+use crate::geometry::{Geometry, Grid};
+use crate::profile::DFTProfile;
+use feos_core::si::{Density, Diffusivity, Moles, Time};
+use feos_core::{log_iter, log_result, Components, EosError, EosResult, SolverOptions, Verbosity};
+use ndarray::{s, Array1, Array2, Axis, Ix1};
+use std::f64::consts::PI;
+use std::sync::Arc;
+
+const MAX_STEPS_DDFT: usize = 100_000;
+const TOL_DDFT: f64 = 1e-4;
+const EPSILON_STABILIZATION: f64 = 1e-6;
+const SAFETY_FACTOR: f64 = 0.9;
+const MIN_STEP_FACTOR: f64 = 0.2;
+const MAX_STEP_FACTOR: f64 = 5.0;
+
+/// Boundary condition at the outer boundary of the axis.
+///
+/// At the origin of the axis (the center of pores or nuclei), the flux
+/// always vanishes.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum BoundaryCondition {
+    /// No flux through the outer boundary, i.e., the amount of every
+    /// component in the system is conserved.
+    Closed,
+    /// The outer boundary is in contact with a reservoir at the density and
+    /// chemical potential of the bulk phase of the profile. The external
+    /// potential should vanish close to the reservoir.
+    Reservoir,
+}
+
+/// A time-dependent external potential.
+///
+/// The potential is implemented for all closures that map a time to
+/// the reduced external potential of every segment on the grid.
+pub trait TimeDependentPotential: Send + Sync {
+    /// Reduced external potential $\beta V_\alpha^\mathrm{ext}(\mathbf{r},t)$ at time `t`.
+    fn external_potential(&self, time: Time) -> Array2<f64>;
+}
+
+impl<P: Fn(Time) -> Array2<f64> + Send + Sync> TimeDependentPotential for P {
+    fn external_potential(&self, time: Time) -> Array2<f64> {
+        self(time)
+    }
+}
+
+/// Time integration of a density profile on a one-dimensional grid.
+///
+/// The density profiles evolve according to the diffusive DDFT equation
+/// $$\frac{\partial\rho_\alpha}{\partial t}=\nabla\cdot\left(D_\alpha\rho_\alpha\nabla\left(m_\alpha\ln\rho_\alpha+\frac{\delta\beta\mathcal{F}^\mathrm{res}}{\delta\rho_\alpha}+\beta V_\alpha^\mathrm{ext}\right)\right)$$
+/// in which $D_\alpha$ is the diffusion coefficient of the molecule that
+/// segment $\alpha$ belongs to. In the limit of an ideal gas, the equation
+/// reduces to Fick's law. The stationary solutions coincide with the
+/// solutions of the Euler-Lagrange equation.
+///
+/// The equation is discretized with finite volumes and a Scharfetter-Gummel
+/// scheme for the fluxes between cells, which reproduces the equilibrium
+/// profiles exactly. The integration in time uses a linearly implicit Euler
+/// method with adaptive time steps.
+pub struct DDFT<F: HelmholtzEnergyFunctional> {
+    pub profile: DFTProfile<Ix1, F>,
+    pub time: Time,
+    pub diffusivity: Diffusivity<Array1<f64>>,
+    pub boundary_condition: BoundaryCondition,
+    potential: Option<Arc<dyn TimeDependentPotential>>,
+    time_step: Option<f64>,
+    distances: Array1<f64>,
+    areas: Array1<f64>,
+    volumes: Array1<f64>,
+}
+
+impl<F: HelmholtzEnergyFunctional> Clone for DDFT<F> {
+    fn clone(&self) -> Self {
+        Self {
+            profile: self.profile.clone(),
+            time: self.time,
+            diffusivity: self.diffusivity.clone(),
+            boundary_condition: self.boundary_condition,
+            potential: self.potential.clone(),
+            time_step: self.time_step,
+            distances: self.distances.clone(),
+            areas: self.areas.clone(),
+            volumes: self.volumes.clone(),
+        }
+    }
+}
+
+/// Density profiles at a sequence of times obtained from DDFT.
+pub struct DDFTTrajectory<F: HelmholtzEnergyFunctional> {
+    pub time: Time<Array1<f64>>,
+    pub profiles: Vec<DFTProfile<Ix1, F>>,
+}
+
+impl<F: HelmholtzEnergyFunctional> Clone for DDFTTrajectory<F> {
+    fn clone(&self) -> Self {
+        Self {
+            time: self.time.clone(),
+            profiles: self.profiles.clone(),
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> DDFT<F> {
+    /// Prepare the time integration starting from the density profile at $t=0$.
+    ///
+    /// The diffusion coefficients are given for every component. The
+    /// external potential of the profile is used for all times, unless
+    /// a time-dependent potential is specified.
+    pub fn new(
+        profile: &DFTProfile<Ix1, F>,
+        diffusivity: Diffusivity<Array1<f64>>,
+        boundary_condition: BoundaryCondition,
+    ) -> EosResult<Self> {
+        if let MoleculeShape::Heterosegmented(_) = profile.dft.molecule_shape() {
+            return Err(EosError::Error(
+                "DDFT is only implemented for spherical and homosegmented molecules.".into(),
+            ));
+        }
+        if diffusivity.len() != profile.dft.components() {
+            return Err(EosError::IncompatibleComponents(
+                profile.dft.components(),
+                diffusivity.len(),
+            ));
+        }
+
+        // geometry of the finite volumes
+        let axis = match &profile.grid {
+            Grid::Cartesian1(axis) | Grid::Polar(axis) | Grid::Spherical(axis) => axis,
+            _ => {
+                return Err(EosError::Error(
+                    "DDFT is only implemented for one-dimensional grids.".into(),
+                ))
+            }
+        };
+        let (weights, _) = profile.grid.integration_weights();
+        let n = axis.grid.len();
+        let mut distances = Array1::zeros(n);
+        for k in 0..n - 1 {
+            distances[k] = axis.grid[k + 1] - axis.grid[k];
+        }
+        distances[n - 1] = axis.edges[n] - axis.grid[n - 1];
+        let areas = axis.edges.slice(s![1..]).mapv(|r| match axis.geometry {
+            Geometry::Cartesian => 1.0,
+            Geometry::Cylindrical => 2.0 * PI * r,
+            Geometry::Spherical => 4.0 * PI * r * r,
+        });
+
+        Ok(Self {
+            profile: profile.clone(),
+            time: Time::from_reduced(0.0),
+            diffusivity,
+            boundary_condition,
+            potential: None,
+            time_step: None,
+            distances,
+            areas,
+            volumes: weights[0].clone(),
+        })
+    }
+
+    /// Use a time-dependent external potential instead of the external
+    /// potential of the initial profile.
+    ///
+    /// The potential has to have the same shape as the external potential of the
+    /// profile, i.e., one row per segment and one column per grid point.
+    pub fn time_dependent_potential<P: TimeDependentPotential + 'static>(
+        mut self,
+        potential: P,
+    ) -> EosResult<Self> {
+        self.potential = Some(Arc::new(potential));
+        self.profile.external_potential = self.external_potential(self.time.to_reduced())?;
+        Ok(self)
+    }
+
+    fn external_potential(&self, time: f64) -> EosResult<Array2<f64>> {
+        let Some(p) = &self.potential else {
+            return Ok(self.profile.external_potential.clone());
+        };
+        let potential = p.external_potential(Time::from_reduced(time));
+        if potential.shape() != self.profile.density.shape() {
+            return Err(EosError::Error(format!(
+                "The time-dependent external potential has the shape {:?} instead of {:?}.",
+                potential.shape(),
+                self.profile.density.shape()
+            )));
+        }
+        Ok(potential)
+    }
+
+    /// Reduced residual functional derivative of every segment including the
+    /// mean-field electrostatic potential.
+    fn functional_derivative(&self, density: &Array2<f64>) -> EosResult<Array2<f64>> {
+        let temperature = self.profile.temperature.to_reduced();
+        let (_, mut dfdrho) = self.profile.dft.functional_derivative(
+            temperature,
+            density,
+            &self.profile.convolver,
+        )?;
+        if let Some(e) = self.profile.electrostatics() {
+            let (mut psi, _) = e.poisson(&self.profile.grid, &e.charge_density(density))?;
+            if self.boundary_condition == BoundaryCondition::Reservoir {
+                let psi_reservoir = psi[psi.len() - 1];
+                psi -= psi_reservoir;
+            }
+            e.add_potential(&mut dfdrho, &psi);
+        }
+        if dfdrho.iter().all(|x| x.is_finite()) {
+            Ok(dfdrho)
+        } else {
+            Err(EosError::IterationFailed("DDFT".into()))
+        }
+    }
+
+    /// Reduced density and potential $\frac{1}{m_\alpha}\frac{\delta\beta\mathcal{F}^\mathrm{res}}{\delta\rho_\alpha}$
+    /// of every segment in a bulk phase.
+    fn bulk_potential(
+        &self,
+        partial_density: &Array1<f64>,
+    ) -> EosResult<(Array1<f64>, Array1<f64>)> {
+        let temperature = self.profile.temperature.to_reduced();
+        let dft = &self.profile.dft;
+        let density = dft.component_index().mapv(|i| partial_density[i]);
+        let bulk_convolver = BulkConvolver::new(dft.weight_functions(temperature));
+        let (_, dfdrho) = dft.functional_derivative(temperature, &density, &bulk_convolver)?;
+        Ok((density, dfdrho / &*dft.m()))
+    }
+
+    /// Stabilization $a_\alpha=\frac{\partial u_\alpha}{\partial\ln\rho}$ of the
+    /// implicit step, estimated from a bulk phase at the average density in the
+    /// system or the density of the bulk phase, whichever is larger.
+    fn stabilization(&self, density: &Array2<f64>) -> EosResult<Array1<f64>> {
+        let dft = &self.profile.dft;
+        let volume = self.volumes.sum();
+        let mut partial_density = self.profile.bulk.partial_density.to_reduced();
+        for (rho, &i) in density.outer_iter().zip(dft.component_index().iter()) {
+            let rho_mean = (&rho * &self.volumes).sum() / volume;
+            partial_density[i] = partial_density[i].max(rho_mean);
+        }
+        let (_, u_0) = self.bulk_potential(&partial_density)?;
+        let (_, u_1) = self.bulk_potential(&(partial_density * (1.0 + EPSILON_STABILIZATION)))?;
+        Ok(((u_1 - u_0) / EPSILON_STABILIZATION)
+            .mapv(|a| if a.is_finite() { a.max(0.0) } else { 0.0 }))
+    }
+
+    /// A linearly implicit Euler step with the time step `h`.
+    ///
+    /// The residual functional derivative is evaluated at the beginning of the
+    /// step and the external potential at its end, the diffusion of the segments
+    /// in these potentials is treated implicitly. The term $a_\alpha\nabla^2\rho_\alpha$ is
+    /// added implicitly and subtracted explicitly to stabilize the step for dense
+    /// fluids.
+    fn implicit_step(
+        &self,
+        density: &Array2<f64>,
+        dfdrho: &Array2<f64>,
+        external_potential: &Array2<f64>,
+        stabilization: &Array1<f64>,
+        reservoir: Option<&(Array1<f64>, Array1<f64>)>,
+        h: f64,
+    ) -> Array2<f64> {
+        let dft = &self.profile.dft;
+        let m = dft.m();
+        let component_index = dft.component_index();
+        let n = self.volumes.len();
+        let mut result = Array2::zeros(density.raw_dim());
+        for (s, mut rho_new) in result.outer_iter_mut().enumerate() {
+            let rho = density.index_axis(Axis(0), s);
+            let u = (&dfdrho.index_axis(Axis(0), s) + &external_potential.index_axis(Axis(0), s))
+                / m[s];
+            let d = self.diffusivity.get(component_index[s]).to_reduced() * m[s];
+            let a = stabilization[s];
+
+            // assemble the tridiagonal system of the fluxes F = alpha * rho_k - beta * rho_k+1
+            let mut lower = Array1::zeros(n);
+            let mut diagonal = Array1::ones(n);
+            let mut upper = Array1::zeros(n);
+            let mut rhs = rho.to_owned();
+            for k in 0..n {
+                let (rho_1, u_1) = if k < n - 1 {
+                    (rho[k + 1], u[k + 1])
+                } else if let Some((rho_bulk, u_bulk)) = reservoir {
+                    (rho_bulk[s], u_bulk[s])
+                } else {
+                    continue;
+                };
+                let du = u_1 - u[k];
+                let c = d * self.areas[k] / self.distances[k];
+                let alpha = c * (bernoulli(du) + a);
+                let beta = c * (bernoulli(-du) + a);
+                let flux_stabilization = c * a * (rho[k] - rho_1);
+                diagonal[k] += h * alpha / self.volumes[k];
+                rhs[k] += h * flux_stabilization / self.volumes[k];
+                if k < n - 1 {
+                    upper[k] = -h * beta / self.volumes[k];
+                    lower[k + 1] = -h * alpha / self.volumes[k + 1];
+                    diagonal[k + 1] += h * beta / self.volumes[k + 1];
+                    rhs[k + 1] -= h * flux_stabilization / self.volumes[k + 1];
+                } else {
+                    rhs[k] += h * beta * rho_1 / self.volumes[k];
+                }
+            }
+            rho_new.assign(&solve_tridiagonal(&lower, &diagonal, &upper, rhs));
+        }
+        result
+    }
+
+    /// Integrate the density profile up to the given times.
+    ///
+    /// The times have to be in ascending order and can not be earlier than the
+    /// current time of the integration. The time step is adapted by step doubling
+    /// so that the local error of every step stays below `tol` times the maximum
+    /// density (default: 1e-4). The integration can be continued by calling this
+    /// function again.
+    pub fn integrate(
+        &mut self,
+        times: &Time<Array1<f64>>,
+        options: SolverOptions,
+    ) -> EosResult<DDFTTrajectory<F>> {
+        let (max_steps, tol, verbosity) = options.unwrap_or(MAX_STEPS_DDFT, TOL_DDFT);
+        let times = times.to_reduced();
+        let mut t = self.time.to_reduced();
+        if times.iter().any(|&t_out| t_out < t) || times.windows(2).into_iter().any(|w| w[1] < w[0])
+        {
+            return Err(EosError::Error(
+                "The times have to be in ascending order and later than the current time.".into(),
+            ));
+        }
+
+        // density and potential in the reservoir
+        let reservoir = match self.boundary_condition {
+            BoundaryCondition::Closed => None,
+            BoundaryCondition::Reservoir => {
+                Some(self.bulk_potential(&self.profile.bulk.partial_density.to_reduced())?)
+            }
+        };
+
+        // initial time step from the stability limit of the explicit diffusion equation
+        let mut dt = self.time_step.unwrap_or_else(|| {
+            let h = self.distances.fold(f64::INFINITY, |h, &x| h.min(x));
+            let d = self
+                .diffusivity
+                .to_reduced()
+                .fold(0.0, |d, &x| f64::max(d, x));
+            0.5 * h * h / d
+        });
+
+        let mut rho = self.profile.density.to_reduced();
+        let mut potential = self.external_potential(t)?;
+        let mut steps = 0;
+        let mut profiles = Vec::with_capacity(times.len());
+        log_iter!(verbosity, " time       | steps  | time step ");
+        log_iter!(verbosity, "{:-<33}", "");
+        for &t_out in times.iter() {
+            while t < t_out {
+                if steps == max_steps {
+                    return Err(EosError::NotConverged("DDFT".into()));
+                }
+                steps += 1;
+                let h = dt.min(t_out - t);
+
+                // one full step and two half steps
+                let dfdrho = self.functional_derivative(&rho)?;
+                let a = self.stabilization(&rho)?;
+                let potential_new = self.external_potential(t + h)?;
+                let rho_full =
+                    self.implicit_step(&rho, &dfdrho, &potential_new, &a, reservoir.as_ref(), h);
+                let rho_half = self.implicit_step(
+                    &rho,
+                    &dfdrho,
+                    &self.external_potential(t + 0.5 * h)?,
+                    &a,
+                    reservoir.as_ref(),
+                    0.5 * h,
+                );
+                let rho_new = if rho_half.iter().all(|&r| r >= 0.0) {
+                    let dfdrho = self.functional_derivative(&rho_half)?;
+                    self.implicit_step(
+                        &rho_half,
+                        &dfdrho,
+                        &potential_new,
+                        &a,
+                        reservoir.as_ref(),
+                        0.5 * h,
+                    )
+                } else {
+                    rho_half
+                };
+
+                // adapt the time step
+                let scale = tol * rho.fold(0.0, |r, &x| f64::max(r, x));
+                let error = (&rho_new - &rho_full).fold(0.0, |e, &x| f64::max(e, x.abs())) / scale;
+                let positive = rho_new.iter().all(|&r| r >= 0.0);
+                let factor = if error > 0.0 {
+                    SAFETY_FACTOR * error.powf(-0.5)
+                } else {
+                    MAX_STEP_FACTOR
+                };
+                let factor = factor.clamp(MIN_STEP_FACTOR, MAX_STEP_FACTOR);
+                if error <= 1.0 && positive {
+                    t = if h == t_out - t { t_out } else { t + h };
+                    rho = rho_new;
+                    potential = potential_new;
+                    if h == dt {
+                        dt *= factor;
+                    }
+                } else {
+                    dt = h * factor.min(0.5);
+                }
+            }
+
+            // store the profile
+            let mut profile = self.profile.clone();
+            profile.density = Density::from_reduced(rho.clone());
+            profile.external_potential = potential.clone();
+            profile.solver_log = None;
+            profiles.push(profile);
+            log_iter!(verbosity, " {:10.4e} | {:>6} | {:.4e}", t, steps, dt);
+        }
+        log_result!(verbosity, "DDFT integrated in {} time steps", steps);
+
+        // update the state of the integration
+        self.time = Time::from_reduced(t);
+        self.time_step = Some(dt);
+        self.profile.density = Density::from_reduced(rho);
+        self.profile.external_potential = potential;
+
+        Ok(DDFTTrajectory {
+            time: Time::from_reduced(times),
+            profiles,
+        })
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> DDFTTrajectory<F> {
+    pub fn moles(&self) -> Moles<Array2<f64>> {
+        let components = self.profiles.first().map_or(0, |p| p.dft.components());
+        Moles::from_shape_fn((components, self.profiles.len()), |(j, i)| {
+            self.profiles[i].moles().get(j)
+        })
+    }
+
+    pub fn total_moles(&self) -> Moles<Array1<f64>> {
+        Moles::from_shape_fn(self.profiles.len(), |i| self.profiles[i].total_moles())
+    }
+}
+
+/// The Bernoulli function $B(x)=\frac{x}{e^x-1}$.
+fn bernoulli(x: f64) -> f64 {
+    if x.abs() < 1e-8 {
+        1.0 - 0.5 * x
+    } else {
+        x / x.exp_m1()
+    }
+}
+
+/// Solve a tridiagonal system of equations with the Thomas algorithm.
+fn solve_tridiagonal(
+    lower: &Array1<f64>,
+    diagonal: &Array1<f64>,
+    upper: &Array1<f64>,
+    mut rhs: Array1<f64>,
+) -> Array1<f64> {
+    let n = rhs.len();
+    let mut c = Array1::zeros(n);
+    c[0] = upper[0] / diagonal[0];
+    rhs[0] /= diagonal[0];
+    for k in 1..n {
+        let denominator = diagonal[k] - lower[k] * c[k - 1];
+        c[k] = upper[k] / denominator;
+        rhs[k] = (rhs[k] - lower[k] * rhs[k - 1]) / denominator;
+    }
+    for k in (0..n - 1).rev() {
+        rhs[k] -= c[k] * rhs[k + 1];
+    }
+    rhs
+}
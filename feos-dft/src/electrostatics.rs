@@ -0,0 +1,143 @@
+use crate::geometry::{Geometry, Grid};
+use feos_core::{EosError, EosResult};
+use ndarray::{Array, Array1, Axis, Dimension, Ix1, RemoveAxis};
+use std::f64::consts::PI;
+
+const MAX_ITER_NEUTRALITY: usize = 100;
+const TOL_NEUTRALITY: f64 = 1e-12;
+
+/// Valences and Bjerrum length of a fluid that contains charged species.
+///
+/// The charges interact via the mean-field electrostatic potential $\psi$,
+/// which is the solution of Poisson's equation
+/// $$\nabla^2\psi(\mathbf{r})=-\frac{e}{\varepsilon_0\varepsilon_r}\sum_\alpha z_\alpha\rho_\alpha(\mathbf{r})$$
+/// with a uniform relative permittivity $\varepsilon_r$. The corresponding
+/// Helmholtz energy is the energy of the electric field
+/// $\mathcal{F}^\mathrm{C}=\frac{\varepsilon_0\varepsilon_r}{2}\int\left|\nabla\psi(\mathbf{r})\right|^2\mathrm{d}\mathbf{r}$.
+#[derive(Clone, Debug)]
+pub struct Electrostatics {
+    /// Valence of every segment.
+    pub valences: Array1<f64>,
+    /// Bjerrum length $\lambda_B=\frac{e^2}{4\pi\varepsilon_0\varepsilon_rk_BT}$ in Å.
+    pub bjerrum_length: f64,
+}
+
+impl Electrostatics {
+    pub fn new(valences: Array1<f64>, bjerrum_length: f64) -> Self {
+        Self {
+            valences,
+            bjerrum_length,
+        }
+    }
+
+    /// Charge density $q(\mathbf{r})=\sum_\alpha z_\alpha\rho_\alpha(\mathbf{r})$ in units of $e/\AA^3$.
+    pub fn charge_density<D: RemoveAxis>(&self, density: &Array<f64, D>) -> Array<f64, D::Smaller> {
+        let mut q = Array::zeros(density.raw_dim().remove_axis(Axis(0)));
+        for (rho, &z) in density.outer_iter().zip(self.valences.iter()) {
+            if z != 0.0 {
+                q.scaled_add(z, &rho);
+            }
+        }
+        q
+    }
+
+    /// Add the electrostatic contribution $z_\alpha\frac{e\psi}{k_BT}$ to the functional derivative.
+    pub(crate) fn add_potential<D: RemoveAxis>(
+        &self,
+        functional_derivative: &mut Array<f64, D>,
+        potential: &Array<f64, D::Smaller>,
+    ) {
+        for (mut df, &z) in functional_derivative
+            .outer_iter_mut()
+            .zip(self.valences.iter())
+        {
+            if z != 0.0 {
+                df.scaled_add(z, potential);
+            }
+        }
+    }
+
+    /// Solve Poisson's equation on a one-dimensional grid.
+    ///
+    /// Returns the reduced electrostatic potential $\frac{e\psi}{k_BT}$ and the
+    /// reduced electric field $\frac{eE}{k_BT}$ (in units of 1/Å). The field is
+    /// obtained from Gauss's law and vanishes at the origin of the axis, i.e., at
+    /// the center of pores or in the bulk phase at the boundary of a planar
+    /// interface. The potential is 0 at the origin.
+    pub(crate) fn poisson<D: Dimension>(
+        &self,
+        grid: &Grid,
+        charge_density: &Array<f64, D>,
+    ) -> EosResult<(Array<f64, D>, Array<f64, D>)> {
+        let axis =
+            match grid {
+                Grid::Cartesian1(axis) | Grid::Polar(axis) | Grid::Spherical(axis) => axis,
+                _ => return Err(EosError::Error(
+                    "Electrostatic interactions are only implemented for one-dimensional grids."
+                        .into(),
+                )),
+            };
+        let q = charge_density.view().into_dimensionality::<Ix1>().unwrap();
+        let (weights, _) = grid.integration_weights();
+        let weights = weights[0];
+
+        let n = axis.grid.len();
+        let mut potential = Array1::zeros(n);
+        let mut field = Array1::zeros(n);
+        let (mut charge, mut r0, mut psi0, mut e0) = (0.0, 0.0, 0.0, 0.0);
+        for k in 0..n {
+            let r = axis.grid[k];
+            let enclosed_charge = charge + 0.5 * q[k] * weights[k];
+            charge += q[k] * weights[k];
+            let area = match axis.geometry {
+                Geometry::Cartesian => 1.0,
+                Geometry::Cylindrical => 2.0 * PI * r,
+                Geometry::Spherical => 4.0 * PI * r * r,
+            };
+            let e = 4.0 * PI * self.bjerrum_length * enclosed_charge / area;
+            let psi = psi0 - 0.5 * (e0 + e) * (r - r0);
+            field[k] = e;
+            potential[k] = psi;
+            (r0, psi0, e0) = (r, psi, e);
+        }
+        Ok((
+            potential.into_dimensionality().unwrap(),
+            field.into_dimensionality().unwrap(),
+        ))
+    }
+
+    /// Shift of the reduced electrostatic potential for which Boltzmann-distributed
+    /// segments with the amounts `moles` compensate the charge `wall_charge` of the walls.
+    ///
+    /// The amount of every segment is assumed to scale with $e^{-z_\alpha\frac{e\psi_0}{k_BT}}$,
+    /// which is exact for spherical and homosegmented molecules.
+    pub(crate) fn neutralizing_potential(
+        &self,
+        moles: &Array1<f64>,
+        wall_charge: f64,
+    ) -> EosResult<f64> {
+        let scale = (&self.valences.mapv(f64::abs) * moles).sum() + wall_charge.abs();
+        if scale == 0.0 {
+            return Ok(0.0);
+        }
+        let mut psi_0: f64 = 0.0;
+        for _ in 0..MAX_ITER_NEUTRALITY {
+            let (mut charge, mut dcharge) = (wall_charge, 0.0);
+            for (&z, &n) in self.valences.iter().zip(moles.iter()) {
+                let q = z * n * (-z * psi_0).exp();
+                charge += q;
+                dcharge -= z * q;
+            }
+            if charge.abs() < TOL_NEUTRALITY * scale {
+                return Ok(psi_0);
+            }
+            if dcharge == 0.0 {
+                break;
+            }
+            psi_0 -= (charge / dcharge).clamp(-1.0, 1.0);
+        }
+        Err(EosError::NotConverged(
+            "electroneutrality of the fluid and the walls".into(),
+        ))
+    }
+}
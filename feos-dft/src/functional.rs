@@ -1,5 +1,6 @@
 use crate::adsorption::FluidParameters;
 use crate::convolver::Convolver;
+use crate::electrostatics::Electrostatics;
 use crate::functional_contribution::*;
 use crate::ideal_chain_contribution::IdealChainContribution;
 use crate::solvation::PairPotential;
@@ -39,6 +40,10 @@ impl<I: Components + Send + Sync, F: HelmholtzEnergyFunctional> HelmholtzEnergyF
     fn bond_lengths(&self, temperature: f64) -> UnGraph<(), f64> {
         self.residual.bond_lengths(temperature)
     }
+
+    fn electrostatics(&self, temperature: f64, density: &Array1<f64>) -> Option<Electrostatics> {
+        self.residual.electrostatics(temperature, density)
+    }
 }
 
 impl<I, F: PairPotential> PairPotential for EquationOfState<I, F> {
@@ -169,6 +174,14 @@ pub trait HelmholtzEnergyFunctional: Components + Sized + Send + Sync {
         Graph::with_capacity(0, 0)
     }
 
+    /// Overwrite this, if the functional contains charged species.
+    ///
+    /// The permittivity that determines the Bjerrum length is evaluated
+    /// at the temperature and the partial densities `density` of the bulk phase.
+    fn electrostatics(&self, _temperature: f64, _density: &Array1<f64>) -> Option<Electrostatics> {
+        None
+    }
+
     fn weight_functions(&self, temperature: f64) -> Vec<WeightFunctionInfo<f64>> {
         self.contributions()
             .map(|c| c.weight_functions(temperature))
@@ -0,0 +1,282 @@
+use crate::convolver::ConvolverFFT;
+use crate::functional::{HelmholtzEnergyFunctional, DFT};
+use crate::geometry::{Axis, Grid};
+use crate::profile::DFTProfile;
+use crate::solver::DFTSolver;
+use feos_core::si::{Density, Energy, Length, Moles, SurfaceTension, Temperature, Volume};
+use feos_core::{
+    Contributions, DensityInitialization, EosError, EosResult, PhaseEquilibrium, State,
+};
+use ndarray::{Axis as Axis_nd, Ix1};
+use num_dual::linalg::{norm, LU};
+use std::f64::consts::PI;
+
+const MAX_ITER_INTERIOR: usize = 50;
+const TOL_INTERIOR: f64 = 1e-12;
+
+/// Density profile and properties of a critical nucleus, i.e., a droplet or bubble
+/// in (unstable) equilibrium with a supersaturated bulk phase.
+///
+/// The critical nucleus is a saddle point of the grand potential at the chemical
+/// potential of the supersaturated bulk phase. By default, it is determined with
+/// a few damped Picard iterations followed by a Newton solver, which, in contrast
+/// to Picard iterations or Anderson mixing, converges to saddle points as well as
+/// to minima of the grand potential.
+pub struct CriticalNucleus<F: HelmholtzEnergyFunctional> {
+    pub profile: DFTProfile<Ix1, F>,
+    pub vle: PhaseEquilibrium<DFT<F>, 2>,
+    /// The supersaturated bulk phase surrounding the nucleus.
+    pub bulk: State<DFT<F>>,
+    /// The bulk phase inside the nucleus at the chemical potential of the surrounding phase.
+    pub interior: State<DFT<F>>,
+    pub nucleation_barrier: Option<Energy>,
+    pub equimolar_radius: Option<Length>,
+    pub surface_of_tension_radius: Option<Length>,
+    pub surface_tension: Option<SurfaceTension>,
+    pub tolman_length: Option<Length>,
+}
+
+impl<F: HelmholtzEnergyFunctional> Clone for CriticalNucleus<F> {
+    fn clone(&self) -> Self {
+        Self {
+            profile: self.profile.clone(),
+            vle: self.vle.clone(),
+            bulk: self.bulk.clone(),
+            interior: self.interior.clone(),
+            nucleation_barrier: self.nucleation_barrier,
+            equimolar_radius: self.equimolar_radius,
+            surface_of_tension_radius: self.surface_of_tension_radius,
+            surface_tension: self.surface_tension,
+            tolman_length: self.tolman_length,
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> CriticalNucleus<F> {
+    pub fn solve_inplace(&mut self, solver: Option<&DFTSolver>, debug: bool) -> EosResult<()> {
+        // Solve the profile with a saddle-point solver
+        let solver = solver.cloned().unwrap_or_else(|| {
+            DFTSolver::new(None)
+                .picard_iteration(None, Some(20), None, Some(0.05))
+                .newton(None, None, None, None)
+        });
+        self.profile.solve(Some(&solver), debug)?;
+
+        // check that the nucleus neither vanished nor filled the whole domain
+        let rho = self.profile.density.sum_axis(Axis_nd(0));
+        let delta_rho = self.interior.density - self.bulk.density;
+        let center = ((rho.get(0) - self.bulk.density) / delta_rho).into_value();
+        let boundary = ((rho.get(rho.len() - 1) - self.bulk.density) / delta_rho).into_value();
+        if center < 0.5 || boundary.abs() > 0.1 {
+            return Err(EosError::TrivialSolution);
+        }
+
+        // postprocess
+        let bulk = &self.profile.bulk;
+        let p_bulk = bulk.pressure(Contributions::Total);
+        let delta_p = self.interior.pressure(Contributions::Total) - p_bulk;
+        let delta_rho = self.interior.density - bulk.density;
+
+        // nucleation barrier (work of formation of the nucleus)
+        let barrier = self.profile.grand_potential()? + p_bulk * self.profile.volume();
+        self.nucleation_barrier = Some(barrier);
+
+        // equimolar radius from the excess number of particles
+        let excess = self.profile.total_moles() - bulk.density * self.profile.volume();
+        let r_e = Length::from_reduced((0.75 / PI * (excess / delta_rho).to_reduced()).cbrt());
+        self.equimolar_radius = Some(r_e);
+
+        // surface of tension from the nucleation barrier and the Laplace equation
+        let r_s = Length::from_reduced((1.5 / PI * (barrier / delta_p).to_reduced()).cbrt());
+        self.surface_of_tension_radius = Some(r_s);
+        self.surface_tension = Some(0.5 * delta_p * r_s);
+        self.tolman_length = Some(r_e - r_s);
+
+        Ok(())
+    }
+
+    pub fn solve(mut self, solver: Option<&DFTSolver>) -> EosResult<Self> {
+        self.solve_inplace(solver, false)?;
+        Ok(self)
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> CriticalNucleus<F> {
+    /// Create a nucleus in the supersaturated phase `bulk`.
+    ///
+    /// The bulk phases of the phase equilibrium `vle` are used as initial
+    /// guess for the phase inside the nucleus.
+    pub fn new(
+        bulk: &State<DFT<F>>,
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        n_grid: usize,
+        l_grid: Length,
+    ) -> EosResult<Self> {
+        let dft = &bulk.eos;
+
+        // determine the phase inside the nucleus
+        let rho_l = vle.liquid().density;
+        let rho_v = vle.vapor().density;
+        let initial = if bulk.density.to_reduced() < 0.5 * (rho_l + rho_v).to_reduced() {
+            vle.liquid()
+        } else {
+            vle.vapor()
+        };
+        let interior = interior_state(initial, bulk)?;
+
+        // generate grid
+        let grid = Grid::Spherical(Axis::new_spherical(n_grid, l_grid));
+
+        // initialize convolver
+        let t = bulk.temperature.to_reduced();
+        let weight_functions = dft.weight_functions(t);
+        let convolver = ConvolverFFT::plan(&grid, &weight_functions, None);
+
+        Ok(Self {
+            profile: DFTProfile::new(grid, convolver, bulk, None, None),
+            vle: vle.clone(),
+            bulk: bulk.clone(),
+            interior,
+            nucleation_barrier: None,
+            equimolar_radius: None,
+            surface_of_tension_radius: None,
+            surface_tension: None,
+            tolman_length: None,
+        })
+    }
+
+    /// Initialize the nucleus with a hyperbolic tangent around the given radius.
+    pub fn from_tanh(
+        bulk: &State<DFT<F>>,
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        n_grid: usize,
+        l_grid: Length,
+        radius: Length,
+        critical_temperature: Temperature,
+    ) -> EosResult<Self> {
+        let mut nucleus = Self::new(bulk, vle, n_grid, l_grid)?;
+
+        // calculate segment indices
+        let indices = &nucleus.profile.dft.component_index();
+
+        // calculate density profile
+        let r0 = radius.to_reduced();
+        let reduced_temperature = (bulk.temperature / critical_temperature).into_value();
+        nucleus.profile.density =
+            Density::from_shape_fn(nucleus.profile.density.raw_dim(), |(i, r)| {
+                let rho_in = nucleus.interior.partial_density.get(indices[i]);
+                let rho_out = nucleus.bulk.partial_density.get(indices[i]);
+                0.5 * (rho_in - rho_out)
+                    * (-(nucleus.profile.grid.grids()[0][r] - r0) / 3.0
+                        * (2.4728 - 2.3625 * reduced_temperature))
+                        .tanh()
+                    + 0.5 * (rho_in + rho_out)
+            });
+
+        Ok(nucleus)
+    }
+
+    /// Create a nucleus of a pure substance at the given supersaturation $S=p/p^\mathrm{sat}$.
+    ///
+    /// For $S>1$, the bulk phase is a supersaturated vapor and the nucleus is a
+    /// droplet, for $S<1$ (including negative pressures), the bulk phase is a
+    /// superheated or stretched liquid and the nucleus is a bubble. If no initial
+    /// radius is given, it is estimated from classical nucleation theory using the
+    /// surface tension from pDGT.
+    pub fn from_supersaturation(
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        supersaturation: f64,
+        n_grid: usize,
+        l_grid: Length,
+        radius: Option<Length>,
+        critical_temperature: Temperature,
+    ) -> EosResult<Self> {
+        let dft = &vle.vapor().eos;
+        if dft.components() != 1 {
+            return Err(EosError::Error(
+                "The supersaturation can only be specified for pure substances".into(),
+            ));
+        }
+
+        let temperature = vle.vapor().temperature;
+        let pressure = vle.vapor().pressure(Contributions::Total) * supersaturation;
+        let density_initialization = if supersaturation > 1.0 {
+            DensityInitialization::InitialDensity(vle.vapor().density)
+        } else {
+            DensityInitialization::InitialDensity(vle.liquid().density)
+        };
+        let bulk = State::new_npt(
+            dft,
+            temperature,
+            pressure,
+            &vle.vapor().moles,
+            density_initialization,
+        )?;
+
+        let radius = match radius {
+            Some(radius) => radius,
+            None => {
+                let interior = if supersaturation > 1.0 {
+                    interior_state(vle.liquid(), &bulk)?
+                } else {
+                    interior_state(vle.vapor(), &bulk)?
+                };
+                let delta_p = interior.pressure(Contributions::Total) - pressure;
+                let (_, surface_tension) = dft.solve_pdgt(vle, 20, 0, None)?;
+                2.0 * surface_tension / delta_p
+            }
+        };
+
+        Self::from_tanh(&bulk, vle, n_grid, l_grid, radius, critical_temperature)
+    }
+}
+
+/// Calculate the bulk state with the same temperature and chemical potential
+/// as `bulk` starting from `initial`.
+fn interior_state<F: HelmholtzEnergyFunctional>(
+    initial: &State<DFT<F>>,
+    bulk: &State<DFT<F>>,
+) -> EosResult<State<DFT<F>>> {
+    let t = bulk.temperature.to_reduced();
+    let volume = Volume::from_reduced(1.0);
+
+    // the ideal gas contribution is only required up to a temperature dependent constant
+    let mu = |s: &State<DFT<F>>| {
+        s.residual_chemical_potential().to_reduced() / t
+            + s.partial_density.to_reduced().mapv(f64::ln)
+    };
+    let mu_bulk = mu(bulk);
+
+    let mut density = initial.partial_density.to_reduced();
+    for _ in 0..MAX_ITER_INTERIOR {
+        let state = State::new_nvt(
+            &bulk.eos,
+            bulk.temperature,
+            volume,
+            &Moles::from_reduced(density.clone()),
+        )?;
+        let res = mu(&state) - &mu_bulk;
+        if norm(&res) < TOL_INTERIOR {
+            return Ok(state);
+        }
+        let jacobian = (state.dmu_dni(Contributions::Total) * volume).to_reduced() / t;
+        let delta = LU::new(jacobian)?.solve(&res);
+
+        // damp the step to keep the densities positive
+        let mut step = 1.0;
+        while (&density - &(&delta * step)).iter().any(|&rho| rho <= 0.0) {
+            step *= 0.5;
+        }
+        density = density - delta * step;
+    }
+    Err(EosError::NotConverged(String::from("interior_state")))
+}
+
+impl<F: HelmholtzEnergyFunctional> CriticalNucleus<F> {
+    /// The supersaturation $S=p/p^\mathrm{sat}$ of the surrounding bulk phase.
+    pub fn supersaturation(&self) -> f64 {
+        (self.profile.bulk.pressure(Contributions::Total)
+            / self.vle.vapor().pressure(Contributions::Total))
+        .into_value()
+    }
+}
@@ -1,4 +1,4 @@
-//! Density profiles at planar interfaces and interfacial tensions.
+//! Density profiles at planar and curved interfaces and interfacial tensions.
 use crate::convolver::ConvolverFFT;
 use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::geometry::{Axis, Grid};
@@ -8,7 +8,9 @@ use feos_core::si::{Area, Density, Length, Moles, SurfaceTension, Temperature};
 use feos_core::{Contributions, EosError, EosResult, PhaseEquilibrium};
 use ndarray::{s, Array1, Array2, Axis as Axis_nd, Ix1};
 
+mod critical_nucleus;
 mod surface_tension_diagram;
+pub use critical_nucleus::CriticalNucleus;
 pub use surface_tension_diagram::SurfaceTensionDiagram;
 
 const RELATIVE_WIDTH: f64 = 6.0;
@@ -5,6 +5,8 @@
 
 pub mod adsorption;
 mod convolver;
+pub mod ddft;
+mod electrostatics;
 mod functional;
 mod functional_contribution;
 mod geometry;
@@ -17,6 +19,7 @@ mod solver;
 mod weight_functions;
 
 pub use convolver::{Convolver, ConvolverFFT};
+pub use electrostatics::Electrostatics;
 pub use functional::{HelmholtzEnergyFunctional, MoleculeShape, DFT};
 pub use functional_contribution::FunctionalContribution;
 pub use geometry::{Axis, Geometry, Grid};
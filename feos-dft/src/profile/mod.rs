@@ -1,8 +1,12 @@
 use crate::convolver::{BulkConvolver, Convolver};
+use crate::electrostatics::Electrostatics;
 use crate::functional::{HelmholtzEnergyFunctional, DFT};
 use crate::geometry::Grid;
 use crate::solver::{DFTSolver, DFTSolverLog};
-use feos_core::si::{Density, Length, Moles, Quantity, Temperature, Volume, _Volume, DEGREES};
+use feos_core::si::{
+    _Volume, Density, ElectricPotential, Length, Moles, Quantity, Temperature, Volume, DEGREES, KB,
+    QE,
+};
 use feos_core::{Components, EosError, EosResult, State};
 use ndarray::{
     Array, Array1, Array2, Array3, ArrayBase, Axis as Axis_nd, Data, Dimension, Ix1, Ix2, Ix3,
@@ -106,6 +110,12 @@ pub struct DFTProfile<D: Dimension, F> {
     pub external_potential: Array<f64, D::Larger>,
     pub bulk: State<DFT<F>>,
     pub solver_log: Option<DFTSolverLog>,
+    /// Total charge of the walls in the domain (in units of $e$, per Å² or Å in
+    /// periodic directions) that is compensated by the fluid.
+    pub wall_charge: Option<f64>,
+    /// Shift of the reduced electrostatic potential for which the fluid compensates
+    /// the charge of the walls, determined when the profile is solved.
+    pub(crate) electrostatic_shift: f64,
 }
 
 impl<F> DFTProfile<Ix1, F> {
@@ -244,6 +254,8 @@ where
             external_potential,
             bulk: bulk.clone(),
             solver_log: None,
+            wall_charge: None,
+            electrostatic_shift: 0.0,
         }
     }
 
@@ -342,10 +354,50 @@ impl<D: Dimension + Clone, F> Clone for DFTProfile<D, F> {
             external_potential: self.external_potential.clone(),
             bulk: self.bulk.clone(),
             solver_log: self.solver_log.clone(),
+            wall_charge: self.wall_charge,
+            electrostatic_shift: self.electrostatic_shift,
         }
     }
 }
 
+impl<D: Dimension, F: HelmholtzEnergyFunctional> DFTProfile<D, F>
+where
+    D::Larger: Dimension<Smaller = D>,
+{
+    /// Valences and Bjerrum length, if the functional contains charged species.
+    pub(crate) fn electrostatics(&self) -> Option<Electrostatics> {
+        self.dft.electrostatics(
+            self.temperature.to_reduced(),
+            &self.bulk.partial_density.to_reduced(),
+        )
+    }
+
+    /// Reduced electrostatic potential $\frac{e\psi}{k_BT}$ of the current density profile.
+    ///
+    /// The potential is the solution of Poisson's equation shifted by the
+    /// potential that neutralizes the charge of the walls.
+    pub(crate) fn reduced_electrostatic_potential(
+        &self,
+        electrostatics: &Electrostatics,
+    ) -> EosResult<Array<f64, D>> {
+        let q = electrostatics.charge_density(&self.density.to_reduced());
+        let (psi, _) = electrostatics.poisson(&self.grid, &q)?;
+        Ok(psi + self.electrostatic_shift)
+    }
+
+    /// Calculate the electrostatic potential $\psi$ relative to the bulk phase.
+    ///
+    /// Returns `None` if the functional does not contain charged species.
+    pub fn electrostatic_potential(&self) -> EosResult<Option<ElectricPotential<Array<f64, D>>>> {
+        self.electrostatics()
+            .map(|e| {
+                let psi = self.reduced_electrostatic_potential(&e)?;
+                Ok(psi * (self.temperature * KB / QE))
+            })
+            .transpose()
+    }
+}
+
 impl<D: Dimension, F> DFTProfile<D, F>
 where
     D::Larger: Dimension<Smaller = D>,
@@ -365,7 +417,7 @@ where
         let partial_density = self.bulk.partial_density.to_reduced();
         let bulk_density = self.dft.component_index().mapv(|i| partial_density[i]);
 
-        let (res, res_bulk, res_norm, _, _) =
+        let (res, res_bulk, res_norm, _, _, _) =
             self.euler_lagrange_equation(&density, &bulk_density, log)?;
         Ok((res, res_bulk, res_norm))
     }
@@ -382,6 +434,7 @@ where
         f64,
         Array<f64, D::Larger>,
         Array<f64, D::Larger>,
+        f64,
     )> {
         // calculate reduced temperature
         let temperature = self.temperature.to_reduced();
@@ -394,6 +447,13 @@ where
         // calculate total functional derivative
         dfdrho += &self.external_potential;
 
+        // add the mean-field electrostatic potential
+        let electrostatics = self.electrostatics();
+        if let Some(e) = &electrostatics {
+            let (psi, _) = e.poisson(&self.grid, &e.charge_density(density))?;
+            e.add_potential(&mut dfdrho, &psi);
+        }
+
         // calculate bulk functional derivative
         let bulk_convolver = BulkConvolver::new(self.dft.weight_functions(temperature));
         let (_, dfdrho_bulk) =
@@ -409,7 +469,7 @@ where
             });
 
         // calculate bond integrals
-        let exp_dfdrho = dfdrho.mapv(|x| (-x).exp());
+        let mut exp_dfdrho = dfdrho.mapv(|x| (-x).exp());
         let bonds = self
             .dft
             .bond_integrals(temperature, &exp_dfdrho, &self.convolver);
@@ -423,6 +483,21 @@ where
                 x *= rho_b;
             });
 
+        // shift the electrostatic potential so that the fluid compensates the charge of the walls
+        let mut psi_0 = 0.0;
+        if let (Some(e), Some(wall_charge)) = (&electrostatics, self.wall_charge) {
+            let moles = self.integrate_reduced_comp(&rho_projected);
+            psi_0 = e.neutralizing_potential(&moles, wall_charge)?;
+            rho_projected
+                .outer_iter_mut()
+                .zip(exp_dfdrho.outer_iter_mut())
+                .zip(e.valences.iter().zip(self.dft.m().iter()))
+                .for_each(|((mut rho, mut exp), (&z, &m))| {
+                    rho *= (-z * psi_0).exp();
+                    exp *= (-z * psi_0 / m).exp();
+                });
+        }
+
         // calculate residual
         let mut res = if log {
             rho_projected.mapv(f64::ln) - density.mapv(f64::ln)
@@ -450,7 +525,7 @@ where
             / ((res.len() + res_bulk.len()) as f64).sqrt();
 
         if res_norm.is_finite() {
-            Ok((res, res_bulk, res_norm, exp_dfdrho, rho_projected))
+            Ok((res, res_bulk, res_norm, exp_dfdrho, rho_projected, psi_0))
         } else {
             Err(EosError::IterationFailed("Euler-Lagrange equation".into()))
         }
@@ -469,6 +544,13 @@ where
         // Call solver(s)
         self.call_solver(&mut density, &mut bulk_density, &solver, debug)?;
 
+        // Store the shift of the electrostatic potential at the solution
+        if self.wall_charge.is_some() && self.electrostatics().is_some() {
+            let (_, _, _, _, _, psi_0) =
+                self.euler_lagrange_equation(&density, &bulk_density, false)?;
+            self.electrostatic_shift = psi_0;
+        }
+
         // Update profile
         self.density = Density::from_reduced(density);
         let volume = Volume::from_reduced(1.0);
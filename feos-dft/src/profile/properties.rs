@@ -9,6 +9,7 @@ use feos_core::si::{
 use feos_core::{Contributions, EosResult, IdealGas, Verbosity};
 use ndarray::{Array, Array1, Array2, Axis, Dimension, RemoveAxis, ScalarOperand};
 use num_dual::{Dual64, DualNum};
+use std::f64::consts::PI;
 use std::ops::{AddAssign, Div};
 use std::sync::Arc;
 
@@ -46,6 +47,14 @@ where
             f += &(&rho.index_axis(Axis(0), segment.index()) * (0.5 * n as f64));
         }
 
+        // add the energy of the electric field
+        if let Some(e) = self.electrostatics() {
+            let q = e.charge_density(&rho);
+            let (psi, field) = e.poisson(&self.grid, &q)?;
+            let psi = psi + self.electrostatic_shift;
+            f += &(field.mapv(|e| e * e) / (8.0 * PI * e.bjerrum_length) - q * psi);
+        }
+
         Ok(Pressure::from_reduced(f * t))
     }
 
@@ -278,17 +287,17 @@ where
         let rho_bulk = self.dft.component_index().mapv(|i| partial_density[i]);
 
         let second_partial_derivatives = self.second_partial_derivatives(&rho)?;
-        let (_, _, _, exp_dfdrho, _) = self.euler_lagrange_equation(&rho, &rho_bulk, false)?;
+        let (_, _, _, exp_dfdrho, _, _) = self.euler_lagrange_equation(&rho, &rho_bulk, false)?;
 
         let rhs = |x: &_| {
             let delta_functional_derivative =
-                self.delta_functional_derivative(x, &second_partial_derivatives);
+                self.delta_functional_derivative(x, &second_partial_derivatives)?;
             let mut xm = x.clone();
             xm.outer_iter_mut()
                 .zip(self.dft.m().iter())
                 .for_each(|(mut x, &m)| x *= m);
             let delta_i = self.delta_bond_integrals(&exp_dfdrho, &delta_functional_derivative);
-            xm + (delta_functional_derivative - delta_i) * &rho
+            Ok(xm + (delta_functional_derivative - delta_i) * &rho)
         };
         let mut log = DFTSolverLog::new(Verbosity::None);
         Self::gmres(rhs, lhs, 200, 1e-13, &mut log)
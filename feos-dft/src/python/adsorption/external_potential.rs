@@ -251,4 +251,28 @@ impl PyExternalPotential {
             cutoff_radius,
         }))
     }
+
+    /// Charged wall
+    ///
+    /// The surface charge is compensated by the fluid and enters the
+    /// calculation via the mean-field electrostatic potential.
+    ///
+    /// Parameters
+    /// ----------
+    /// potential : ExternalPotential
+    ///     The (non-electrostatic) potential of the wall.
+    /// surface_charge : float
+    ///     Surface charge density of the wall in units of e/Å².
+    ///
+    /// Returns
+    /// -------
+    /// ExternalPotential
+    ///
+    #[staticmethod]
+    pub fn ChargedWall(potential: PyExternalPotential, surface_charge: f64) -> Self {
+        Self(ExternalPotential::ChargedWall {
+            potential: Box::new(potential.0),
+            surface_charge,
+        })
+    }
 }
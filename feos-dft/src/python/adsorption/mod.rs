@@ -1,5 +1,6 @@
 mod external_potential;
 mod pore;
+mod wetting;
 
 pub use external_potential::PyExternalPotential;
 
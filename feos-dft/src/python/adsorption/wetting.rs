@@ -0,0 +1,210 @@
+#[macro_export]
+macro_rules! impl_wetting {
+    ($func:ty) => {
+        /// Wall-fluid interfacial tensions and contact angle of a fluid
+        /// at vapor-liquid coexistence.
+        ///
+        /// Parameters
+        /// ----------
+        /// vle : PhaseEquilibrium
+        ///     The bulk phase equilibrium.
+        /// pore : Pore1D
+        ///     A wide slit pore (cartesian geometry) that specifies the wall.
+        /// critical_temperature: SINumber
+        ///     An estimate for the critical temperature, used to initialize
+        ///     the density profile of the vapor-liquid interface.
+        ///
+        /// Returns
+        /// -------
+        /// Wetting
+        ///
+        #[pyclass(name = "Wetting")]
+        pub struct PyWetting(Wetting<$func>);
+
+        #[pymethods]
+        impl PyWetting {
+            #[new]
+            #[pyo3(text_signature = "(vle, pore, critical_temperature)")]
+            fn new(
+                vle: &PyPhaseEquilibrium,
+                pore: &PyPore1D,
+                critical_temperature: PySINumber,
+            ) -> PyResult<Self> {
+                Ok(Self(Wetting::new(
+                    &vle.0,
+                    &pore.0,
+                    critical_temperature.try_into()?,
+                )?))
+            }
+
+            /// Solve the wall-vapor, wall-liquid and vapor-liquid profiles.
+            ///
+            /// Parameters
+            /// ----------
+            /// solver: DFTSolver, optional
+            ///     Custom solver options.
+            /// debug: bool, optional
+            ///     If True, do not check for convergence.
+            ///
+            /// Returns
+            /// -------
+            /// Wetting
+            ///
+            #[pyo3(signature = (solver=None, debug=false), text_signature = "($self, solver=None, debug=False)")]
+            fn solve<'py>(slf: Bound<'py, Self>, solver: Option<PyDFTSolver>, debug: bool) -> PyResult<Bound<'py, Self>> {
+                slf.borrow_mut()
+                    .0
+                    .solve_inplace(solver.map(|s| s.0).as_ref(), debug)?;
+                Ok(slf)
+            }
+
+            #[getter]
+            fn get_vle(&self) -> PyPhaseEquilibrium {
+                PyPhaseEquilibrium(self.0.vle.clone())
+            }
+
+            #[getter]
+            fn get_wall_vapor(&self) -> PyPoreProfile1D {
+                PyPoreProfile1D(self.0.wall_vapor.clone())
+            }
+
+            #[getter]
+            fn get_wall_liquid(&self) -> PyPoreProfile1D {
+                PyPoreProfile1D(self.0.wall_liquid.clone())
+            }
+
+            #[getter]
+            fn get_vapor_liquid(&self) -> PyPlanarInterface {
+                PyPlanarInterface(self.0.vapor_liquid.clone())
+            }
+
+            #[getter]
+            fn get_wall_vapor_tension(&self) -> Option<PySINumber> {
+                self.0.wall_vapor_tension.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_wall_liquid_tension(&self) -> Option<PySINumber> {
+                self.0.wall_liquid_tension.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_surface_tension(&self) -> Option<PySINumber> {
+                self.0.surface_tension.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_contact_angle(&self) -> Option<PyAngle> {
+                self.0.contact_angle.map(PyAngle::from)
+            }
+
+            #[getter]
+            fn get_spreading_coefficient(&self) -> Option<PySINumber> {
+                self.0.spreading_coefficient().map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_film_thickness(&self) -> PySINumber {
+                self.0.film_thickness().into()
+            }
+        }
+
+        /// Container structure for the calculation of wetting properties
+        /// along the vapor-liquid coexistence curve.
+        ///
+        /// Parameters
+        /// ----------
+        /// dia : [PhaseEquilibrium]
+        ///     The underlying phase diagram given as a list of states
+        ///     for which wetting properties shall be calculated.
+        /// pore : Pore1D
+        ///     A wide slit pore (cartesian geometry) that specifies the wall.
+        /// critical_temperature: SINumber
+        ///     An estimate for the critical temperature, used to initialize
+        ///     the density profiles of the vapor-liquid interfaces.
+        /// solver: DFTSolver, optional
+        ///     Custom solver options.
+        ///
+        /// Returns
+        /// -------
+        /// WettingDiagram
+        ///
+        #[pyclass(name = "WettingDiagram")]
+        pub struct PyWettingDiagram(WettingDiagram<$func>);
+
+        #[pymethods]
+        impl PyWettingDiagram {
+            #[new]
+            #[pyo3(text_signature = "(dia, pore, critical_temperature, solver=None)")]
+            fn new(
+                dia: Vec<PyPhaseEquilibrium>,
+                pore: &PyPore1D,
+                critical_temperature: PySINumber,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                let x = dia.into_iter().map(|vle| vle.0).collect();
+                Ok(Self(WettingDiagram::new(
+                    &x,
+                    &pore.0,
+                    critical_temperature.try_into()?,
+                    solver.map(|s| s.0).as_ref(),
+                )?))
+            }
+
+            #[getter]
+            fn get_profiles(&self) -> Vec<PyWetting> {
+                self.0
+                    .profiles
+                    .iter()
+                    .map(|p| PyWetting(p.clone()))
+                    .collect()
+            }
+
+            #[getter]
+            fn get_temperature(&self) -> PySIArray1 {
+                self.0.temperature().into()
+            }
+
+            #[getter]
+            fn get_wall_vapor_tension(&self) -> PySIArray1 {
+                self.0.wall_vapor_tension().into()
+            }
+
+            #[getter]
+            fn get_wall_liquid_tension(&self) -> PySIArray1 {
+                self.0.wall_liquid_tension().into()
+            }
+
+            #[getter]
+            fn get_surface_tension(&self) -> PySIArray1 {
+                self.0.surface_tension().into()
+            }
+
+            #[getter]
+            fn get_spreading_coefficient(&self) -> PySIArray1 {
+                self.0.spreading_coefficient().into()
+            }
+
+            #[getter]
+            fn get_contact_angle(&self) -> Vec<PyAngle> {
+                self.0.contact_angle().into_iter().map(PyAngle::from).collect()
+            }
+
+            #[getter]
+            fn get_film_thickness(&self) -> PySIArray1 {
+                self.0.film_thickness().into()
+            }
+
+            /// Estimate the wetting temperature from the sign change of the
+            /// spreading coefficient.
+            ///
+            /// Returns
+            /// -------
+            /// SINumber, optional
+            ///
+            fn wetting_temperature(&self) -> Option<PySINumber> {
+                self.0.wetting_temperature().map(PySINumber::from)
+            }
+        }
+    };
+}
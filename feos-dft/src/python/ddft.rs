@@ -0,0 +1,124 @@
+#[macro_export]
+macro_rules! impl_ddft {
+    ($func:ty) => {
+        /// Time integration of the density profile in a 1D pore with dynamic DFT.
+        ///
+        /// Parameters
+        /// ----------
+        /// profile : PoreProfile1D
+        ///     The density profile at t=0.
+        /// diffusivity : SIArray1
+        ///     The diffusion coefficient of every component.
+        /// boundary_condition : BoundaryCondition, optional
+        ///     The boundary condition at the outer boundary of the pore.
+        ///     Defaults to BoundaryCondition.Closed.
+        ///
+        /// Returns
+        /// -------
+        /// DDFT
+        ///
+        #[pyclass(name = "DDFT")]
+        pub struct PyDDFT(DDFT<$func>);
+
+        /// Density profiles at a sequence of times obtained from DDFT.
+        #[pyclass(name = "DDFTTrajectory")]
+        pub struct PyDDFTTrajectory(DDFTTrajectory<$func>);
+
+        #[pymethods]
+        impl PyDDFT {
+            #[new]
+            #[pyo3(
+                signature = (profile, diffusivity, boundary_condition=BoundaryCondition::Closed),
+                text_signature = "(profile, diffusivity, boundary_condition)"
+            )]
+            fn new(
+                profile: &PyPoreProfile1D,
+                diffusivity: PySIArray1,
+                boundary_condition: BoundaryCondition,
+            ) -> PyResult<Self> {
+                Ok(Self(DDFT::new(
+                    &profile.0.profile,
+                    diffusivity.try_into()?,
+                    boundary_condition,
+                )?))
+            }
+
+            /// Integrate the density profile up to the given times.
+            ///
+            /// The integration can be continued by calling this function again.
+            ///
+            /// Parameters
+            /// ----------
+            /// times : SIArray1
+            ///     The times in ascending order at which the profiles are stored.
+            /// max_iter : int, optional
+            ///     The maximum number of time steps.
+            /// tol: float, optional
+            ///     The tolerance of the local error of a time step.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity.
+            ///
+            /// Returns
+            /// -------
+            /// DDFTTrajectory
+            #[pyo3(text_signature = "($self, times, max_iter=None, tol=None, verbosity=None)")]
+            fn integrate(
+                &mut self,
+                times: PySIArray1,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<PyDDFTTrajectory> {
+                Ok(PyDDFTTrajectory(self.0.integrate(
+                    &times.try_into()?,
+                    (max_iter, tol, verbosity).into(),
+                )?))
+            }
+
+            #[getter]
+            fn get_time(&self) -> PySINumber {
+                self.0.time.into()
+            }
+
+            #[getter]
+            fn get_density(&self) -> PySIArray2 {
+                self.0.profile.density.clone().into()
+            }
+        }
+
+        #[pymethods]
+        impl PyDDFTTrajectory {
+            #[getter]
+            fn get_time(&self) -> PySIArray1 {
+                self.0.time.clone().into()
+            }
+
+            #[getter]
+            fn get_r(&self) -> Option<PySIArray1> {
+                self.0
+                    .profiles
+                    .first()
+                    .map(|p| Length::from_reduced(p.grid.grids()[0].clone()).into())
+            }
+
+            #[getter]
+            fn get_density(&self) -> Vec<PySIArray2> {
+                self.0
+                    .profiles
+                    .iter()
+                    .map(|p| p.density.clone().into())
+                    .collect()
+            }
+
+            #[getter]
+            fn get_moles(&self) -> PySIArray2 {
+                self.0.moles().into()
+            }
+
+            #[getter]
+            fn get_total_moles(&self) -> PySIArray1 {
+                self.0.total_moles().into()
+            }
+        }
+    };
+}
@@ -0,0 +1,147 @@
+#[macro_export]
+macro_rules! impl_critical_nucleus {
+    ($func:ty) => {
+        /// A one-dimensional density profile of a critical nucleus (droplet or bubble)
+        /// in a supersaturated bulk phase.
+        #[pyclass(name = "CriticalNucleus")]
+        pub struct PyCriticalNucleus(CriticalNucleus<$func>);
+
+        impl_1d_profile!(PyCriticalNucleus, [get_r]);
+
+        #[pymethods]
+        impl PyCriticalNucleus {
+            /// Initialize a critical nucleus with a hyperbolic tangent.
+            ///
+            /// Parameters
+            /// ----------
+            /// bulk : State
+            ///     The supersaturated bulk phase surrounding the nucleus.
+            /// vle : PhaseEquilibrium
+            ///     The phase equilibrium used as initial guess for the
+            ///     phase inside the nucleus.
+            /// n_grid : int
+            ///     The number of grid points.
+            /// l_grid: SINumber
+            ///     The radius of the calculation domain.
+            /// radius: SINumber
+            ///     The initial radius of the nucleus.
+            /// critical_temperature: SINumber
+            ///     An estimate for the critical temperature of the system.
+            ///     Used to guess the width of the interface.
+            ///
+            /// Returns
+            /// -------
+            /// CriticalNucleus
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(bulk, vle, n_grid, l_grid, radius, critical_temperature)")]
+            fn from_tanh(
+                bulk: &PyState,
+                vle: &PyPhaseEquilibrium,
+                n_grid: usize,
+                l_grid: PySINumber,
+                radius: PySINumber,
+                critical_temperature: PySINumber,
+            ) -> PyResult<Self> {
+                let profile = CriticalNucleus::from_tanh(
+                    &bulk.0,
+                    &vle.0,
+                    n_grid,
+                    l_grid.try_into()?,
+                    radius.try_into()?,
+                    critical_temperature.try_into()?,
+                )?;
+                Ok(PyCriticalNucleus(profile))
+            }
+
+            /// Initialize the critical nucleus of a pure substance at
+            /// the given supersaturation S = p/p^sat.
+            ///
+            /// Parameters
+            /// ----------
+            /// vle : PhaseEquilibrium
+            ///     The phase equilibrium at the given temperature.
+            /// supersaturation : float
+            ///     The supersaturation of the bulk phase. For S > 1 the
+            ///     nucleus is a droplet, for S < 1 it is a bubble.
+            /// n_grid : int
+            ///     The number of grid points.
+            /// l_grid: SINumber
+            ///     The radius of the calculation domain.
+            /// critical_temperature: SINumber
+            ///     An estimate for the critical temperature of the system.
+            ///     Used to guess the width of the interface.
+            /// radius: SINumber, optional
+            ///     The initial radius of the nucleus. Defaults to the
+            ///     radius from classical nucleation theory.
+            ///
+            /// Returns
+            /// -------
+            /// CriticalNucleus
+            ///
+            #[staticmethod]
+            #[pyo3(text_signature = "(vle, supersaturation, n_grid, l_grid, critical_temperature, radius=None)")]
+            fn from_supersaturation(
+                vle: &PyPhaseEquilibrium,
+                supersaturation: f64,
+                n_grid: usize,
+                l_grid: PySINumber,
+                critical_temperature: PySINumber,
+                radius: Option<PySINumber>,
+            ) -> PyResult<Self> {
+                let profile = CriticalNucleus::from_supersaturation(
+                    &vle.0,
+                    supersaturation,
+                    n_grid,
+                    l_grid.try_into()?,
+                    radius.map(|r| r.try_into()).transpose()?,
+                    critical_temperature.try_into()?,
+                )?;
+                Ok(PyCriticalNucleus(profile))
+            }
+        }
+
+        #[pymethods]
+        impl PyCriticalNucleus {
+            #[getter]
+            fn get_nucleation_barrier(&self) -> Option<PySINumber> {
+                self.0.nucleation_barrier.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_equimolar_radius(&self) -> Option<PySINumber> {
+                self.0.equimolar_radius.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_surface_of_tension_radius(&self) -> Option<PySINumber> {
+                self.0.surface_of_tension_radius.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_surface_tension(&self) -> Option<PySINumber> {
+                self.0.surface_tension.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_tolman_length(&self) -> Option<PySINumber> {
+                self.0.tolman_length.map(PySINumber::from)
+            }
+
+            #[getter]
+            fn get_supersaturation(&self) -> f64 {
+                self.0.supersaturation()
+            }
+
+            #[getter]
+            fn get_vle(&self) -> PyPhaseEquilibrium {
+                PyPhaseEquilibrium(self.0.vle.clone())
+            }
+
+            #[getter]
+            fn get_interior(&self) -> PyState {
+                PyState(self.0.interior.clone())
+            }
+        }
+    };
+}
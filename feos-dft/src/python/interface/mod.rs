@@ -1,3 +1,4 @@
+mod critical_nucleus;
 mod surface_tension_diagram;
 
 #[macro_export]
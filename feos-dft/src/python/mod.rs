@@ -1,8 +1,9 @@
-mod adsorption;
-mod interface;
-mod profile;
-mod solvation;
-mod solver;
-
-pub use adsorption::PyExternalPotential;
-pub use solver::{PyDFTSolver, PyDFTSolverLog};
+mod adsorption;
+mod ddft;
+mod interface;
+mod profile;
+mod solvation;
+mod solver;
+
+pub use adsorption::PyExternalPotential;
+pub use solver::{PyDFTSolver, PyDFTSolverLog};
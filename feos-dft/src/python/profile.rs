@@ -173,6 +173,15 @@ macro_rules! impl_profile {
                     self.0.profile.grand_potential_density()?,
                 ))
             }
+
+            #[getter]
+            fn get_electrostatic_potential(&self) -> PyResult<Option<$si_arr>> {
+                Ok(self
+                    .0
+                    .profile
+                    .electrostatic_potential()?
+                    .map($si_arr::from))
+            }
             $(
                 #[getter]
                 fn get_drho_dmu(&self) -> PyResult<$si_arr3> {
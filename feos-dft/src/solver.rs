@@ -266,7 +266,7 @@ where
 
         for k in 0..picard.max_iter {
             // calculate residual
-            let (res, res_bulk, res_norm, _, _) =
+            let (res, res_bulk, res_norm, _, _, _) =
                 self.euler_lagrange_equation(&*rho, &*rho_bulk, picard.log)?;
             log.add_residual(solver, k, res_norm);
 
@@ -313,7 +313,7 @@ where
             } else {
                 rho + alpha * delta_rho
             };
-            let Ok((_, _, res2, _, _)) =
+            let Ok((_, _, res2, _, _, _)) =
                 self.euler_lagrange_equation(&rho_new, rho_bulk, logarithm)
             else {
                 continue;
@@ -328,7 +328,7 @@ where
             } else {
                 rho + 0.5 * alpha * delta_rho
             };
-            let Ok((_, _, res1, _, _)) =
+            let Ok((_, _, res1, _, _, _)) =
                 self.euler_lagrange_equation(&rho_new, rho_bulk, logarithm)
             else {
                 continue;
@@ -384,7 +384,7 @@ where
             let m = resm.len() + 1;
 
             // calculate residual
-            let (res, res_bulk, res_norm, _, _) =
+            let (res, res_bulk, res_norm, _, _, _) =
                 self.euler_lagrange_equation(&*rho, &*rho_bulk, anderson.log)?;
             log.add_residual(solver, k, res_norm);
 
@@ -447,7 +447,7 @@ where
         let solver = if newton.log { "Newton (log)" } else { "Newton" };
         for k in 0..newton.max_iter {
             // calculate initial residual
-            let (res, _, res_norm, exp_dfdrho, rho_p) =
+            let (res, _, res_norm, exp_dfdrho, rho_p, _) =
                 self.euler_lagrange_equation(rho, rho_bulk, newton.log)?;
             log.add_residual(solver, k, res_norm);
 
@@ -462,14 +462,14 @@ where
             // define rhs function
             let rhs = |delta_rho: &_| {
                 let mut delta_functional_derivative =
-                    self.delta_functional_derivative(delta_rho, &second_partial_derivatives);
+                    self.delta_functional_derivative(delta_rho, &second_partial_derivatives)?;
                 delta_functional_derivative
                     .outer_iter_mut()
                     .zip(self.dft.m().iter())
                     .for_each(|(mut q, &m)| q /= m);
                 let delta_i = self.delta_bond_integrals(&exp_dfdrho, &delta_functional_derivative);
                 let rho = if newton.log { &*rho } else { &rho_p };
-                delta_rho + (delta_functional_derivative - delta_i) * rho
+                Ok(delta_rho + (delta_functional_derivative - delta_i) * rho)
             };
 
             // update solution
@@ -490,7 +490,7 @@ where
         log: &mut DFTSolverLog,
     ) -> EosResult<Array<f64, D::Larger>>
     where
-        R: Fn(&Array<f64, D::Larger>) -> Array<f64, D::Larger>,
+        R: Fn(&Array<f64, D::Larger>) -> EosResult<Array<f64, D::Larger>>,
     {
         // allocate vectors and arrays
         let mut v = Vec::with_capacity(max_iter);
@@ -506,7 +506,7 @@ where
         let mut iter = 0;
         for j in 0..max_iter {
             // calculate q=Av_j
-            let mut q = rhs(&v[j]);
+            let mut q = rhs(&v[j])?;
 
             // calculate h_ij
             v.iter()
@@ -583,11 +583,18 @@ where
         Ok(second_partial_derivatives)
     }
 
+    /// Change of the functional derivative caused by a change `delta_density` of the density.
+    ///
+    /// For functionals with charged species, the change of the mean-field
+    /// electrostatic potential is included, but not the change of the shift
+    /// of the potential that neutralizes the charge of the walls. The
+    /// resulting Jacobian is inexact, which slows down the convergence of
+    /// Newton's method but does not affect the solution.
     pub(crate) fn delta_functional_derivative(
         &self,
         delta_density: &Array<f64, D::Larger>,
         second_partial_derivatives: &[Array<f64, <D::Larger as Dimension>::Larger>],
-    ) -> Array<f64, D::Larger> {
+    ) -> EosResult<Array<f64, D::Larger>> {
         let delta_weighted_densities = self.convolver.weighted_densities(delta_density);
         let delta_partial_derivatives: Vec<_> = second_partial_derivatives
             .iter()
@@ -609,8 +616,16 @@ where
                 delta_partial_derivatives
             })
             .collect();
-        self.convolver
-            .functional_derivative(&delta_partial_derivatives)
+        let mut delta_functional_derivative = self
+            .convolver
+            .functional_derivative(&delta_partial_derivatives);
+
+        // the mean-field electrostatic potential is linear in the density
+        if let Some(e) = self.electrostatics() {
+            let (delta_psi, _) = e.poisson(&self.grid, &e.charge_density(delta_density))?;
+            e.add_potential(&mut delta_functional_derivative, &delta_psi);
+        }
+        Ok(delta_functional_derivative)
     }
 
     pub(crate) fn delta_bond_integrals(
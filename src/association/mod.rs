@@ -259,18 +259,37 @@ impl<P: AssociationStrength> Association<P> {
         state: &StateHD<D>,
         diameter: &Array1<D>,
     ) -> D {
+        let zeta = self
+            .parameters
+            .zeta(state.temperature, &state.partial_density, [2, 3]);
+        self.helmholtz_energy_with_strength(state, diameter, zeta, |i, j, assoc_ij| {
+            self.parameters
+                .association_strength(state.temperature, i, j, assoc_ij)
+        })
+    }
+
+    /// Helmholtz energy for packing fractions and association strengths that are
+    /// provided by the caller, e.g., to evaluate the contribution with dual-valued
+    /// model parameters.
+    pub(crate) fn helmholtz_energy_with_strength<D: DualNum<f64> + Copy, F>(
+        &self,
+        state: &StateHD<D>,
+        diameter: &Array1<D>,
+        [zeta2, n3]: [D; 2],
+        association_strength: F,
+    ) -> D
+    where
+        F: Fn(usize, usize, P::Record) -> D,
+    {
         let a = &self.association_parameters;
 
         // auxiliary variables
-        let [zeta2, n3] = self
-            .parameters
-            .zeta(state.temperature, &state.partial_density, [2, 3]);
         let n2 = zeta2 * 6.0;
         let n3i = (-n3 + 1.0).recip();
 
         // association strength
         let [delta_ab, delta_cc] =
-            self.association_strength(state.temperature, diameter, n2, n3i, D::one());
+            self.association_strength_with(diameter, n2, n3i, D::one(), association_strength);
 
         match (
             a.sites_a.len() * a.sites_b.len(),
@@ -309,6 +328,7 @@ impl<P: AssociationStrength> Association<P> {
         }
     }
 
+    #[cfg(feature = "dft")]
     fn association_strength<D: DualNum<f64> + Copy>(
         &self,
         temperature: D,
@@ -317,6 +337,23 @@ impl<P: AssociationStrength> Association<P> {
         n3i: D,
         xi: D,
     ) -> [Array2<D>; 2] {
+        self.association_strength_with(diameter, n2, n3i, xi, |i, j, assoc_ij| {
+            self.parameters
+                .association_strength(temperature, i, j, assoc_ij)
+        })
+    }
+
+    fn association_strength_with<D: DualNum<f64> + Copy, F>(
+        &self,
+        diameter: &Array1<D>,
+        n2: D,
+        n3i: D,
+        xi: D,
+        association_strength: F,
+    ) -> [Array2<D>; 2]
+    where
+        F: Fn(usize, usize, P::Record) -> D,
+    {
         let p = &self.association_parameters;
 
         let delta_ab = Array2::from_shape_fn([p.sites_a.len(), p.sites_b.len()], |(i, j)| {
@@ -324,8 +361,7 @@ impl<P: AssociationStrength> Association<P> {
             let dj = diameter[p.sites_b[j].assoc_comp];
             let k = di * dj / (di + dj) * (n2 * n3i);
             n3i * (k * xi * (k / 18.0 + 0.5) + 1.0)
-                * self.parameters.association_strength(
-                    temperature,
+                * association_strength(
                     p.sites_a[i].assoc_comp,
                     p.sites_b[j].assoc_comp,
                     p.parameters_ab[(i, j)],
@@ -336,8 +372,7 @@ impl<P: AssociationStrength> Association<P> {
             let dj = diameter[p.sites_c[j].assoc_comp];
             let k = di * dj / (di + dj) * (n2 * n3i);
             n3i * (k * xi * (k / 18.0 + 0.5) + 1.0)
-                * self.parameters.association_strength(
-                    temperature,
+                * association_strength(
                     p.sites_c[i].assoc_comp,
                     p.sites_c[j].assoc_comp,
                     p.parameters_cc[(i, j)],
@@ -0,0 +1,286 @@
+//! Temperature-dependent binary interaction parameters.
+//!
+//! The binary records of SAFT models store a constant binary interaction
+//! parameter $k_{ij}$ that corrects the combining rule of the dispersion energy
+//! $\varepsilon_{ij}=\left(1-k_{ij}\right)\sqrt{\varepsilon_i\varepsilon_j}$.
+//! Optionally, a [KijTemperatureDependence] can be added to the records which is
+//! evaluated at the (dual number) temperature of the state, so that all
+//! temperature derivatives include the temperature dependence of $k_{ij}$.
+//!
+//! The temperature dependence is available for `pcsaft`, `pets`, `saftvrmie`,
+//! `saftvrqmie` and `uvtheory` via the `k_ij_t` field of the binary records.
+//! The polynomial $k_{ij}(T)$ of `epcsaft` is read from its own binary records
+//! and evaluated with the same machinery. `gc_pcsaft` is not covered, because
+//! its binary records between segments are plain values of $k_{ij}$.
+use feos_core::parameter::{FitBounds, ParameterError};
+use ndarray::Array2;
+use num_dual::DualNum;
+use serde::{Deserialize, Serialize};
+use std::fmt;
+
+const POLYNOMIAL_NAMES: [&str; 6] = ["k_ij_1", "k_ij_2", "k_ij_3", "k_ij_4", "k_ij_5", "k_ij_6"];
+
+/// Temperature dependence of the binary interaction parameter $k_{ij}$.
+///
+/// The constant contribution is the $k_{ij}$ of the binary record, the
+/// temperature dependence is added to it. Temperatures are in units of K.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "form", rename_all = "snake_case")]
+pub enum KijTemperatureDependence {
+    /// $k_{ij}(T)=k_{ij}+\sum_{n=1}^Nk_{ij,n}\left(T-T_\mathrm{ref}\right)^n$
+    ///
+    /// The coefficients $k_{ij,n}$ are adjustable as `k_ij_1` to `k_ij_6`.
+    Polynomial {
+        coefficients: Vec<f64>,
+        reference_temperature: f64,
+    },
+    /// $k_{ij}(T)=k_{ij}+\frac{b}{T}+c\ln T$
+    ///
+    /// The coefficients are adjustable as `k_ij_b` and `k_ij_c`.
+    Logarithmic { b: f64, c: f64 },
+}
+
+impl KijTemperatureDependence {
+    /// Polynomial in $T-T_\mathrm{ref}$ with at most 6 coefficients,
+    /// starting with the linear term.
+    pub fn polynomial(
+        coefficients: Vec<f64>,
+        reference_temperature: f64,
+    ) -> Result<Self, ParameterError> {
+        if coefficients.len() > POLYNOMIAL_NAMES.len() {
+            return Err(ParameterError::IncompatibleParameters(format!(
+                "A polynomial k_ij(T) can have at most {} coefficients, got {}.",
+                POLYNOMIAL_NAMES.len(),
+                coefficients.len()
+            )));
+        }
+        Ok(Self::Polynomial {
+            coefficients,
+            reference_temperature,
+        })
+    }
+
+    /// $\frac{b}{T}+c\ln T$
+    pub fn logarithmic(b: f64, c: f64) -> Self {
+        Self::Logarithmic { b, c }
+    }
+
+    /// Temperature-dependent contribution to $k_{ij}$ at `temperature` in K.
+    pub fn evaluate<D: DualNum<f64> + Copy>(&self, temperature: D) -> D {
+        match self {
+            Self::Polynomial {
+                coefficients,
+                reference_temperature,
+            } => polynomial(coefficients, *reference_temperature, temperature),
+            Self::Logarithmic { b, c } => temperature.recip() * *b + temperature.ln() * *c,
+        }
+    }
+
+    /// Names of the adjustable coefficients.
+    pub fn names(&self) -> Vec<&'static str> {
+        match self {
+            Self::Polynomial { coefficients, .. } => {
+                POLYNOMIAL_NAMES[..coefficients.len()].to_vec()
+            }
+            Self::Logarithmic { .. } => vec!["k_ij_b", "k_ij_c"],
+        }
+    }
+
+    /// Value of the coefficient `name`, if it exists.
+    pub fn get(&self, name: &str) -> Option<f64> {
+        match self {
+            Self::Polynomial { coefficients, .. } => POLYNOMIAL_NAMES
+                .iter()
+                .position(|&n| n == name)
+                .and_then(|k| coefficients.get(k).copied()),
+            Self::Logarithmic { b, c } => match name {
+                "k_ij_b" => Some(*b),
+                "k_ij_c" => Some(*c),
+                _ => None,
+            },
+        }
+    }
+
+    /// Default bounds and scaling of the coefficient `name`.
+    ///
+    /// The coefficients are unbounded and scaled so that their contribution
+    /// to $k_{ij}$ is of the order of 0.01 at a temperature of around 300 K.
+    pub fn bounds(&self, name: &str) -> FitBounds {
+        let scale = match name {
+            "k_ij_b" => 1.0,
+            "k_ij_c" => 1e-3,
+            _ => POLYNOMIAL_NAMES
+                .iter()
+                .position(|&n| n == name)
+                .map_or(1.0, |k| 1e-2 * 1e-2f64.powi(k as i32 + 1)),
+        };
+        FitBounds::new(f64::NEG_INFINITY, f64::INFINITY, scale)
+    }
+
+    /// Mutable reference to the coefficient `name`, if it exists.
+    pub fn get_mut(&mut self, name: &str) -> Option<&mut f64> {
+        match self {
+            Self::Polynomial { coefficients, .. } => POLYNOMIAL_NAMES
+                .iter()
+                .position(|&n| n == name)
+                .and_then(|k| coefficients.get_mut(k)),
+            Self::Logarithmic { b, c } => match name {
+                "k_ij_b" => Some(b),
+                "k_ij_c" => Some(c),
+                _ => None,
+            },
+        }
+    }
+}
+
+impl fmt::Display for KijTemperatureDependence {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Polynomial {
+                coefficients,
+                reference_temperature,
+            } => write!(
+                f,
+                "polynomial(coefficients={coefficients:?}, reference_temperature={reference_temperature})"
+            ),
+            Self::Logarithmic { b, c } => write!(f, "logarithmic(b={b}, c={c})"),
+        }
+    }
+}
+
+/// $\sum_{n=1}^Nc_n\left(T-T_\mathrm{ref}\right)^n$ evaluated with Horner's scheme.
+fn polynomial<D: DualNum<f64> + Copy>(
+    coefficients: &[f64],
+    reference_temperature: f64,
+    temperature: D,
+) -> D {
+    let dt = temperature - reference_temperature;
+    coefficients
+        .iter()
+        .rev()
+        .fold(D::zero(), |acc, &c| (acc + c) * dt)
+}
+
+/// Temperature dependence of the binary interaction parameters of all
+/// pairs of components of a mixture.
+#[derive(Clone, Debug, Default)]
+pub struct TemperatureDependentKij(Vec<(usize, usize, KijTemperatureDependence)>);
+
+impl TemperatureDependentKij {
+    /// Collect the temperature dependencies from the binary records.
+    pub fn new<B>(
+        binary_records: Option<&Array2<B>>,
+        temperature_dependence: impl Fn(&B) -> Option<&KijTemperatureDependence>,
+    ) -> Self {
+        let mut pairs = Vec::new();
+        if let Some(br) = binary_records {
+            for ((i, j), record) in br.indexed_iter() {
+                if i < j {
+                    if let Some(k) = temperature_dependence(record) {
+                        pairs.push((i, j, k.clone()));
+                    }
+                }
+            }
+        }
+        Self(pairs)
+    }
+
+    /// Return `true` if all binary interaction parameters are constant.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Temperature-dependent contributions to the binary interaction
+    /// parameters of all pairs of `n` components at `temperature`.
+    pub fn k_ij<D: DualNum<f64> + Copy>(&self, n: usize, temperature: D) -> Array2<D> {
+        let mut k_ij = Array2::zeros((n, n));
+        for (i, j, k) in &self.0 {
+            let k = k.evaluate(temperature);
+            k_ij[(*i, *j)] = k;
+            k_ij[(*j, *i)] = k;
+        }
+        k_ij
+    }
+
+    /// Dispersion energy parameters $\frac{\varepsilon_{ij}(T)}{k_\mathrm{B}}$ at `temperature`.
+    ///
+    /// `epsilon_k_ij` contains the energy parameters with the constant $k_{ij}$
+    /// and `e_k_ij` the energy parameters without binary interaction parameter.
+    pub fn epsilon_k_ij<D: DualNum<f64> + Copy>(
+        &self,
+        epsilon_k_ij: &Array2<f64>,
+        e_k_ij: &Array2<f64>,
+        temperature: D,
+    ) -> Array2<D> {
+        let mut eps = epsilon_k_ij.mapv(D::from);
+        for (i, j, k) in &self.0 {
+            let eps_ij = -k.evaluate(temperature) * e_k_ij[(*i, *j)] + epsilon_k_ij[(*i, *j)];
+            eps[(*i, *j)] = eps_ij;
+            eps[(*j, *i)] = eps_ij;
+        }
+        eps
+    }
+
+    /// Dispersion energy parameter $\frac{\varepsilon_{ij}(T)}{k_\mathrm{B}}$ of the
+    /// pair `i`, `j` at `temperature`.
+    ///
+    /// Used by models that evaluate the energy parameters pair by pair, e.g.,
+    /// in the integration of a pair potential.
+    pub fn epsilon_k<D: DualNum<f64> + Copy>(
+        &self,
+        i: usize,
+        j: usize,
+        epsilon_k_ij: &Array2<f64>,
+        e_k_ij: &Array2<f64>,
+        temperature: D,
+    ) -> D {
+        let (i, j) = (i.min(j), i.max(j));
+        self.0
+            .iter()
+            .find(|(k, l, _)| (*k, *l) == (i, j))
+            .map_or(D::from(epsilon_k_ij[(i, j)]), |(_, _, k)| {
+                -k.evaluate(temperature) * e_k_ij[(i, j)] + epsilon_k_ij[(i, j)]
+            })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr2;
+    use num_dual::Dual64;
+
+    #[test]
+    fn test_temperature_dependence() -> Result<(), ParameterError> {
+        let t = Dual64::from(300.0).derivative();
+        let k = KijTemperatureDependence::polynomial(vec![1e-4, 2e-6], 250.0)?;
+        let k_t = k.evaluate(t);
+        assert_relative_eq!(k_t.re, 1e-4 * 50.0 + 2e-6 * 2500.0);
+        assert_relative_eq!(k_t.eps, 1e-4 + 2.0 * 2e-6 * 50.0);
+        assert_eq!(k.names(), ["k_ij_1", "k_ij_2"]);
+
+        let k = KijTemperatureDependence::logarithmic(3.0, -0.01);
+        let k_t = k.evaluate(t);
+        assert_relative_eq!(k_t.re, 0.01 - 0.01 * 300f64.ln());
+        assert_relative_eq!(k_t.eps, -3.0 / 300f64.powi(2) - 0.01 / 300.0);
+
+        let br = arr2(&[[None, Some(k.clone())], [Some(k), None]]);
+        let k_ij = TemperatureDependentKij::new(Some(&br), |k| k.as_ref());
+        let e_k_ij = arr2(&[[200.0, 150.0], [150.0, 100.0]]);
+        let epsilon_k_ij = &e_k_ij * 0.99;
+        let eps = k_ij.epsilon_k_ij(&epsilon_k_ij, &e_k_ij, 300.0);
+        assert_eq!(eps[(0, 0)], epsilon_k_ij[(0, 0)]);
+        assert_relative_eq!(eps[(0, 1)], 150.0 * (0.99 - k_t.re));
+        assert_eq!(eps[(0, 1)], eps[(1, 0)]);
+        assert_eq!(
+            k_ij.epsilon_k(1, 0, &epsilon_k_ij, &e_k_ij, 300.0),
+            eps[(0, 1)]
+        );
+        assert_eq!(
+            k_ij.epsilon_k(1, 1, &epsilon_k_ij, &e_k_ij, 300.0),
+            eps[(1, 1)]
+        );
+        Ok(())
+    }
+}
@@ -2,6 +2,8 @@
 use crate::epcsaft::ElectrolytePcSaft;
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::GcPcSaft;
+#[cfg(feature = "multiparameter")]
+use crate::multiparameter::Multiparameter;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::PcSaft;
 #[cfg(feature = "pets")]
@@ -12,7 +14,8 @@ use crate::saftvrmie::SaftVRMie;
 use crate::saftvrqmie::SaftVRQMie;
 #[cfg(feature = "uvtheory")]
 use crate::uvtheory::UVTheory;
-use feos_core::cubic::PengRobinson;
+use feos_core::activity::ActivityModel;
+use feos_core::cubic::{Cubic, CubicExcessGibbs};
 #[cfg(feature = "python")]
 use feos_core::python::user_defined::PyResidual;
 use feos_core::si::*;
@@ -36,7 +39,8 @@ pub enum ResidualModel {
     ElectrolytePcSaft(ElectrolytePcSaft),
     #[cfg(feature = "gc_pcsaft")]
     GcPcSaft(GcPcSaft),
-    PengRobinson(PengRobinson),
+    Cubic(Cubic),
+    CubicExcessGibbs(CubicExcessGibbs<ActivityModel>),
     #[cfg(feature = "python")]
     Python(PyResidual),
     #[cfg(feature = "saftvrqmie")]
@@ -48,4 +52,6 @@ pub enum ResidualModel {
     Pets(Pets),
     #[cfg(feature = "uvtheory")]
     UVTheory(UVTheory),
+    #[cfg(feature = "multiparameter")]
+    Multiparameter(Multiparameter),
 }
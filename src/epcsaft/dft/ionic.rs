@@ -0,0 +1,138 @@
+use super::ElectrolytePcSaftParameters;
+use crate::epcsaft::eos::born::Born;
+use crate::epcsaft::eos::ionic::Ionic;
+use crate::epcsaft::eos::ElectrolytePcSaftVariants;
+use crate::hard_sphere::HardSphereProperties;
+use feos_core::{EosError, StateHD};
+use feos_dft::{FunctionalContribution, WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use ndarray::*;
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// Densities averaged over spheres with the hard-sphere diameter as radius.
+///
+/// Evaluating the contributions at averaged instead of local densities avoids
+/// the unphysical solvation of ions in regions that are only accessible to
+/// the ions, e.g., directly at walls.
+fn ionic_weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+    p: &ElectrolytePcSaftParameters,
+    temperature: N,
+) -> WeightFunctionInfo<N> {
+    let d = p.hs_diameter(temperature);
+    WeightFunctionInfo::new(p.component_index().into_owned(), false).add(
+        WeightFunction::new_scaled(d, WeightFunctionShape::Theta),
+        false,
+    )
+}
+
+/// Evaluate a Helmholtz energy contribution of the equation of state
+/// at the weighted density of every grid point.
+///
+/// Weighted densities that are negative due to numerical noise
+/// of the convolution are set to 0.
+fn local_helmholtz_energy_density<N: DualNum<f64> + Copy>(
+    temperature: N,
+    density: ArrayView2<N>,
+    helmholtz_energy: impl Fn(&StateHD<N>) -> N,
+) -> Array1<N> {
+    density
+        .axis_iter(Axis(1))
+        .map(|rho| {
+            let rho = rho.mapv(|r| if r.re() > 0.0 { r } else { N::zero() });
+            if rho.sum().re() > 0.0 {
+                helmholtz_energy(&StateHD::new(temperature, N::one(), rho))
+            } else {
+                N::zero()
+            }
+        })
+        .collect()
+}
+
+/// Debye-Hückel contribution in a weighted density approximation.
+pub struct IonicFunctional {
+    ionic: Ionic,
+}
+
+impl IonicFunctional {
+    pub fn new(
+        parameters: Arc<ElectrolytePcSaftParameters>,
+        variant: ElectrolytePcSaftVariants,
+    ) -> Self {
+        Self {
+            ionic: Ionic {
+                parameters,
+                variant,
+            },
+        }
+    }
+}
+
+impl FunctionalContribution for IonicFunctional {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        ionic_weight_functions(&self.ionic.parameters, temperature)
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+        density: ArrayView2<N>,
+    ) -> Result<Array1<N>, EosError> {
+        let d = self.ionic.parameters.hs_diameter(temperature);
+        Ok(local_helmholtz_energy_density(
+            temperature,
+            density,
+            |state| self.ionic.helmholtz_energy(state, &d),
+        ))
+    }
+}
+
+impl fmt::Display for IonicFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Ionic functional")
+    }
+}
+
+/// Born contribution in a weighted density approximation.
+pub struct BornFunctional {
+    born: Born,
+}
+
+impl BornFunctional {
+    pub fn new(parameters: Arc<ElectrolytePcSaftParameters>) -> Self {
+        Self {
+            born: Born { parameters },
+        }
+    }
+}
+
+impl FunctionalContribution for BornFunctional {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        ionic_weight_functions(&self.born.parameters, temperature)
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+        density: ArrayView2<N>,
+    ) -> Result<Array1<N>, EosError> {
+        let d = self.born.parameters.hs_diameter(temperature);
+        Ok(local_helmholtz_energy_density(
+            temperature,
+            density,
+            |state| self.born.helmholtz_energy(state, &d),
+        ))
+    }
+}
+
+impl fmt::Display for BornFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Born functional")
+    }
+}
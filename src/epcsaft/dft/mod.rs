@@ -0,0 +1,178 @@
+use super::eos::{ElectrolytePcSaftOptions, ElectrolytePcSaftVariants};
+use super::ElectrolytePcSaftParameters;
+use crate::association::Association;
+use crate::hard_sphere::{FMTContribution, FMTVersion};
+use feos_core::parameter::Parameter;
+use feos_core::si::{MolarWeight, GRAM, MOL};
+use feos_core::{Components, EosResult, StateHD};
+use feos_derive::FunctionalContribution;
+use feos_dft::adsorption::FluidParameters;
+use feos_dft::{
+    Electrostatics, FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape,
+    WeightFunctionInfo, DFT,
+};
+use ndarray::{Array1, ArrayView2, ScalarOperand};
+use num_dual::DualNum;
+use num_traits::One;
+use std::f64::consts::FRAC_PI_6;
+use std::sync::Arc;
+
+mod dispersion;
+mod hard_chain;
+mod ionic;
+use dispersion::AttractiveFunctional;
+use hard_chain::ChainFunctional;
+use ionic::{BornFunctional, IonicFunctional};
+
+/// ePC-SAFT Helmholtz energy functional.
+///
+/// The direct Coulomb interactions between the ions are described by the
+/// mean-field electrostatic potential (see [Electrostatics]) with the
+/// permittivity of the bulk phase. The Debye-Hückel and Born contributions
+/// of the equation of state are included in a weighted density approximation,
+/// so that the functional reduces to [ElectrolytePcSaft](super::ElectrolytePcSaft)
+/// for homogeneous systems.
+pub struct ElectrolytePcSaftFunctional {
+    pub parameters: Arc<ElectrolytePcSaftParameters>,
+    fmt_version: FMTVersion,
+    options: ElectrolytePcSaftOptions,
+}
+
+impl ElectrolytePcSaftFunctional {
+    pub fn new(parameters: Arc<ElectrolytePcSaftParameters>) -> DFT<Self> {
+        Self::with_options(
+            parameters,
+            FMTVersion::WhiteBear,
+            ElectrolytePcSaftOptions::default(),
+        )
+    }
+
+    pub fn new_full(
+        parameters: Arc<ElectrolytePcSaftParameters>,
+        fmt_version: FMTVersion,
+    ) -> DFT<Self> {
+        Self::with_options(parameters, fmt_version, ElectrolytePcSaftOptions::default())
+    }
+
+    pub fn with_options(
+        parameters: Arc<ElectrolytePcSaftParameters>,
+        fmt_version: FMTVersion,
+        options: ElectrolytePcSaftOptions,
+    ) -> DFT<Self> {
+        if let ElectrolytePcSaftVariants::Revised = options.epcsaft_variant {
+            if parameters.nionic > 0 {
+                panic!("Ionic contribution is not available in the revised ePC-SAFT variant.")
+            }
+        }
+        DFT(Self {
+            parameters,
+            fmt_version,
+            options,
+        })
+    }
+}
+
+impl Components for ElectrolytePcSaftFunctional {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.fmt_version,
+            self.options,
+        )
+        .0
+    }
+}
+
+impl HelmholtzEnergyFunctional for ElectrolytePcSaftFunctional {
+    type Contribution = ElectrolytePcSaftFunctionalContribution;
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_eta * moles.sum()
+            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
+                .sum()
+    }
+
+    fn contributions(&self) -> Box<dyn Iterator<Item = ElectrolytePcSaftFunctionalContribution>> {
+        let p = &self.parameters;
+        let mut contributions = Vec::with_capacity(6);
+
+        // Hard sphere contribution
+        let hs = FMTContribution::new(p, self.fmt_version);
+        contributions.push(hs.into());
+
+        // Hard chains
+        if p.m.iter().any(|&mi| !mi.is_one()) {
+            let chain = ChainFunctional::new(p.clone());
+            contributions.push(chain.into());
+        }
+
+        // Dispersion
+        let att = AttractiveFunctional::new(p.clone());
+        contributions.push(att.into());
+
+        // Association
+        if !p.association.is_empty() {
+            let assoc = Association::new(
+                p,
+                &p.association,
+                self.options.max_iter_cross_assoc,
+                self.options.tol_cross_assoc,
+            );
+            contributions.push(assoc.into());
+        }
+
+        // Ion correlations and solvation
+        if p.nionic > 0 {
+            let ionic = IonicFunctional::new(p.clone(), self.options.epcsaft_variant);
+            contributions.push(ionic.into());
+            if let ElectrolytePcSaftVariants::Advanced = self.options.epcsaft_variant {
+                let born = BornFunctional::new(p.clone());
+                contributions.push(born.into());
+            }
+        }
+        Box::new(contributions.into_iter())
+    }
+
+    fn molecule_shape(&self) -> MoleculeShape {
+        MoleculeShape::NonSpherical(&self.parameters.m)
+    }
+
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+
+    fn electrostatics(&self, temperature: f64, density: &Array1<f64>) -> Option<Electrostatics> {
+        let p = &self.parameters;
+        if p.nionic == 0 {
+            return None;
+        }
+        let state = StateHD::new(temperature, 1.0, density.clone());
+        let bjerrum_length = p.bjerrum_length(&state, self.options.epcsaft_variant);
+        Some(Electrostatics::new(p.z.clone(), bjerrum_length))
+    }
+}
+
+impl FluidParameters for ElectrolytePcSaftFunctional {
+    fn epsilon_k_ff(&self) -> Array1<f64> {
+        self.parameters.epsilon_k.clone()
+    }
+
+    fn sigma_ff(&self) -> &Array1<f64> {
+        &self.parameters.sigma
+    }
+}
+
+/// Individual contributions for the ePC-SAFT Helmholtz energy functional.
+#[derive(FunctionalContribution)]
+pub enum ElectrolytePcSaftFunctionalContribution {
+    Fmt(FMTContribution<ElectrolytePcSaftParameters>),
+    Chain(ChainFunctional),
+    Attractive(AttractiveFunctional),
+    Association(Association<ElectrolytePcSaftParameters>),
+    Ionic(IonicFunctional),
+    Born(BornFunctional),
+}
@@ -1,6 +1,6 @@
 use crate::epcsaft::parameters::ElectrolytePcSaftParameters;
 use feos_core::StateHD;
-use ndarray::{Array, Array1, Array2};
+use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use std::f64::consts::{FRAC_PI_3, PI};
 use std::fmt;
@@ -64,37 +64,15 @@ pub const B2: [f64; 7] = [
 pub const T_REF: f64 = 298.15;
 
 impl ElectrolytePcSaftParameters {
-    pub fn k_ij_t<D: DualNum<f64>>(&self, temperature: D) -> Array2<f64> {
-        let k_ij = &self.k_ij;
-        let n = self.m.len();
-
-        let mut k_ij_t = Array::zeros((n, n));
-
-        for i in 0..n {
-            for j in 0..n {
-                // Calculate k_ij(T)
-                k_ij_t[[i, j]] = (temperature.re() - T_REF) * k_ij[[i, j]][1]
-                    + (temperature.re() - T_REF).powi(2) * k_ij[[i, j]][2]
-                    + (temperature.re() - T_REF).powi(3) * k_ij[[i, j]][3]
-                    + k_ij[[i, j]][0];
-            }
-        }
-        //println!("k_ij_t: {}", k_ij_t);
-        k_ij_t
+    pub fn k_ij_t<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array2<D> {
+        // k_ij(T) = k_ij_0 + sum_n k_ij_n (T - T_REF)^n
+        let k_ij_0 = self.k_ij.map(|k_ij| k_ij[0]);
+        self.k_ij_t.k_ij(self.m.len(), temperature) + k_ij_0.mapv(D::from)
     }
 
-    pub fn epsilon_k_ij_t<D: DualNum<f64>>(&self, temperature: D) -> Array2<f64> {
-        let k_ij_t = self.k_ij_t(temperature);
-        let n = self.m.len();
-
-        let mut epsilon_k_ij_t = Array::zeros((n, n));
-
-        for i in 0..n {
-            for j in 0..n {
-                epsilon_k_ij_t[[i, j]] = (1.0 - k_ij_t[[i, j]]) * self.e_k_ij[[i, j]];
-            }
-        }
-        epsilon_k_ij_t
+    pub fn epsilon_k_ij_t<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array2<D> {
+        self.k_ij_t
+            .epsilon_k_ij(&self.epsilon_k_ij, &self.e_k_ij, temperature)
     }
 }
 
@@ -175,7 +153,9 @@ mod tests {
     };
     use crate::hard_sphere::HardSphereProperties;
     use approx::assert_relative_eq;
+    use feos_core::parameter::Parameter;
     use ndarray::arr1;
+    use num_dual::Dual64;
 
     #[test]
     fn helmholtz_energy() {
@@ -215,4 +195,27 @@ mod tests {
         assert_relative_eq!(a1, a1m, epsilon = 1e-14);
         assert_relative_eq!(a2, a2m, epsilon = 1e-14);
     }
+
+    #[test]
+    fn k_ij_temperature_derivative() {
+        let k_ij = vec![0.01, 1e-4, -2e-6, 3e-8];
+        let parameters = ElectrolytePcSaftParameters::new_binary(
+            propane_butane_parameters().pure_records.clone(),
+            Some(k_ij.clone().into()),
+        )
+        .unwrap();
+        let t = Dual64::from(320.0).derivative();
+        let k_ij_t = parameters.k_ij_t(t)[[0, 1]];
+        let dt = 320.0 - T_REF;
+        assert_relative_eq!(
+            k_ij_t.re,
+            k_ij[0] + k_ij[1] * dt + k_ij[2] * dt.powi(2) + k_ij[3] * dt.powi(3)
+        );
+        assert_relative_eq!(
+            k_ij_t.eps,
+            k_ij[1] + 2.0 * k_ij[2] * dt + 3.0 * k_ij[3] * dt.powi(2)
+        );
+        let eps_ij_t = parameters.epsilon_k_ij_t(t)[[0, 1]];
+        assert_relative_eq!(eps_ij_t.eps, -k_ij_t.eps * parameters.e_k_ij[[0, 1]]);
+    }
 }
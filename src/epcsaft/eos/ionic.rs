@@ -12,6 +12,7 @@ use super::ElectrolytePcSaftVariants;
 const EPSILON_0: f64 = 8.85416e-12;
 const QE: f64 = 1.602176634e-19f64;
 const BOLTZMANN: f64 = 1.380649e-23;
+const CHI_SERIES_LIMIT: f64 = 1e-2;
 
 impl ElectrolytePcSaftParameters {
     pub fn bjerrum_length<D: DualNum<f64> + Copy>(
@@ -70,10 +71,16 @@ impl Ionic {
         let chi: Array1<D> = diameter
             .iter()
             .map(|&d| {
-                (kappa * d).powi(3).recip()
-                    * ((kappa * d + 1.0).ln() - (kappa * d + 1.0) * 2.0
-                        + (kappa * d + 1.0).powi(2) * 0.5
-                        + 1.5)
+                let kd = kappa * d;
+                if kd.re() < CHI_SERIES_LIMIT {
+                    // series expansion that avoids the cancellation at low ion densities
+                    (0..8)
+                        .rev()
+                        .fold(D::zero(), |acc, n| -acc * kd + 1.0 / (n + 3) as f64)
+                } else {
+                    kd.powi(3).recip()
+                        * ((kd + 1.0).ln() - (kd + 1.0) * 2.0 + (kd + 1.0).powi(2) * 0.5 + 1.5)
+                }
             })
             .collect();
 
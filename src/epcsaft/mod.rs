@@ -1,8 +1,12 @@
 //! Electrolyte Perturbed-Chain Statistical Associating Fluid Theory (ePC-SAFT)
 
+#[cfg(feature = "dft")]
+mod dft;
 mod eos;
 pub(crate) mod parameters;
 
+#[cfg(feature = "dft")]
+pub use dft::{ElectrolytePcSaftFunctional, ElectrolytePcSaftFunctionalContribution};
 pub use eos::{ElectrolytePcSaft, ElectrolytePcSaftOptions, ElectrolytePcSaftVariants};
 pub use parameters::{
     ElectrolytePcSaftBinaryRecord, ElectrolytePcSaftParameters, ElectrolytePcSaftRecord,
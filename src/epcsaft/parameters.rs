@@ -1,8 +1,11 @@
 use crate::association::{
     AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
 };
+use crate::binary_interaction::{KijTemperatureDependence, TemperatureDependentKij};
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
-use feos_core::parameter::{FromSegments, Parameter, ParameterError, PureRecord};
+use feos_core::parameter::{
+    FitBounds, FitRecord, FromSegments, Parameter, ParameterError, PureRecord,
+};
 use ndarray::{Array, Array1, Array2};
 use num_dual::DualNum;
 use num_traits::Zero;
@@ -11,6 +14,7 @@ use std::collections::HashMap;
 use std::fmt::Write;
 use std::sync::Arc;
 
+use crate::epcsaft::eos::dispersion::T_REF;
 use crate::epcsaft::eos::permittivity::PermittivityRecord;
 
 /// ePC-SAFT pure-component parameters.
@@ -154,6 +158,52 @@ impl ElectrolytePcSaftRecord {
     }
 }
 
+impl FitRecord for ElectrolytePcSaftRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["m", "sigma", "epsilon_k"];
+        if self.association_record.is_some() {
+            names.extend(["kappa_ab", "epsilon_k_ab"]);
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association_record.map(|r| r.parameters);
+        match name {
+            "m" => Some(self.m),
+            "sigma" => Some(self.sigma),
+            "epsilon_k" => Some(self.epsilon_k),
+            "kappa_ab" => association.map(|a| a.kappa_ab),
+            "epsilon_k_ab" => association.map(|a| a.epsilon_k_ab),
+            _ => None,
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association_record.as_mut().map(|r| &mut r.parameters);
+        let parameter = match name {
+            "m" => Some(&mut self.m),
+            "sigma" => Some(&mut self.sigma),
+            "epsilon_k" => Some(&mut self.epsilon_k),
+            "kappa_ab" => association.map(|a| &mut a.kappa_ab),
+            "epsilon_k_ab" => association.map(|a| &mut a.epsilon_k_ab),
+            _ => None,
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "epsilon_k" => FitBounds::positive(100.0),
+            "kappa_ab" => FitBounds::positive(0.01),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            _ => FitBounds::positive(1.0),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Default)]
 pub struct ElectrolytePcSaftAssociationRecord {
     /// Association volume parameter
@@ -262,6 +312,69 @@ impl std::fmt::Display for ElectrolytePcSaftBinaryRecord {
     }
 }
 
+/// The coefficients of the temperature-dependent $k_{ij}$ are
+/// available as `k_ij_0` to `k_ij_3`.
+impl FitRecord for ElectrolytePcSaftBinaryRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["k_ij_0", "k_ij_1", "k_ij_2", "k_ij_3"];
+        if let Some(association) = self.association {
+            if association.parameters.kappa_ab.is_some() {
+                names.push("kappa_ab");
+            }
+            if association.parameters.epsilon_k_ab.is_some() {
+                names.push("epsilon_k_ab");
+            }
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association.map(|r| r.parameters);
+        match name {
+            "k_ij_0" | "k_ij_1" | "k_ij_2" | "k_ij_3" => {
+                let k = name[5..].parse::<usize>().unwrap();
+                Some(self.k_ij.get(k).copied().unwrap_or_default())
+            }
+            "kappa_ab" => association.and_then(|a| a.kappa_ab),
+            "epsilon_k_ab" => association.and_then(|a| a.epsilon_k_ab),
+            _ => None,
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association.as_mut().map(|r| &mut r.parameters);
+        let parameter = match name {
+            "k_ij_0" | "k_ij_1" | "k_ij_2" | "k_ij_3" => {
+                let k = name[5..].parse::<usize>().unwrap();
+                if self.k_ij.len() <= k {
+                    self.k_ij.resize(k + 1, 0.0);
+                }
+                Some(&mut self.k_ij[k])
+            }
+            "kappa_ab" => association.and_then(|a| a.kappa_ab.as_mut()),
+            "epsilon_k_ab" => association.and_then(|a| a.epsilon_k_ab.as_mut()),
+            _ => None,
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "kappa_ab" => FitBounds::positive(0.01),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            // the temperature coefficients are scaled like the polynomial k_ij(T)
+            "k_ij_1" | "k_ij_2" | "k_ij_3" => KijTemperatureDependence::Polynomial {
+                coefficients: self.k_ij.get(1..).unwrap_or_default().to_vec(),
+                reference_temperature: T_REF,
+            }
+            .bounds(name),
+            _ => FitBounds::new(f64::NEG_INFINITY, f64::INFINITY, 0.01),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Default)]
 pub struct ElectrolytePcSaftBinaryAssociationRecord {
     /// Cross-association association volume parameter.
@@ -292,6 +405,10 @@ pub struct ElectrolytePcSaftParameters {
     pub k_ij: Array2<Vec<f64>>,
     pub sigma_ij: Array2<f64>,
     pub e_k_ij: Array2<f64>,
+    /// energy parameter matrix including the constant part of k_ij
+    pub epsilon_k_ij: Array2<f64>,
+    /// temperature dependence of the binary interaction parameters
+    pub k_ij_t: TemperatureDependentKij,
     pub nionic: usize,
     pub nsolvent: usize,
     pub water_sigma_t_comp: Option<usize>,
@@ -431,6 +548,20 @@ impl Parameter for ElectrolytePcSaftParameters {
             }
         }
 
+        // the coefficients k_ij_1 to k_ij_3 form a polynomial in T - T_REF
+        let epsilon_k_ij =
+            Array2::from_shape_fn((n, n), |(i, j)| (1.0 - k_ij[[i, j]][0]) * e_k_ij[[i, j]]);
+        let k_ij_poly = k_ij.map(|k| {
+            k[1..]
+                .iter()
+                .any(|k| !k.is_zero())
+                .then(|| KijTemperatureDependence::Polynomial {
+                    coefficients: k[1..].to_vec(),
+                    reference_temperature: T_REF,
+                })
+        });
+        let k_ij_t = TemperatureDependentKij::new(Some(&k_ij_poly), |k| k.as_ref());
+
         // Permittivity records
         let mut permittivity_records: Array1<Option<PermittivityRecord>> = pure_records
             .iter()
@@ -521,6 +652,8 @@ impl Parameter for ElectrolytePcSaftParameters {
             k_ij,
             sigma_ij,
             e_k_ij,
+            epsilon_k_ij,
+            k_ij_t,
             nionic,
             nsolvent,
             ionic_comp,
@@ -0,0 +1,299 @@
+use super::{DataSet, EstimatorError};
+use feos_core::si::{
+    MolarWeight, Pressure, SpecificAmount, SpecificVolume, Temperature, GRAM, MILLI, MOL,
+};
+use feos_core::{Components, EosResult, Residual, StateHD};
+use feos_dft::adsorption::{
+    Adsorption1D, FluidParameters, Pore1D, PoreProfile1D, PoreSpecification,
+};
+use feos_dft::{DFTSolver, HelmholtzEnergyFunctional, DFT};
+use ndarray::{Array1, ScalarOperand};
+use num_dual::DualNum;
+use std::sync::Arc;
+
+/// A Helmholtz energy functional together with the pore model of an adsorbent.
+///
+/// Using an `Adsorbent` as model in an [Estimator](super::Estimator) allows to
+/// fit the parameters of the external potential, the pore size and the
+/// specific pore volume together with the parameters of the functional.
+/// All bulk properties are calculated from the functional, so bulk data
+/// sets can be combined with [AdsorptionIsotherm]s.
+pub struct Adsorbent<F> {
+    /// Helmholtz energy functional of the fluid.
+    pub functional: Arc<DFT<F>>,
+    /// Geometry, size and external potential of the pore.
+    pub pore: Pore1D,
+    /// Pore volume per mass of adsorbent.
+    pub specific_pore_volume: SpecificVolume,
+}
+
+impl<F> Adsorbent<F> {
+    pub fn new(
+        functional: Arc<DFT<F>>,
+        pore: Pore1D,
+        specific_pore_volume: SpecificVolume,
+    ) -> Self {
+        Self {
+            functional,
+            pore,
+            specific_pore_volume,
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> Components for Adsorbent<F> {
+    fn components(&self) -> usize {
+        self.functional.components()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        let pore = Pore1D::new(
+            self.pore.geometry,
+            self.pore.pore_size,
+            self.pore.potential.clone(),
+            self.pore.n_grid,
+            self.pore.potential_cutoff,
+        );
+        Self::new(
+            Arc::new(self.functional.subset(component_list)),
+            pore,
+            self.specific_pore_volume,
+        )
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> Residual for Adsorbent<F> {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.functional.compute_max_density(moles)
+    }
+
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.functional.molar_weight()
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        self.functional
+            .residual_helmholtz_energy_contributions(state)
+    }
+}
+
+/// Branch of an isotherm that is compared to the experimental data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum IsothermBranch {
+    /// Adsorption branch starting from an empty pore.
+    Adsorption,
+    /// Desorption branch starting from a filled pore.
+    Desorption,
+    /// Thermodynamically stable branch.
+    Equilibrium,
+}
+
+/// Amount adsorbed that is compared to the experimental data.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum AdsorbedAmount {
+    /// Total amount of fluid in the pore.
+    Absolute,
+    /// Amount of fluid in the pore in excess of the bulk density.
+    Excess,
+}
+
+/// Store experimental data of an adsorption isotherm.
+///
+/// The isotherm is calculated for an [Adsorbent] at the experimental
+/// temperature and pressures, which have to be strictly ascending.
+/// Along the isotherm, the converged density profile of every pressure is
+/// used as initial guess for the next one.
+///
+/// The specific amount adsorbed, i.e., the amount adsorbed per mass of
+/// adsorbent, is the amount adsorbed per pore volume multiplied with the
+/// specific pore volume of the adsorbent. The pore volume is determined with
+/// helium at 298 K as reference.
+///
+/// If the calculation of a data point fails, its prediction is set to `NAN`.
+#[derive(Clone)]
+pub struct AdsorptionIsotherm {
+    pub target: Array1<f64>,
+    /// unit of the specific amount adsorbed
+    unit: SpecificAmount,
+    /// temperature of the isotherm
+    temperature: Temperature,
+    /// pressure
+    pressure: Pressure<Array1<f64>>,
+    /// composition of the bulk phase
+    molefracs: Option<Array1<f64>>,
+    /// absolute or excess amount adsorbed
+    adsorbed_amount: AdsorbedAmount,
+    /// branch of the isotherm
+    branch: IsothermBranch,
+    /// solver for the DFT calculations
+    solver: Option<DFTSolver>,
+}
+
+impl AdsorptionIsotherm {
+    /// Create a new data set for an experimental adsorption isotherm.
+    ///
+    /// Returns an error if there are no data points, if the pressures are not
+    /// strictly ascending or if the number of pressures does not match the
+    /// number of amounts adsorbed.
+    pub fn new(
+        target: SpecificAmount<Array1<f64>>,
+        temperature: Temperature,
+        pressure: Pressure<Array1<f64>>,
+        molefracs: Option<Array1<f64>>,
+        adsorbed_amount: AdsorbedAmount,
+        branch: IsothermBranch,
+        solver: Option<DFTSolver>,
+    ) -> Result<Self, EstimatorError> {
+        if pressure.is_empty() {
+            return Err(EstimatorError::InvalidData(
+                "An adsorption isotherm requires at least one data point.".into(),
+            ));
+        }
+        if target.len() != pressure.len() {
+            return Err(EstimatorError::IncompatibleInput);
+        }
+        if (1..pressure.len()).any(|i| pressure.get(i) <= pressure.get(i - 1)) {
+            return Err(EstimatorError::InvalidData(
+                "The pressures of an adsorption isotherm have to be strictly ascending.".into(),
+            ));
+        }
+        let unit = MILLI * MOL / GRAM;
+        Ok(Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            pressure,
+            molefracs,
+            adsorbed_amount,
+            branch,
+            solver,
+        })
+    }
+
+    /// Returns the temperature of the isotherm.
+    pub fn temperature(&self) -> Temperature {
+        self.temperature
+    }
+
+    /// Returns the pressure of the data points.
+    pub fn pressure(&self) -> &Pressure<Array1<f64>> {
+        &self.pressure
+    }
+
+    fn pressure_range(&self, start: usize, end: usize) -> Pressure<Array1<f64>> {
+        Pressure::from_shape_fn(end - start, |i| self.pressure.get(start + i))
+    }
+
+    /// Calculate the density profiles for all data points.
+    fn profiles<F: HelmholtzEnergyFunctional + FluidParameters>(
+        &self,
+        adsorbent: &Adsorbent<F>,
+    ) -> EosResult<Vec<EosResult<PoreProfile1D<F>>>> {
+        let functional = &adsorbent.functional;
+        let pore = &adsorbent.pore;
+        let x = self.molefracs.as_ref();
+        let solver = self.solver.as_ref();
+        let t = self.temperature;
+        let adsorption =
+            |p: &Pressure<Array1<f64>>| -> EosResult<Vec<EosResult<PoreProfile1D<F>>>> {
+                if p.is_empty() {
+                    return Ok(Vec::new());
+                }
+                Ok(Adsorption1D::adsorption_isotherm(functional, t, p, pore, x, solver)?.profiles)
+            };
+        let desorption =
+            |p: &Pressure<Array1<f64>>| -> EosResult<Vec<EosResult<PoreProfile1D<F>>>> {
+                if p.is_empty() {
+                    return Ok(Vec::new());
+                }
+                Ok(Adsorption1D::desorption_isotherm(functional, t, p, pore, x, solver)?.profiles)
+            };
+        match self.branch {
+            IsothermBranch::Adsorption => adsorption(&self.pressure),
+            IsothermBranch::Desorption => desorption(&self.pressure),
+            IsothermBranch::Equilibrium => {
+                let n = self.pressure.len();
+                let equilibrium = Adsorption1D::phase_equilibrium(
+                    functional,
+                    t,
+                    self.pressure.get(0),
+                    self.pressure.get(n - 1),
+                    pore,
+                    x,
+                    solver,
+                    Default::default(),
+                );
+                match equilibrium {
+                    // the empty pore is stable below and the filled pore
+                    // above the equilibrium pressure
+                    Ok(equilibrium) => {
+                        let p_eq = equilibrium.pressure().get(0);
+                        let k = self.pressure.into_iter().filter(|&p| p <= p_eq).count();
+                        let mut profiles = adsorption(&self.pressure_range(0, k))?;
+                        profiles.extend(desorption(&self.pressure_range(k, n))?);
+                        Ok(profiles)
+                    }
+                    // without a phase transition in the pressure range, the
+                    // branch with the lower grand potential is stable
+                    Err(_) => {
+                        let omega = |profile: &EosResult<PoreProfile1D<F>>| match profile {
+                            Ok(p) => p.grand_potential.unwrap().to_reduced(),
+                            Err(_) => f64::NAN,
+                        };
+                        Ok(adsorption(&self.pressure)?
+                            .into_iter()
+                            .zip(desorption(&self.pressure)?)
+                            .map(|(a, d)| {
+                                if omega(&d).is_nan() || omega(&a) < omega(&d) {
+                                    a
+                                } else {
+                                    d
+                                }
+                            })
+                            .collect())
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional + FluidParameters> DataSet<Adsorbent<F>> for AdsorptionIsotherm {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        match self.adsorbed_amount {
+            AdsorbedAmount::Absolute => "absolute adsorption",
+            AdsorbedAmount::Excess => "excess adsorption",
+        }
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<Adsorbent<F>>) -> Result<Array1<f64>, EstimatorError> {
+        let pore_volume = eos.pore.pore_volume()?;
+        let Ok(profiles) = self.profiles(eos) else {
+            return Ok(Array1::from_elem(self.target.len(), f64::NAN));
+        };
+        Ok(profiles
+            .iter()
+            .map(|profile| match profile {
+                Ok(p) => {
+                    let mut n = p.profile.total_moles();
+                    if self.adsorbed_amount == AdsorbedAmount::Excess {
+                        n -= p.profile.bulk.density * pore_volume;
+                    }
+                    (n / pore_volume * eos.specific_pore_volume / self.unit).into_value()
+                }
+                Err(_) => f64::NAN,
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,72 @@
+use super::{DataSet, EstimatorError};
+use feos_core::si::{MolarEnergy, Temperature, JOULE, KILO, MOL};
+use feos_core::{PhaseEquilibrium, Residual, SolverOptions};
+use ndarray::Array1;
+use std::sync::Arc;
+
+/// Store experimental data of the enthalpy of vaporization of pure substances.
+///
+/// The enthalpy of vaporization is the difference of the residual enthalpies of
+/// the coexisting phases, so no ideal gas model is required.
+#[derive(Clone)]
+pub struct EnthalpyOfVaporization {
+    pub target: Array1<f64>,
+    /// unit of the enthalpy of vaporization
+    unit: MolarEnergy,
+    /// temperature
+    temperature: Temperature<Array1<f64>>,
+    /// options for VLE solver
+    solver_options: SolverOptions,
+}
+
+impl EnthalpyOfVaporization {
+    /// Create a new data set for experimental enthalpies of vaporization.
+    pub fn new(
+        target: MolarEnergy<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        vle_options: Option<SolverOptions>,
+    ) -> Self {
+        let unit = KILO * JOULE / MOL;
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            solver_options: vle_options.unwrap_or_default(),
+        }
+    }
+
+    /// Returns temperature of data points.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+}
+
+impl<E: Residual> DataSet<E> for EnthalpyOfVaporization {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "enthalpy of vaporization"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        Ok(self
+            .temperature
+            .into_iter()
+            .map(|t| {
+                if let Ok(vle) = PhaseEquilibrium::pure(eos, t, None, self.solver_options) {
+                    let h_vap = vle.vapor().residual_molar_enthalpy()
+                        - vle.liquid().residual_molar_enthalpy();
+                    (h_vap / self.unit).into_value()
+                } else {
+                    f64::NAN
+                }
+            })
+            .collect())
+    }
+}
@@ -52,6 +52,27 @@ impl<E: Residual> Estimator<E> {
         Ok(concatenate(Axis(0), &aview)?)
     }
 
+    /// Weighted residuals of all data points together with the loss
+    /// functions that are applied to them.
+    ///
+    /// The cost of every data point as returned by [Estimator::cost]
+    /// is $\sqrt{f^2\rho\left(\frac{r^2}{f^2}\right)}$ with the weighted residual $r$.
+    pub(crate) fn weighted_residuals(
+        &self,
+        eos: &Arc<E>,
+    ) -> Result<(Array1<f64>, Vec<Loss>), EstimatorError> {
+        let w_sum = self.weights.iter().sum::<f64>();
+        let mut residuals = Vec::new();
+        let mut losses = Vec::new();
+        for ((d, w), loss) in self.data.iter().zip(&self.weights).zip(&self.losses) {
+            let rd = d.relative_difference(eos)?;
+            let factor = w / w_sum / rd.len() as f64;
+            residuals.extend(rd.iter().map(|r| r * factor));
+            losses.resize(losses.len() + rd.len(), loss.scale(factor));
+        }
+        Ok((Array1::from_vec(residuals), losses))
+    }
+
     /// Returns the properties as computed by the equation of state for each `DataSet`.
     pub fn predict(&self, eos: &Arc<E>) -> Result<Vec<Array1<f64>>, EstimatorError> {
         self.data.iter().map(|d| d.predict(eos)).collect()
@@ -0,0 +1,160 @@
+//! Simultaneous regression of group contribution parameters to data of many molecules.
+use super::least_squares::{box_bounds, levenberg_marquardt, residuals_and_jacobian};
+use super::{
+    Estimator, EstimatorError, LeastSquaresOptions, LeastSquaresResult, Loss, ParameterUncertainty,
+};
+use feos_core::Residual;
+use ndarray::{Array1, Array2};
+use std::sync::Arc;
+
+/// A collection of molecules, each with an [Estimator] containing its data sets.
+///
+/// The model of every molecule is constructed from its chemical record and
+/// the same parameter vector, e.g., using a
+/// [SegmentVector](feos_core::parameter::SegmentVector). The cost function
+/// is the combination of the cost functions of all molecules, where the
+/// weights of the molecules are normalized.
+pub struct GcEstimator<C, E: Residual> {
+    molecules: Vec<(C, Estimator<E>)>,
+    weights: Vec<f64>,
+}
+
+impl<C, E: Residual> Default for GcEstimator<C, E> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<C, E: Residual> GcEstimator<C, E> {
+    /// Create a new `GcEstimator` without molecules.
+    pub fn new() -> Self {
+        Self {
+            molecules: Vec::new(),
+            weights: Vec::new(),
+        }
+    }
+
+    /// Add a molecule given by its chemical record, its data sets and its weight.
+    pub fn add_molecule(&mut self, chemical_record: C, estimator: Estimator<E>, weight: f64) {
+        self.molecules.push((chemical_record, estimator));
+        self.weights.push(weight);
+    }
+
+    /// Returns the chemical records and estimators of all molecules.
+    pub fn molecules(&self) -> &[(C, Estimator<E>)] {
+        &self.molecules
+    }
+
+    /// Weighted residuals of the data points of all molecules together
+    /// with the loss functions that are applied to them.
+    fn weighted_residuals<F>(
+        &self,
+        model: &F,
+        parameters: &Array1<f64>,
+    ) -> Result<(Array1<f64>, Vec<Loss>), EstimatorError>
+    where
+        F: Fn(&C, &Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let w_sum = self.weights.iter().sum::<f64>();
+        let mut residuals = Vec::new();
+        let mut losses = Vec::new();
+        for ((cr, estimator), w) in self.molecules.iter().zip(&self.weights) {
+            let (r, l) = estimator.weighted_residuals(&model(cr, parameters)?)?;
+            let factor = w / w_sum;
+            residuals.extend(r.iter().map(|r| r * factor));
+            losses.extend(l.iter().map(|l| l.scale(factor)));
+        }
+        Ok((Array1::from_vec(residuals), losses))
+    }
+
+    /// Returns the cost of each `DataSet` for every molecule.
+    pub fn cost<F>(
+        &self,
+        model: F,
+        parameters: &Array1<f64>,
+    ) -> Result<Vec<Array1<f64>>, EstimatorError>
+    where
+        F: Fn(&C, &Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let w_sum = self.weights.iter().sum::<f64>();
+        self.molecules
+            .iter()
+            .zip(&self.weights)
+            .map(|((cr, estimator), w)| {
+                let eos = model(cr, parameters)?;
+                Ok(estimator.cost(&eos)? * (w / w_sum))
+            })
+            .collect()
+    }
+
+    /// Returns the mean absolute relative difference of each `DataSet` for every molecule.
+    pub fn mean_absolute_relative_difference<F>(
+        &self,
+        model: F,
+        parameters: &Array1<f64>,
+    ) -> Result<Vec<Array1<f64>>, EstimatorError>
+    where
+        F: Fn(&C, &Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        self.molecules
+            .iter()
+            .map(|(cr, estimator)| {
+                estimator.mean_absolute_relative_difference(&model(cr, parameters)?)
+            })
+            .collect()
+    }
+
+    /// Minimize the combined cost function of all molecules with a bounded
+    /// Levenberg-Marquardt algorithm.
+    ///
+    /// The equation of state of every molecule is generated from its chemical
+    /// record and the parameter vector by `model`. See [Estimator::least_squares]
+    /// for details on the algorithm.
+    pub fn least_squares<F>(
+        &self,
+        model: F,
+        initial_parameters: &Array1<f64>,
+        bounds: Option<(&Array1<f64>, &Array1<f64>)>,
+        options: LeastSquaresOptions,
+    ) -> Result<LeastSquaresResult, EstimatorError>
+    where
+        F: Fn(&C, &Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let (lower, upper) = box_bounds(initial_parameters.len(), bounds)?;
+        levenberg_marquardt(
+            |x| self.weighted_residuals(&model, x),
+            initial_parameters,
+            &lower,
+            &upper,
+            options,
+        )
+    }
+
+    /// Jacobian of the weighted residuals of all data points with respect to the
+    /// parameters, approximated by forward differences.
+    pub fn jacobian<F>(
+        &self,
+        model: F,
+        parameters: &Array1<f64>,
+    ) -> Result<Array2<f64>, EstimatorError>
+    where
+        F: Fn(&C, &Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let residuals = |x: &Array1<f64>| self.weighted_residuals(&model, x);
+        Ok(residuals_and_jacobian(residuals, parameters)?.1)
+    }
+
+    /// Covariance of the parameters that minimize the combined cost function.
+    pub fn uncertainty<F>(
+        &self,
+        model: F,
+        parameters: &Array1<f64>,
+    ) -> Result<ParameterUncertainty, EstimatorError>
+    where
+        F: Fn(&C, &Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let residuals = |x: &Array1<f64>| self.weighted_residuals(&model, x);
+        let (residuals, jacobian) = residuals_and_jacobian(residuals, parameters)?;
+        ParameterUncertainty::new(parameters, &residuals, &jacobian)
+    }
+}
@@ -0,0 +1,140 @@
+use super::{DataSet, EstimatorError, Phase};
+use feos_core::si::{MolarEntropy, Moles, Pressure, Temperature, JOULE, KELVIN, MOL};
+use feos_core::{Contributions, DensityInitialization, IdealGas, Residual, State};
+use itertools::izip;
+use ndarray::{arr1, Array1};
+use std::sync::Arc;
+
+/// Store experimental data of the molar isobaric heat capacity.
+#[derive(Clone)]
+pub struct IsobaricHeatCapacity {
+    pub target: Array1<f64>,
+    unit: MolarEntropy,
+    temperature: Temperature<Array1<f64>>,
+    pressure: Pressure<Array1<f64>>,
+    initial_density: Vec<DensityInitialization>,
+}
+
+impl IsobaricHeatCapacity {
+    /// Create a new data set for experimental isobaric heat capacities.
+    pub fn new(
+        target: MolarEntropy<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        phase: Option<&Vec<Phase>>,
+    ) -> Self {
+        let n = temperature.len();
+        let unit = JOULE / MOL / KELVIN;
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            pressure,
+            initial_density: phase.map_or(vec![DensityInitialization::None; n], |phase| {
+                phase.iter().map(|&p| p.into()).collect()
+            }),
+        }
+    }
+
+    /// Return temperature.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+
+    /// Return pressure.
+    pub fn pressure(&self) -> &Pressure<Array1<f64>> {
+        &self.pressure
+    }
+}
+
+impl<E: Residual + IdealGas> DataSet<E> for IsobaricHeatCapacity {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "isobaric heat capacity"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        let moles = Moles::from_reduced(arr1(&[1.0]));
+        izip!(&self.temperature, &self.pressure, &self.initial_density)
+            .map(|(t, p, &initial_density)| {
+                let state = State::new_npt(eos, t, p, &moles, initial_density)?;
+                let c_p = state.molar_isobaric_heat_capacity(Contributions::Total);
+                Ok((c_p / self.unit).into_value())
+            })
+            .collect()
+    }
+}
+
+/// Store experimental data of the molar isochoric heat capacity.
+#[derive(Clone)]
+pub struct IsochoricHeatCapacity {
+    pub target: Array1<f64>,
+    unit: MolarEntropy,
+    temperature: Temperature<Array1<f64>>,
+    pressure: Pressure<Array1<f64>>,
+    initial_density: Vec<DensityInitialization>,
+}
+
+impl IsochoricHeatCapacity {
+    /// Create a new data set for experimental isochoric heat capacities.
+    pub fn new(
+        target: MolarEntropy<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        phase: Option<&Vec<Phase>>,
+    ) -> Self {
+        let n = temperature.len();
+        let unit = JOULE / MOL / KELVIN;
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            pressure,
+            initial_density: phase.map_or(vec![DensityInitialization::None; n], |phase| {
+                phase.iter().map(|&p| p.into()).collect()
+            }),
+        }
+    }
+
+    /// Return temperature.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+
+    /// Return pressure.
+    pub fn pressure(&self) -> &Pressure<Array1<f64>> {
+        &self.pressure
+    }
+}
+
+impl<E: Residual + IdealGas> DataSet<E> for IsochoricHeatCapacity {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "isochoric heat capacity"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        let moles = Moles::from_reduced(arr1(&[1.0]));
+        izip!(&self.temperature, &self.pressure, &self.initial_density)
+            .map(|(t, p, &initial_density)| {
+                let state = State::new_npt(eos, t, p, &moles, initial_density)?;
+                let c_v = state.molar_isochoric_heat_capacity(Contributions::Total);
+                Ok((c_v / self.unit).into_value())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,275 @@
+//! Reader for experimental data in CSV files.
+use super::{column_to_si, dimension, ExperimentalData, Property};
+use crate::estimator::{EstimatorError, Phase};
+use feos_core::si::{KELVIN, PASCAL};
+use ndarray::Array2;
+use std::collections::BTreeMap;
+use std::path::Path;
+
+/// Name, phase and unit of a column.
+struct Header {
+    name: String,
+    phase: Option<Phase>,
+    unit: String,
+}
+
+impl Header {
+    /// Parse a header of the form `name (phase) [unit]` where phase and unit are optional.
+    fn parse(cell: &str) -> Result<Self, EstimatorError> {
+        let invalid = || EstimatorError::InvalidData(format!("Invalid column header '{cell}'."));
+        let cell = cell.trim();
+        let (rest, unit) = match cell.split_once('[') {
+            Some((rest, unit)) => (rest, unit.strip_suffix(']').ok_or_else(invalid)?),
+            None => (cell, ""),
+        };
+        let (name, phase) = match rest.split_once('(') {
+            Some((name, phase)) => {
+                let phase = phase.trim().strip_suffix(')').ok_or_else(invalid)?;
+                (name, Some(phase.parse()?))
+            }
+            None => (rest, None),
+        };
+        Ok(Self {
+            name: name.trim().to_lowercase(),
+            phase,
+            unit: unit.trim().into(),
+        })
+    }
+
+    /// Phase label and (1-based) component index of composition columns like `x_1` or `y_2`.
+    fn composition(&self) -> Option<(&str, usize)> {
+        let (label, index) = self.name.split_once('_')?;
+        let index = index.parse().ok().filter(|&i| i > 0)?;
+        ["x", "y", "x1", "x2"]
+            .contains(&label)
+            .then_some((label, index))
+    }
+}
+
+impl ExperimentalData {
+    /// Read experimental data of a single property from a CSV file.
+    ///
+    /// The first line that is not empty or a comment (starting with `#`)
+    /// is the header. Every column is named by `name (phase) [unit]`,
+    /// where the phase and, for dimensionless quantities, the unit are
+    /// optional. The following columns are recognized:
+    /// - `temperature` and `pressure`,
+    /// - `phase` with the entries `liquid` or `vapor` for every data point,
+    /// - the mole fractions `x_1`, `x_2`, ... of the liquid and `y_1`, `y_2`, ...
+    ///   of the vapor phase for VLE data or `x1_1`, ... and `x2_1`, ... of both
+    ///   liquid phases for LLE data,
+    /// - a single property, e.g., `vapor pressure [kPa]` or
+    ///   `speed of sound (liquid) [m/s]`.
+    ///
+    /// A phase given in the header of the property applies to all data points.
+    pub fn from_csv<P: AsRef<Path>>(file: P) -> Result<Self, EstimatorError> {
+        Self::parse_csv(&std::fs::read_to_string(file)?)
+    }
+
+    fn parse_csv(content: &str) -> Result<Self, EstimatorError> {
+        let mut lines = content
+            .lines()
+            .map(str::trim)
+            .filter(|l| !l.is_empty() && !l.starts_with('#'));
+        let headers = lines
+            .next()
+            .ok_or_else(|| EstimatorError::InvalidData("The file is empty.".into()))?
+            .split(',')
+            .map(Header::parse)
+            .collect::<Result<Vec<_>, _>>()?;
+        let mut columns = vec![Vec::new(); headers.len()];
+        for line in lines {
+            let cells: Vec<_> = line.split(',').map(str::trim).collect();
+            if cells.len() != headers.len() {
+                return Err(EstimatorError::InvalidData(format!(
+                    "The line '{line}' has {} entries, expected {}.",
+                    cells.len(),
+                    headers.len()
+                )));
+            }
+            columns.iter_mut().zip(cells).for_each(|(c, v)| c.push(v));
+        }
+        let n = columns.first().map_or(0, |c| c.len());
+        let values = |column: &[&str]| -> Result<Vec<f64>, EstimatorError> {
+            Ok(column.iter().map(|v| v.parse()).collect::<Result<_, _>>()?)
+        };
+
+        let mut temperature = None;
+        let mut pressure = None;
+        let mut phase = None;
+        let mut property = None;
+        let mut compositions: BTreeMap<&str, BTreeMap<usize, Vec<f64>>> = BTreeMap::new();
+        for (header, column) in headers.iter().zip(&columns) {
+            match header.name.as_str() {
+                "temperature" => {
+                    temperature = Some(column_to_si(
+                        &values(column)?,
+                        &header.unit,
+                        dimension(KELVIN),
+                        "temperature",
+                    )?)
+                }
+                "pressure" => {
+                    pressure = Some(column_to_si(
+                        &values(column)?,
+                        &header.unit,
+                        dimension(PASCAL),
+                        "pressure",
+                    )?)
+                }
+                "phase" => {
+                    phase = Some(
+                        column
+                            .iter()
+                            .map(|p| p.parse())
+                            .collect::<Result<Vec<Phase>, _>>()?,
+                    )
+                }
+                name => {
+                    if let Some((label, index)) = header.composition() {
+                        compositions
+                            .entry(label)
+                            .or_default()
+                            .insert(index, values(column)?);
+                        continue;
+                    }
+                    let p: Property = name.parse()?;
+                    if property.is_some() {
+                        return Err(EstimatorError::InvalidData(
+                            "A CSV file can only contain a single property.".into(),
+                        ));
+                    }
+                    let target = column_to_si(&values(column)?, &header.unit, p.dimension(), name)?;
+                    property = Some((p, target, header.phase));
+                }
+            }
+        }
+        let temperature =
+            temperature.ok_or_else(|| EstimatorError::MissingColumn("temperature".into()))?;
+
+        let (property, target, molefracs) = match (property, compositions.is_empty()) {
+            (Some((property, target, header_phase)), true) => {
+                if let (None, Some(p)) = (&phase, header_phase) {
+                    phase = Some(vec![p; n]);
+                }
+                (property, target, None)
+            }
+            (None, false) => {
+                let labels: Vec<_> = compositions.keys().copied().collect();
+                let property = match labels.as_slice() {
+                    ["x", "y"] => Property::Vle,
+                    ["x1", "x2"] => Property::Lle,
+                    _ => {
+                        return Err(EstimatorError::InvalidData(format!(
+                            "Invalid combination of compositions {labels:?}."
+                        )))
+                    }
+                };
+                let x1 = molefracs(labels[0], &compositions[labels[0]], n)?;
+                let x2 = molefracs(labels[1], &compositions[labels[1]], n)?;
+                (property, Default::default(), Some([x1, x2]))
+            }
+            (None, true) => return Err(EstimatorError::MissingColumn("property".into())),
+            (Some(_), false) => {
+                return Err(EstimatorError::InvalidData(
+                    "A CSV file can contain either a property or compositions.".into(),
+                ))
+            }
+        };
+        Self::new(property, target, temperature, pressure, phase, molefracs)
+    }
+}
+
+/// Collect the mole fractions of all components into an array with one row per data point.
+fn molefracs(
+    label: &str,
+    columns: &BTreeMap<usize, Vec<f64>>,
+    n: usize,
+) -> Result<Array2<f64>, EstimatorError> {
+    let components = columns.len();
+    if columns.keys().copied().ne(1..=components) {
+        return Err(EstimatorError::MissingColumn(format!(
+            "{label}_{}",
+            (1..=components).find(|i| !columns.contains_key(i)).unwrap()
+        )));
+    }
+    Ok(Array2::from_shape_fn((n, components), |(i, j)| {
+        columns[&(j + 1)][i]
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    #[test]
+    fn test_parse_csv() -> Result<(), EstimatorError> {
+        let csv = "# speed of sound of propane
+            temperature [°C], pressure [MPa], speed of sound (liquid) [km/s]
+            26.85, 5.0, 0.7
+            46.85, 5.0, 0.6";
+        let data = ExperimentalData::parse_csv(csv)?;
+        assert_eq!(data.property(), Property::SpeedOfSound);
+        assert_eq!(data.datapoints(), 2);
+        assert_relative_eq!(
+            data.temperature,
+            arr1(&[300.0, 320.0]),
+            max_relative = 1e-12
+        );
+        assert_relative_eq!(data.pressure.unwrap(), arr1(&[5e6, 5e6]));
+        assert_relative_eq!(data.target, arr1(&[700.0, 600.0]), max_relative = 1e-12);
+        assert!(matches!(
+            data.phase.unwrap()[..],
+            [Phase::Liquid, Phase::Liquid]
+        ));
+
+        let csv = "temperature [K], pressure [bar], x_1, x_2, y_2, y_1
+            300, 5, 0.4, 0.6, 0.2, 0.8";
+        let data = ExperimentalData::parse_csv(csv)?;
+        assert_eq!(data.property(), Property::Vle);
+        let [x, y] = data.molefracs().unwrap();
+        assert_eq!(x.row(0).to_vec(), vec![0.4, 0.6]);
+        assert_eq!(y.row(0).to_vec(), vec![0.8, 0.2]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_csv_errors() {
+        let errors = [
+            (
+                "temperature [K], vapor pressure [m]\n300, 1",
+                "not a valid unit",
+            ),
+            (
+                "temperature [K], vapor pressure [kPascal]\n300, 1",
+                "kPascal",
+            ),
+            (
+                "temperature [K], pressure [bar], speed of sound [m/s]\n300, 1",
+                "2 entries",
+            ),
+            (
+                "pressure [bar], vapor pressure [bar]\n1, 1",
+                "'temperature'",
+            ),
+            (
+                "temperature [K], pressure [bar], liquid density [kg/m3]\n300, 1, a",
+                "float",
+            ),
+            (
+                "temperature [K], pressure [bar], x_1, y_2\n300, 1, 1, 1",
+                "'y_1'",
+            ),
+            (
+                "temperature [K], refractive index\n300, 1",
+                "refractive index",
+            ),
+        ];
+        for (csv, message) in errors {
+            let error = ExperimentalData::parse_csv(csv).unwrap_err().to_string();
+            assert!(error.contains(message), "{error}");
+        }
+    }
+}
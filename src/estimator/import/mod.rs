@@ -0,0 +1,405 @@
+//! Import of experimental data from CSV and ThermoML files.
+//!
+//! Data files are read into [ExperimentalData] which can be
+//! converted into the matching [DataSet].
+use super::{
+    DataSet, Diffusion, EnthalpyOfVaporization, EquilibriumLiquidDensity, EstimatorError,
+    IsobaricHeatCapacity, IsochoricHeatCapacity, JouleThomsonCoefficient, LiquidDensity,
+    MulticomponentChemicalPotential, Phase, SecondVirialCoefficient, SpeedOfSound,
+    ThermalConductivity, VaporPressure, Viscosity,
+};
+#[cfg(feature = "dft")]
+use super::{SurfaceTension, SurfaceTensionMethod};
+use feos_core::si::*;
+use feos_core::{EntropyScaling, IdealGas, Residual};
+#[cfg(feature = "dft")]
+use feos_dft::{HelmholtzEnergyFunctional, DFT};
+use ndarray::{Array1, Array2};
+use std::fmt;
+use std::str::FromStr;
+use std::sync::Arc;
+use typenum::{P2, P3};
+
+mod csv;
+mod thermoml;
+mod unit;
+use unit::{dimension, Dimension, Unit};
+
+/// Properties that can be imported from data files.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Property {
+    VaporPressure,
+    LiquidDensity,
+    EquilibriumLiquidDensity,
+    EnthalpyOfVaporization,
+    SecondVirialCoefficient,
+    SpeedOfSound,
+    IsobaricHeatCapacity,
+    IsochoricHeatCapacity,
+    JouleThomsonCoefficient,
+    Viscosity,
+    ThermalConductivity,
+    Diffusion,
+    SurfaceTension,
+    /// Compositions of coexisting liquid and vapor phases.
+    Vle,
+    /// Compositions of coexisting liquid phases.
+    Lle,
+}
+
+impl Property {
+    const ALL: [Self; 15] = [
+        Self::VaporPressure,
+        Self::LiquidDensity,
+        Self::EquilibriumLiquidDensity,
+        Self::EnthalpyOfVaporization,
+        Self::SecondVirialCoefficient,
+        Self::SpeedOfSound,
+        Self::IsobaricHeatCapacity,
+        Self::IsochoricHeatCapacity,
+        Self::JouleThomsonCoefficient,
+        Self::Viscosity,
+        Self::ThermalConductivity,
+        Self::Diffusion,
+        Self::SurfaceTension,
+        Self::Vle,
+        Self::Lle,
+    ];
+
+    fn name(&self) -> &'static str {
+        match self {
+            Self::VaporPressure => "vapor pressure",
+            Self::LiquidDensity => "liquid density",
+            Self::EquilibriumLiquidDensity => "equilibrium liquid density",
+            Self::EnthalpyOfVaporization => "enthalpy of vaporization",
+            Self::SecondVirialCoefficient => "second virial coefficient",
+            Self::SpeedOfSound => "speed of sound",
+            Self::IsobaricHeatCapacity => "isobaric heat capacity",
+            Self::IsochoricHeatCapacity => "isochoric heat capacity",
+            Self::JouleThomsonCoefficient => "joule thomson coefficient",
+            Self::Viscosity => "viscosity",
+            Self::ThermalConductivity => "thermal conductivity",
+            Self::Diffusion => "diffusion coefficient",
+            Self::SurfaceTension => "surface tension",
+            Self::Vle => "vle",
+            Self::Lle => "lle",
+        }
+    }
+
+    /// Dimension of the target quantity.
+    fn dimension(&self) -> Dimension {
+        match self {
+            Self::VaporPressure => dimension(PASCAL),
+            Self::LiquidDensity | Self::EquilibriumLiquidDensity => {
+                dimension(KILOGRAM / METER.powi::<P3>())
+            }
+            Self::EnthalpyOfVaporization => dimension(JOULE / MOL),
+            Self::SecondVirialCoefficient => dimension(METER.powi::<P3>() / MOL),
+            Self::SpeedOfSound => dimension(METER / SECOND),
+            Self::IsobaricHeatCapacity | Self::IsochoricHeatCapacity => {
+                dimension(JOULE / MOL / KELVIN)
+            }
+            Self::JouleThomsonCoefficient => dimension(KELVIN / PASCAL),
+            Self::Viscosity => dimension(PASCAL * SECOND),
+            Self::ThermalConductivity => dimension(WATT / METER / KELVIN),
+            Self::Diffusion => dimension(METER.powi::<P2>() / SECOND),
+            Self::SurfaceTension => dimension(NEWTON / METER),
+            Self::Vle | Self::Lle => [0; 7],
+        }
+    }
+
+    /// Whether the data set needs the pressure as input.
+    fn requires_pressure(&self) -> bool {
+        !matches!(
+            self,
+            Self::VaporPressure
+                | Self::EquilibriumLiquidDensity
+                | Self::EnthalpyOfVaporization
+                | Self::SecondVirialCoefficient
+                | Self::SurfaceTension
+        )
+    }
+}
+
+impl fmt::Display for Property {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Property {
+    type Err = EstimatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let name = s.trim().to_lowercase().replace(['-', '_'], " ");
+        Self::ALL
+            .into_iter()
+            .find(|p| p.name() == name)
+            .ok_or_else(|| EstimatorError::UnknownProperty(s.into()))
+    }
+}
+
+impl FromStr for Phase {
+    type Err = EstimatorError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_lowercase().as_str() {
+            "liquid" | "l" => Ok(Self::Liquid),
+            "vapor" | "vapour" | "gas" | "v" | "g" => Ok(Self::Vapor),
+            _ => Err(EstimatorError::InvalidData(format!("Unknown phase '{s}'."))),
+        }
+    }
+}
+
+/// Experimental data of a single property read from a data file.
+///
+/// All quantities are stored in SI units. Use [ExperimentalData::data_set]
+/// (or [ExperimentalData::data_set_caloric] and
+/// [ExperimentalData::data_set_entropy_scaling] for properties that require
+/// an ideal gas model or entropy scaling) to create the corresponding [DataSet].
+#[derive(Clone, Debug)]
+pub struct ExperimentalData {
+    property: Property,
+    target: Array1<f64>,
+    temperature: Array1<f64>,
+    pressure: Option<Array1<f64>>,
+    phase: Option<Vec<Phase>>,
+    molefracs: Option<[Array2<f64>; 2]>,
+}
+
+impl ExperimentalData {
+    fn new(
+        property: Property,
+        target: Array1<f64>,
+        temperature: Array1<f64>,
+        pressure: Option<Array1<f64>>,
+        phase: Option<Vec<Phase>>,
+        molefracs: Option<[Array2<f64>; 2]>,
+    ) -> Result<Self, EstimatorError> {
+        if property.requires_pressure() && pressure.is_none() {
+            return Err(EstimatorError::MissingColumn("pressure".into()));
+        }
+        if matches!(property, Property::Vle | Property::Lle) && molefracs.is_none() {
+            return Err(EstimatorError::MissingColumn("molefracs".into()));
+        }
+        let n = temperature.len();
+        let mut lengths = vec![
+            pressure.as_ref().map(|p| p.len()),
+            phase.as_ref().map(|p| p.len()),
+        ];
+        if let Some([x1, x2]) = &molefracs {
+            if x1.ncols() != x2.ncols() {
+                return Err(EstimatorError::IncompatibleInput);
+            }
+            lengths.extend([Some(x1.nrows()), Some(x2.nrows())]);
+        } else {
+            lengths.push(Some(target.len()));
+        }
+        if lengths.into_iter().flatten().any(|l| l != n) {
+            return Err(EstimatorError::IncompatibleInput);
+        }
+        Ok(Self {
+            property,
+            target,
+            temperature,
+            pressure,
+            phase,
+            molefracs,
+        })
+    }
+
+    /// The property of the experimental data.
+    pub fn property(&self) -> Property {
+        self.property
+    }
+
+    /// Number of experimental data points.
+    pub fn datapoints(&self) -> usize {
+        self.temperature.len()
+    }
+
+    /// Temperature of the experimental data points.
+    pub fn temperature(&self) -> Temperature<Array1<f64>> {
+        &self.temperature * KELVIN
+    }
+
+    /// Pressure of the experimental data points.
+    pub fn pressure(&self) -> Option<Pressure<Array1<f64>>> {
+        self.pressure.as_ref().map(|p| p * PASCAL)
+    }
+
+    /// Phases of the experimental data points.
+    pub fn phase(&self) -> Option<&Vec<Phase>> {
+        self.phase.as_ref()
+    }
+
+    /// Compositions of both phases of VLE or LLE data
+    /// with one row per data point.
+    pub fn molefracs(&self) -> Option<&[Array2<f64>; 2]> {
+        self.molefracs.as_ref()
+    }
+
+    fn pressure_or_err(&self) -> Result<Pressure<Array1<f64>>, EstimatorError> {
+        self.pressure()
+            .ok_or_else(|| EstimatorError::MissingColumn("pressure".into()))
+    }
+
+    fn incompatible_model(&self, model: &str) -> EstimatorError {
+        EstimatorError::IncompatibleModel(self.property.to_string(), model.into())
+    }
+
+    /// Create the [DataSet] for the experimental data.
+    ///
+    /// VLE and LLE data are converted into a [MulticomponentChemicalPotential].
+    pub fn data_set<E: Residual>(&self) -> Result<Arc<dyn DataSet<E>>, EstimatorError> {
+        let t = self.temperature();
+        Ok(match self.property {
+            Property::VaporPressure => Arc::new(VaporPressure::new(
+                &self.target * PASCAL,
+                t,
+                true,
+                None,
+                None,
+            )),
+            Property::LiquidDensity => Arc::new(LiquidDensity::new(
+                &self.target * (KILOGRAM / METER.powi::<P3>()),
+                t,
+                self.pressure_or_err()?,
+            )),
+            Property::EquilibriumLiquidDensity => Arc::new(EquilibriumLiquidDensity::new(
+                &self.target * (KILOGRAM / METER.powi::<P3>()),
+                t,
+                None,
+            )),
+            Property::EnthalpyOfVaporization => Arc::new(EnthalpyOfVaporization::new(
+                &self.target * (JOULE / MOL),
+                t,
+                None,
+            )),
+            Property::SecondVirialCoefficient => Arc::new(SecondVirialCoefficient::new(
+                &self.target * (METER.powi::<P3>() / MOL),
+                t,
+            )),
+            Property::Vle | Property::Lle => {
+                let [x1, x2] = self.molefracs.clone().unwrap();
+                let p = self.pressure_or_err()?;
+                Arc::new(if self.property == Property::Vle {
+                    MulticomponentChemicalPotential::new_vle(t, p, x1, x2)?
+                } else {
+                    MulticomponentChemicalPotential::new_lle(t, p, x1, x2)?
+                })
+            }
+            Property::SpeedOfSound
+            | Property::IsobaricHeatCapacity
+            | Property::IsochoricHeatCapacity
+            | Property::JouleThomsonCoefficient => return Err(self.incompatible_model("IdealGas")),
+            Property::Viscosity | Property::ThermalConductivity | Property::Diffusion => {
+                return Err(self.incompatible_model("EntropyScaling"))
+            }
+            Property::SurfaceTension => {
+                return Err(self.incompatible_model("HelmholtzEnergyFunctional"))
+            }
+        })
+    }
+
+    /// Create the [DataSet] for the experimental data including
+    /// properties that require an ideal gas model.
+    pub fn data_set_caloric<E: Residual + IdealGas>(
+        &self,
+    ) -> Result<Arc<dyn DataSet<E>>, EstimatorError> {
+        let t = self.temperature();
+        let phase = self.phase.as_ref();
+        Ok(match self.property {
+            Property::SpeedOfSound => Arc::new(SpeedOfSound::new(
+                &self.target * (METER / SECOND),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            Property::IsobaricHeatCapacity => Arc::new(IsobaricHeatCapacity::new(
+                &self.target * (JOULE / MOL / KELVIN),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            Property::IsochoricHeatCapacity => Arc::new(IsochoricHeatCapacity::new(
+                &self.target * (JOULE / MOL / KELVIN),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            Property::JouleThomsonCoefficient => Arc::new(JouleThomsonCoefficient::new(
+                &self.target * (KELVIN / PASCAL),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            _ => return self.data_set(),
+        })
+    }
+
+    /// Create the [DataSet] for the experimental data including
+    /// transport properties that require entropy scaling.
+    pub fn data_set_entropy_scaling<E: Residual + EntropyScaling>(
+        &self,
+    ) -> Result<Arc<dyn DataSet<E>>, EstimatorError> {
+        let t = self.temperature();
+        let phase = self.phase.as_ref();
+        Ok(match self.property {
+            Property::Viscosity => Arc::new(Viscosity::new(
+                &self.target * (PASCAL * SECOND),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            Property::ThermalConductivity => Arc::new(ThermalConductivity::new(
+                &self.target * (WATT / METER / KELVIN),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            Property::Diffusion => Arc::new(Diffusion::new(
+                &self.target * (METER.powi::<P2>() / SECOND),
+                t,
+                self.pressure_or_err()?,
+                phase,
+            )),
+            _ => return self.data_set(),
+        })
+    }
+
+    /// Create the [DataSet] for the experimental data including
+    /// surface tensions that require a Helmholtz energy functional.
+    #[cfg(feature = "dft")]
+    pub fn data_set_dft<F: HelmholtzEnergyFunctional>(
+        &self,
+        method: SurfaceTensionMethod,
+    ) -> Result<Arc<dyn DataSet<DFT<F>>>, EstimatorError> {
+        Ok(match self.property {
+            Property::SurfaceTension => Arc::new(SurfaceTension::new(
+                &self.target * (NEWTON / METER),
+                self.temperature(),
+                method,
+                None,
+                None,
+                None,
+            )),
+            _ => return self.data_set(),
+        })
+    }
+}
+
+/// Convert the values of a column given in `unit` to SI units
+/// after checking the dimension of the unit.
+fn column_to_si(
+    values: &[f64],
+    unit: &str,
+    expected: Dimension,
+    name: &str,
+) -> Result<Array1<f64>, EstimatorError> {
+    let unit_parsed = Unit::parse(unit)?;
+    if unit_parsed.dimension != expected {
+        return Err(EstimatorError::WrongUnits(name.into(), unit.into()));
+    }
+    Ok(values.iter().map(|&v| unit_parsed.to_si(v)).collect())
+}
@@ -0,0 +1,485 @@
+//! Reader for experimental data in ThermoML files.
+//!
+//! Only the parts of the ThermoML schema that are required to
+//! construct data sets are considered.
+use super::{column_to_si, dimension, ExperimentalData, Property};
+use crate::estimator::{EstimatorError, Phase};
+use feos_core::si::{KELVIN, PASCAL};
+use ndarray::{Array1, Array2};
+use roxmltree::{Document, Node};
+use std::collections::HashMap;
+use std::path::Path;
+use std::str::FromStr;
+
+/// Quantities of ThermoML variables, properties and constraints.
+#[derive(Clone, Copy, Debug, PartialEq)]
+enum Kind {
+    Temperature,
+    Pressure,
+    MoleFraction(usize, Phase),
+    Property(Property, Option<Phase>),
+}
+
+/// Values of a quantity for all data points of a `PureOrMixtureData` element.
+struct Column<'a> {
+    kind: Kind,
+    name: &'a str,
+    unit: &'a str,
+    values: Vec<Option<f64>>,
+}
+
+impl Column<'_> {
+    /// Values of the selected rows converted to SI units.
+    fn to_si(&self, rows: &[usize], dimension: [i8; 7]) -> Result<Array1<f64>, EstimatorError> {
+        let values: Vec<_> = rows.iter().map(|&i| self.values[i].unwrap()).collect();
+        column_to_si(&values, self.unit, dimension, self.name)
+    }
+}
+
+fn children<'a, 'i: 'a>(node: Node<'a, 'i>, name: &'a str) -> impl Iterator<Item = Node<'a, 'i>> {
+    node.children()
+        .filter(move |n| n.is_element() && n.tag_name().name() == name)
+}
+
+fn text<'a>(node: Node<'a, '_>, name: &str) -> Option<&'a str> {
+    node.descendants()
+        .find(|n| n.tag_name().name() == name)
+        .and_then(|n| n.text())
+        .map(str::trim)
+}
+
+fn number<T: FromStr>(node: Node, name: &str) -> Result<T, EstimatorError> {
+    text(node, name)
+        .and_then(|t| t.parse().ok())
+        .ok_or_else(|| {
+            EstimatorError::InvalidData(format!(
+                "Missing or invalid element '{name}' in '{}'.",
+                node.tag_name().name()
+            ))
+        })
+}
+
+/// Split a ThermoML quantity like `Temperature, K` into name and unit.
+fn name_and_unit(text: &str) -> (&str, &str) {
+    text.rsplit_once(", ").unwrap_or((text, ""))
+}
+
+fn phase(text: Option<&str>) -> Option<Phase> {
+    text.and_then(|t| t.parse().ok())
+}
+
+/// The supported pure component properties.
+fn property(name: &str) -> Option<Property> {
+    Some(match name {
+        "Vapor or sublimation pressure" => Property::VaporPressure,
+        "Mass density" => Property::LiquidDensity,
+        "Molar enthalpy of vaporization or sublimation" => Property::EnthalpyOfVaporization,
+        "Second virial coefficient" => Property::SecondVirialCoefficient,
+        "Speed of sound" => Property::SpeedOfSound,
+        "Molar heat capacity at constant pressure" => Property::IsobaricHeatCapacity,
+        "Molar heat capacity at constant volume" => Property::IsochoricHeatCapacity,
+        "Joule-Thomson coefficient" => Property::JouleThomsonCoefficient,
+        "Viscosity" => Property::Viscosity,
+        "Thermal conductivity" => Property::ThermalConductivity,
+        "Self-diffusion coefficient" => Property::Diffusion,
+        "Surface tension liquid-gas" => Property::SurfaceTension,
+        _ => return None,
+    })
+}
+
+/// Classify a variable, property or constraint by the name
+/// of its type element and its text.
+fn quantity(
+    element: &str,
+    text: &str,
+    phase: Option<Phase>,
+    component: Option<usize>,
+    mixture: bool,
+) -> Option<Kind> {
+    let (name, _) = name_and_unit(text);
+    match (element, name) {
+        ("eTemperature", _) => Some(Kind::Temperature),
+        ("ePressure", _) => Some(Kind::Pressure),
+        (_, "Mole fraction") => Some(Kind::MoleFraction(component?, phase?)),
+        (_, "Vapor or sublimation pressure") if mixture => Some(Kind::Pressure),
+        (_, name) if !mixture => property(name).map(|p| Kind::Property(p, phase)),
+        _ => None,
+    }
+}
+
+impl ExperimentalData {
+    /// Read all supported experimental data from a ThermoML file.
+    ///
+    /// For pure components, the data of every supported property is
+    /// returned separately. Mass densities of liquid phases in equilibrium
+    /// with a gas phase are read as [Property::EquilibriumLiquidDensity].
+    /// For mixtures, the compositions of coexisting liquid and gas phases
+    /// are read as [Property::Vle]. Other properties and phases, as well as
+    /// data that require a pressure that is not given, are skipped.
+    pub fn from_thermoml<P: AsRef<Path>>(file: P) -> Result<Vec<Self>, EstimatorError> {
+        Self::parse_thermoml(&std::fs::read_to_string(file)?)
+    }
+
+    fn parse_thermoml(content: &str) -> Result<Vec<Self>, EstimatorError> {
+        let document = Document::parse(content)?;
+        let mut data = Vec::new();
+        for node in document
+            .descendants()
+            .filter(|n| n.tag_name().name() == "PureOrMixtureData")
+        {
+            data.extend(parse_pure_or_mixture_data(node)?);
+        }
+        Ok(data)
+    }
+}
+
+fn parse_pure_or_mixture_data<'a, 'i: 'a>(
+    node: Node<'a, 'i>,
+) -> Result<Vec<ExperimentalData>, EstimatorError> {
+    let components: Vec<usize> = children(node, "Component")
+        .map(|c| number(c, "nOrgNum"))
+        .collect::<Result<_, _>>()?;
+    let component_index = |n: Node| -> Result<usize, EstimatorError> {
+        let org_num: usize = number(n, "nOrgNum")?;
+        components
+            .iter()
+            .position(|&c| c == org_num)
+            .ok_or_else(|| {
+                EstimatorError::InvalidData(format!("Unknown compound with nOrgNum {org_num}."))
+            })
+    };
+    let mixture = components.len() > 1;
+    let phases: Vec<_> = children(node, "PhaseID")
+        .filter_map(|p| phase(text(p, "ePhase")))
+        .collect();
+
+    // data points
+    let mut variable_values = Vec::new();
+    let mut property_values = Vec::new();
+    for row in children(node, "NumValues") {
+        let mut variables = HashMap::new();
+        for v in children(row, "VariableValue") {
+            variables.insert(number::<usize>(v, "nVarNumber")?, number(v, "nVarValue")?);
+        }
+        variable_values.push(variables);
+        let mut properties = HashMap::new();
+        for p in children(row, "PropertyValue") {
+            properties.insert(number::<usize>(p, "nPropNumber")?, number(p, "nPropValue")?);
+        }
+        property_values.push(properties);
+    }
+    let rows = variable_values.len();
+
+    // variables, properties and constraints
+    let mut columns = Vec::new();
+    let kind = |element: Node<'a, 'i>,
+                phase: Option<Phase>,
+                component: Option<Node>|
+     -> Result<Option<(Kind, &'a str)>, EstimatorError> {
+        let text = element.text().unwrap_or_default().trim();
+        let component = component.map(component_index).transpose()?;
+        let kind = quantity(element.tag_name().name(), text, phase, component, mixture);
+        Ok(kind.map(|k| (k, text)))
+    };
+    for v in children(node, "Variable") {
+        let n: usize = number(v, "nVarNumber")?;
+        let Some(var_type) = children(v, "VariableID")
+            .flat_map(|id| children(id, "VariableType"))
+            .find_map(|t| t.first_element_child())
+        else {
+            continue;
+        };
+        let component = children(v, "VariableID").find_map(|id| children(id, "RegNum").next());
+        if let Some((kind, text)) = kind(var_type, phase(text(v, "eVarPhase")), component)? {
+            let values = variable_values.iter().map(|r| r.get(&n).copied()).collect();
+            columns.push((kind, text, values));
+        }
+    }
+    for p in children(node, "Property") {
+        let n: usize = number(p, "nPropNumber")?;
+        let Some(prop_name) = p.descendants().find(|d| d.tag_name().name() == "ePropName") else {
+            continue;
+        };
+        let component =
+            children(p, "Property-MethodID").find_map(|id| children(id, "RegNum").next());
+        if let Some((kind, text)) = kind(prop_name, phase(text(p, "ePropPhase")), component)? {
+            let values = property_values.iter().map(|r| r.get(&n).copied()).collect();
+            columns.push((kind, text, values));
+        }
+    }
+    for c in children(node, "Constraint") {
+        let value: f64 = number(c, "nConstraintValue")?;
+        let Some(constraint_type) = children(c, "ConstraintID")
+            .flat_map(|id| children(id, "ConstraintType"))
+            .find_map(|t| t.first_element_child())
+        else {
+            continue;
+        };
+        let component = children(c, "ConstraintID").find_map(|id| children(id, "RegNum").next());
+        if let Some((kind, text)) =
+            kind(constraint_type, phase(text(c, "eConstrPhase")), component)?
+        {
+            columns.push((kind, text, vec![Some(value); rows]));
+        }
+    }
+    let columns: Vec<_> = columns
+        .into_iter()
+        .map(|(kind, text, values)| {
+            let (name, unit) = name_and_unit(text);
+            Column {
+                kind,
+                name,
+                unit,
+                values,
+            }
+        })
+        .collect();
+
+    let temperature = columns.iter().find(|c| c.kind == Kind::Temperature);
+    let pressure = columns.iter().find(|c| c.kind == Kind::Pressure);
+    let available =
+        |column: Option<&Column>, i: usize| column.is_some_and(|c| c.values[i].is_some());
+    if mixture {
+        return vle(&columns, components.len(), temperature, pressure);
+    }
+
+    let mut data = Vec::new();
+    for column in &columns {
+        let Kind::Property(mut property, phase) = column.kind else {
+            continue;
+        };
+        let temperature =
+            temperature.ok_or_else(|| EstimatorError::MissingColumn("temperature".into()))?;
+        if property == Property::LiquidDensity {
+            if phase != Some(Phase::Liquid) {
+                continue;
+            }
+            if pressure.is_none() && phases.contains(&Phase::Vapor) {
+                property = Property::EquilibriumLiquidDensity;
+            }
+        }
+        // data sets that require a pressure cannot be created without it
+        let pressure = match (property.requires_pressure(), pressure) {
+            (false, _) => None,
+            (true, Some(pressure)) => Some(pressure),
+            (true, None) => continue,
+        };
+        let rows: Vec<_> = (0..rows)
+            .filter(|&i| {
+                column.values[i].is_some()
+                    && available(Some(temperature), i)
+                    && (pressure.is_none() || available(pressure, i))
+            })
+            .collect();
+        if rows.is_empty() {
+            continue;
+        }
+        data.push(ExperimentalData::new(
+            property,
+            column.to_si(&rows, property.dimension())?,
+            temperature.to_si(&rows, dimension(KELVIN))?,
+            pressure
+                .map(|p| p.to_si(&rows, dimension(PASCAL)))
+                .transpose()?,
+            phase.map(|p| vec![p; rows.len()]),
+            None,
+        )?);
+    }
+    Ok(data)
+}
+
+/// Compositions of coexisting liquid and gas phases of mixtures.
+///
+/// The mole fraction of at most one component per phase may be missing
+/// and is calculated from the sum of the others.
+fn vle(
+    columns: &[Column],
+    components: usize,
+    temperature: Option<&Column>,
+    pressure: Option<&Column>,
+) -> Result<Vec<ExperimentalData>, EstimatorError> {
+    let molefracs = |phase: Phase| -> Vec<Option<&Column>> {
+        (0..components)
+            .map(|i| {
+                columns
+                    .iter()
+                    .find(|c| c.kind == Kind::MoleFraction(i, phase))
+            })
+            .collect()
+    };
+    let (liquid, vapor) = (molefracs(Phase::Liquid), molefracs(Phase::Vapor));
+    if [&liquid, &vapor]
+        .iter()
+        .any(|x| x.iter().filter(|c| c.is_some()).count() + 1 < components)
+    {
+        return Ok(vec![]);
+    }
+    let temperature =
+        temperature.ok_or_else(|| EstimatorError::MissingColumn("temperature".into()))?;
+    let Some(pressure) = pressure else {
+        return Ok(vec![]);
+    };
+
+    // mole fractions of a phase in a data point with at most one missing value
+    let composition = |x: &[Option<&Column>], i: usize| -> Option<Vec<f64>> {
+        let values: Vec<_> = x.iter().map(|c| c.and_then(|c| c.values[i])).collect();
+        let missing = values.iter().filter(|v| v.is_none()).count();
+        let sum: f64 = values.iter().flatten().sum();
+        match missing {
+            0 => Some(values.into_iter().flatten().collect()),
+            1 => Some(values.into_iter().map(|v| v.unwrap_or(1.0 - sum)).collect()),
+            _ => None,
+        }
+    };
+    let mut rows = Vec::new();
+    let mut x = Vec::new();
+    let mut y = Vec::new();
+    for i in 0..temperature.values.len() {
+        if let (Some(_), Some(_), Some(xi), Some(yi)) = (
+            temperature.values[i],
+            pressure.values[i],
+            composition(&liquid, i),
+            composition(&vapor, i),
+        ) {
+            rows.push(i);
+            x.extend(xi);
+            y.extend(yi);
+        }
+    }
+    if rows.is_empty() {
+        return Ok(vec![]);
+    }
+    let n = rows.len();
+    Ok(vec![ExperimentalData::new(
+        Property::Vle,
+        Array1::zeros(0),
+        temperature.to_si(&rows, dimension(KELVIN))?,
+        Some(pressure.to_si(&rows, dimension(PASCAL))?),
+        None,
+        Some([
+            Array2::from_shape_vec((n, components), x)?,
+            Array2::from_shape_vec((n, components), y)?,
+        ]),
+    )?])
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    const THERMOML: &str = r#"<?xml version="1.0" encoding="utf-8"?>
+<DataReport xmlns="http://www.iupac.org/namespaces/ThermoML">
+  <Compound><RegNum><nOrgNum>1</nOrgNum></RegNum><sCommonName>propane</sCommonName></Compound>
+  <Compound><RegNum><nOrgNum>2</nOrgNum></RegNum><sCommonName>butane</sCommonName></Compound>
+  <PureOrMixtureData>
+    <nPureOrMixtureDataNumber>1</nPureOrMixtureDataNumber>
+    <Component><RegNum><nOrgNum>1</nOrgNum></RegNum></Component>
+    <PhaseID><ePhase>Liquid</ePhase></PhaseID>
+    <PhaseID><ePhase>Gas</ePhase></PhaseID>
+    <Property>
+      <nPropNumber>1</nPropNumber>
+      <Property-MethodID><PropertyGroup><VaporPBoilingTAzeotropTandP>
+        <ePropName>Vapor or sublimation pressure, kPa</ePropName>
+      </VaporPBoilingTAzeotropTandP></PropertyGroup></Property-MethodID>
+      <PropPhaseID><ePropPhase>Liquid</ePropPhase></PropPhaseID>
+    </Property>
+    <Property>
+      <nPropNumber>2</nPropNumber>
+      <Property-MethodID><PropertyGroup><VolumetricProp>
+        <ePropName>Mass density, kg/m3</ePropName>
+      </VolumetricProp></PropertyGroup></Property-MethodID>
+      <PropPhaseID><ePropPhase>Liquid</ePropPhase></PropPhaseID>
+    </Property>
+    <Variable>
+      <nVarNumber>1</nVarNumber>
+      <VariableID><VariableType><eTemperature>Temperature, K</eTemperature></VariableType></VariableID>
+      <VarPhaseID><eVarPhase>Liquid</eVarPhase></VarPhaseID>
+    </Variable>
+    <NumValues>
+      <VariableValue><nVarNumber>1</nVarNumber><nVarValue>250.0</nVarValue></VariableValue>
+      <PropertyValue><nPropNumber>1</nPropNumber><nPropValue>217.6</nPropValue></PropertyValue>
+      <PropertyValue><nPropNumber>2</nPropNumber><nPropValue>559.6</nPropValue></PropertyValue>
+    </NumValues>
+    <NumValues>
+      <VariableValue><nVarNumber>1</nVarNumber><nVarValue>300.0</nVarValue></VariableValue>
+      <PropertyValue><nPropNumber>1</nPropNumber><nPropValue>997.5</nPropValue></PropertyValue>
+    </NumValues>
+  </PureOrMixtureData>
+  <PureOrMixtureData>
+    <nPureOrMixtureDataNumber>2</nPureOrMixtureDataNumber>
+    <Component><RegNum><nOrgNum>1</nOrgNum></RegNum></Component>
+    <Component><RegNum><nOrgNum>2</nOrgNum></RegNum></Component>
+    <PhaseID><ePhase>Liquid</ePhase></PhaseID>
+    <PhaseID><ePhase>Gas</ePhase></PhaseID>
+    <Property>
+      <nPropNumber>1</nPropNumber>
+      <Property-MethodID><PropertyGroup><VaporPBoilingTAzeotropTandP>
+        <ePropName>Vapor or sublimation pressure, MPa</ePropName>
+      </VaporPBoilingTAzeotropTandP></PropertyGroup></Property-MethodID>
+      <PropPhaseID><ePropPhase>Liquid</ePropPhase></PropPhaseID>
+    </Property>
+    <Constraint>
+      <ConstraintID><ConstraintType><eTemperature>Temperature, K</eTemperature></ConstraintType></ConstraintID>
+      <nConstraintValue>300</nConstraintValue>
+    </Constraint>
+    <Variable>
+      <nVarNumber>1</nVarNumber>
+      <VariableID><RegNum><nOrgNum>1</nOrgNum></RegNum><VariableType><eComponentComposition>Mole fraction</eComponentComposition></VariableType></VariableID>
+      <VarPhaseID><eVarPhase>Liquid</eVarPhase></VarPhaseID>
+    </Variable>
+    <Variable>
+      <nVarNumber>2</nVarNumber>
+      <VariableID><RegNum><nOrgNum>1</nOrgNum></RegNum><VariableType><eComponentComposition>Mole fraction</eComponentComposition></VariableType></VariableID>
+      <VarPhaseID><eVarPhase>Gas</eVarPhase></VarPhaseID>
+    </Variable>
+    <NumValues>
+      <VariableValue><nVarNumber>1</nVarNumber><nVarValue>0.5</nVarValue></VariableValue>
+      <VariableValue><nVarNumber>2</nVarNumber><nVarValue>0.75</nVarValue></VariableValue>
+      <PropertyValue><nPropNumber>1</nPropNumber><nPropValue>0.62</nPropValue></PropertyValue>
+    </NumValues>
+  </PureOrMixtureData>
+</DataReport>"#;
+
+    #[test]
+    fn test_parse_thermoml() -> Result<(), EstimatorError> {
+        let data = ExperimentalData::parse_thermoml(THERMOML)?;
+        assert_eq!(data.len(), 3);
+
+        assert_eq!(data[0].property(), Property::VaporPressure);
+        assert_relative_eq!(data[0].temperature, arr1(&[250.0, 300.0]));
+        assert_relative_eq!(
+            data[0].target,
+            arr1(&[217.6e3, 997.5e3]),
+            max_relative = 1e-12
+        );
+
+        assert_eq!(data[1].property(), Property::EquilibriumLiquidDensity);
+        assert_eq!(data[1].datapoints(), 1);
+        assert_relative_eq!(data[1].target, arr1(&[559.6]));
+
+        assert_eq!(data[2].property(), Property::Vle);
+        assert_relative_eq!(data[2].temperature, arr1(&[300.0]));
+        assert_relative_eq!(data[2].pressure.clone().unwrap(), arr1(&[0.62e6]));
+        let [x, y] = data[2].molefracs().unwrap();
+        assert_relative_eq!(x.row(0), arr1(&[0.5, 0.5]));
+        assert_relative_eq!(y.row(0), arr1(&[0.75, 0.25]), max_relative = 1e-12);
+
+        // data sets without the required pressure are skipped
+        let no_pressure = THERMOML
+            .replacen("<PhaseID><ePhase>Gas</ePhase></PhaseID>", "", 1)
+            .replace("Vapor or sublimation pressure, MPa", "Speed of sound, m/s");
+        let data = ExperimentalData::parse_thermoml(&no_pressure)?;
+        assert_eq!(data.len(), 1);
+        assert_eq!(data[0].property(), Property::VaporPressure);
+
+        let invalid = THERMOML.replace("kg/m3", "kg/m");
+        let error = ExperimentalData::parse_thermoml(&invalid).unwrap_err();
+        assert_eq!(
+            error.to_string(),
+            "The unit 'kg/m' is not a valid unit for Mass density."
+        );
+        Ok(())
+    }
+}
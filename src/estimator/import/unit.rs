@@ -0,0 +1,275 @@
+//! Parser for units given as strings in data files.
+use super::super::EstimatorError;
+use feos_core::si::{Quantity, SIUnit};
+use std::iter::Peekable;
+use std::str::Chars;
+use typenum::Integer;
+
+/// Exponents of the SI base units in the order s, m, kg, A, K, mol, cd.
+pub(super) type Dimension = [i8; 7];
+
+/// A quantity with units given by the exponents of the SI base units.
+type SIQuantity<T, L, M, I, THETA, N, J> = Quantity<f64, SIUnit<T, L, M, I, THETA, N, J>>;
+
+/// Exponents of the SI base units of a quantity.
+pub(super) fn dimension<
+    T: Integer,
+    L: Integer,
+    M: Integer,
+    I: Integer,
+    THETA: Integer,
+    N: Integer,
+    J: Integer,
+>(
+    _: SIQuantity<T, L, M, I, THETA, N, J>,
+) -> Dimension {
+    [T::I8, L::I8, M::I8, I::I8, THETA::I8, N::I8, J::I8]
+}
+
+const PREFIXES: [(&str, f64); 11] = [
+    ("p", 1e-12),
+    ("n", 1e-9),
+    ("µ", 1e-6),
+    ("u", 1e-6),
+    ("m", 1e-3),
+    ("c", 1e-2),
+    ("d", 1e-1),
+    ("h", 1e2),
+    ("k", 1e3),
+    ("M", 1e6),
+    ("G", 1e9),
+];
+
+const SYMBOLS: [(&str, f64, Dimension); 23] = [
+    ("s", 1.0, [1, 0, 0, 0, 0, 0, 0]),
+    ("min", 60.0, [1, 0, 0, 0, 0, 0, 0]),
+    ("h", 3600.0, [1, 0, 0, 0, 0, 0, 0]),
+    ("m", 1.0, [0, 1, 0, 0, 0, 0, 0]),
+    ("Å", 1e-10, [0, 1, 0, 0, 0, 0, 0]),
+    ("g", 1e-3, [0, 0, 1, 0, 0, 0, 0]),
+    ("A", 1.0, [0, 0, 0, 1, 0, 0, 0]),
+    ("K", 1.0, [0, 0, 0, 0, 1, 0, 0]),
+    ("mol", 1.0, [0, 0, 0, 0, 0, 1, 0]),
+    ("cd", 1.0, [0, 0, 0, 0, 0, 0, 1]),
+    ("l", 1e-3, [0, 3, 0, 0, 0, 0, 0]),
+    ("L", 1e-3, [0, 3, 0, 0, 0, 0, 0]),
+    ("Hz", 1.0, [-1, 0, 0, 0, 0, 0, 0]),
+    ("N", 1.0, [-2, 1, 1, 0, 0, 0, 0]),
+    ("Pa", 1.0, [-2, -1, 1, 0, 0, 0, 0]),
+    ("bar", 1e5, [-2, -1, 1, 0, 0, 0, 0]),
+    ("atm", 101325.0, [-2, -1, 1, 0, 0, 0, 0]),
+    ("Torr", 101325.0 / 760.0, [-2, -1, 1, 0, 0, 0, 0]),
+    ("mmHg", 133.322387415, [-2, -1, 1, 0, 0, 0, 0]),
+    ("J", 1.0, [-2, 2, 1, 0, 0, 0, 0]),
+    ("cal", 4.184, [-2, 2, 1, 0, 0, 0, 0]),
+    ("W", 1.0, [-3, 2, 1, 0, 0, 0, 0]),
+    ("P", 0.1, [-1, -1, 1, 0, 0, 0, 0]),
+];
+
+/// A unit given by its value in SI units and its dimension.
+///
+/// Temperatures in degree Celsius are the only units with an `offset`.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub(super) struct Unit {
+    pub factor: f64,
+    pub offset: f64,
+    pub dimension: Dimension,
+}
+
+impl Unit {
+    const DIMENSIONLESS: Self = Self {
+        factor: 1.0,
+        offset: 0.0,
+        dimension: [0; 7],
+    };
+
+    /// Parse a unit like `kPa`, `kg/m^3`, `J/(mol K)` or `J/K/mol`.
+    ///
+    /// Products are separated by spaces, `*` or `·` and exponents are given
+    /// with or without `^` or as superscripts. Every `/` divides by the
+    /// following factor only.
+    pub fn parse(unit: &str) -> Result<Self, EstimatorError> {
+        let unit = unit.trim();
+        match unit {
+            "" | "1" | "-" => return Ok(Self::DIMENSIONLESS),
+            "°C" | "degC" => {
+                return Ok(Self {
+                    factor: 1.0,
+                    offset: 273.15,
+                    dimension: [0, 0, 0, 0, 1, 0, 0],
+                })
+            }
+            _ => (),
+        }
+        let mut chars = unit.chars().peekable();
+        let result = Self::parse_product(&mut chars, unit)?;
+        match chars.next() {
+            None => Ok(result),
+            Some(_) => Err(EstimatorError::UnknownUnit(unit.into())),
+        }
+    }
+
+    /// Convert values given in this unit to SI units.
+    pub fn to_si(self, value: f64) -> f64 {
+        value * self.factor + self.offset
+    }
+
+    fn powi(self, n: i8) -> Self {
+        Self {
+            factor: self.factor.powi(n as i32),
+            offset: 0.0,
+            dimension: self.dimension.map(|d| d * n),
+        }
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        let mut dimension = self.dimension;
+        dimension
+            .iter_mut()
+            .zip(rhs.dimension)
+            .for_each(|(d, r)| *d += r);
+        Self {
+            factor: self.factor * rhs.factor,
+            offset: 0.0,
+            dimension,
+        }
+    }
+
+    fn parse_product(chars: &mut Peekable<Chars>, unit: &str) -> Result<Self, EstimatorError> {
+        let mut result = Self::parse_factor(chars, unit)?;
+        loop {
+            let mut separated = false;
+            while chars.next_if_eq(&' ').is_some() {
+                separated = true;
+            }
+            match chars.peek() {
+                Some('/') => {
+                    chars.next();
+                    Self::skip_whitespace(chars);
+                    result = result.mul(Self::parse_factor(chars, unit)?.powi(-1));
+                }
+                Some('*' | '·' | '.') => {
+                    chars.next();
+                    Self::skip_whitespace(chars);
+                    result = result.mul(Self::parse_factor(chars, unit)?);
+                }
+                Some(&c) if separated && (c.is_alphabetic() || c == '(') => {
+                    result = result.mul(Self::parse_factor(chars, unit)?);
+                }
+                _ => return Ok(result),
+            }
+        }
+    }
+
+    fn skip_whitespace(chars: &mut Peekable<Chars>) {
+        while chars.next_if_eq(&' ').is_some() {}
+    }
+
+    fn parse_factor(chars: &mut Peekable<Chars>, unit: &str) -> Result<Self, EstimatorError> {
+        let base = if chars.peek() == Some(&'(') {
+            chars.next();
+            let group = Self::parse_product(chars, unit)?;
+            if chars.next() != Some(')') {
+                return Err(EstimatorError::UnknownUnit(unit.into()));
+            }
+            group
+        } else {
+            let mut symbol = String::new();
+            while let Some(&c) = chars.peek() {
+                if !c.is_alphabetic() {
+                    break;
+                }
+                symbol.push(c);
+                chars.next();
+            }
+            Self::parse_symbol(&symbol).ok_or_else(|| EstimatorError::UnknownUnit(unit.into()))?
+        };
+        Ok(base.powi(Self::parse_exponent(chars, unit)?))
+    }
+
+    fn parse_symbol(symbol: &str) -> Option<Self> {
+        let unit = |s: &str| {
+            SYMBOLS
+                .iter()
+                .find(|(name, _, _)| *name == s)
+                .map(|&(_, factor, dimension)| Self {
+                    factor,
+                    offset: 0.0,
+                    dimension,
+                })
+        };
+        unit(symbol).or_else(|| {
+            PREFIXES.iter().find_map(|&(prefix, factor)| {
+                let mut unit = unit(symbol.strip_prefix(prefix)?)?;
+                unit.factor *= factor;
+                Some(unit)
+            })
+        })
+    }
+
+    fn parse_exponent(chars: &mut Peekable<Chars>, unit: &str) -> Result<i8, EstimatorError> {
+        let mut exponent = String::new();
+        if chars.peek() == Some(&'^') {
+            chars.next();
+        }
+        while let Some(&c) = chars.peek() {
+            match c {
+                '-' | '⁻' if exponent.is_empty() => exponent.push('-'),
+                '0'..='9' => exponent.push(c),
+                '¹' => exponent.push('1'),
+                '²' => exponent.push('2'),
+                '³' => exponent.push('3'),
+                _ => break,
+            }
+            chars.next();
+        }
+        match exponent.as_str() {
+            "" => Ok(1),
+            e => e
+                .parse()
+                .map_err(|_| EstimatorError::UnknownUnit(unit.into())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use feos_core::si::*;
+    use typenum::P3;
+
+    #[test]
+    fn test_parse_units() -> Result<(), EstimatorError> {
+        let cases = [
+            ("kPa", 1e3, dimension(PASCAL)),
+            ("MPa", 1e6, dimension(PASCAL)),
+            ("bar", 1e5, dimension(PASCAL)),
+            ("kg/m3", 1.0, dimension(KILOGRAM / METER.powi::<P3>())),
+            ("g cm^-3", 1e3, dimension(KILOGRAM / METER.powi::<P3>())),
+            ("kg/m³", 1.0, dimension(KILOGRAM / METER.powi::<P3>())),
+            ("J/K/mol", 1.0, dimension(JOULE / KELVIN / MOL)),
+            ("J/(mol K)", 1.0, dimension(JOULE / KELVIN / MOL)),
+            ("kJ/mol", 1e3, dimension(JOULE / MOL)),
+            ("cm3/mol", 1e-6, dimension(METER.powi::<P3>() / MOL)),
+            ("m/s", 1.0, dimension(METER / SECOND)),
+            ("mPa*s", 1e-3, dimension(PASCAL * SECOND)),
+            ("cP", 1e-3, dimension(PASCAL * SECOND)),
+            ("W/m/K", 1.0, dimension(WATT / METER / KELVIN)),
+            ("K/MPa", 1e-6, dimension(KELVIN / PASCAL)),
+            ("m2/s", 1.0, dimension(METER * METER / SECOND)),
+            ("mol/dm3", 1e3, dimension(MOL / METER.powi::<P3>())),
+            ("mN/m", 1e-3, dimension(NEWTON / METER)),
+            ("K", 1.0, dimension(KELVIN)),
+        ];
+        for (unit, factor, dimension) in cases {
+            let u = Unit::parse(unit)?;
+            assert_relative_eq!(u.factor, factor, max_relative = 1e-12);
+            assert_eq!(u.dimension, dimension, "{unit}");
+        }
+        assert_relative_eq!(Unit::parse("°C")?.to_si(25.0), 298.15);
+        assert!(Unit::parse("furlong").is_err());
+        assert!(Unit::parse("kg/(m3").is_err());
+        Ok(())
+    }
+}
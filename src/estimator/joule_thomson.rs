@@ -0,0 +1,75 @@
+use super::{DataSet, EstimatorError, Phase};
+use feos_core::si::{Moles, Pressure, Temperature, KELVIN, MEGA, PASCAL};
+use feos_core::{DensityInitialization, IdealGas, Residual, State};
+use itertools::izip;
+use ndarray::{arr1, Array1};
+use std::ops::Div;
+use std::sync::Arc;
+
+type JouleThomsonUnit<T = f64> = <Temperature<T> as Div<Pressure>>::Output;
+
+/// Store experimental data of the Joule-Thomson coefficient.
+#[derive(Clone)]
+pub struct JouleThomsonCoefficient {
+    pub target: Array1<f64>,
+    unit: JouleThomsonUnit,
+    temperature: Temperature<Array1<f64>>,
+    pressure: Pressure<Array1<f64>>,
+    initial_density: Vec<DensityInitialization>,
+}
+
+impl JouleThomsonCoefficient {
+    /// Create a new data set for experimental Joule-Thomson coefficients.
+    pub fn new(
+        target: JouleThomsonUnit<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        phase: Option<&Vec<Phase>>,
+    ) -> Self {
+        let n = temperature.len();
+        let unit = KELVIN / (MEGA * PASCAL);
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            pressure,
+            initial_density: phase.map_or(vec![DensityInitialization::None; n], |phase| {
+                phase.iter().map(|&p| p.into()).collect()
+            }),
+        }
+    }
+
+    /// Return temperature.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+
+    /// Return pressure.
+    pub fn pressure(&self) -> &Pressure<Array1<f64>> {
+        &self.pressure
+    }
+}
+
+impl<E: Residual + IdealGas> DataSet<E> for JouleThomsonCoefficient {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "Joule-Thomson coefficient"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        let moles = Moles::from_reduced(arr1(&[1.0]));
+        izip!(&self.temperature, &self.pressure, &self.initial_density)
+            .map(|(t, p, &initial_density)| {
+                let state = State::new_npt(eos, t, p, &moles, initial_density)?;
+                Ok((state.joule_thomson() / self.unit).into_value())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,474 @@
+//! Bounded nonlinear least-squares optimization of model parameters.
+use super::{Estimator, EstimatorError, Loss};
+use feos_core::{log_iter, log_result, EosError, Residual, Verbosity};
+use ndarray::{Array1, Array2, Axis, Zip};
+use num_dual::linalg::LU;
+use std::fmt;
+use std::sync::Arc;
+
+/// Options for the nonlinear least-squares solver.
+#[derive(Clone, Copy)]
+pub struct LeastSquaresOptions {
+    /// Maximum number of iterations.
+    pub max_iter: usize,
+    /// Tolerance for the relative reduction of the cost function.
+    pub ftol: f64,
+    /// Tolerance for the relative change of the parameters.
+    pub xtol: f64,
+    /// Tolerance for the maximum norm of the projected gradient.
+    pub gtol: f64,
+    /// Relative step size used for the finite difference approximation of the Jacobian.
+    pub diff_step: f64,
+    /// Iteration output indicated by the [Verbosity] enum.
+    pub verbosity: Verbosity,
+}
+
+impl Default for LeastSquaresOptions {
+    fn default() -> Self {
+        Self {
+            max_iter: 100,
+            ftol: 1e-8,
+            xtol: 1e-8,
+            gtol: 1e-8,
+            diff_step: f64::EPSILON.sqrt(),
+            verbosity: Verbosity::None,
+        }
+    }
+}
+
+/// Reason for the termination of the least-squares solver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Termination {
+    /// The projected gradient is smaller than `gtol`.
+    Gtol,
+    /// The relative reduction of the cost function is smaller than `ftol`.
+    Ftol,
+    /// The relative change of the parameters is smaller than `xtol`.
+    Xtol,
+    /// No step that reduces the cost function was found before the
+    /// relative change of the parameters fell below `xtol`.
+    Stagnation,
+    /// The maximum number of iterations is reached.
+    MaxIterations,
+}
+
+impl fmt::Display for Termination {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Gtol => write!(f, "projected gradient below gtol"),
+            Self::Ftol => write!(f, "relative reduction of the cost function below ftol"),
+            Self::Xtol => write!(f, "relative change of the parameters below xtol"),
+            Self::Stagnation => write!(f, "no reduction of the cost function above xtol"),
+            Self::MaxIterations => write!(f, "maximum number of iterations reached"),
+        }
+    }
+}
+
+/// Result of a nonlinear least-squares optimization.
+#[derive(Clone, Debug)]
+pub struct LeastSquaresResult {
+    /// Optimal parameters.
+    pub parameters: Array1<f64>,
+    /// Value of the cost function $\frac{1}{2}\sum_i f_i^2\rho_i\left(\frac{r_i^2}{f_i^2}\right)$ at the optimum.
+    pub cost: f64,
+    /// Weighted residuals $r_i$ before the loss functions are applied.
+    pub residuals: Array1<f64>,
+    /// Jacobian of the weighted residuals with respect to the parameters.
+    pub jacobian: Array2<f64>,
+    /// Maximum norm of the projected gradient at the optimum.
+    pub optimality: f64,
+    /// For every parameter: -1 if it is at its lower bound, 1 if it is at its upper bound, and 0 otherwise.
+    pub active_bounds: Array1<i8>,
+    /// Number of iterations.
+    pub iterations: usize,
+    /// Number of evaluations of the residuals (including those for the Jacobian).
+    pub function_evaluations: usize,
+    /// Number of evaluations of the Jacobian.
+    pub jacobian_evaluations: usize,
+    /// Reason for the termination of the solver.
+    pub termination: Termination,
+}
+
+impl LeastSquaresResult {
+    /// Return `true` if one of the convergence criteria is met.
+    pub fn success(&self) -> bool {
+        !matches!(
+            self.termination,
+            Termination::Stagnation | Termination::MaxIterations
+        )
+    }
+}
+
+impl fmt::Display for LeastSquaresResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "LeastSquaresResult(success={}, cost={:e}, optimality={:e}, iterations={}, function_evaluations={}, termination=\"{}\")",
+            self.success(),
+            self.cost,
+            self.optimality,
+            self.iterations,
+            self.function_evaluations,
+            self.termination
+        )
+    }
+}
+
+impl<E: Residual> Estimator<E> {
+    /// Minimize the cost function with a bounded Levenberg-Marquardt algorithm.
+    ///
+    /// The equation of state is generated from the parameter vector by `model`.
+    /// The loss functions of the data sets are applied as robust loss functions, i.e.,
+    /// the sum of squares of the costs returned by [Estimator::cost] is minimized.
+    /// If `bounds` are provided, every iterate is projected onto the feasible box.
+    ///
+    /// The Jacobian is approximated by forward differences. Failed evaluations of the
+    /// model or data sets during a trial step lead to a rejection of the step.
+    pub fn least_squares<F>(
+        &self,
+        model: F,
+        initial_parameters: &Array1<f64>,
+        bounds: Option<(&Array1<f64>, &Array1<f64>)>,
+        options: LeastSquaresOptions,
+    ) -> Result<LeastSquaresResult, EstimatorError>
+    where
+        F: Fn(&Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let (lower, upper) = box_bounds(initial_parameters.len(), bounds)?;
+        levenberg_marquardt(
+            |x| self.weighted_residuals(&model(x)?),
+            initial_parameters,
+            &lower,
+            &upper,
+            options,
+        )
+    }
+}
+
+/// Lower and upper bounds of `n` parameters, unbounded if `bounds` is `None`.
+pub(super) fn box_bounds(
+    n: usize,
+    bounds: Option<(&Array1<f64>, &Array1<f64>)>,
+) -> Result<(Array1<f64>, Array1<f64>), EstimatorError> {
+    match bounds {
+        Some((lower, upper)) => {
+            if lower.len() != n || upper.len() != n {
+                return Err(EstimatorError::IncompatibleInput);
+            }
+            Ok((lower.clone(), upper.clone()))
+        }
+        None => Ok((
+            Array1::from_elem(n, f64::NEG_INFINITY),
+            Array1::from_elem(n, f64::INFINITY),
+        )),
+    }
+}
+
+/// Robust cost function and loss-scaled residuals and Jacobian.
+fn robust_scaling(
+    residuals: &Array1<f64>,
+    jacobian: Option<&Array2<f64>>,
+    losses: &[Loss],
+) -> (f64, Array1<f64>, Option<Array2<f64>>) {
+    let mut cost = 0.0;
+    let mut scaled_residuals = residuals.clone();
+    let mut scaled_jacobian = jacobian.cloned();
+    for (i, (r, loss)) in residuals.iter().zip(losses).enumerate() {
+        let [rho, rho1, rho2] = loss.evaluate(*r);
+        cost += 0.5 * rho;
+        let j_scale = (rho1 + rho2).max(f64::EPSILON).sqrt();
+        scaled_residuals[i] = r * rho1 / j_scale;
+        if let Some(j) = scaled_jacobian.as_mut() {
+            j.row_mut(i).mapv_inplace(|j| j * j_scale);
+        }
+    }
+    (cost, scaled_residuals, scaled_jacobian)
+}
+
+/// Forward difference approximation of the Jacobian that respects the bounds.
+pub(super) fn jacobian<F>(
+    residuals: &F,
+    x: &Array1<f64>,
+    r: &Array1<f64>,
+    upper: &Array1<f64>,
+    diff_step: f64,
+) -> Result<Array2<f64>, EstimatorError>
+where
+    F: Fn(&Array1<f64>) -> Result<(Array1<f64>, Vec<Loss>), EstimatorError>,
+{
+    let mut jacobian = Array2::zeros((r.len(), x.len()));
+    for (j, mut column) in jacobian.axis_iter_mut(Axis(1)).enumerate() {
+        let mut h = diff_step * x[j].abs().max(1.0);
+        if x[j] + h > upper[j] {
+            h = -h;
+        }
+        let mut x_h = x.clone();
+        x_h[j] += h;
+        let (r_h, _) = residuals(&x_h)?;
+        column.assign(&((r_h - r) / h));
+    }
+    Ok(jacobian)
+}
+
+/// Weighted residuals and their unbounded forward difference Jacobian at the
+/// given parameters, as required for the analysis of parameter uncertainties.
+pub(crate) fn residuals_and_jacobian<F>(
+    residuals: F,
+    parameters: &Array1<f64>,
+) -> Result<(Array1<f64>, Array2<f64>), EstimatorError>
+where
+    F: Fn(&Array1<f64>) -> Result<(Array1<f64>, Vec<Loss>), EstimatorError>,
+{
+    let (r, _) = residuals(parameters)?;
+    let upper = Array1::from_elem(parameters.len(), f64::INFINITY);
+    let jacobian = jacobian(&residuals, parameters, &r, &upper, f64::EPSILON.sqrt())?;
+    Ok((r, jacobian))
+}
+
+pub(super) fn levenberg_marquardt<F>(
+    residuals: F,
+    initial_parameters: &Array1<f64>,
+    lower: &Array1<f64>,
+    upper: &Array1<f64>,
+    options: LeastSquaresOptions,
+) -> Result<LeastSquaresResult, EstimatorError>
+where
+    F: Fn(&Array1<f64>) -> Result<(Array1<f64>, Vec<Loss>), EstimatorError>,
+{
+    let n = initial_parameters.len();
+    let project = |x: &Array1<f64>| Array1::from_shape_fn(n, |i| x[i].max(lower[i]).min(upper[i]));
+    let finite = |r: &Array1<f64>| r.iter().all(|r| r.is_finite());
+
+    let mut x = project(initial_parameters);
+    let (mut r, losses) = residuals(&x)?;
+    if !finite(&r) {
+        return Err(EstimatorError::NonFiniteResiduals);
+    }
+    let mut function_evaluations = 1;
+    let mut jacobian_evaluations = 0;
+    let mut mu = None;
+    let mut nu = 2.0;
+    let mut scale = Array1::<f64>::zeros(n);
+
+    log_iter!(
+        options.verbosity,
+        " iter |     cost     |  optimality  |     damping    "
+    );
+    log_iter!(options.verbosity, "{:-<52}", "");
+
+    let mut iterations = 0;
+    let (termination, j) = loop {
+        let j = jacobian(&residuals, &x, &r, upper, options.diff_step)?;
+        function_evaluations += n;
+        jacobian_evaluations += 1;
+        let (cost, r_scaled, j_scaled) = robust_scaling(&r, Some(&j), &losses);
+        let j_scaled = j_scaled.unwrap();
+        let g = j_scaled.t().dot(&r_scaled);
+        let h = j_scaled.t().dot(&j_scaled);
+
+        // parameters at their bounds with the gradient pointing outwards are fixed
+        let active: Vec<_> = (0..n)
+            .map(|i| (x[i] <= lower[i] && g[i] > 0.0) || (x[i] >= upper[i] && g[i] < 0.0))
+            .collect();
+        let optimality = (0..n)
+            .filter(|&i| !active[i])
+            .map(|i| g[i].abs())
+            .fold(0.0, f64::max);
+        log_iter!(
+            options.verbosity,
+            " {:4} | {:12.6e} | {:12.6e} | {:12.6e}",
+            iterations,
+            cost,
+            optimality,
+            mu.unwrap_or(0.0)
+        );
+        if optimality < options.gtol {
+            break (Termination::Gtol, j);
+        }
+        if iterations == options.max_iter {
+            break (Termination::MaxIterations, j);
+        }
+        iterations += 1;
+
+        // Marquardt scaling of the damping term
+        Zip::from(&mut scale)
+            .and(&h.diag())
+            .for_each(|s, &h| *s = s.max(h).max(f64::EPSILON));
+        let mut damping = *mu.get_or_insert(1e-3 * scale.iter().copied().fold(0.0, f64::max));
+
+        // inner loop: increase damping until a step is accepted
+        let mut rejected = false;
+        let step = loop {
+            let mut a = h.clone();
+            a.diag_mut().scaled_add(damping, &scale);
+            let mut b = -&g;
+            for i in (0..n).filter(|&i| active[i]) {
+                a.row_mut(i).fill(0.0);
+                a.column_mut(i).fill(0.0);
+                a[(i, i)] = 1.0;
+                b[i] = 0.0;
+            }
+            let delta = LU::new(a).map_err(EosError::from)?.solve(&b);
+            let x_new = project(&(&x + &delta));
+            let delta = &x_new - &x;
+            let x_norm = x.dot(&x).sqrt();
+            if delta.dot(&delta).sqrt() <= options.xtol * (x_norm + options.xtol) {
+                break Err(rejected);
+            }
+            let predicted = -(g.dot(&delta) + 0.5 * delta.dot(&h.dot(&delta)));
+            function_evaluations += 1;
+            let trial = residuals(&x_new)
+                .ok()
+                .filter(|(r_new, _)| finite(r_new))
+                .map(|(r_new, _)| {
+                    let (cost_new, _, _) = robust_scaling(&r_new, None, &losses);
+                    (r_new, cost_new)
+                });
+            if let Some((r_new, cost_new)) = trial {
+                let ratio = (cost - cost_new) / predicted;
+                if predicted > 0.0 && ratio > 0.0 {
+                    damping *= (1.0 - (2.0 * ratio - 1.0).powi(3)).max(1.0 / 3.0);
+                    nu = 2.0;
+                    break Ok((x_new, r_new, cost - cost_new, cost, ratio));
+                }
+            }
+            rejected = true;
+            damping *= nu;
+            nu *= 2.0;
+        };
+        mu = Some(damping);
+
+        match step {
+            Ok((x_new, r_new, reduction, cost, ratio)) => {
+                x = x_new;
+                r = r_new;
+                if reduction < options.ftol * cost && ratio > 0.25 {
+                    let j = jacobian(&residuals, &x, &r, upper, options.diff_step)?;
+                    function_evaluations += n;
+                    jacobian_evaluations += 1;
+                    break (Termination::Ftol, j);
+                }
+            }
+            // the step size only falls below xtol after rejected steps
+            // if the damping was increased without finding a better point
+            Err(false) => break (Termination::Xtol, j),
+            Err(true) => break (Termination::Stagnation, j),
+        }
+    };
+
+    let (cost, r_scaled, j_scaled) = robust_scaling(&r, Some(&j), &losses);
+    let g = j_scaled.unwrap().t().dot(&r_scaled);
+    let active_bounds = Array1::from_shape_fn(n, |i| {
+        if x[i] <= lower[i] {
+            -1
+        } else if x[i] >= upper[i] {
+            1
+        } else {
+            0
+        }
+    });
+    let optimality = (0..n)
+        .filter(|&i| active_bounds[i] == 0 || active_bounds[i] as f64 * g[i] > 0.0)
+        .map(|i| g[i].abs())
+        .fold(0.0, f64::max);
+    log_result!(
+        options.verbosity,
+        "Least squares: {} after {} iterations (cost = {:e}).",
+        termination,
+        iterations,
+        cost
+    );
+
+    Ok(LeastSquaresResult {
+        parameters: x,
+        cost,
+        residuals: r,
+        jacobian: j,
+        optimality,
+        active_bounds,
+        iterations,
+        function_evaluations,
+        jacobian_evaluations,
+        termination,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::arr1;
+
+    fn rosenbrock(x: &Array1<f64>) -> Result<(Array1<f64>, Vec<Loss>), EstimatorError> {
+        Ok((
+            arr1(&[10.0 * (x[1] - x[0] * x[0]), 1.0 - x[0]]),
+            vec![Loss::Linear; 2],
+        ))
+    }
+
+    #[test]
+    fn test_rosenbrock() -> Result<(), EstimatorError> {
+        let (lower, upper) = (arr1(&[f64::NEG_INFINITY; 2]), arr1(&[f64::INFINITY; 2]));
+        let x0 = arr1(&[-1.2, 1.0]);
+        let res = levenberg_marquardt(rosenbrock, &x0, &lower, &upper, Default::default())?;
+        assert!(res.success());
+        assert_relative_eq!(res.parameters, arr1(&[1.0, 1.0]), max_relative = 1e-6);
+        assert!(res.cost < 1e-12);
+        Ok(())
+    }
+
+    #[test]
+    fn test_rosenbrock_bounds() -> Result<(), EstimatorError> {
+        let (lower, upper) = (arr1(&[f64::NEG_INFINITY, 0.0]), arr1(&[0.5, f64::INFINITY]));
+        let x0 = arr1(&[-1.2, 1.0]);
+        let res = levenberg_marquardt(rosenbrock, &x0, &lower, &upper, Default::default())?;
+        assert!(res.success());
+        assert_relative_eq!(res.parameters, arr1(&[0.5, 0.25]), max_relative = 1e-6);
+        assert_eq!(res.active_bounds, arr1(&[1, 0]));
+        assert_relative_eq!(res.cost, 0.125, max_relative = 1e-8);
+        Ok(())
+    }
+
+    #[test]
+    fn test_stagnation() -> Result<(), EstimatorError> {
+        // the forward difference Jacobian does not see the kink at the initial
+        // point, so every step increases the cost function
+        let residuals = |x: &Array1<f64>| Ok((arr1(&[1.0 + x[0].abs()]), vec![Loss::Linear]));
+        let (lower, upper) = (arr1(&[f64::NEG_INFINITY]), arr1(&[f64::INFINITY]));
+        let x0 = arr1(&[0.0]);
+        let res = levenberg_marquardt(residuals, &x0, &lower, &upper, Default::default())?;
+        assert_eq!(res.termination, Termination::Stagnation);
+        assert!(!res.success());
+        Ok(())
+    }
+
+    #[test]
+    fn test_robust_loss() -> Result<(), EstimatorError> {
+        // straight line with an outlier
+        let t = arr1(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0]);
+        let y = arr1(&[1.0, 3.0, 5.0, 7.0, 30.0, 11.0]);
+        let (lower, upper) = (arr1(&[f64::NEG_INFINITY; 2]), arr1(&[f64::INFINITY; 2]));
+        let x0 = arr1(&[0.0, 0.0]);
+        let residuals = |loss: Loss| {
+            let (t, y) = (t.clone(), y.clone());
+            move |x: &Array1<f64>| Ok((&t * x[0] + x[1] - &y, vec![loss; 6]))
+        };
+        let linear = levenberg_marquardt(
+            residuals(Loss::Linear),
+            &x0,
+            &lower,
+            &upper,
+            Default::default(),
+        )?;
+        let soft_l1 = levenberg_marquardt(
+            residuals(Loss::SoftL1(0.01)),
+            &x0,
+            &lower,
+            &upper,
+            Default::default(),
+        )?;
+        assert!((linear.parameters[0] - 2.0).abs() > 0.5);
+        assert_relative_eq!(soft_l1.parameters, arr1(&[2.0, 1.0]), epsilon = 1e-2);
+        Ok(())
+    }
+}
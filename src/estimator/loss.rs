@@ -34,6 +34,49 @@ impl Loss {
         Self::Arctan(scaling_factor)
     }
 
+    /// Return the loss function with the scaling factor multiplied by `factor`.
+    pub(crate) fn scale(&self, factor: f64) -> Self {
+        match self {
+            Self::Linear => Self::Linear,
+            Self::SoftL1(s) => Self::SoftL1(s * factor),
+            Self::Huber(s) => Self::Huber(s * factor),
+            Self::Cauchy(s) => Self::Cauchy(s * factor),
+            Self::Arctan(s) => Self::Arctan(s * factor),
+        }
+    }
+
+    /// Evaluate the loss $f^2\rho(z)$ of a single residual together with
+    /// $\rho'(z)$ and $2z\rho''(z)$ as required for the robust scaling of
+    /// residuals and Jacobian in nonlinear least-squares problems.
+    pub(crate) fn evaluate(&self, r: f64) -> [f64; 3] {
+        let s = match self {
+            Self::Linear => 1.0,
+            Self::SoftL1(s) | Self::Huber(s) | Self::Cauchy(s) | Self::Arctan(s) => *s,
+        };
+        let z = r * r / (s * s);
+        let [rho, rho1, rho2] = match self {
+            Self::Linear => [z, 1.0, 0.0],
+            Self::SoftL1(_) => {
+                let t = (1.0 + z).sqrt();
+                [2.0 * (t - 1.0), 1.0 / t, -0.5 / (t * t * t)]
+            }
+            Self::Huber(_) => {
+                if z <= 1.0 {
+                    [z, 1.0, 0.0]
+                } else {
+                    let t = z.sqrt();
+                    [2.0 * t - 1.0, 1.0 / t, -0.5 / (t * z)]
+                }
+            }
+            Self::Cauchy(_) => [(1.0 + z).ln(), 1.0 / (1.0 + z), -1.0 / (1.0 + z).powi(2)],
+            Self::Arctan(_) => {
+                let t = 1.0 + z * z;
+                [z.atan(), 1.0 / t, -2.0 * z / (t * t)]
+            }
+        };
+        [s * s * rho, rho1, 2.0 * z * rho2]
+    }
+
     /// Apply function to array of residuals.
     pub fn apply(&self, res: &mut Array1<f64>) {
         match self {
@@ -10,6 +10,12 @@ mod estimator;
 pub use estimator::Estimator;
 mod loss;
 pub use loss::Loss;
+mod least_squares;
+pub use least_squares::{LeastSquaresOptions, LeastSquaresResult, Termination};
+mod uncertainty;
+pub use uncertainty::ParameterUncertainty;
+mod group_contribution;
+pub use group_contribution::GcEstimator;
 
 // Properties
 mod vapor_pressure;
@@ -18,18 +24,42 @@ mod liquid_density;
 pub use liquid_density::{EquilibriumLiquidDensity, LiquidDensity};
 mod binary_vle;
 pub use binary_vle::{BinaryPhaseDiagram, BinaryVleChemicalPotential, BinaryVlePressure};
+mod multicomponent_vle;
+pub use multicomponent_vle::{MulticomponentChemicalPotential, MulticomponentFlash};
 mod viscosity;
 pub use viscosity::Viscosity;
 mod thermal_conductivity;
 pub use thermal_conductivity::ThermalConductivity;
 mod diffusion;
 pub use diffusion::Diffusion;
+mod speed_of_sound;
+pub use speed_of_sound::SpeedOfSound;
+mod heat_capacity;
+pub use heat_capacity::{IsobaricHeatCapacity, IsochoricHeatCapacity};
+mod enthalpy_of_vaporization;
+pub use enthalpy_of_vaporization::EnthalpyOfVaporization;
+mod second_virial_coefficient;
+pub use second_virial_coefficient::SecondVirialCoefficient;
+mod joule_thomson;
+pub use joule_thomson::JouleThomsonCoefficient;
+#[cfg(feature = "dft")]
+mod surface_tension;
+#[cfg(feature = "dft")]
+pub use surface_tension::{SurfaceTension, SurfaceTensionMethod};
+#[cfg(feature = "dft")]
+mod adsorption;
+#[cfg(feature = "dft")]
+pub use adsorption::{AdsorbedAmount, Adsorbent, AdsorptionIsotherm, IsothermBranch};
+
+// Import
+mod import;
+pub use import::{ExperimentalData, Property};
 
 #[cfg(feature = "python")]
 pub mod python;
 
 /// Different phases of experimental data points.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq)]
 #[cfg_attr(feature = "python", pyo3::pyclass)]
 pub enum Phase {
     Vapor,
@@ -49,6 +79,26 @@ impl From<Phase> for DensityInitialization {
 pub enum EstimatorError {
     #[error("Input has not the same amount of data as the target.")]
     IncompatibleInput,
+    #[error("The residuals at the initial parameters are not finite.")]
+    NonFiniteResiduals,
+    #[error("The number of data points has to exceed the number of parameters.")]
+    InsufficientData,
+    #[error("Unknown unit '{0}'.")]
+    UnknownUnit(String),
+    #[error("The unit '{1}' is not a valid unit for {0}.")]
+    WrongUnits(String, String),
+    #[error("Missing column '{0}'.")]
+    MissingColumn(String),
+    #[error("Unknown property '{0}'.")]
+    UnknownProperty(String),
+    #[error("Data sets for {0} require a model that implements {1}.")]
+    IncompatibleModel(String, String),
+    #[error("{0}")]
+    InvalidData(String),
+    #[error(transparent)]
+    IoError(#[from] std::io::Error),
+    #[error(transparent)]
+    XmlError(#[from] roxmltree::Error),
     #[error(transparent)]
     ShapeError(#[from] ndarray::ShapeError),
     #[error(transparent)]
@@ -0,0 +1,334 @@
+use super::{DataSet, EstimatorError};
+use feos_core::si::{MolarEnergy, Moles, Pressure, Temperature, RGAS};
+use feos_core::{DensityInitialization, PhaseEquilibrium, Residual, SolverOptions, State};
+use ndarray::{Array1, Array2, ArrayView1, Axis};
+use std::sync::Arc;
+
+/// Type of the phase equilibrium described by the experimental data.
+#[derive(Clone, Copy)]
+enum Equilibrium {
+    /// Vapor-liquid equilibrium with liquid and vapor compositions.
+    Vle,
+    /// Liquid-liquid equilibrium with the compositions of both liquid phases.
+    Lle,
+}
+
+impl Equilibrium {
+    fn input_str(&self) -> [&'static str; 2] {
+        match self {
+            Self::Vle => ["liquid molefracs", "vapor molefracs"],
+            Self::Lle => ["liquid 1 molefracs", "liquid 2 molefracs"],
+        }
+    }
+
+    fn density_initialization(&self) -> [DensityInitialization; 2] {
+        match self {
+            Self::Vle => [DensityInitialization::Liquid, DensityInitialization::Vapor],
+            Self::Lle => [DensityInitialization::Liquid, DensityInitialization::Liquid],
+        }
+    }
+}
+
+/// Check that the compositions of the data set match the number of components of the model.
+fn check_components<E: Residual>(
+    eos: &Arc<E>,
+    molefracs: &[&Array2<f64>; 2],
+) -> Result<(), EstimatorError> {
+    if molefracs.iter().any(|x| x.ncols() != eos.components()) {
+        return Err(EstimatorError::IncompatibleInput);
+    }
+    Ok(())
+}
+
+/// Check that every data point has a temperature, a pressure and the compositions of both phases.
+fn check_shapes(
+    temperature: &Temperature<Array1<f64>>,
+    pressure: &Pressure<Array1<f64>>,
+    molefracs_1: &Array2<f64>,
+    molefracs_2: &Array2<f64>,
+) -> Result<(), EstimatorError> {
+    let n = molefracs_1.nrows();
+    if temperature.len() != n || pressure.len() != n || molefracs_1.dim() != molefracs_2.dim() {
+        return Err(EstimatorError::IncompatibleInput);
+    }
+    Ok(())
+}
+
+/// Store experimental multicomponent VLE or LLE data for the calculation
+/// of chemical potential residuals.
+///
+/// The compositions of both phases are stored row-wise, i.e., with one row per
+/// data point and one column per component. For every data point and
+/// component, the difference of the chemical potentials in both phases
+/// is used as residual. The constructors return an error if the number of
+/// temperatures, pressures and compositions or the shapes of both
+/// compositions do not match.
+#[derive(Clone)]
+pub struct MulticomponentChemicalPotential {
+    temperature: Temperature<Array1<f64>>,
+    pressure: Pressure<Array1<f64>>,
+    molefracs_1: Array2<f64>,
+    molefracs_2: Array2<f64>,
+    equilibrium: Equilibrium,
+    target: Array1<f64>,
+}
+
+impl MulticomponentChemicalPotential {
+    fn new(
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        molefracs_1: Array2<f64>,
+        molefracs_2: Array2<f64>,
+        equilibrium: Equilibrium,
+    ) -> Result<Self, EstimatorError> {
+        check_shapes(&temperature, &pressure, &molefracs_1, &molefracs_2)?;
+        let target = Array1::ones(molefracs_1.len());
+        Ok(Self {
+            temperature,
+            pressure,
+            molefracs_1,
+            molefracs_2,
+            equilibrium,
+            target,
+        })
+    }
+
+    /// Vapor-liquid equilibrium data with the compositions of the liquid and vapor phases.
+    pub fn new_vle(
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        liquid_molefracs: Array2<f64>,
+        vapor_molefracs: Array2<f64>,
+    ) -> Result<Self, EstimatorError> {
+        Self::new(
+            temperature,
+            pressure,
+            liquid_molefracs,
+            vapor_molefracs,
+            Equilibrium::Vle,
+        )
+    }
+
+    /// Liquid-liquid equilibrium data with the compositions of both liquid phases.
+    pub fn new_lle(
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        liquid1_molefracs: Array2<f64>,
+        liquid2_molefracs: Array2<f64>,
+    ) -> Result<Self, EstimatorError> {
+        Self::new(
+            temperature,
+            pressure,
+            liquid1_molefracs,
+            liquid2_molefracs,
+            Equilibrium::Lle,
+        )
+    }
+}
+
+impl<E: Residual> DataSet<E> for MulticomponentChemicalPotential {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "chemical potential"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        let mut vec = vec!["temperature", "pressure"];
+        vec.extend(self.equilibrium.input_str());
+        vec
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        check_components(eos, &[&self.molefracs_1, &self.molefracs_2])?;
+        let [init_1, init_2] = self.equilibrium.density_initialization();
+        let mut prediction = Vec::with_capacity(self.target.len());
+        for (i, (x1, x2)) in self
+            .molefracs_1
+            .axis_iter(Axis(0))
+            .zip(self.molefracs_2.axis_iter(Axis(0)))
+            .enumerate()
+        {
+            let t = self.temperature.get(i);
+            let p = self.pressure.get(i);
+            let moles_1 = Moles::from_reduced(x1.to_owned());
+            let state_1 = State::new_npt(eos, t, p, &moles_1, init_1)?;
+            let moles_2 = Moles::from_reduced(x2.to_owned());
+            let state_2 = State::new_npt(eos, t, p, &moles_2, init_2)?;
+
+            let mu_res_1 = state_1.residual_chemical_potential();
+            let mu_res_2 = state_2.residual_chemical_potential();
+            let kt = RGAS * t;
+            let rho_frac = (&state_1.partial_density / &state_2.partial_density).into_value();
+            for (j, rho_frac) in rho_frac.into_iter().enumerate() {
+                prediction.push(mu_res_1.get(j) - mu_res_2.get(j) + kt * rho_frac.ln());
+            }
+        }
+        let prediction = (MolarEnergy::from_vec(prediction) / MolarEnergy::from_reduced(500.0))
+            .into_value()
+            + 1.0;
+        Ok(prediction)
+    }
+}
+
+/// Store experimental multicomponent VLE or LLE data for the calculation
+/// of residuals from Tp-flash calculations.
+///
+/// For every data point, a flash is performed at the experimental temperature
+/// and pressure with the mean of both experimental compositions as feed.
+/// The residuals are the deviations of the calculated mole fractions of both
+/// phases from the experimental ones. Because mole fractions can be zero,
+/// the deviations are absolute and shifted by 1, i.e., the target is 1 for
+/// every mole fraction.
+///
+/// If the flash does not converge or only yields a single phase, the
+/// predictions of the data point are set to `NAN`. As for
+/// [MulticomponentChemicalPotential], the shapes of the data are checked
+/// in the constructors.
+#[derive(Clone)]
+pub struct MulticomponentFlash {
+    temperature: Temperature<Array1<f64>>,
+    pressure: Pressure<Array1<f64>>,
+    molefracs_1: Array2<f64>,
+    molefracs_2: Array2<f64>,
+    equilibrium: Equilibrium,
+    solver_options: SolverOptions,
+    target: Array1<f64>,
+}
+
+impl MulticomponentFlash {
+    fn new(
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        molefracs_1: Array2<f64>,
+        molefracs_2: Array2<f64>,
+        equilibrium: Equilibrium,
+        solver_options: Option<SolverOptions>,
+    ) -> Result<Self, EstimatorError> {
+        check_shapes(&temperature, &pressure, &molefracs_1, &molefracs_2)?;
+        let target = Array1::ones(2 * molefracs_1.len());
+        Ok(Self {
+            temperature,
+            pressure,
+            molefracs_1,
+            molefracs_2,
+            equilibrium,
+            solver_options: solver_options.unwrap_or_default(),
+            target,
+        })
+    }
+
+    /// Vapor-liquid equilibrium data with the compositions of the liquid and vapor phases.
+    pub fn new_vle(
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        liquid_molefracs: Array2<f64>,
+        vapor_molefracs: Array2<f64>,
+        solver_options: Option<SolverOptions>,
+    ) -> Result<Self, EstimatorError> {
+        Self::new(
+            temperature,
+            pressure,
+            liquid_molefracs,
+            vapor_molefracs,
+            Equilibrium::Vle,
+            solver_options,
+        )
+    }
+
+    /// Liquid-liquid equilibrium data (tie lines) with the compositions of both liquid phases.
+    pub fn new_lle(
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        liquid1_molefracs: Array2<f64>,
+        liquid2_molefracs: Array2<f64>,
+        solver_options: Option<SolverOptions>,
+    ) -> Result<Self, EstimatorError> {
+        Self::new(
+            temperature,
+            pressure,
+            liquid1_molefracs,
+            liquid2_molefracs,
+            Equilibrium::Lle,
+            solver_options,
+        )
+    }
+
+    fn flash<E: Residual>(
+        &self,
+        eos: &Arc<E>,
+        i: usize,
+        x1: ArrayView1<f64>,
+        x2: ArrayView1<f64>,
+    ) -> Option<[Array1<f64>; 2]> {
+        let t = self.temperature.get(i);
+        let p = self.pressure.get(i);
+        let feed = Moles::from_reduced((&x1 + &x2) * 0.5);
+        let feed = State::new_npt(eos, t, p, &feed, DensityInitialization::None).ok()?;
+        // for VLE, the experimental compositions are a good initial guess;
+        // for LLE, the initial phases are found by a stability analysis.
+        let initial_state = match self.equilibrium {
+            Equilibrium::Vle => PhaseEquilibrium::new_npt(
+                eos,
+                t,
+                p,
+                &Moles::from_reduced(x2.to_owned()),
+                &Moles::from_reduced(x1.to_owned()),
+            )
+            .ok(),
+            Equilibrium::Lle => None,
+        };
+        let vle = feed
+            .tp_flash(initial_state.as_ref(), self.solver_options, None)
+            .ok()?;
+        let (y1, y2) = (&vle.liquid().molefracs, &vle.vapor().molefracs);
+        if (y1 - y2).mapv(f64::abs).sum() < 1e-8 {
+            return None;
+        }
+        // assign the calculated phases to the experimental ones
+        let distance = |a: &Array1<f64>, b: ArrayView1<f64>| (a - &b).mapv(|d| d * d).sum();
+        if distance(y1, x1) + distance(y2, x2) <= distance(y2, x1) + distance(y1, x2) {
+            Some([y1.clone(), y2.clone()])
+        } else {
+            Some([y2.clone(), y1.clone()])
+        }
+    }
+}
+
+impl<E: Residual> DataSet<E> for MulticomponentFlash {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "molefracs"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        let mut vec = vec!["temperature", "pressure"];
+        vec.extend(self.equilibrium.input_str());
+        vec
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        check_components(eos, &[&self.molefracs_1, &self.molefracs_2])?;
+        let mut prediction = Vec::with_capacity(self.target.len());
+        for (i, (x1, x2)) in self
+            .molefracs_1
+            .axis_iter(Axis(0))
+            .zip(self.molefracs_2.axis_iter(Axis(0)))
+            .enumerate()
+        {
+            match self.flash(eos, i, x1, x2) {
+                Some([y1, y2]) => {
+                    prediction.extend(y1 - x1 + 1.0);
+                    prediction.extend(y2 - x2 + 1.0);
+                }
+                None => prediction.resize(prediction.len() + 2 * x1.len(), f64::NAN),
+            }
+        }
+        Ok(Array1::from_vec(prediction))
+    }
+}
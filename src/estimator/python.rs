@@ -451,6 +451,168 @@ macro_rules! impl_estimator {
                 }
             }
 
+            /// Create a DataSet with experimental data for multicomponent
+            /// vapor-liquid equilibria using the chemical potential residual.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SIArray1
+            ///     Temperature of the experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure of the experimental data points.
+            /// liquid_molefracs : np.ndarray[float]
+            ///     Molar composition of the liquid phase with
+            ///     one row per data point.
+            /// vapor_molefracs : np.ndarray[float]
+            ///     Molar composition of the vapor phase with
+            ///     one row per data point.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(temperature, pressure, liquid_molefracs, vapor_molefracs)")]
+            fn vle_chemical_potential(
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                liquid_molefracs: &Bound<'_, PyArray2<f64>>,
+                vapor_molefracs: &Bound<'_, PyArray2<f64>>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(MulticomponentChemicalPotential::new_vle(
+                    temperature.clone().try_into()?,
+                    pressure.clone().try_into()?,
+                    liquid_molefracs.to_owned_array(),
+                    vapor_molefracs.to_owned_array(),
+                )?)))
+            }
+
+            /// Create a DataSet with experimental data for multicomponent
+            /// liquid-liquid equilibria using the chemical potential residual.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SIArray1
+            ///     Temperature of the experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure of the experimental data points.
+            /// liquid1_molefracs : np.ndarray[float]
+            ///     Molar composition of the first liquid phase with
+            ///     one row per data point.
+            /// liquid2_molefracs : np.ndarray[float]
+            ///     Molar composition of the second liquid phase with
+            ///     one row per data point.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(temperature, pressure, liquid1_molefracs, liquid2_molefracs)")]
+            fn lle_chemical_potential(
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                liquid1_molefracs: &Bound<'_, PyArray2<f64>>,
+                liquid2_molefracs: &Bound<'_, PyArray2<f64>>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(MulticomponentChemicalPotential::new_lle(
+                    temperature.clone().try_into()?,
+                    pressure.clone().try_into()?,
+                    liquid1_molefracs.to_owned_array(),
+                    liquid2_molefracs.to_owned_array(),
+                )?)))
+            }
+
+            /// Create a DataSet with experimental data for multicomponent
+            /// vapor-liquid equilibria using the composition residuals
+            /// of Tp-flash calculations.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SIArray1
+            ///     Temperature of the experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure of the experimental data points.
+            /// liquid_molefracs : np.ndarray[float]
+            ///     Molar composition of the liquid phase with
+            ///     one row per data point.
+            /// vapor_molefracs : np.ndarray[float]
+            ///     Molar composition of the vapor phase with
+            ///     one row per data point.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for the flash.
+            /// tol: float, optional
+            ///     The solution tolerance for the flash.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the flash.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(temperature, pressure, liquid_molefracs, vapor_molefracs, max_iter=None, tol=None, verbosity=None)")]
+            fn vle_flash(
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                liquid_molefracs: &Bound<'_, PyArray2<f64>>,
+                vapor_molefracs: &Bound<'_, PyArray2<f64>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(MulticomponentFlash::new_vle(
+                    temperature.clone().try_into()?,
+                    pressure.clone().try_into()?,
+                    liquid_molefracs.to_owned_array(),
+                    vapor_molefracs.to_owned_array(),
+                    Some((max_iter, tol, verbosity).into()),
+                )?)))
+            }
+
+            /// Create a DataSet with experimental data for multicomponent
+            /// liquid-liquid equilibria (tie lines) using the composition
+            /// residuals of Tp-flash calculations.
+            ///
+            /// Parameters
+            /// ----------
+            /// temperature : SIArray1
+            ///     Temperature of the experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure of the experimental data points.
+            /// liquid1_molefracs : np.ndarray[float]
+            ///     Molar composition of the first liquid phase with
+            ///     one row per data point.
+            /// liquid2_molefracs : np.ndarray[float]
+            ///     Molar composition of the second liquid phase with
+            ///     one row per data point.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for the flash.
+            /// tol: float, optional
+            ///     The solution tolerance for the flash.
+            /// verbosity : Verbosity, optional
+            ///     The verbosity of the flash.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(temperature, pressure, liquid1_molefracs, liquid2_molefracs, max_iter=None, tol=None, verbosity=None)")]
+            fn lle_flash(
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                liquid1_molefracs: &Bound<'_, PyArray2<f64>>,
+                liquid2_molefracs: &Bound<'_, PyArray2<f64>>,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(MulticomponentFlash::new_lle(
+                    temperature.clone().try_into()?,
+                    pressure.clone().try_into()?,
+                    liquid1_molefracs.to_owned_array(),
+                    liquid2_molefracs.to_owned_array(),
+                    Some((max_iter, tol, verbosity).into()),
+                )?)))
+            }
+
             /// Return `target` as ``SIArray1``.
             #[getter]
             fn get_target<'py>(&self, py: Python<'py>,) -> Bound<'py, PyArray1<f64>> {
@@ -734,3 +896,374 @@ macro_rules! impl_estimator_entropy_scaling {
         }
     };
 }
+
+#[macro_export]
+macro_rules! impl_estimator_caloric {
+    ($eos:ty, $py_eos:ty) => {
+        impl PyDataSet {
+            fn from_experimental_data(
+                data: &$crate::estimator::ExperimentalData,
+            ) -> PyResult<Self> {
+                use $crate::estimator::Property;
+                Ok(Self(match data.property() {
+                    #[cfg(feature = "pcsaft")]
+                    Property::Viscosity | Property::ThermalConductivity | Property::Diffusion => {
+                        data.data_set_entropy_scaling()?
+                    }
+                    _ => data.data_set_caloric()?,
+                }))
+            }
+        }
+
+        #[pymethods]
+        impl PyDataSet {
+            /// Read a DataSet with experimental data from a CSV file.
+            ///
+            /// The first line of the file contains the headers of the
+            /// columns in the form `name (phase) [unit]`, e.g.,
+            /// `temperature [K]`, `pressure [MPa]` or
+            /// `speed of sound (liquid) [m/s]`. Compositions of VLE
+            /// (`x_1`, `y_1`, ...) or LLE data (`x1_1`, `x2_1`, ...) are
+            /// converted into a DataSet for the chemical potential.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     Path to the CSV file.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(path)")]
+            fn from_csv(path: &str) -> PyResult<Self> {
+                Self::from_experimental_data(&$crate::estimator::ExperimentalData::from_csv(path)?)
+            }
+
+            /// Read DataSets with experimental data from a ThermoML file.
+            ///
+            /// Every property of a pure substance and the VLE data of
+            /// mixtures are converted into separate DataSets.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     Path to the ThermoML file.
+            ///
+            /// Returns
+            /// -------
+            /// List[DataSet]
+            #[staticmethod]
+            #[pyo3(text_signature = "(path)")]
+            fn from_thermoml(path: &str) -> PyResult<Vec<Self>> {
+                $crate::estimator::ExperimentalData::from_thermoml(path)?
+                    .iter()
+                    .map(Self::from_experimental_data)
+                    .collect()
+            }
+
+            /// Create a DataSet with experimental data for the speed of sound.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the speed of sound.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure for experimental data points.
+            /// phase : List[Phase], optional
+            ///     Phase of data. Used to determine the starting
+            ///     density for the density iteration. If provided,
+            ///     resulting states may not be stable.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            fn speed_of_sound(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                phase: Option<Vec<Phase>>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new($crate::estimator::SpeedOfSound::new(
+                    target.clone().try_into()?,
+                    temperature.clone().try_into()?,
+                    pressure.clone().try_into()?,
+                    phase.as_ref(),
+                ))))
+            }
+
+            /// Create a DataSet with experimental data for the molar
+            /// isobaric heat capacity.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the molar isobaric heat capacity.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure for experimental data points.
+            /// phase : List[Phase], optional
+            ///     Phase of data. Used to determine the starting
+            ///     density for the density iteration. If provided,
+            ///     resulting states may not be stable.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            fn isobaric_heat_capacity(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                phase: Option<Vec<Phase>>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(
+                    $crate::estimator::IsobaricHeatCapacity::new(
+                        target.clone().try_into()?,
+                        temperature.clone().try_into()?,
+                        pressure.clone().try_into()?,
+                        phase.as_ref(),
+                    ),
+                )))
+            }
+
+            /// Create a DataSet with experimental data for the molar
+            /// isochoric heat capacity.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the molar isochoric heat capacity.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure for experimental data points.
+            /// phase : List[Phase], optional
+            ///     Phase of data. Used to determine the starting
+            ///     density for the density iteration. If provided,
+            ///     resulting states may not be stable.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            fn isochoric_heat_capacity(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                phase: Option<Vec<Phase>>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(
+                    $crate::estimator::IsochoricHeatCapacity::new(
+                        target.clone().try_into()?,
+                        temperature.clone().try_into()?,
+                        pressure.clone().try_into()?,
+                        phase.as_ref(),
+                    ),
+                )))
+            }
+
+            /// Create a DataSet with experimental data for the
+            /// Joule-Thomson coefficient.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the Joule-Thomson coefficient.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// pressure : SIArray1
+            ///     Pressure for experimental data points.
+            /// phase : List[Phase], optional
+            ///     Phase of data. Used to determine the starting
+            ///     density for the density iteration. If provided,
+            ///     resulting states may not be stable.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            fn joule_thomson_coefficient(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                pressure: &PySIArray1,
+                phase: Option<Vec<Phase>>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(
+                    $crate::estimator::JouleThomsonCoefficient::new(
+                        target.clone().try_into()?,
+                        temperature.clone().try_into()?,
+                        pressure.clone().try_into()?,
+                        phase.as_ref(),
+                    ),
+                )))
+            }
+
+            /// Create a DataSet with experimental data for the
+            /// enthalpy of vaporization of a pure substance.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the enthalpy of vaporization.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// max_iter : int, optional
+            ///     The maximum number of iterations for the VLE algorithm.
+            /// tol: float, optional
+            ///     Solution tolerance for the VLE algorithm.
+            /// verbosity : Verbosity, optional
+            ///     Verbosity for the VLE algorithm.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(
+                text_signature = "(target, temperature, max_iter=None, tol=None, verbosity=None)"
+            )]
+            fn enthalpy_of_vaporization(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                max_iter: Option<usize>,
+                tol: Option<f64>,
+                verbosity: Option<Verbosity>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(
+                    $crate::estimator::EnthalpyOfVaporization::new(
+                        target.clone().try_into()?,
+                        temperature.clone().try_into()?,
+                        Some((max_iter, tol, verbosity).into()),
+                    ),
+                )))
+            }
+
+            /// Create a DataSet with experimental data for the
+            /// second virial coefficient of a pure substance.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the second virial coefficient.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(target, temperature)")]
+            fn second_virial_coefficient(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new(
+                    $crate::estimator::SecondVirialCoefficient::new(
+                        target.clone().try_into()?,
+                        temperature.clone().try_into()?,
+                    ),
+                )))
+            }
+        }
+    };
+}
+
+#[macro_export]
+macro_rules! impl_estimator_dft {
+    ($func:ty, $py_func:ty) => {
+        #[pymethods]
+        impl PyDataSet {
+            /// Create a DataSet with experimental data for the
+            /// surface tension of a pure substance.
+            ///
+            /// Parameters
+            /// ----------
+            /// target : SIArray1
+            ///     Experimental data for the surface tension.
+            /// temperature : SIArray1
+            ///     Temperature for experimental data points.
+            /// method : SurfaceTensionMethod, optional
+            ///     Calculate the surface tension with DFT (default)
+            ///     or with predictive density gradient theory.
+            /// n_grid : int, optional
+            ///     The number of grid points.
+            /// solver : DFTSolver, optional
+            ///     Custom solver options for the DFT calculation.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(target, temperature, method=None, n_grid=None, solver=None)")]
+            fn surface_tension(
+                target: &PySIArray1,
+                temperature: &PySIArray1,
+                method: Option<SurfaceTensionMethod>,
+                n_grid: Option<usize>,
+                solver: Option<PyDFTSolver>,
+            ) -> PyResult<Self> {
+                Ok(Self(Arc::new($crate::estimator::SurfaceTension::new(
+                    target.clone().try_into()?,
+                    temperature.clone().try_into()?,
+                    method.unwrap_or(SurfaceTensionMethod::Dft),
+                    n_grid,
+                    None,
+                    solver.map(|s| s.0),
+                ))))
+            }
+
+            /// Read a DataSet with experimental data from a CSV file.
+            ///
+            /// See `feos.eos.estimator.DataSet.from_csv` for the
+            /// format of the file.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     Path to the CSV file.
+            /// method : SurfaceTensionMethod, optional
+            ///     Method for the calculation of surface tensions.
+            ///
+            /// Returns
+            /// -------
+            /// DataSet
+            #[staticmethod]
+            #[pyo3(text_signature = "(path, method=None)")]
+            fn from_csv(path: &str, method: Option<SurfaceTensionMethod>) -> PyResult<Self> {
+                let data = $crate::estimator::ExperimentalData::from_csv(path)?;
+                Ok(Self(data.data_set_dft(
+                    method.unwrap_or(SurfaceTensionMethod::Dft),
+                )?))
+            }
+
+            /// Read DataSets with experimental data from a ThermoML file.
+            ///
+            /// Parameters
+            /// ----------
+            /// path : str
+            ///     Path to the ThermoML file.
+            /// method : SurfaceTensionMethod, optional
+            ///     Method for the calculation of surface tensions.
+            ///
+            /// Returns
+            /// -------
+            /// List[DataSet]
+            #[staticmethod]
+            #[pyo3(text_signature = "(path, method=None)")]
+            fn from_thermoml(
+                path: &str,
+                method: Option<SurfaceTensionMethod>,
+            ) -> PyResult<Vec<Self>> {
+                let method = method.unwrap_or(SurfaceTensionMethod::Dft);
+                Ok($crate::estimator::ExperimentalData::from_thermoml(path)?
+                    .iter()
+                    .map(|data| Ok(Self(data.data_set_dft(method)?)))
+                    .collect::<Result<_, $crate::estimator::EstimatorError>>()?)
+            }
+        }
+    };
+}
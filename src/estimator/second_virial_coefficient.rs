@@ -0,0 +1,57 @@
+use super::{DataSet, EstimatorError};
+use feos_core::si::{MolarVolume, Temperature, CENTI, METER, MOL};
+use feos_core::Residual;
+use ndarray::Array1;
+use std::sync::Arc;
+use typenum::P3;
+
+/// Store experimental data of second virial coefficients of pure substances.
+///
+/// The relative difference is not meaningful close to the Boyle temperature,
+/// where the second virial coefficient changes its sign.
+#[derive(Clone)]
+pub struct SecondVirialCoefficient {
+    pub target: Array1<f64>,
+    /// unit of the second virial coefficient
+    unit: MolarVolume,
+    /// temperature
+    temperature: Temperature<Array1<f64>>,
+}
+
+impl SecondVirialCoefficient {
+    /// Create a new data set for experimental second virial coefficients.
+    pub fn new(target: MolarVolume<Array1<f64>>, temperature: Temperature<Array1<f64>>) -> Self {
+        let unit = (CENTI * METER).powi::<P3>() / MOL;
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+        }
+    }
+
+    /// Returns temperature of data points.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+}
+
+impl<E: Residual> DataSet<E> for SecondVirialCoefficient {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "second virial coefficient"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        self.temperature
+            .into_iter()
+            .map(|t| Ok((eos.second_virial_coefficient(t, None)? / self.unit).into_value()))
+            .collect()
+    }
+}
@@ -0,0 +1,72 @@
+use super::{DataSet, EstimatorError, Phase};
+use feos_core::si::{Moles, Pressure, Temperature, Velocity, METER, SECOND};
+use feos_core::{DensityInitialization, IdealGas, Residual, State};
+use itertools::izip;
+use ndarray::{arr1, Array1};
+use std::sync::Arc;
+
+/// Store experimental speed of sound data.
+#[derive(Clone)]
+pub struct SpeedOfSound {
+    pub target: Array1<f64>,
+    unit: Velocity,
+    temperature: Temperature<Array1<f64>>,
+    pressure: Pressure<Array1<f64>>,
+    initial_density: Vec<DensityInitialization>,
+}
+
+impl SpeedOfSound {
+    /// Create a new data set for experimental speed of sound data.
+    pub fn new(
+        target: Velocity<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        pressure: Pressure<Array1<f64>>,
+        phase: Option<&Vec<Phase>>,
+    ) -> Self {
+        let n = temperature.len();
+        let unit = METER / SECOND;
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            pressure,
+            initial_density: phase.map_or(vec![DensityInitialization::None; n], |phase| {
+                phase.iter().map(|&p| p.into()).collect()
+            }),
+        }
+    }
+
+    /// Return temperature.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+
+    /// Return pressure.
+    pub fn pressure(&self) -> &Pressure<Array1<f64>> {
+        &self.pressure
+    }
+}
+
+impl<E: Residual + IdealGas> DataSet<E> for SpeedOfSound {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "speed of sound"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature", "pressure"]
+    }
+
+    fn predict(&self, eos: &Arc<E>) -> Result<Array1<f64>, EstimatorError> {
+        let moles = Moles::from_reduced(arr1(&[1.0]));
+        izip!(&self.temperature, &self.pressure, &self.initial_density)
+            .map(|(t, p, &initial_density)| {
+                let state = State::new_npt(eos, t, p, &moles, initial_density)?;
+                Ok((state.speed_of_sound() / self.unit).into_value())
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,142 @@
+use super::{DataSet, EstimatorError};
+use feos_core::si::{self, Temperature, ANGSTROM, METER, MILLI, NEWTON};
+use feos_core::{PhaseEquilibrium, SolverOptions, State};
+use feos_dft::interface::PlanarInterface;
+use feos_dft::{DFTSolver, HelmholtzEnergyFunctional, DFT};
+use ndarray::Array1;
+use std::sync::Arc;
+
+const DEFAULT_GRID_POINTS_DFT: usize = 1024;
+const DEFAULT_GRID_POINTS_PDGT: usize = 50;
+
+/// Methods for the calculation of surface tensions.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+#[cfg_attr(feature = "python", pyo3::pyclass)]
+pub enum SurfaceTensionMethod {
+    /// Density functional theory for a planar vapor-liquid interface.
+    Dft,
+    /// Predictive density gradient theory.
+    Pdgt,
+}
+
+/// Store experimental surface tension data of pure substances.
+///
+/// For every data point, the vapor-liquid equilibrium is calculated at
+/// the experimental temperature. The surface tension of the planar
+/// interface is then obtained either from a full DFT calculation or
+/// from predictive density gradient theory (pDGT).
+///
+/// If the calculation fails, the prediction is set to `NAN`.
+#[derive(Clone)]
+pub struct SurfaceTension {
+    pub target: Array1<f64>,
+    /// unit of the surface tension
+    unit: si::SurfaceTension,
+    /// temperature
+    temperature: Temperature<Array1<f64>>,
+    /// method used for the calculation of the interface
+    method: SurfaceTensionMethod,
+    /// number of grid points
+    n_grid: usize,
+    /// options for VLE solver
+    vle_options: SolverOptions,
+    /// solver for the DFT calculation
+    solver: Option<DFTSolver>,
+}
+
+impl SurfaceTension {
+    /// Create a new data set for experimental surface tensions.
+    ///
+    /// The number of grid points defaults to 1024 for DFT and
+    /// 50 for pDGT calculations.
+    pub fn new(
+        target: si::SurfaceTension<Array1<f64>>,
+        temperature: Temperature<Array1<f64>>,
+        method: SurfaceTensionMethod,
+        n_grid: Option<usize>,
+        vle_options: Option<SolverOptions>,
+        solver: Option<DFTSolver>,
+    ) -> Self {
+        let unit = MILLI * NEWTON / METER;
+        let n_grid = n_grid.unwrap_or(match method {
+            SurfaceTensionMethod::Dft => DEFAULT_GRID_POINTS_DFT,
+            SurfaceTensionMethod::Pdgt => DEFAULT_GRID_POINTS_PDGT,
+        });
+        Self {
+            target: (target / unit).into_value(),
+            unit,
+            temperature,
+            method,
+            n_grid,
+            vle_options: vle_options.unwrap_or_default(),
+            solver,
+        }
+    }
+
+    /// Returns temperature of data points.
+    pub fn temperature(&self) -> &Temperature<Array1<f64>> {
+        &self.temperature
+    }
+
+    /// Returns the method used for the calculation of the surface tension.
+    pub fn method(&self) -> SurfaceTensionMethod {
+        self.method
+    }
+
+    fn surface_tension<F: HelmholtzEnergyFunctional>(
+        &self,
+        vle: &PhaseEquilibrium<DFT<F>, 2>,
+        critical_temperature: Option<Temperature>,
+    ) -> Option<si::SurfaceTension> {
+        let dft = &vle.vapor().eos;
+        match self.method {
+            SurfaceTensionMethod::Pdgt => {
+                let (_, gamma) = dft.solve_pdgt(vle, self.n_grid, 0, None).ok()?;
+                Some(gamma)
+            }
+            SurfaceTensionMethod::Dft => {
+                // initialize with pDGT for single segments and tanh for segment DFT
+                let interface = match critical_temperature {
+                    None => PlanarInterface::from_pdgt(vle, self.n_grid, false).ok()?,
+                    Some(tc) => {
+                        PlanarInterface::from_tanh(vle, self.n_grid, 100.0 * ANGSTROM, tc, false)
+                    }
+                };
+                interface.solve(self.solver.as_ref()).ok()?.surface_tension
+            }
+        }
+    }
+}
+
+impl<F: HelmholtzEnergyFunctional> DataSet<DFT<F>> for SurfaceTension {
+    fn target(&self) -> &Array1<f64> {
+        &self.target
+    }
+
+    fn target_str(&self) -> &str {
+        "surface tension"
+    }
+
+    fn input_str(&self) -> Vec<&str> {
+        vec!["temperature"]
+    }
+
+    fn predict(&self, eos: &Arc<DFT<F>>) -> Result<Array1<f64>, EstimatorError> {
+        let critical_temperature =
+            if self.method == SurfaceTensionMethod::Dft && eos.component_index().len() > 1 {
+                Some(State::critical_point(eos, None, None, self.vle_options)?.temperature)
+            } else {
+                None
+            };
+        Ok(self
+            .temperature
+            .into_iter()
+            .map(|t| {
+                PhaseEquilibrium::pure(eos, t, None, self.vle_options)
+                    .ok()
+                    .and_then(|vle| self.surface_tension(&vle, critical_temperature))
+                    .map_or(f64::NAN, |gamma| (gamma / self.unit).into_value())
+            })
+            .collect())
+    }
+}
@@ -0,0 +1,172 @@
+//! Statistical analysis of regressed model parameters.
+use super::least_squares::{residuals_and_jacobian, LeastSquaresResult};
+use super::{Estimator, EstimatorError};
+use feos_core::{EosError, Residual};
+use ndarray::{Array1, Array2, Axis};
+use num_dual::linalg::LU;
+use std::sync::Arc;
+
+/// Covariance of regressed parameters from the linearization of the
+/// residuals at the optimum.
+///
+/// The covariance matrix is $C=s^2\left(J^TJ\right)^{-1}$ with the Jacobian $J$ of
+/// the weighted residuals $r_i$ and the residual variance $s^2=\frac{\sum_ir_i^2}{m-n}$
+/// for $m$ data points and $n$ parameters.
+#[derive(Clone, Debug)]
+pub struct ParameterUncertainty {
+    /// Parameters at which the covariance is evaluated.
+    pub parameters: Array1<f64>,
+    /// Covariance matrix of the parameters.
+    pub covariance: Array2<f64>,
+    /// Estimate of the variance of the weighted residuals.
+    pub residual_variance: f64,
+    /// Number of data points minus number of parameters.
+    pub degrees_of_freedom: usize,
+}
+
+impl ParameterUncertainty {
+    /// Calculate the covariance from the weighted residuals and
+    /// their Jacobian at the optimal parameters.
+    pub fn new(
+        parameters: &Array1<f64>,
+        residuals: &Array1<f64>,
+        jacobian: &Array2<f64>,
+    ) -> Result<Self, EstimatorError> {
+        let (m, n) = jacobian.dim();
+        if residuals.len() != m || parameters.len() != n {
+            return Err(EstimatorError::IncompatibleInput);
+        }
+        if m <= n {
+            return Err(EstimatorError::InsufficientData);
+        }
+        let degrees_of_freedom = m - n;
+        let residual_variance = residuals.dot(residuals) / degrees_of_freedom as f64;
+        let covariance = LU::new(jacobian.t().dot(jacobian))
+            .map_err(EosError::from)?
+            .inverse()
+            * residual_variance;
+        Ok(Self {
+            parameters: parameters.clone(),
+            covariance,
+            residual_variance,
+            degrees_of_freedom,
+        })
+    }
+
+    /// Standard errors of the parameters.
+    pub fn standard_errors(&self) -> Array1<f64> {
+        self.covariance.diag().mapv(f64::sqrt)
+    }
+
+    /// Correlation matrix of the parameters.
+    pub fn correlation(&self) -> Array2<f64> {
+        let sigma = self.standard_errors();
+        let outer = &sigma.view().insert_axis(Axis(1)) * &sigma.view().insert_axis(Axis(0));
+        &self.covariance / &outer
+    }
+
+    /// Propagate the uncertainty of the parameters to the properties
+    /// calculated by `property`.
+    ///
+    /// Returns the properties at the optimal parameters together with their
+    /// standard deviations $\sigma_g=\sqrt{\mathrm{diag}\left(GCG^T\right)}$, where the
+    /// Jacobian $G$ of the properties is approximated by central differences.
+    pub fn propagate<F>(&self, property: F) -> Result<(Array1<f64>, Array1<f64>), EstimatorError>
+    where
+        F: Fn(&Array1<f64>) -> Result<Array1<f64>, EstimatorError>,
+    {
+        let x = &self.parameters;
+        let value = property(x)?;
+        let mut g = Array2::zeros((value.len(), x.len()));
+        for (i, mut column) in g.axis_iter_mut(Axis(1)).enumerate() {
+            let h = f64::EPSILON.cbrt() * x[i].abs().max(1.0);
+            let mut x_h = x.clone();
+            x_h[i] = x[i] + h;
+            let g_plus = property(&x_h)?;
+            x_h[i] = x[i] - h;
+            let g_minus = property(&x_h)?;
+            column.assign(&((g_plus - g_minus) / (2.0 * h)));
+        }
+        let variance = (&g.dot(&self.covariance) * &g).sum_axis(Axis(1));
+        Ok((value, variance.mapv(f64::sqrt)))
+    }
+}
+
+impl LeastSquaresResult {
+    /// Covariance of the parameters from the residuals and Jacobian at the optimum.
+    pub fn uncertainty(&self) -> Result<ParameterUncertainty, EstimatorError> {
+        ParameterUncertainty::new(&self.parameters, &self.residuals, &self.jacobian)
+    }
+}
+
+impl<E: Residual> Estimator<E> {
+    /// Jacobian of the weighted residuals of all data points with respect to the
+    /// parameters, approximated by forward differences.
+    pub fn jacobian<F>(
+        &self,
+        model: F,
+        parameters: &Array1<f64>,
+    ) -> Result<Array2<f64>, EstimatorError>
+    where
+        F: Fn(&Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let residuals = |x: &Array1<f64>| self.weighted_residuals(&model(x)?);
+        Ok(residuals_and_jacobian(residuals, parameters)?.1)
+    }
+
+    /// Covariance of the parameters that minimize the cost function.
+    ///
+    /// The parameters are typically the result of [Estimator::least_squares],
+    /// but can be obtained from any optimizer.
+    pub fn uncertainty<F>(
+        &self,
+        model: F,
+        parameters: &Array1<f64>,
+    ) -> Result<ParameterUncertainty, EstimatorError>
+    where
+        F: Fn(&Array1<f64>) -> Result<Arc<E>, EstimatorError>,
+    {
+        let residuals = |x: &Array1<f64>| self.weighted_residuals(&model(x)?);
+        let (residuals, jacobian) = residuals_and_jacobian(residuals, parameters)?;
+        ParameterUncertainty::new(parameters, &residuals, &jacobian)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use approx::assert_relative_eq;
+    use ndarray::{arr1, arr2, Array};
+
+    #[test]
+    fn test_straight_line() -> Result<(), EstimatorError> {
+        // residuals of y = a * t + b are linear in the parameters
+        let t = Array::linspace(0.0, 4.0, 5);
+        let y = arr1(&[1.1, 2.9, 5.2, 6.8, 9.1]);
+        let jacobian = Array2::from_shape_fn((5, 2), |(i, j)| if j == 0 { t[i] } else { 1.0 });
+        let parameters = arr1(&[1.99, 1.04]);
+        let residuals = &t * parameters[0] + parameters[1] - &y;
+        let uncertainty = ParameterUncertainty::new(&parameters, &residuals, &jacobian)?;
+
+        let s2 = residuals.dot(&residuals) / 3.0;
+        let sxx = 10.0;
+        assert_relative_eq!(uncertainty.residual_variance, s2, max_relative = 1e-12);
+        assert_relative_eq!(
+            uncertainty.standard_errors(),
+            arr1(&[(s2 / sxx).sqrt(), (s2 * (0.2 + 4.0 / sxx)).sqrt()]),
+            max_relative = 1e-12
+        );
+        let rho = -2.0 / (sxx / 5.0 + 4.0).sqrt();
+        assert_relative_eq!(
+            uncertainty.correlation(),
+            arr2(&[[1.0, rho], [rho, 1.0]]),
+            max_relative = 1e-12
+        );
+
+        // prediction at t = 2 has the variance s^2 / 5
+        let (value, sigma) = uncertainty.propagate(|x| Ok(arr1(&[2.0 * x[0] + x[1]])))?;
+        assert_relative_eq!(value[0], 5.02, max_relative = 1e-12);
+        assert_relative_eq!(sigma[0], (s2 / 5.0).sqrt(), max_relative = 1e-8);
+        Ok(())
+    }
+}
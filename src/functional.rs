@@ -1,3 +1,5 @@
+#[cfg(feature = "epcsaft")]
+use crate::epcsaft::{ElectrolytePcSaftFunctional, ElectrolytePcSaftFunctionalContribution};
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::{GcPcSaftFunctional, GcPcSaftFunctionalContribution};
 use crate::hard_sphere::{FMTContribution, FMTFunctional, HardSphereParameters};
@@ -5,6 +7,8 @@ use crate::hard_sphere::{FMTContribution, FMTFunctional, HardSphereParameters};
 use crate::pcsaft::{PcSaftFunctional, PcSaftFunctionalContribution};
 #[cfg(feature = "pets")]
 use crate::pets::{PetsFunctional, PetsFunctionalContribution};
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::{SaftVRMieFunctional, SaftVRMieFunctionalContribution};
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::{SaftVRQMieFunctional, SaftVRQMieFunctionalContribution};
 use feos_core::si::MolarWeight;
@@ -39,6 +43,12 @@ pub enum FunctionalVariant {
     #[cfg(feature = "saftvrqmie")]
     #[implement(fluid_parameters, molar_weight, pair_potential)]
     SaftVRQMie(SaftVRQMieFunctional),
+    #[cfg(feature = "saftvrmie")]
+    #[implement(fluid_parameters, molar_weight, pair_potential)]
+    SaftVRMie(SaftVRMieFunctional),
+    #[cfg(feature = "epcsaft")]
+    #[implement(fluid_parameters, molar_weight, electrostatics)]
+    ElectrolytePcSaft(ElectrolytePcSaftFunctional),
 }
 
 #[derive(FunctionalContribution)]
@@ -52,4 +62,8 @@ pub enum FunctionalContributionVariant {
     Fmt(FMTContribution<HardSphereParameters>),
     #[cfg(feature = "saftvrqmie")]
     SaftVRQMieFunctional(SaftVRQMieFunctionalContribution),
+    #[cfg(feature = "saftvrmie")]
+    SaftVRMieFunctional(SaftVRMieFunctionalContribution),
+    #[cfg(feature = "epcsaft")]
+    ElectrolytePcSaftFunctional(ElectrolytePcSaftFunctionalContribution),
 }
@@ -13,6 +13,10 @@ use std::sync::Arc;
 const PSI_GC_DFT: f64 = 1.5357;
 
 /// Parameter set required for the gc-PC-SAFT Helmholtz energy functional.
+///
+/// The binary interaction parameters between segments are constant, a
+/// [KijTemperatureDependence](crate::binary_interaction::KijTemperatureDependence)
+/// is not supported.
 pub struct GcPcSaftFunctionalParameters {
     pub molarweight: Array1<f64>,
     pub component_index: Array1<usize>,
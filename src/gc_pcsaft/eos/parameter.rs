@@ -63,6 +63,10 @@ impl From<ChemicalRecord> for GcPcSaftChemicalRecord {
 }
 
 /// Parameter set required for the gc-PC-SAFT equation of state.
+///
+/// The binary interaction parameters between segments are constant, a
+/// [KijTemperatureDependence](crate::binary_interaction::KijTemperatureDependence)
+/// is not supported.
 #[derive(Clone)]
 pub struct GcPcSaftEosParameters {
     pub molarweight: Array1<f64>,
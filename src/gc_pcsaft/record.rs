@@ -1,4 +1,5 @@
 use crate::association::AssociationRecord;
+use feos_core::parameter::{FitBounds, FitRecord, ParameterError};
 use num_traits::Zero;
 use serde::{Deserialize, Serialize};
 
@@ -80,6 +81,62 @@ impl std::fmt::Display for GcPcSaftRecord {
     }
 }
 
+impl FitRecord for GcPcSaftRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["m", "sigma", "epsilon_k"];
+        if self.mu.is_some() {
+            names.push("mu");
+        }
+        if self.association_record.is_some() {
+            names.extend(["kappa_ab", "epsilon_k_ab"]);
+        }
+        if self.psi_dft.is_some() {
+            names.push("psi_dft");
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association_record.map(|r| r.parameters);
+        match name {
+            "m" => Some(self.m),
+            "sigma" => Some(self.sigma),
+            "epsilon_k" => Some(self.epsilon_k),
+            "mu" => self.mu,
+            "kappa_ab" => association.map(|a| a.kappa_ab),
+            "epsilon_k_ab" => association.map(|a| a.epsilon_k_ab),
+            "psi_dft" => self.psi_dft,
+            _ => None,
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association_record.as_mut().map(|r| &mut r.parameters);
+        let parameter = match name {
+            "m" => Some(&mut self.m),
+            "sigma" => Some(&mut self.sigma),
+            "epsilon_k" => Some(&mut self.epsilon_k),
+            "mu" => self.mu.as_mut(),
+            "kappa_ab" => association.map(|a| &mut a.kappa_ab),
+            "epsilon_k_ab" => association.map(|a| &mut a.epsilon_k_ab),
+            "psi_dft" => self.psi_dft.as_mut(),
+            _ => None,
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "epsilon_k" => FitBounds::positive(100.0),
+            "kappa_ab" => FitBounds::positive(0.01),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            _ => FitBounds::positive(1.0),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Default)]
 pub struct GcPcSaftAssociationRecord {
     /// Association volume parameter
@@ -121,15 +121,9 @@ impl<P: HardSphereProperties> HardSphere<P> {
                     * (geometry_coefficients[k as usize][i] * FRAC_PI_6);
             }
         }
-        let zeta_23 = zeta[2] / zeta[3];
         let density = state.partial_density.sum();
-        zeta.iter_mut().for_each(|z| *z *= density);
-        let frac_1mz3 = -(zeta[3] - 1.0).recip();
-        let a = state.volume / std::f64::consts::FRAC_PI_6
-            * (zeta[1] * zeta[2] * frac_1mz3 * 3.0
-                + zeta[2].powi(2) * frac_1mz3.powi(2) * zeta_23
-                + (zeta[2] * zeta_23.powi(2) - zeta[0]) * (zeta[3] * (-1.0)).ln_1p());
-        (a, zeta, diameter)
+        let (a, zeta) = bmcsl_helmholtz_energy_density(zeta, density);
+        (a * state.volume, zeta, diameter)
     }
 
     #[inline]
@@ -138,6 +132,26 @@ impl<P: HardSphereProperties> HardSphere<P> {
     }
 }
 
+/// Helmholtz energy density of the BMCSL equation of state and the packing
+/// fractions $\zeta_k$ for the packing fractions per density `zeta_x`,
+/// i.e., calculated with mole fractions instead of partial densities.
+///
+/// Using `zeta_x` avoids the division by zero in $\frac{\zeta_2}{\zeta_3}$
+/// at vanishing density.
+pub(crate) fn bmcsl_helmholtz_energy_density<D: DualNum<f64> + Copy>(
+    zeta_x: [D; 4],
+    density: D,
+) -> (D, [D; 4]) {
+    let zeta_23 = zeta_x[2] / zeta_x[3];
+    let zeta = zeta_x.map(|z| z * density);
+    let frac_1mz3 = -(zeta[3] - 1.0).recip();
+    let a = (zeta[1] * zeta[2] * frac_1mz3 * 3.0
+        + zeta[2].powi(2) * frac_1mz3.powi(2) * zeta_23
+        + (zeta[2] * zeta_23.powi(2) - zeta[0]) * (zeta[3] * (-1.0)).ln_1p())
+        / FRAC_PI_6;
+    (a, zeta)
+}
+
 impl<P> fmt::Display for HardSphere<P> {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "Hard Sphere")
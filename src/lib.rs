@@ -47,6 +47,7 @@ pub mod estimator;
 
 #[cfg(feature = "association")]
 pub mod association;
+pub mod binary_interaction;
 pub mod hard_sphere;
 
 // models
@@ -54,6 +55,8 @@ pub mod hard_sphere;
 pub mod epcsaft;
 #[cfg(feature = "gc_pcsaft")]
 pub mod gc_pcsaft;
+#[cfg(feature = "multiparameter")]
+pub mod multiparameter;
 #[cfg(feature = "pcsaft")]
 pub mod pcsaft;
 #[cfg(feature = "pets")]
@@ -0,0 +1,202 @@
+use super::parameters::{
+    DepartureFunction, MultiparameterParameters, ResidualHelmholtzTerm, MOL_PER_M3,
+};
+use feos_core::parameter::Parameter;
+use feos_core::si::{MolarWeight, GRAM, MOL};
+use feos_core::{Components, Residual, StateHD};
+use ndarray::{Array1, ScalarOperand};
+use num_dual::DualNum;
+use std::sync::Arc;
+
+/// Power with an integer exponent where possible, so that
+/// vanishing densities (e.g., for virial coefficients) are handled correctly.
+fn pow<D: DualNum<f64> + Copy>(x: D, exponent: f64) -> D {
+    if exponent.fract() == 0.0 {
+        x.powi(exponent as i32)
+    } else {
+        x.powf(exponent)
+    }
+}
+
+impl ResidualHelmholtzTerm {
+    fn alphar<D: DualNum<f64> + Copy>(&self, delta: D, tau: D) -> D {
+        let mut res = D::zero();
+        match self {
+            Self::Power { n, d, t, l } => {
+                for k in 0..n.len() {
+                    let mut term = pow(delta, d[k]) * pow(tau, t[k]) * n[k];
+                    if l[k] != 0.0 {
+                        term *= (-pow(delta, l[k])).exp();
+                    }
+                    res += term;
+                }
+            }
+            Self::Exponential { n, d, t, g, l } => {
+                for k in 0..n.len() {
+                    res +=
+                        pow(delta, d[k]) * pow(tau, t[k]) * (-pow(delta, l[k]) * g[k]).exp() * n[k];
+                }
+            }
+            Self::Gaussian {
+                n,
+                d,
+                t,
+                eta,
+                epsilon,
+                beta,
+                gamma,
+            } => {
+                for k in 0..n.len() {
+                    let exponent =
+                        -(delta - epsilon[k]).powi(2) * eta[k] - (tau - gamma[k]).powi(2) * beta[k];
+                    res += pow(delta, d[k]) * pow(tau, t[k]) * exponent.exp() * n[k];
+                }
+            }
+        }
+        res
+    }
+}
+
+impl DepartureFunction {
+    fn alphar<D: DualNum<f64> + Copy>(&self, delta: D, tau: D) -> D {
+        let mut res = D::zero();
+        match self {
+            Self::Gerg2008 {
+                n,
+                d,
+                t,
+                eta,
+                epsilon,
+                beta,
+                gamma,
+                n_power,
+            } => {
+                for k in 0..n.len() {
+                    let mut term = pow(delta, d[k]) * pow(tau, t[k]) * n[k];
+                    if k >= *n_power {
+                        term *= (-(delta - epsilon[k]).powi(2) * eta[k]
+                            - (delta - gamma[k]) * beta[k])
+                            .exp();
+                    }
+                    res += term;
+                }
+            }
+            Self::Exponential { n, d, t, l } => {
+                for k in 0..n.len() {
+                    let mut term = pow(delta, d[k]) * pow(tau, t[k]) * n[k];
+                    if l[k] != 0.0 {
+                        term *= (-pow(delta, l[k])).exp();
+                    }
+                    res += term;
+                }
+            }
+        }
+        res
+    }
+}
+
+/// Multiparameter equation of state.
+///
+/// The residual Helmholtz energy of a mixture is given by
+/// $$\alpha^\mathrm{r}=\sum_ix_i\alpha^\mathrm{r}_{i}(\delta,\tau)+\sum_i\sum_{j>i}x_ix_jF_{ij}\alpha_{ij}(\delta,\tau)$$
+/// with the reduced density $\delta=\frac{\rho}{\rho_\mathrm{r}}$ and the inverse reduced
+/// temperature $\tau=\frac{T_\mathrm{r}}{T}$ calculated from the reducing functions of the
+/// [GERG-2008](https://doi.org/10.1021/je300655b) model (see [MultiparameterBinaryRecord](super::MultiparameterBinaryRecord)).
+///
+/// The Helmholtz energy is made dimensionless with the ideal gas constant used in
+/// FeOs which can differ from the value used to parametrize the model.
+pub struct Multiparameter {
+    pub parameters: Arc<MultiparameterParameters>,
+}
+
+impl Multiparameter {
+    /// Create a new multiparameter equation of state from a set of parameters.
+    pub fn new(parameters: Arc<MultiparameterParameters>) -> Self {
+        Self { parameters }
+    }
+
+    /// Reducing temperature (in K) and reducing density (in mol/m³) of the mixture.
+    pub fn reducing_functions<D: DualNum<f64> + Copy>(&self, molefracs: &Array1<D>) -> (D, D) {
+        let p = &self.parameters;
+        let n = molefracs.len();
+        let mut t_r = D::zero();
+        let mut v_r = D::zero();
+        for i in 0..n {
+            let xi = molefracs[i];
+            t_r += xi * xi * p.t_reducing[i];
+            v_r += xi * xi / p.rhomolar_reducing[i];
+            for j in i + 1..n {
+                let xj = molefracs[j];
+                if xi.re() + xj.re() == 0.0 {
+                    continue;
+                }
+                let t_ij = (p.t_reducing[i] * p.t_reducing[j]).sqrt();
+                let v_ij = (p.rhomolar_reducing[i].cbrt().recip()
+                    + p.rhomolar_reducing[j].cbrt().recip())
+                .powi(3)
+                    / 8.0;
+                let (beta_t, gamma_t) = (p.beta_t[(i, j)], p.gamma_t[(i, j)]);
+                let (beta_v, gamma_v) = (p.beta_v[(i, j)], p.gamma_v[(i, j)]);
+                t_r += xi * xj * (xi + xj) / (xi * beta_t.powi(2) + xj)
+                    * (2.0 * beta_t * gamma_t * t_ij);
+                v_r += xi * xj * (xi + xj) / (xi * beta_v.powi(2) + xj)
+                    * (2.0 * beta_v * gamma_v * v_ij);
+            }
+        }
+        (t_r, v_r.recip())
+    }
+}
+
+impl Components for Multiparameter {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::new(Arc::new(self.parameters.subset(component_list)))
+    }
+}
+
+impl Residual for Multiparameter {
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        let v_max = (moles / &self.parameters.rhomolar_max).sum() / moles.sum();
+        v_max.recip() / MOL_PER_M3
+    }
+
+    fn residual_helmholtz_energy_contributions<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        state: &StateHD<D>,
+    ) -> Vec<(String, D)> {
+        let p = &self.parameters;
+        let x = &state.molefracs;
+        let (t_r, rho_r) = self.reducing_functions(x);
+        let delta = state.partial_density.sum() * MOL_PER_M3 / rho_r;
+        let tau = t_r / state.temperature;
+        let n = state.moles.sum();
+
+        let mut alphar = D::zero();
+        for (i, record) in p.pure_records.iter().enumerate() {
+            for term in &record.model_record.alphar {
+                alphar += x[i] * term.alphar(delta, tau);
+            }
+        }
+        let mut contributions = vec![("Multiparameter".to_string(), n * alphar)];
+
+        if p.f.iter().any(|&f| f != 0.0) {
+            let mut departure = D::zero();
+            for i in 0..x.len() {
+                for j in i + 1..x.len() {
+                    if let Some(d) = &p.binary_records[(i, j)].departure {
+                        departure += x[i] * x[j] * p.f[(i, j)] * d.alphar(delta, tau);
+                    }
+                }
+            }
+            contributions.push(("Departure function".to_string(), n * departure));
+        }
+        contributions
+    }
+
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+}
@@ -0,0 +1,24 @@
+//! Multiparameter Helmholtz energy equations of state.
+//!
+//! Reference equations of state, e.g., of the
+//! [Span and Wagner (2003)](https://doi.org/10.1023/A:1022390430888) type,
+//! represent the residual Helmholtz energy of a pure substance as a sum of
+//! polynomial, exponential and Gaussian terms in reduced density and
+//! inverse reduced temperature. Mixtures are described with the reducing
+//! functions and departure functions of the
+//! [GERG-2008](https://doi.org/10.1021/je300655b) model.
+//!
+//! Parameters can be read from fluid files in the layout used by
+//! [CoolProp](http://www.coolprop.org) with
+//! [MultiparameterParameters::from_coolprop_json].
+mod eos;
+mod parameters;
+
+pub use eos::Multiparameter;
+pub use parameters::{
+    DepartureFunction, MultiparameterBinaryRecord, MultiparameterParameters, MultiparameterRecord,
+    ResidualHelmholtzTerm,
+};
+
+#[cfg(feature = "python")]
+pub mod python;
@@ -0,0 +1,563 @@
+use feos_core::parameter::{
+    oriented_binary_matrix, BinaryRecord, Identifier, IdentifierOption, Parameter, ParameterError,
+    PureRecord,
+};
+use ndarray::{Array1, Array2};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::fmt;
+use std::fs::File;
+use std::io::BufReader;
+use std::path::Path;
+
+/// Conversion factor from a number density in 1/Å³ to a molar density in mol/m³.
+pub(super) const MOL_PER_M3: f64 = 1e30 / 6.02214076e23;
+
+/// A sum of terms of the residual Helmholtz energy of a pure substance.
+///
+/// The terms are functions of the reduced density $\delta=\frac{\rho}{\rho_\mathrm{r}}$
+/// and the inverse reduced temperature $\tau=\frac{T_\mathrm{r}}{T}$. The layout
+/// follows the fluid files of [CoolProp](http://www.coolprop.org).
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum ResidualHelmholtzTerm {
+    /// $$\alpha^\mathrm{r}=\sum_kn_k\delta^{d_k}\tau^{t_k}\exp\left(-\delta^{l_k}\right)$$
+    /// where the exponential is omitted for $l_k=0$.
+    #[serde(rename = "ResidualHelmholtzPower")]
+    Power {
+        n: Vec<f64>,
+        d: Vec<f64>,
+        t: Vec<f64>,
+        l: Vec<f64>,
+    },
+    /// $$\alpha^\mathrm{r}=\sum_kn_k\delta^{d_k}\tau^{t_k}\exp\left(-g_k\delta^{l_k}\right)$$
+    #[serde(rename = "ResidualHelmholtzExponential")]
+    Exponential {
+        n: Vec<f64>,
+        d: Vec<f64>,
+        t: Vec<f64>,
+        g: Vec<f64>,
+        l: Vec<f64>,
+    },
+    /// $$\alpha^\mathrm{r}=\sum_kn_k\delta^{d_k}\tau^{t_k}\exp\left(-\eta_k\left(\delta-\varepsilon_k\right)^2-\beta_k\left(\tau-\gamma_k\right)^2\right)$$
+    #[serde(rename = "ResidualHelmholtzGaussian")]
+    Gaussian {
+        n: Vec<f64>,
+        d: Vec<f64>,
+        t: Vec<f64>,
+        eta: Vec<f64>,
+        epsilon: Vec<f64>,
+        beta: Vec<f64>,
+        gamma: Vec<f64>,
+    },
+}
+
+impl ResidualHelmholtzTerm {
+    fn name(&self) -> &'static str {
+        match self {
+            Self::Power { .. } => "Power",
+            Self::Exponential { .. } => "Exponential",
+            Self::Gaussian { .. } => "Gaussian",
+        }
+    }
+
+    fn coefficients(&self) -> Vec<&Vec<f64>> {
+        match self {
+            Self::Power { n, d, t, l } => vec![n, d, t, l],
+            Self::Exponential { n, d, t, g, l } => vec![n, d, t, g, l],
+            Self::Gaussian {
+                n,
+                d,
+                t,
+                eta,
+                epsilon,
+                beta,
+                gamma,
+            } => vec![n, d, t, eta, epsilon, beta, gamma],
+        }
+    }
+
+    /// Number of individual terms.
+    pub fn len(&self) -> usize {
+        self.coefficients()[0].len()
+    }
+
+    /// Returns `true` if the sum does not contain any terms.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+/// Departure function of a binary mixture in the
+/// [GERG-2008](https://doi.org/10.1021/je300655b) framework.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(tag = "type")]
+pub enum DepartureFunction {
+    /// $$\alpha_{ij}=\sum_{k<N_\mathrm{pow}}n_k\delta^{d_k}\tau^{t_k}+\sum_{k\geq N_\mathrm{pow}}n_k\delta^{d_k}\tau^{t_k}\exp\left(-\eta_k\left(\delta-\varepsilon_k\right)^2-\beta_k\left(\delta-\gamma_k\right)\right)$$
+    #[serde(rename = "GERG-2008")]
+    Gerg2008 {
+        n: Vec<f64>,
+        d: Vec<f64>,
+        t: Vec<f64>,
+        eta: Vec<f64>,
+        epsilon: Vec<f64>,
+        beta: Vec<f64>,
+        gamma: Vec<f64>,
+        #[serde(rename = "Npower")]
+        n_power: usize,
+    },
+    /// $$\alpha_{ij}=\sum_kn_k\delta^{d_k}\tau^{t_k}\exp\left(-\delta^{l_k}\right)$$
+    /// where the exponential is omitted for $l_k=0$.
+    Exponential {
+        n: Vec<f64>,
+        d: Vec<f64>,
+        t: Vec<f64>,
+        l: Vec<f64>,
+    },
+}
+
+impl DepartureFunction {
+    fn validate(&self) -> Result<(), ParameterError> {
+        let (coefficients, n_power) = match self {
+            Self::Gerg2008 {
+                n,
+                d,
+                t,
+                eta,
+                epsilon,
+                beta,
+                gamma,
+                n_power,
+            } => (vec![n, d, t, eta, epsilon, beta, gamma], *n_power),
+            Self::Exponential { n, d, t, l } => (vec![n, d, t, l], 0),
+        };
+        let len = coefficients[0].len();
+        if coefficients.iter().any(|c| c.len() != len) || n_power > len {
+            return Err(ParameterError::IncompatibleParameters(
+                "the coefficients of a departure function must have the same length.".into(),
+            ));
+        }
+        Ok(())
+    }
+}
+
+/// Multiparameter equation of state of a pure substance.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MultiparameterRecord {
+    /// reducing temperature in units of K
+    pub t_reducing: f64,
+    /// reducing density in units of mol/m³
+    pub rhomolar_reducing: f64,
+    /// maximum (e.g., liquid triple point) density in units of mol/m³
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rhomolar_max: Option<f64>,
+    /// terms of the residual Helmholtz energy
+    pub alphar: Vec<ResidualHelmholtzTerm>,
+}
+
+impl MultiparameterRecord {
+    /// Create a new pure substance record for a multiparameter equation of state.
+    pub fn new(
+        t_reducing: f64,
+        rhomolar_reducing: f64,
+        rhomolar_max: Option<f64>,
+        alphar: Vec<ResidualHelmholtzTerm>,
+    ) -> Self {
+        Self {
+            t_reducing,
+            rhomolar_reducing,
+            rhomolar_max,
+            alphar,
+        }
+    }
+}
+
+impl Default for MultiparameterRecord {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, None, Vec::new())
+    }
+}
+
+impl fmt::Display for MultiparameterRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MultiparameterRecord(t_reducing={} K", self.t_reducing)?;
+        write!(f, ", rhomolar_reducing={} mol/m³", self.rhomolar_reducing)?;
+        if let Some(rhomolar_max) = self.rhomolar_max {
+            write!(f, ", rhomolar_max={} mol/m³", rhomolar_max)?;
+        }
+        let terms: Vec<_> = self
+            .alphar
+            .iter()
+            .map(|term| format!("{}({})", term.name(), term.len()))
+            .collect();
+        write!(f, ", alphar=[{}])", terms.join(", "))
+    }
+}
+
+/// Binary interaction parameters of the GERG-2008 mixing rules.
+///
+/// The reducing functions for a record of components $i$ and $j$ are
+/// $$T_\mathrm{r}=\sum_ix_i^2T_{\mathrm{r},i}+\sum_i\sum_{j>i}2x_ix_j\beta_{T,ij}\gamma_{T,ij}\frac{x_i+x_j}{\beta_{T,ij}^2x_i+x_j}\sqrt{T_{\mathrm{r},i}T_{\mathrm{r},j}}$$
+/// $$\frac{1}{\rho_\mathrm{r}}=\sum_i\frac{x_i^2}{\rho_{\mathrm{r},i}}+\sum_i\sum_{j>i}2x_ix_j\beta_{v,ij}\gamma_{v,ij}\frac{x_i+x_j}{\beta_{v,ij}^2x_i+x_j}\frac{1}{8}\left(\rho_{\mathrm{r},i}^{-1/3}+\rho_{\mathrm{r},j}^{-1/3}\right)^3$$
+/// and the departure function is weighted with $F_{ij}$.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+#[serde(default)]
+pub struct MultiparameterBinaryRecord {
+    #[serde(rename = "betaT")]
+    pub beta_t: f64,
+    #[serde(rename = "gammaT")]
+    pub gamma_t: f64,
+    #[serde(rename = "betaV")]
+    pub beta_v: f64,
+    #[serde(rename = "gammaV")]
+    pub gamma_v: f64,
+    #[serde(rename = "F")]
+    pub f: f64,
+    /// departure function (required if $F_{ij}\neq0$)
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub departure: Option<DepartureFunction>,
+}
+
+impl MultiparameterBinaryRecord {
+    /// Create a new binary record without departure function.
+    pub fn new(beta_t: f64, gamma_t: f64, beta_v: f64, gamma_v: f64) -> Self {
+        Self {
+            beta_t,
+            gamma_t,
+            beta_v,
+            gamma_v,
+            f: 0.0,
+            departure: None,
+        }
+    }
+
+    /// Add a departure function with weight `f` to the binary record.
+    pub fn departure(mut self, f: f64, departure: DepartureFunction) -> Self {
+        self.f = f;
+        self.departure = Some(departure);
+        self
+    }
+
+    /// The same interactions for the components in reversed order.
+    fn reversed(&self) -> Self {
+        Self {
+            beta_t: self.beta_t.recip(),
+            beta_v: self.beta_v.recip(),
+            ..self.clone()
+        }
+    }
+}
+
+impl Default for MultiparameterBinaryRecord {
+    fn default() -> Self {
+        Self::new(1.0, 1.0, 1.0, 1.0)
+    }
+}
+
+impl fmt::Display for MultiparameterBinaryRecord {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "MultiparameterBinaryRecord(beta_t={}", self.beta_t)?;
+        write!(f, ", gamma_t={}", self.gamma_t)?;
+        write!(f, ", beta_v={}", self.beta_v)?;
+        write!(f, ", gamma_v={}", self.gamma_v)?;
+        write!(f, ", f={})", self.f)
+    }
+}
+
+/// Parameters of multiparameter equations of state for one or more substances.
+pub struct MultiparameterParameters {
+    /// molar weight in units of g/mol
+    pub molarweight: Array1<f64>,
+    /// reducing temperatures in units of K
+    pub t_reducing: Array1<f64>,
+    /// reducing densities in units of mol/m³
+    pub rhomolar_reducing: Array1<f64>,
+    /// maximum densities in units of mol/m³
+    pub rhomolar_max: Array1<f64>,
+    /// binary parameters of the reducing temperature
+    pub beta_t: Array2<f64>,
+    pub gamma_t: Array2<f64>,
+    /// binary parameters of the reducing density
+    pub beta_v: Array2<f64>,
+    pub gamma_v: Array2<f64>,
+    /// weights of the departure functions
+    pub f: Array2<f64>,
+    pub pure_records: Vec<PureRecord<MultiparameterRecord>>,
+    pub binary_records: Array2<MultiparameterBinaryRecord>,
+}
+
+impl Parameter for MultiparameterParameters {
+    type Pure = MultiparameterRecord;
+    type Binary = MultiparameterBinaryRecord;
+
+    /// Creates parameters from records.
+    ///
+    /// The binary records above the diagonal determine the interactions;
+    /// the records below the diagonal are replaced by their reversed counterparts.
+    fn from_records(
+        pure_records: Vec<PureRecord<Self::Pure>>,
+        binary_records: Option<Array2<Self::Binary>>,
+    ) -> Result<Self, ParameterError> {
+        for record in &pure_records {
+            for term in &record.model_record.alphar {
+                let coefficients = term.coefficients();
+                if coefficients.iter().any(|c| c.len() != term.len()) {
+                    return Err(ParameterError::IncompatibleParameters(format!(
+                        "the coefficients of the {} terms of {} must have the same length.",
+                        term.name(),
+                        record.identifier
+                    )));
+                }
+            }
+        }
+
+        let n = pure_records.len();
+        let mut binary_records = binary_records.unwrap_or_else(|| Array2::default([n, n]));
+        for i in 0..n {
+            binary_records[(i, i)] = MultiparameterBinaryRecord::default();
+            for j in i + 1..n {
+                let br = &binary_records[(i, j)];
+                match &br.departure {
+                    Some(departure) => departure.validate()?,
+                    None if br.f != 0.0 => {
+                        return Err(ParameterError::IncompatibleParameters(format!(
+                            "no departure function provided for {} and {}.",
+                            pure_records[i].identifier, pure_records[j].identifier
+                        )))
+                    }
+                    None => (),
+                }
+                binary_records[(j, i)] = br.reversed();
+            }
+        }
+
+        let molarweight = pure_records.iter().map(|pr| pr.molarweight).collect();
+        let t_reducing = pure_records
+            .iter()
+            .map(|pr| pr.model_record.t_reducing)
+            .collect();
+        let rhomolar_reducing: Array1<f64> = pure_records
+            .iter()
+            .map(|pr| pr.model_record.rhomolar_reducing)
+            .collect();
+        let rhomolar_max = pure_records
+            .iter()
+            .zip(&rhomolar_reducing)
+            .map(|(pr, &rho)| pr.model_record.rhomolar_max.unwrap_or(3.0 * rho))
+            .collect();
+
+        Ok(Self {
+            molarweight,
+            t_reducing,
+            rhomolar_reducing,
+            rhomolar_max,
+            beta_t: binary_records.map(|br| br.beta_t),
+            gamma_t: binary_records.map(|br| br.gamma_t),
+            beta_v: binary_records.map(|br| br.beta_v),
+            gamma_v: binary_records.map(|br| br.gamma_v),
+            f: binary_records.map(|br| br.f),
+            pure_records,
+            binary_records,
+        })
+    }
+
+    fn records(
+        &self,
+    ) -> (
+        &[PureRecord<MultiparameterRecord>],
+        Option<&Array2<MultiparameterBinaryRecord>>,
+    ) {
+        (&self.pure_records, Some(&self.binary_records))
+    }
+
+    /// Binary records are oriented: if a record is found with reversed
+    /// identifiers, the reversed interactions are used.
+    fn binary_matrix_from_records(
+        pure_records: &[PureRecord<Self::Pure>],
+        binary_records: &[BinaryRecord<Identifier, Self::Binary>],
+        identifier_option: IdentifierOption,
+    ) -> Option<Array2<Self::Binary>> {
+        oriented_binary_matrix(
+            pure_records,
+            binary_records,
+            identifier_option,
+            MultiparameterBinaryRecord::reversed,
+        )
+    }
+}
+
+#[derive(Deserialize)]
+struct CoolPropFluid {
+    #[serde(rename = "INFO")]
+    info: CoolPropInfo,
+    #[serde(rename = "EOS")]
+    eos: Vec<CoolPropEos>,
+}
+
+#[derive(Deserialize)]
+struct CoolPropInfo {
+    #[serde(rename = "NAME")]
+    name: String,
+    #[serde(rename = "CAS")]
+    cas: String,
+}
+
+#[derive(Deserialize)]
+struct CoolPropEos {
+    #[serde(rename = "STATES")]
+    states: CoolPropStates,
+    alphar: Vec<Value>,
+    /// molar mass in units of kg/mol
+    molar_mass: f64,
+}
+
+#[derive(Deserialize)]
+struct CoolPropStates {
+    reducing: CoolPropState,
+    triple_liquid: Option<CoolPropState>,
+}
+
+#[derive(Deserialize)]
+struct CoolPropState {
+    rhomolar: f64,
+    #[serde(rename = "T")]
+    temperature: f64,
+}
+
+impl MultiparameterParameters {
+    /// Read parameters from fluid files in the layout used by CoolProp.
+    ///
+    /// Only the first equation of state in each fluid file is used.
+    /// Its residual Helmholtz energy can only consist of power, exponential
+    /// and Gaussian terms. Other term types, e.g., the non-analytic terms
+    /// of water and carbon dioxide, result in an error that names the term type.
+    /// Binary interaction parameters (`mixture_binary_pairs.json`) are
+    /// matched via the CAS numbers of the components. Departure functions
+    /// (`mixture_departure_functions.json`) are matched via the name
+    /// given in the binary interaction parameters.
+    pub fn from_coolprop_json<P: AsRef<Path>>(
+        fluid_paths: &[P],
+        binary_path: Option<P>,
+        departure_path: Option<P>,
+    ) -> Result<Self, ParameterError> {
+        let pure_records = fluid_paths
+            .iter()
+            .map(|path| {
+                let fluid: CoolPropFluid = serde_json::from_reader(open(path)?)?;
+                let eos = fluid
+                    .eos
+                    .into_iter()
+                    .next()
+                    .ok_or(ParameterError::InsufficientInformation)?;
+                let alphar = eos
+                    .alphar
+                    .into_iter()
+                    .map(|term| residual_helmholtz_term(term, &fluid.info.name))
+                    .collect::<Result<_, _>>()?;
+                let record = MultiparameterRecord::new(
+                    eos.states.reducing.temperature,
+                    eos.states.reducing.rhomolar,
+                    eos.states.triple_liquid.map(|s| s.rhomolar),
+                    alphar,
+                );
+                let identifier = Identifier::new(
+                    Some(&fluid.info.cas),
+                    Some(&fluid.info.name),
+                    None,
+                    None,
+                    None,
+                    None,
+                );
+                Ok(PureRecord::new(identifier, eos.molar_mass * 1000.0, record))
+            })
+            .collect::<Result<Vec<_>, ParameterError>>()?;
+
+        let n = pure_records.len();
+        let binary_records = if let Some(binary_path) = binary_path {
+            let pairs: Vec<Value> = serde_json::from_reader(open(binary_path)?)?;
+            let departures: Vec<Value> = match departure_path {
+                Some(path) => serde_json::from_reader(open(path)?)?,
+                None => Vec::new(),
+            };
+            let mut binary_records = Array2::default([n, n]);
+            for i in 0..n {
+                for j in i + 1..n {
+                    let (cas_i, cas_j) = (
+                        pure_records[i].identifier.cas.as_deref(),
+                        pure_records[j].identifier.cas.as_deref(),
+                    );
+                    for pair in &pairs {
+                        let cas = (pair["CAS1"].as_str(), pair["CAS2"].as_str());
+                        let reversed = if cas == (cas_i, cas_j) {
+                            false
+                        } else if cas == (cas_j, cas_i) {
+                            true
+                        } else {
+                            continue;
+                        };
+                        let mut record: MultiparameterBinaryRecord =
+                            serde_json::from_value(pair.clone())?;
+                        if record.f != 0.0 {
+                            let function = pair["function"].as_str().unwrap_or_default();
+                            let departure = departures
+                                .iter()
+                                .find(|d| {
+                                    d["Name"].as_str() == Some(function)
+                                        || d["aliases"].as_array().is_some_and(|aliases| {
+                                            aliases.iter().any(|a| a.as_str() == Some(function))
+                                        })
+                                })
+                                .ok_or_else(|| {
+                                    ParameterError::IncompatibleParameters(format!(
+                                        "departure function '{function}' not found."
+                                    ))
+                                })?;
+                            record.departure = Some(serde_json::from_value(departure.clone())?);
+                        }
+                        binary_records[(i, j)] = if reversed { record.reversed() } else { record };
+                        break;
+                    }
+                }
+            }
+            Some(binary_records)
+        } else {
+            None
+        };
+        Self::from_records(pure_records, binary_records)
+    }
+}
+
+fn residual_helmholtz_term(
+    term: Value,
+    fluid: &str,
+) -> Result<ResidualHelmholtzTerm, ParameterError> {
+    let term_type = term["type"].as_str().unwrap_or_default();
+    match term_type {
+        "ResidualHelmholtzPower" | "ResidualHelmholtzExponential" | "ResidualHelmholtzGaussian" => {
+            Ok(serde_json::from_value(term)?)
+        }
+        _ => Err(ParameterError::IncompatibleParameters(format!(
+            "term type '{term_type}' of the residual Helmholtz energy of {fluid} is not supported."
+        ))),
+    }
+}
+
+fn open<P: AsRef<Path>>(path: P) -> Result<BufReader<File>, ParameterError> {
+    Ok(BufReader::new(File::open(path)?))
+}
+
+impl fmt::Display for MultiparameterParameters {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.pure_records
+            .iter()
+            .try_for_each(|pr| writeln!(f, "{}", pr))?;
+        if self.pure_records.len() > 1 {
+            writeln!(f, "\nbeta_t:\n{}", self.beta_t)?;
+            writeln!(f, "\ngamma_t:\n{}", self.gamma_t)?;
+            writeln!(f, "\nbeta_v:\n{}", self.beta_v)?;
+            writeln!(f, "\ngamma_v:\n{}", self.gamma_v)?;
+            writeln!(f, "\nF:\n{}", self.f)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,95 @@
+use super::parameters::MultiparameterParameters;
+use feos_core::parameter::*;
+use feos_core::python::parameter::*;
+use pyo3::prelude::*;
+use pyo3::pybacked::PyBackedStr;
+use std::sync::Arc;
+
+/// Parameters of multiparameter equations of state.
+#[pyclass(name = "MultiparameterParameters")]
+#[derive(Clone)]
+pub struct PyMultiparameterParameters(pub Arc<MultiparameterParameters>);
+
+#[pymethods]
+impl PyMultiparameterParameters {
+    /// Creates parameters from json files.
+    ///
+    /// Parameters
+    /// ----------
+    /// substances : List[str]
+    ///     The substances to search.
+    /// pure_path : str
+    ///     Path to file containing pure substance parameters.
+    /// binary_path : str, optional
+    ///     Path to file containing binary substance parameters.
+    /// identifier_option : IdentifierOption, optional, defaults to IdentifierOption.Name
+    ///     Identifier that is used to search substance.
+    ///
+    /// Returns
+    /// -------
+    /// MultiparameterParameters
+    #[staticmethod]
+    #[pyo3(
+        signature = (substances, pure_path, binary_path=None, identifier_option=IdentifierOption::Name),
+        text_signature = "(substances, pure_path, binary_path=None, identifier_option)"
+    )]
+    fn from_json(
+        substances: Vec<PyBackedStr>,
+        pure_path: String,
+        binary_path: Option<String>,
+        identifier_option: IdentifierOption,
+    ) -> PyResult<Self> {
+        let substances = substances.iter().map(|s| &**s).collect();
+        Ok(Self(Arc::new(MultiparameterParameters::from_json(
+            substances,
+            pure_path,
+            binary_path,
+            identifier_option,
+        )?)))
+    }
+
+    /// Creates parameters from fluid files in the layout used by CoolProp.
+    ///
+    /// Parameters
+    /// ----------
+    /// fluid_paths : List[str]
+    ///     Paths to the fluid files of all components.
+    /// binary_path : str, optional
+    ///     Path to file containing binary interaction parameters.
+    /// departure_path : str, optional
+    ///     Path to file containing departure functions.
+    ///
+    /// Returns
+    /// -------
+    /// MultiparameterParameters
+    #[staticmethod]
+    #[pyo3(
+        signature = (fluid_paths, binary_path=None, departure_path=None),
+        text_signature = "(fluid_paths, binary_path=None, departure_path=None)"
+    )]
+    fn from_coolprop_json(
+        fluid_paths: Vec<String>,
+        binary_path: Option<String>,
+        departure_path: Option<String>,
+    ) -> PyResult<Self> {
+        Ok(Self(Arc::new(
+            MultiparameterParameters::from_coolprop_json(
+                &fluid_paths,
+                binary_path,
+                departure_path,
+            )?,
+        )))
+    }
+
+    fn __repr__(&self) -> PyResult<String> {
+        Ok(self.0.to_string())
+    }
+}
+
+#[pymodule]
+pub fn multiparameter(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyMultiparameterParameters>()?;
+    Ok(())
+}
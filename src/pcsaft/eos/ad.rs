@@ -0,0 +1,191 @@
+use super::{dispersion, hard_chain, PcSaft};
+use crate::hard_sphere::{bmcsl_helmholtz_energy_density, HardSphereProperties};
+use feos_core::sensitivity::ParametersAD;
+use feos_core::StateHD;
+use ndarray::{Array1, Array2, ScalarOperand};
+use num_dual::DualNum;
+use std::f64::consts::FRAC_PI_6;
+
+/// PC-SAFT parameters as dual numbers.
+struct PcSaftParametersAD<D> {
+    m: Array1<D>,
+    sigma: Array1<D>,
+    epsilon_k: Array1<D>,
+    kappa_ab: Array1<D>,
+    epsilon_k_ab: Array1<D>,
+    k_ij: Array2<D>,
+}
+
+impl PcSaft {
+    /// Indices of all components with association sites.
+    fn associating_components(&self) -> Vec<usize> {
+        self.parameters
+            .pure_records
+            .iter()
+            .enumerate()
+            .filter_map(|(i, r)| r.model_record.association_record.map(|_| i))
+            .collect()
+    }
+
+    fn parameters_ad<D: DualNum<f64> + Copy>(
+        &self,
+        parameters: &Array1<D>,
+    ) -> PcSaftParametersAD<D> {
+        let n = self.parameters.m.len();
+        let assoc = self.associating_components();
+        let mut kappa_ab = Array1::zeros(n);
+        let mut epsilon_k_ab = Array1::zeros(n);
+        let mut k = 3 * n;
+        for &i in &assoc {
+            kappa_ab[i] = parameters[k];
+            epsilon_k_ab[i] = parameters[k + 1];
+            k += 2;
+        }
+        let mut k_ij = Array2::zeros((n, n));
+        for i in 0..n {
+            for j in i + 1..n {
+                k_ij[(i, j)] = parameters[k];
+                k_ij[(j, i)] = parameters[k];
+                k += 1;
+            }
+        }
+        PcSaftParametersAD {
+            m: Array1::from_shape_fn(n, |i| parameters[3 * i]),
+            sigma: Array1::from_shape_fn(n, |i| parameters[3 * i + 1]),
+            epsilon_k: Array1::from_shape_fn(n, |i| parameters[3 * i + 2]),
+            kappa_ab,
+            epsilon_k_ab,
+            k_ij,
+        }
+    }
+}
+
+/// The parameters are the segment number, segment diameter and dispersion energy
+/// of every component, followed by the association volume and association energy
+/// of every associating component and the binary interaction parameters $k_{ij}$
+/// for all pairs $i<j$.
+///
+/// The temperature dependence of the binary interaction parameters (`k_ij_t`) is
+/// added to $k_{ij}$ with fixed coefficients, i.e., the coefficients are not part
+/// of the parameter vector.
+///
+/// The polar contributions are evaluated with the fixed parameters of the model,
+/// i.e., their dependence on the parameters is not included in the derivatives.
+/// Binary association parameters that are set explicitly in the binary records
+/// are also not part of the parameter vector.
+impl ParametersAD for PcSaft {
+    fn parameter_names(&self) -> Vec<String> {
+        let n = self.parameters.m.len();
+        let mut names = Vec::new();
+        for i in 0..n {
+            names.push(format!("m[{i}]"));
+            names.push(format!("sigma[{i}]"));
+            names.push(format!("epsilon_k[{i}]"));
+        }
+        for i in self.associating_components() {
+            names.push(format!("kappa_ab[{i}]"));
+            names.push(format!("epsilon_k_ab[{i}]"));
+        }
+        for i in 0..n {
+            for j in i + 1..n {
+                names.push(format!("k_ij[{i},{j}]"));
+            }
+        }
+        names
+    }
+
+    fn parameters(&self) -> Array1<f64> {
+        let p = &self.parameters;
+        let n = p.m.len();
+        let mut parameters = Vec::new();
+        for i in 0..n {
+            parameters.extend([p.m[i], p.sigma[i], p.epsilon_k[i]]);
+        }
+        for i in self.associating_components() {
+            let record = p.pure_records[i].model_record.association_record.unwrap();
+            parameters.extend([record.parameters.kappa_ab, record.parameters.epsilon_k_ab]);
+        }
+        for i in 0..n {
+            for j in i + 1..n {
+                parameters.push(p.binary_records.as_ref().map_or(0.0, |br| br[(i, j)].k_ij));
+            }
+        }
+        Array1::from_vec(parameters)
+    }
+
+    fn residual_helmholtz_energy_ad<D: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        parameters: &Array1<D>,
+        state: &StateHD<D>,
+    ) -> D {
+        let p = self.parameters_ad(parameters);
+        let n = p.m.len();
+        let t = state.temperature;
+        let rho = &state.partial_density;
+        let x = &state.molefracs;
+
+        // temperature dependent segment diameter
+        let d = Array1::from_shape_fn(n, |i| {
+            -((t.recip() * p.epsilon_k[i] * -3.0).exp() * 0.12 - 1.0) * p.sigma[i]
+        });
+
+        // hard sphere
+        let mut zeta = [D::zero(); 4];
+        for i in 0..n {
+            for (k, z) in zeta.iter_mut().enumerate() {
+                *z += x[i] * p.m[i] * d[i].powi(k as i32) * FRAC_PI_6;
+            }
+        }
+        let (a_hs, zeta) = bmcsl_helmholtz_energy_density(zeta, rho.sum());
+
+        // hard chain
+        let a_hc = hard_chain::helmholtz_energy_density(rho, &p.m, &d, [zeta[2], zeta[3]]);
+
+        // dispersion with the temperature dependence of the binary interaction parameters
+        let k_ij = &p.k_ij + &self.parameters.k_ij_t.k_ij(n, t);
+        let epsilon_k_ij = Array2::from_shape_fn((n, n), |(i, j)| {
+            (p.epsilon_k[i] * p.epsilon_k[j]).sqrt() * (-k_ij[(i, j)] + 1.0)
+        });
+        let sigma3_ij =
+            Array2::from_shape_fn((n, n), |(i, j)| ((p.sigma[i] + p.sigma[j]) * 0.5).powi(3));
+        let a_disp =
+            dispersion::helmholtz_energy_density(state, &d, &p.m, &sigma3_ij, &epsilon_k_ij);
+
+        let mut a = (a_hs + a_hc + a_disp) * state.volume;
+
+        // polar contributions with fixed parameters
+        let d_fixed = self.parameters.hs_diameter(t);
+        if let Some(dipole) = self.dipole.as_ref() {
+            a += dipole.helmholtz_energy(state, &d_fixed);
+        }
+        if let Some(quadrupole) = self.quadrupole.as_ref() {
+            a += quadrupole.helmholtz_energy(state, &d_fixed);
+        }
+        if let Some(dipole_quadrupole) = self.dipole_quadrupole.as_ref() {
+            a += dipole_quadrupole.helmholtz_energy(state, &d_fixed);
+        }
+
+        // association
+        if let Some(association) = self.association.as_ref() {
+            let binary_records = self.parameters.binary_records.as_ref();
+            a += association.helmholtz_energy_with_strength(
+                state,
+                &d,
+                [zeta[2], zeta[3]],
+                |i, j, assoc_ij| {
+                    let (kappa_ab, epsilon_k_ab) =
+                        if binary_records.is_some_and(|br| br[(i, j)].association.is_some()) {
+                            (D::from(assoc_ij.kappa_ab), D::from(assoc_ij.epsilon_k_ab))
+                        } else {
+                            (
+                                (p.kappa_ab[i] * p.kappa_ab[j]).sqrt(),
+                                (p.epsilon_k_ab[i] + p.epsilon_k_ab[j]) * 0.5,
+                            )
+                        };
+                    (epsilon_k_ab / t).exp_m1() * kappa_ab * (p.sigma[i] * p.sigma[j]).powf(1.5)
+                },
+            );
+        }
+        a
+    }
+}
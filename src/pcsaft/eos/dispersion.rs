@@ -1,6 +1,6 @@
 use super::PcSaftParameters;
 use feos_core::StateHD;
-use ndarray::Array1;
+use ndarray::{Array1, Array2};
 use num_dual::DualNum;
 use std::f64::consts::{FRAC_PI_3, PI};
 use std::fmt;
@@ -71,56 +71,84 @@ impl Dispersion {
         state: &StateHD<D>,
         diameter: &Array1<D>,
     ) -> D {
-        // auxiliary variables
-        let n = self.parameters.m.len();
         let p = &self.parameters;
-        let rho = &state.partial_density;
-
-        // temperature dependent segment radius
-        let r = diameter * 0.5;
-
-        // packing fraction
-        let eta = (rho * &p.m * &r * &r * &r).sum() * 4.0 * FRAC_PI_3;
-
-        // mean segment number
-        let m = (&state.molefracs * &p.m).sum();
-
-        // inverse temperature
-        let t_inv = state.temperature.recip();
-
-        // mixture densities, crosswise interactions of all segments on all chains
-        let mut rho1mix = D::zero();
-        let mut rho2mix = D::zero();
-        for i in 0..n {
-            for j in 0..n {
-                let eps_ij = t_inv * p.epsilon_k_ij[(i, j)];
-                let sigma_ij = p.sigma_ij[[i, j]].powi(3);
-                rho1mix += rho[i] * rho[j] * p.m[i] * p.m[j] * eps_ij * sigma_ij;
-                rho2mix += rho[i] * rho[j] * p.m[i] * p.m[j] * eps_ij * eps_ij * sigma_ij;
-            }
-        }
 
-        // I1, I2 and C1
-        let mut i1 = D::zero();
-        let mut i2 = D::zero();
-        let mut eta_i = D::one();
-        let m1_m = (m - 1.0) / m;
-        let m2_m = (m - 2.0) / m;
-        for i in 0..=6 {
-            i1 += (m1_m * (m2_m * A2[i] + A1[i]) + A0[i]) * eta_i;
-            i2 += (m1_m * (m2_m * B2[i] + B1[i]) + B0[i]) * eta_i;
-            eta_i *= eta;
+        // energy parameters with temperature-dependent binary interaction parameters
+        let epsilon_k_ij = p
+            .k_ij_t
+            .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, state.temperature);
+
+        helmholtz_energy_density(
+            state,
+            diameter,
+            &p.m.mapv(D::from),
+            &p.sigma_ij.mapv(|s| D::from(s.powi(3))),
+            &epsilon_k_ij,
+        ) * state.volume
+    }
+}
+
+/// Helmholtz energy density of the dispersion contribution for given segment
+/// numbers `m`, cubed segment diameters `sigma3_ij` and energy parameters
+/// `epsilon_k_ij` of all pairs of components.
+///
+/// The parameters are dual numbers, so that the contribution can also be
+/// evaluated with dual-valued parameters.
+pub(super) fn helmholtz_energy_density<D: DualNum<f64> + Copy>(
+    state: &StateHD<D>,
+    diameter: &Array1<D>,
+    m: &Array1<D>,
+    sigma3_ij: &Array2<D>,
+    epsilon_k_ij: &Array2<D>,
+) -> D {
+    // auxiliary variables
+    let n = m.len();
+    let rho = &state.partial_density;
+
+    // temperature dependent segment radius
+    let r = diameter * 0.5;
+
+    // packing fraction
+    let eta = (rho * m * &r * &r * &r).sum() * 4.0 * FRAC_PI_3;
+
+    // mean segment number
+    let m_mean = (&state.molefracs * m).sum();
+
+    // inverse temperature
+    let t_inv = state.temperature.recip();
+
+    // mixture densities, crosswise interactions of all segments on all chains
+    let mut rho1mix = D::zero();
+    let mut rho2mix = D::zero();
+    for i in 0..n {
+        for j in 0..n {
+            let eps_ij = t_inv * epsilon_k_ij[(i, j)];
+            let sigma_ij = sigma3_ij[(i, j)];
+            rho1mix += rho[i] * rho[j] * m[i] * m[j] * eps_ij * sigma_ij;
+            rho2mix += rho[i] * rho[j] * m[i] * m[j] * eps_ij * eps_ij * sigma_ij;
         }
-        let c1 = (m * (eta * 8.0 - eta.powi(2) * 2.0) / (eta - 1.0).powi(4)
-            + (D::one() - m)
-                * (eta * 20.0 - eta.powi(2) * 27.0 + eta.powi(3) * 12.0 - eta.powi(4) * 2.0)
-                / ((eta - 1.0) * (eta - 2.0)).powi(2)
-            + 1.0)
-            .recip();
-
-        // Helmholtz energy
-        (-rho1mix * i1 * 2.0 - rho2mix * m * c1 * i2) * PI * state.volume
     }
+
+    // I1, I2 and C1
+    let mut i1 = D::zero();
+    let mut i2 = D::zero();
+    let mut eta_i = D::one();
+    let m1_m = (m_mean - 1.0) / m_mean;
+    let m2_m = (m_mean - 2.0) / m_mean;
+    for i in 0..=6 {
+        i1 += (m1_m * (m2_m * A2[i] + A1[i]) + A0[i]) * eta_i;
+        i2 += (m1_m * (m2_m * B2[i] + B1[i]) + B0[i]) * eta_i;
+        eta_i *= eta;
+    }
+    let c1 = (m_mean * (eta * 8.0 - eta.powi(2) * 2.0) / (eta - 1.0).powi(4)
+        + (D::one() - m_mean)
+            * (eta * 20.0 - eta.powi(2) * 27.0 + eta.powi(3) * 12.0 - eta.powi(4) * 2.0)
+            / ((eta - 1.0) * (eta - 2.0)).powi(2)
+        + 1.0)
+        .recip();
+
+    // Helmholtz energy density
+    (-rho1mix * i1 * 2.0 - rho2mix * m_mean * c1 * i2) * PI
 }
 
 impl fmt::Display for Dispersion {
@@ -11,6 +11,7 @@ use std::fmt;
 use std::sync::Arc;
 use typenum::P2;
 
+mod ad;
 pub(crate) mod dispersion;
 pub(crate) mod hard_chain;
 pub(crate) mod polar;
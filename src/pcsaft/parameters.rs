@@ -1,10 +1,11 @@
 use crate::association::{
     AssociationParameters, AssociationRecord, AssociationStrength, BinaryAssociationRecord,
 };
+use crate::binary_interaction::{KijTemperatureDependence, TemperatureDependentKij};
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
 use conv::ValueInto;
 use feos_core::parameter::{
-    FromSegments, FromSegmentsBinary, Parameter, ParameterError, PureRecord,
+    FitBounds, FitRecord, FromSegments, FromSegmentsBinary, Parameter, ParameterError, PureRecord,
 };
 use feos_core::si::{JOULE, KB, KELVIN};
 use ndarray::{Array, Array1, Array2};
@@ -296,22 +297,85 @@ impl std::fmt::Display for PcSaftAssociationRecord {
     }
 }
 
+impl FitRecord for PcSaftRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["m", "sigma", "epsilon_k"];
+        if self.mu.is_some() {
+            names.push("mu");
+        }
+        if self.q.is_some() {
+            names.push("q");
+        }
+        if self.association_record.is_some() {
+            names.extend(["kappa_ab", "epsilon_k_ab"]);
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association_record.map(|r| r.parameters);
+        match name {
+            "m" => Some(self.m),
+            "sigma" => Some(self.sigma),
+            "epsilon_k" => Some(self.epsilon_k),
+            "mu" => self.mu,
+            "q" => self.q,
+            "kappa_ab" => association.map(|a| a.kappa_ab),
+            "epsilon_k_ab" => association.map(|a| a.epsilon_k_ab),
+            _ => None,
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association_record.as_mut().map(|r| &mut r.parameters);
+        let parameter = match name {
+            "m" => Some(&mut self.m),
+            "sigma" => Some(&mut self.sigma),
+            "epsilon_k" => Some(&mut self.epsilon_k),
+            "mu" => self.mu.as_mut(),
+            "q" => self.q.as_mut(),
+            "kappa_ab" => association.map(|a| &mut a.kappa_ab),
+            "epsilon_k_ab" => association.map(|a| &mut a.epsilon_k_ab),
+            _ => None,
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "epsilon_k" => FitBounds::positive(100.0),
+            "kappa_ab" => FitBounds::positive(0.01),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            _ => FitBounds::positive(1.0),
+        }
+    }
+}
+
 /// PC-SAFT binary interaction parameters.
-#[derive(Serialize, Deserialize, Clone, Copy, Default)]
+///
+/// The record is not `Copy`, because the coefficients of `k_ij_t` are stored in a `Vec`.
+#[derive(Serialize, Deserialize, Clone, Default)]
 pub struct PcSaftBinaryRecord {
     /// Binary dispersion interaction parameter
     #[serde(skip_serializing_if = "f64::is_zero")]
     #[serde(default)]
     pub k_ij: f64,
+    /// Temperature dependence of the binary dispersion interaction parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub k_ij_t: Option<KijTemperatureDependence>,
     /// Binary association parameters
     #[serde(flatten)]
-    association: Option<BinaryAssociationRecord<PcSaftBinaryAssociationRecord>>,
+    pub(crate) association: Option<BinaryAssociationRecord<PcSaftBinaryAssociationRecord>>,
 }
 
 impl From<f64> for PcSaftBinaryRecord {
     fn from(k_ij: f64) -> Self {
         Self {
             k_ij,
+            k_ij_t: None,
             association: None,
         }
     }
@@ -334,7 +398,17 @@ impl PcSaftBinaryRecord {
                 None,
             ))
         };
-        Self { k_ij, association }
+        Self {
+            k_ij,
+            k_ij_t: None,
+            association,
+        }
+    }
+
+    /// Add a temperature dependence to the binary dispersion interaction parameter.
+    pub fn with_k_ij_t(mut self, k_ij_t: KijTemperatureDependence) -> Self {
+        self.k_ij_t = Some(k_ij_t);
+        self
     }
 }
 
@@ -346,6 +420,7 @@ impl<T: Copy + ValueInto<f64>> FromSegmentsBinary<T> for PcSaftBinaryRecord {
         });
         Ok(Self {
             k_ij: k_ij / n,
+            k_ij_t: None,
             association: None,
         })
     }
@@ -357,6 +432,9 @@ impl std::fmt::Display for PcSaftBinaryRecord {
         if !self.k_ij.is_zero() {
             tokens.push(format!("k_ij={}", self.k_ij));
         }
+        if let Some(k_ij_t) = &self.k_ij_t {
+            tokens.push(format!("k_ij_t={}", k_ij_t));
+        }
         if let Some(association) = self.association {
             if let Some(kappa_ab) = association.parameters.kappa_ab {
                 tokens.push(format!("kappa_ab={}", kappa_ab));
@@ -369,6 +447,59 @@ impl std::fmt::Display for PcSaftBinaryRecord {
     }
 }
 
+impl FitRecord for PcSaftBinaryRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["k_ij"];
+        if let Some(k_ij_t) = &self.k_ij_t {
+            names.extend(k_ij_t.names());
+        }
+        if let Some(association) = self.association {
+            if association.parameters.kappa_ab.is_some() {
+                names.push("kappa_ab");
+            }
+            if association.parameters.epsilon_k_ab.is_some() {
+                names.push("epsilon_k_ab");
+            }
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association.map(|r| r.parameters);
+        match name {
+            "k_ij" => Some(self.k_ij),
+            "kappa_ab" => association.and_then(|a| a.kappa_ab),
+            "epsilon_k_ab" => association.and_then(|a| a.epsilon_k_ab),
+            _ => self.k_ij_t.as_ref().and_then(|k| k.get(name)),
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association.as_mut().map(|r| &mut r.parameters);
+        let parameter = match name {
+            "k_ij" => Some(&mut self.k_ij),
+            "kappa_ab" => association.and_then(|a| a.kappa_ab.as_mut()),
+            "epsilon_k_ab" => association.and_then(|a| a.epsilon_k_ab.as_mut()),
+            _ => self.k_ij_t.as_mut().and_then(|k| k.get_mut(name)),
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "kappa_ab" => FitBounds::positive(0.01),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            "k_ij" => FitBounds::new(-1.0, 1.0, 0.01),
+            _ => self
+                .k_ij_t
+                .as_ref()
+                .map_or_else(FitBounds::default, |k| k.bounds(name)),
+        }
+    }
+}
+
 #[derive(Serialize, Deserialize, Clone, Copy, Default)]
 pub struct PcSaftBinaryAssociationRecord {
     /// Cross-association association volume parameter.
@@ -402,6 +533,7 @@ pub struct PcSaftParameters {
     pub sigma_ij: Array2<f64>,
     pub epsilon_k_ij: Array2<f64>,
     pub e_k_ij: Array2<f64>,
+    pub k_ij_t: TemperatureDependentKij,
     pub ndipole: usize,
     pub nquadpole: usize,
     pub dipole_comp: Array1<usize>,
@@ -493,6 +625,7 @@ impl Parameter for PcSaftParameters {
         if let Some(k_ij) = k_ij.as_ref() {
             epsilon_k_ij *= &(1.0 - k_ij)
         };
+        let k_ij_t = TemperatureDependentKij::new(binary_records.as_ref(), |br| br.k_ij_t.as_ref());
 
         let viscosity_coefficients = if viscosity.iter().any(|v| v.is_none()) {
             None
@@ -538,6 +671,7 @@ impl Parameter for PcSaftParameters {
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
+            k_ij_t,
             ndipole,
             nquadpole,
             dipole_comp,
@@ -649,7 +783,11 @@ impl PcSaftParameters {
 #[cfg(test)]
 pub mod utils {
     use super::*;
-    use feos_core::parameter::{BinaryRecord, ChemicalRecord, SegmentRecord};
+    use approx::assert_relative_eq;
+    use feos_core::parameter::{
+        BinaryRecord, ChemicalRecord, ParameterVector, SegmentRecord, SegmentVector,
+    };
+    use ndarray::arr1;
     use std::sync::Arc;
 
     pub fn propane_parameters() -> Arc<PcSaftParameters> {
@@ -894,4 +1032,66 @@ pub mod utils {
 
         Ok(())
     }
+
+    #[test]
+    pub fn test_parameter_vector() -> Result<(), ParameterError> {
+        let parameters = propane_butane_parameters();
+        let mut vector = ParameterVector::from_parameters(&*parameters);
+        vector.add_pure(0, "m", None)?;
+        vector.add_pure(1, "epsilon_k", None)?;
+        vector.add_binary(1, 0, "k_ij", Some(FitBounds::new(-0.1, 0.1, 0.01)))?;
+        assert!(vector.add_pure(0, "kappa_ab", None).is_err());
+        assert!(vector.add_binary(0, 1, "k_ij", None).is_err());
+        assert_eq!(vector.names(), ["m[0]", "epsilon_k[1]", "k_ij[0,1]"]);
+        assert_relative_eq!(vector.to_vector(), arr1(&[2.001829, 2.228774, 0.0]));
+        let (lower, upper) = vector.bounds();
+        assert_relative_eq!(lower, arr1(&[0.0, 0.0, -10.0]));
+        assert_relative_eq!(upper[2], 10.0);
+
+        let params = vector.parameters(&arr1(&[2.5, 2.5, 1.5]))?;
+        assert_relative_eq!(params.m, arr1(&[2.5, 2.331586]));
+        assert_relative_eq!(params.epsilon_k[1], 250.0);
+        assert_relative_eq!(params.sigma, parameters.sigma);
+        let k_ij = params.binary_records.as_ref().unwrap();
+        assert_relative_eq!(k_ij[[0, 1]].k_ij, 0.015);
+        assert_relative_eq!(k_ij[[1, 0]].k_ij, 0.015);
+
+        let mut vector = ParameterVector::<PcSaftParameters>::from_parameters(&water_parameters());
+        for name in ["kappa_ab", "epsilon_k_ab"] {
+            vector.add_pure(0, name, None)?;
+        }
+        let x = arr1(&[3.0, 2.5]);
+        vector.update(&x)?;
+        assert_relative_eq!(vector.values(), arr1(&[0.03, 2500.0]));
+        assert_relative_eq!(vector.to_vector(), x);
+        Ok(())
+    }
+
+    #[test]
+    pub fn test_segment_vector() -> Result<(), ParameterError> {
+        let segments = |s: &[&str]| s.iter().map(|&s| s.into()).collect();
+        let propane =
+            ChemicalRecord::new(Default::default(), segments(&["CH3", "CH2", "CH3"]), None);
+        let ethanol =
+            ChemicalRecord::new(Default::default(), segments(&["CH3", "CH2", "OH"]), None);
+        let segment_records = SegmentRecord::from_json("parameters/pcsaft/sauer2014_homo.json")?;
+        let mut vector = SegmentVector::new(segment_records, None);
+        vector.add_segment("CH2", "m", None)?;
+        vector.add_binary("OH", "CH3", "k_ij", None)?;
+        assert!(vector.add_segment("CH2", "m", None).is_err());
+        assert!(vector.add_segment("CH4", "m", None).is_err());
+        assert!(vector.add_binary("CH3", "OH", "k_ij", None).is_err());
+        assert_eq!(vector.names(), ["m[CH2]", "k_ij[CH3,OH]"]);
+        assert_eq!(vector.values(), arr1(&[0.45606, 0.0]));
+
+        let x = arr1(&[0.5, -18.0]);
+        let params: PcSaftParameters = vector.parameters(vec![propane, ethanol], &x)?;
+        assert_relative_eq!(params.m[0], 2.0 * 0.61198 + 0.5);
+        let k_ij = params.binary_records.as_ref().unwrap();
+        assert_relative_eq!(k_ij[[0, 1]].k_ij, -0.18 * 2.0 / 9.0);
+
+        vector.update(&x)?;
+        assert_eq!(vector.to_vector(), x);
+        Ok(())
+    }
 }
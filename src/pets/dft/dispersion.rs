@@ -73,12 +73,17 @@ impl FunctionalContribution for AttractiveFunctional {
                 |acc: Array1<N>, (rho, r3)| acc + &rho * r3,
             );
 
+        // energy parameters with temperature-dependent binary interaction parameters
+        let epsilon_k_ij = p
+            .k_ij_t
+            .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, temperature);
+
         // mixture densities, crosswise interactions of all segments on all chains
         let mut rho1mix: Array1<N> = Array::zeros(eta.raw_dim());
         let mut rho2mix: Array1<N> = Array::zeros(eta.raw_dim());
         for i in 0..n {
             for j in 0..n {
-                let eps_ij_t = temperature.recip() * p.epsilon_k_ij[(i, j)];
+                let eps_ij_t = temperature.recip() * epsilon_k_ij[(i, j)];
                 let sigma_ij_3 = p.sigma_ij[(i, j)].powi(3);
                 rho1mix = rho1mix
                     + (&density.index_axis(Axis(0), i) * &density.index_axis(Axis(0), j))
@@ -43,12 +43,17 @@ impl Dispersion {
         // packing fraction
         let eta = (rho * &r * &r * &r).sum() * 4.0 * FRAC_PI_3;
 
+        // energy parameters with temperature-dependent binary interaction parameters
+        let epsilon_k_ij = p
+            .k_ij_t
+            .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, state.temperature);
+
         // mixture densities, crosswise interactions of all segments on all chains
         let mut rho1mix = D::zero();
         let mut rho2mix = D::zero();
         for i in 0..n {
             for j in 0..n {
-                let eps_ij = state.temperature.recip() * p.epsilon_k_ij[(i, j)];
+                let eps_ij = state.temperature.recip() * epsilon_k_ij[(i, j)];
                 let sigma_ij = p.sigma_ij[[i, j]].powi(3);
                 rho1mix += rho[i] * rho[j] * eps_ij * sigma_ij;
                 rho2mix += rho[i] * rho[j] * eps_ij * eps_ij * sigma_ij;
@@ -1,5 +1,6 @@
+use crate::binary_interaction::{KijTemperatureDependence, TemperatureDependentKij};
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
-use feos_core::parameter::{Parameter, ParameterError, PureRecord};
+use feos_core::parameter::{FitBounds, FitRecord, Parameter, ParameterError, PureRecord};
 use ndarray::{Array, Array1, Array2};
 use num_dual::DualNum;
 use serde::{Deserialize, Serialize};
@@ -67,17 +68,58 @@ impl PetsRecord {
     }
 }
 
+impl FitRecord for PetsRecord {
+    fn names(&self) -> Vec<&'static str> {
+        vec!["sigma", "epsilon_k"]
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        match name {
+            "sigma" => Ok(self.sigma),
+            "epsilon_k" => Ok(self.epsilon_k),
+            _ => Err(ParameterError::UnknownParameter(name.into())),
+        }
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        match name {
+            "sigma" => self.sigma = value,
+            "epsilon_k" => self.epsilon_k = value,
+            _ => return Err(ParameterError::UnknownParameter(name.into())),
+        }
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "epsilon_k" => FitBounds::positive(100.0),
+            _ => FitBounds::positive(1.0),
+        }
+    }
+}
+
 /// Parameters that modify binary interactions.
 ///
 /// $\varepsilon_{k,ij} = (1 - k_{ij})\sqrt{\varepsilon_{k,i} \varepsilon_{k,j}}$
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct PetsBinaryRecord {
     k_ij: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    k_ij_t: Option<KijTemperatureDependence>,
+}
+
+impl PetsBinaryRecord {
+    /// Add a temperature dependence to the binary interaction parameter.
+    pub fn with_k_ij_t(mut self, k_ij_t: KijTemperatureDependence) -> Self {
+        self.k_ij_t = Some(k_ij_t);
+        self
+    }
 }
 
 impl From<f64> for PetsBinaryRecord {
     fn from(k_ij: f64) -> Self {
-        Self { k_ij }
+        Self { k_ij, k_ij_t: None }
     }
 }
 
@@ -89,7 +131,45 @@ impl From<PetsBinaryRecord> for f64 {
 
 impl std::fmt::Display for PetsBinaryRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "PetsBinaryRecord(k_ij={})", self.k_ij)
+        write!(f, "PetsBinaryRecord(k_ij={}", self.k_ij)?;
+        if let Some(k_ij_t) = &self.k_ij_t {
+            write!(f, ", k_ij_t={}", k_ij_t)?;
+        }
+        write!(f, ")")
+    }
+}
+
+impl FitRecord for PetsBinaryRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["k_ij"];
+        if let Some(k_ij_t) = &self.k_ij_t {
+            names.extend(k_ij_t.names());
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        match name {
+            "k_ij" => Some(self.k_ij),
+            _ => self.k_ij_t.as_ref().and_then(|k| k.get(name)),
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let parameter = match name {
+            "k_ij" => Some(&mut self.k_ij),
+            _ => self.k_ij_t.as_mut().and_then(|k| k.get_mut(name)),
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match (name, &self.k_ij_t) {
+            ("k_ij", _) | (_, None) => FitBounds::new(-1.0, 1.0, 0.01),
+            (_, Some(k)) => k.bounds(name),
+        }
     }
 }
 
@@ -110,6 +190,9 @@ pub struct PetsParameters {
     pub epsilon_k_ij: Array2<f64>,
     /// energy parameter matrix not including k_ij
     pub e_k_ij: Array2<f64>,
+    /// temperature dependence of the binary interaction parameters
+    #[serde(skip)]
+    pub k_ij_t: TemperatureDependentKij,
     /// viscosity parameters for entropy scaling
     pub viscosity: Option<Array2<f64>>,
     /// diffusion parameters for entropy scaling
@@ -165,6 +248,7 @@ impl Parameter for PetsParameters {
         if let Some(k_ij) = k_ij.as_ref() {
             epsilon_k_ij *= &(1.0 - k_ij);
         }
+        let k_ij_t = TemperatureDependentKij::new(binary_records.as_ref(), |br| br.k_ij_t.as_ref());
 
         let viscosity_coefficients = if viscosity.iter().any(|v| v.is_none()) {
             None
@@ -205,6 +289,7 @@ impl Parameter for PetsParameters {
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
+            k_ij_t,
             viscosity: viscosity_coefficients,
             diffusion: diffusion_coefficients,
             thermal_conductivity: thermal_conductivity_coefficients,
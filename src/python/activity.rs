@@ -0,0 +1,14 @@
+use feos_core::parameter::IdentifierOption;
+use feos_core::python::activity::*;
+use feos_core::python::parameter::{PyChemicalRecord, PyIdentifier};
+use pyo3::prelude::*;
+
+#[pymodule]
+pub fn activity(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_class::<PyIdentifier>()?;
+    m.add_class::<IdentifierOption>()?;
+    m.add_class::<PyChemicalRecord>()?;
+
+    m.add_class::<PyActivityModel>()?;
+    m.add_class::<PyVaporPressureCorrelation>()
+}
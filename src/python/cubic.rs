@@ -1,3 +1,4 @@
+use feos_core::cubic::MixingRule;
 use feos_core::python::cubic::*;
 use feos_core::python::parameter::{PyChemicalRecord, PyIdentifier};
 use pyo3::prelude::*;
@@ -7,9 +8,16 @@ pub fn cubic(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyIdentifier>()?;
     m.add_class::<PyChemicalRecord>()?;
 
-    m.add_class::<PyPengRobinsonRecord>()?;
+    m.add_class::<PyAlphaFunction>()?;
+    m.add_class::<PyVolumeTranslation>()?;
+    m.add_class::<PyCubicRecord>()?;
     m.add_class::<PyPureRecord>()?;
     m.add_class::<PyBinaryRecord>()?;
-    m.add_class::<PyPengRobinsonParameters>()?;
+    m.add_class::<PyCubicParameters>()?;
+    m.add_class::<MixingRule>()?;
+
+    // Names of the previous Peng-Robinson implementation
+    m.add("PengRobinsonRecord", m.getattr("CubicRecord")?)?;
+    m.add("PengRobinsonParameters", m.getattr("CubicParameters")?)?;
     Ok(())
 }
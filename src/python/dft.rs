@@ -1,3 +1,9 @@
+#[cfg(feature = "epcsaft")]
+use crate::epcsaft::python::PyElectrolytePcSaftParameters;
+#[cfg(feature = "epcsaft")]
+use crate::epcsaft::{
+    ElectrolytePcSaftFunctional, ElectrolytePcSaftOptions, ElectrolytePcSaftVariants,
+};
 #[cfg(feature = "estimator")]
 use crate::estimator::*;
 use crate::functional::FunctionalVariant;
@@ -9,6 +15,8 @@ use crate::hard_sphere::{FMTFunctional, FMTVersion};
 use crate::ideal_gas::IdealGasModel;
 #[cfg(feature = "estimator")]
 use crate::impl_estimator;
+#[cfg(feature = "estimator")]
+use crate::impl_estimator_dft;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::PyPcSaftParameters;
 #[cfg(feature = "pcsaft")]
@@ -17,14 +25,21 @@ use crate::pcsaft::{DQVariants, PcSaftFunctional, PcSaftOptions};
 use crate::pets::python::PyPetsParameters;
 #[cfg(feature = "pets")]
 use crate::pets::{PetsFunctional, PetsOptions};
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::python::PySaftVRMieParameters;
+#[cfg(feature = "saftvrmie")]
+use crate::saftvrmie::{SaftVRMieFunctional, SaftVRMieOptions};
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::python::PySaftVRQMieParameters;
 #[cfg(feature = "saftvrqmie")]
 use crate::saftvrqmie::{SaftVRQMieFunctional, SaftVRQMieOptions};
 
+use feos_core::python::activity::{PyActivityModel, PyVaporPressureCorrelation};
+use feos_core::python::chemical_reactions::PyChemicalReactions;
 use feos_core::si::*;
 use feos_core::*;
 use feos_dft::adsorption::*;
+use feos_dft::ddft::*;
 use feos_dft::interface::*;
 use feos_dft::python::*;
 use feos_dft::solvation::*;
@@ -225,6 +240,93 @@ impl PyFunctionalVariant {
         let func = SaftVRQMieFunctional::with_options(parameters.0, fmt_version, options);
         Self::new(func)
     }
+
+    /// SAFT-VR Mie Helmholtz energy functional.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : SaftVRMieParameters
+    ///     The parameters of the SAFT-VR Mie Helmholtz energy functional to use.
+    /// fmt_version: FMTVersion, optional
+    ///     The specific variant of the FMT term. Defaults to FMTVersion.WhiteBear
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_iter_cross_assoc : unsigned integer, optional
+    ///     Maximum number of iterations for cross association. Defaults to 50.
+    /// tol_cross_assoc : float
+    ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[cfg(feature = "saftvrmie")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10)"
+    )]
+    fn saftvrmie(
+        parameters: PySaftVRMieParameters,
+        fmt_version: FMTVersion,
+        max_eta: f64,
+        max_iter_cross_assoc: usize,
+        tol_cross_assoc: f64,
+    ) -> Self {
+        let options = SaftVRMieOptions {
+            max_eta,
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+        };
+        let func = SaftVRMieFunctional::with_options(parameters.0, fmt_version, options);
+        Self::new(func)
+    }
+
+    /// ePC-SAFT Helmholtz energy functional.
+    ///
+    /// The Coulomb interactions are described by the mean-field
+    /// electrostatic potential.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : ElectrolytePcSaftParameters
+    ///     The set of ePC-SAFT parameters.
+    /// fmt_version: FMTVersion, optional
+    ///     The specific variant of the FMT term. Defaults to FMTVersion.WhiteBear
+    /// max_eta : float, optional
+    ///     Maximum packing fraction. Defaults to 0.5.
+    /// max_iter_cross_assoc : unsigned integer, optional
+    ///     Maximum number of iterations for cross association. Defaults to 50.
+    /// tol_cross_assoc : float
+    ///     Tolerance for convergence of cross association. Defaults to 1e-10.
+    /// epcsaft_variant : ElectrolytePcSaftVariants, optional
+    ///     Variant of the ePC-SAFT equation of state. Defaults to 'advanced'
+    ///
+    /// Returns
+    /// -------
+    /// HelmholtzEnergyFunctional
+    #[cfg(feature = "epcsaft")]
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, fmt_version=FMTVersion::WhiteBear, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, epcsaft_variant=ElectrolytePcSaftVariants::Advanced),
+        text_signature = "(parameters, fmt_version, max_eta=0.5, max_iter_cross_assoc=50, tol_cross_assoc=1e-10, epcsaft_variant)"
+    )]
+    fn epcsaft(
+        parameters: PyElectrolytePcSaftParameters,
+        fmt_version: FMTVersion,
+        max_eta: f64,
+        max_iter_cross_assoc: usize,
+        tol_cross_assoc: f64,
+        epcsaft_variant: ElectrolytePcSaftVariants,
+    ) -> Self {
+        let options = ElectrolytePcSaftOptions {
+            max_eta,
+            max_iter_cross_assoc,
+            tol_cross_assoc,
+            epcsaft_variant,
+        };
+        let func = ElectrolytePcSaftFunctional::with_options(parameters.0, fmt_version, options);
+        Self::new(func)
+    }
 }
 
 impl_equation_of_state!(PyFunctionalVariant);
@@ -233,16 +335,22 @@ impl_state!(DFT<Functional>, PyFunctionalVariant);
 impl_phase_equilibrium!(DFT<Functional>, PyFunctionalVariant);
 
 impl_planar_interface!(Functional);
+impl_critical_nucleus!(Functional);
 impl_surface_tension_diagram!(Functional);
 
 impl_pore!(Functional, PyFunctionalVariant);
 impl_adsorption!(Functional, PyFunctionalVariant);
+impl_wetting!(Functional);
+
+impl_ddft!(Functional);
 
 impl_pair_correlation!(Functional);
 impl_solvation_profile!(Functional);
 
 #[cfg(feature = "estimator")]
 impl_estimator!(DFT<Functional>, PyFunctionalVariant);
+#[cfg(feature = "estimator")]
+impl_estimator_dft!(Functional, PyFunctionalVariant);
 
 #[pymodule]
 pub fn dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
@@ -253,10 +361,14 @@ pub fn dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyState>()?;
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
+    m.add_class::<PyPhaseEnvelope>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
+    m.add_class::<PyMultiPhaseEquilibrium>()?;
+    m.add_class::<PyChemicalReactions>()?;
     m.add_class::<FMTVersion>()?;
 
     m.add_class::<PyPlanarInterface>()?;
+    m.add_class::<PyCriticalNucleus>()?;
     m.add_class::<Geometry>()?;
     m.add_class::<PyPore1D>()?;
     m.add_class::<PyPore2D>()?;
@@ -265,9 +377,14 @@ pub fn dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyExternalPotential>()?;
     m.add_class::<PyAdsorption1D>()?;
     m.add_class::<PyAdsorption3D>()?;
+    m.add_class::<PyWetting>()?;
+    m.add_class::<PyWettingDiagram>()?;
     m.add_class::<PySurfaceTensionDiagram>()?;
     m.add_class::<PyDFTSolver>()?;
     m.add_class::<PySolvationProfile>()?;
+    m.add_class::<BoundaryCondition>()?;
+    m.add_class::<PyDDFT>()?;
+    m.add_class::<PyDDFTTrajectory>()?;
 
     #[cfg(feature = "estimator")]
     m.add_wrapped(wrap_pymodule!(estimator_dft))?;
@@ -280,5 +397,6 @@ pub fn dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
 pub fn estimator_dft(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyDataSet>()?;
     m.add_class::<PyEstimator>()?;
-    m.add_class::<PyLoss>()
+    m.add_class::<PyLoss>()?;
+    m.add_class::<SurfaceTensionMethod>()
 }
@@ -12,8 +12,14 @@ use crate::gc_pcsaft::{GcPcSaft, GcPcSaftOptions};
 use crate::ideal_gas::IdealGasModel;
 #[cfg(feature = "estimator")]
 use crate::impl_estimator;
+#[cfg(feature = "estimator")]
+use crate::impl_estimator_caloric;
 #[cfg(all(feature = "estimator", feature = "pcsaft"))]
 use crate::impl_estimator_entropy_scaling;
+#[cfg(feature = "multiparameter")]
+use crate::multiparameter::python::PyMultiparameterParameters;
+#[cfg(feature = "multiparameter")]
+use crate::multiparameter::Multiparameter;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::PyPcSaftParameters;
 #[cfg(feature = "pcsaft")]
@@ -37,8 +43,10 @@ use crate::uvtheory::{Perturbation, UVTheory, UVTheoryOptions};
 
 use super::dippr::PyDippr;
 use super::joback::PyJoback;
-use feos_core::cubic::PengRobinson;
-use feos_core::python::cubic::PyPengRobinsonParameters;
+use feos_core::cubic::{Cubic, CubicExcessGibbs, CubicModel, CubicOptions, MixingRule};
+use feos_core::python::activity::{PyActivityModel, PyVaporPressureCorrelation};
+use feos_core::python::chemical_reactions::PyChemicalReactions;
+use feos_core::python::cubic::PyCubicParameters;
 use feos_core::python::user_defined::{PyIdealGas, PyResidual};
 use feos_core::si::*;
 use feos_core::*;
@@ -244,7 +252,7 @@ impl PyEquationOfState {
     ///
     /// Parameters
     /// ----------
-    /// parameters : PengRobinsonParameters
+    /// parameters : CubicParameters
     ///     The parameters of the PR equation of state to use.
     ///
     /// Returns
@@ -253,12 +261,100 @@ impl PyEquationOfState {
     ///     The PR equation of state that can be used to compute thermodynamic
     ///     states.
     #[staticmethod]
-    pub fn peng_robinson(parameters: PyPengRobinsonParameters) -> Self {
-        let residual = Arc::new(ResidualModel::PengRobinson(PengRobinson::new(parameters.0)));
+    pub fn peng_robinson(parameters: PyCubicParameters) -> Self {
+        let residual = Arc::new(ResidualModel::Cubic(Cubic::new(parameters.0)));
         let ideal_gas = Arc::new(IdealGasModel::NoModel(residual.components()));
         Self(Arc::new(EquationOfState::new(ideal_gas, residual)))
     }
 
+    /// Cubic equation of state.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : CubicParameters
+    ///     The parameters of the cubic equation of state to use.
+    /// model : str, optional
+    ///     The cubic equation of state: 'pr' (Peng-Robinson, 1976),
+    ///     'pr78' (Peng-Robinson, 1978), 'srk' (Soave-Redlich-Kwong)
+    ///     or 'generic'. Defaults to 'pr'.
+    /// delta : (float, float), optional
+    ///     The parameters delta1 and delta2 of the generic cubic
+    ///     equation of state. Only used for `model='generic'`.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The cubic equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, model="pr", delta=None),
+        text_signature = "(parameters, model='pr', delta=None)"
+    )]
+    pub fn cubic(
+        parameters: PyCubicParameters,
+        model: &str,
+        delta: Option<(f64, f64)>,
+    ) -> PyResult<Self> {
+        let model = cubic_model(model, delta)?;
+        let residual = Arc::new(ResidualModel::Cubic(Cubic::with_options(
+            parameters.0,
+            CubicOptions { model },
+        )));
+        let ideal_gas = Arc::new(IdealGasModel::NoModel(residual.components()));
+        Ok(Self(Arc::new(EquationOfState::new(ideal_gas, residual))))
+    }
+
+    /// Cubic equation of state with a mixing rule based on an
+    /// activity coefficient model.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : CubicParameters
+    ///     The parameters of the cubic equation of state to use.
+    /// activity_model : ActivityModel
+    ///     The model for the excess Gibbs energy.
+    /// mixing_rule : MixingRule
+    ///     The mixing rule that combines the cubic equation of state
+    ///     with the excess Gibbs energy.
+    /// model : str, optional
+    ///     The cubic equation of state: 'pr' (Peng-Robinson, 1976),
+    ///     'pr78' (Peng-Robinson, 1978), 'srk' (Soave-Redlich-Kwong)
+    ///     or 'generic'. Defaults to 'pr'.
+    /// delta : (float, float), optional
+    ///     The parameters delta1 and delta2 of the generic cubic
+    ///     equation of state. Only used for `model='generic'`.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The cubic equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[staticmethod]
+    #[pyo3(
+        signature = (parameters, activity_model, mixing_rule, model="pr", delta=None),
+        text_signature = "(parameters, activity_model, mixing_rule, model='pr', delta=None)"
+    )]
+    pub fn cubic_excess_gibbs(
+        parameters: PyCubicParameters,
+        activity_model: PyActivityModel,
+        mixing_rule: MixingRule,
+        model: &str,
+        delta: Option<(f64, f64)>,
+    ) -> PyResult<Self> {
+        let model = cubic_model(model, delta)?;
+        let residual = Arc::new(ResidualModel::CubicExcessGibbs(
+            CubicExcessGibbs::with_options(
+                parameters.0,
+                activity_model.0,
+                mixing_rule,
+                CubicOptions { model },
+            )?,
+        ));
+        let ideal_gas = Arc::new(IdealGasModel::NoModel(residual.components()));
+        Ok(Self(Arc::new(EquationOfState::new(ideal_gas, residual))))
+    }
+
     /// Residual Helmholtz energy model from a Python class.
     ///
     /// Parameters
@@ -304,6 +400,29 @@ impl PyEquationOfState {
         Self(Arc::new(EquationOfState::new(ideal_gas, residual)))
     }
 
+    /// Multiparameter Helmholtz energy equation of state.
+    ///
+    /// Parameters
+    /// ----------
+    /// parameters : MultiparameterParameters
+    ///     The parameters of the multiparameter equations of state to use.
+    ///
+    /// Returns
+    /// -------
+    /// EquationOfState
+    ///     The multiparameter equation of state that can be used to compute thermodynamic
+    ///     states.
+    #[cfg(feature = "multiparameter")]
+    #[staticmethod]
+    #[pyo3(text_signature = "(parameters)")]
+    fn multiparameter(parameters: PyMultiparameterParameters) -> Self {
+        let residual = Arc::new(ResidualModel::Multiparameter(Multiparameter::new(
+            parameters.0,
+        )));
+        let ideal_gas = Arc::new(IdealGasModel::NoModel(residual.components()));
+        Self(Arc::new(EquationOfState::new(ideal_gas, residual)))
+    }
+
     /// UV-Theory equation of state.
     ///
     /// Parameters
@@ -458,9 +577,23 @@ impl_phase_equilibrium!(EquationOfState<IdealGasModel, ResidualModel>, PyEquatio
 
 #[cfg(feature = "estimator")]
 impl_estimator!(EquationOfState<IdealGasModel, ResidualModel>, PyEquationOfState);
+#[cfg(feature = "estimator")]
+impl_estimator_caloric!(EquationOfState<IdealGasModel, ResidualModel>, PyEquationOfState);
 #[cfg(all(feature = "estimator", feature = "pcsaft"))]
 impl_estimator_entropy_scaling!(EquationOfState<IdealGasModel, ResidualModel>, PyEquationOfState);
 
+fn cubic_model(model: &str, delta: Option<(f64, f64)>) -> PyResult<CubicModel> {
+    match (model, delta) {
+        ("pr", None) => Ok(CubicModel::PengRobinson),
+        ("pr78", None) => Ok(CubicModel::PengRobinson78),
+        ("srk", None) => Ok(CubicModel::SoaveRedlichKwong),
+        ("generic", Some((delta1, delta2))) => Ok(CubicModel::Generic { delta1, delta2 }),
+        _ => Err(PyErr::new::<PyValueError, _>(
+            "`model` must be 'pr', 'pr78', 'srk' or 'generic' with `delta`.".to_string(),
+        )),
+    }
+}
+
 #[pymodule]
 pub fn eos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<Contributions>()?;
@@ -470,7 +603,10 @@ pub fn eos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_class::<PyState>()?;
     m.add_class::<PyStateVec>()?;
     m.add_class::<PyPhaseDiagram>()?;
+    m.add_class::<PyPhaseEnvelope>()?;
     m.add_class::<PyPhaseEquilibrium>()?;
+    m.add_class::<PyMultiPhaseEquilibrium>()?;
+    m.add_class::<PyChemicalReactions>()?;
 
     #[cfg(feature = "estimator")]
     m.add_wrapped(wrap_pymodule!(estimator_eos))?;
@@ -2,6 +2,8 @@
 use crate::epcsaft::python::epcsaft as epcsaft_module;
 #[cfg(feature = "gc_pcsaft")]
 use crate::gc_pcsaft::python::gc_pcsaft as gc_pcsaft_module;
+#[cfg(feature = "multiparameter")]
+use crate::multiparameter::python::multiparameter as multiparameter_module;
 #[cfg(feature = "pcsaft")]
 use crate::pcsaft::python::pcsaft as pcsaft_module;
 #[cfg(feature = "pets")]
@@ -17,10 +19,12 @@ use pyo3::prelude::*;
 use pyo3::wrap_pymodule;
 use quantity::python::quantity as quantity_module;
 
+mod activity;
 mod cubic;
 mod dippr;
 mod eos;
 mod joback;
+use activity::activity as activity_module;
 use cubic::cubic as cubic_module;
 use dippr::dippr as dippr_module;
 use eos::eos as eos_module;
@@ -42,6 +46,7 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(joback_module))?;
     m.add_wrapped(wrap_pymodule!(dippr_module))?;
     m.add_wrapped(wrap_pymodule!(cubic_module))?;
+    m.add_wrapped(wrap_pymodule!(activity_module))?;
     #[cfg(feature = "pcsaft")]
     m.add_wrapped(wrap_pymodule!(pcsaft_module))?;
     #[cfg(feature = "epcsaft")]
@@ -56,6 +61,8 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     m.add_wrapped(wrap_pymodule!(saftvrqmie_module))?;
     #[cfg(feature = "saftvrmie")]
     m.add_wrapped(wrap_pymodule!(saftvrmie_module))?;
+    #[cfg(feature = "multiparameter")]
+    m.add_wrapped(wrap_pymodule!(multiparameter_module))?;
 
     set_path(m, "feos.si", "quantity")?;
     set_path(m, "feos.eos", "eos")?;
@@ -68,6 +75,7 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     set_path(m, "feos.joback", "joback")?;
     set_path(m, "feos.dippr", "dippr")?;
     set_path(m, "feos.cubic", "cubic")?;
+    set_path(m, "feos.activity", "activity")?;
     #[cfg(feature = "pcsaft")]
     set_path(m, "feos.pcsaft", "pcsaft")?;
     #[cfg(feature = "epcsaft")]
@@ -82,6 +90,8 @@ pub fn feos(m: &Bound<'_, PyModule>) -> PyResult<()> {
     set_path(m, "feos.saftvrqmie", "saftvrqmie")?;
     #[cfg(feature = "saftvrmie")]
     set_path(m, "feos.saftvrmie", "saftvrmie")?;
+    #[cfg(feature = "multiparameter")]
+    set_path(m, "feos.multiparameter", "multiparameter")?;
 
     m.py().run_bound(
         "\
@@ -0,0 +1,91 @@
+use crate::hard_sphere::HardSphereProperties;
+use crate::saftvrmie::eos::dispersion::{ln_g_mie, Properties};
+use crate::saftvrmie::parameters::SaftVRMieParameters;
+use feos_core::{EosResult, StateHD};
+use feos_dft::{FunctionalContribution, WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use ndarray::*;
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// Chain functional in which the radial distribution function of the
+/// Mie fluid at contact is evaluated at densities averaged over spheres
+/// with the hard-sphere diameter as radius.
+#[derive(Clone)]
+pub struct ChainFunctional {
+    parameters: Arc<SaftVRMieParameters>,
+}
+
+impl ChainFunctional {
+    pub fn new(parameters: Arc<SaftVRMieParameters>) -> Self {
+        Self { parameters }
+    }
+}
+
+impl FunctionalContribution for ChainFunctional {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        let p = &self.parameters;
+        let d = p.hs_diameter(temperature);
+        WeightFunctionInfo::new(p.component_index().into_owned(), true)
+            .add(
+                WeightFunction::new_scaled(d.clone(), WeightFunctionShape::Delta),
+                false,
+            )
+            .add(
+                WeightFunction::new_scaled(d, WeightFunctionShape::Theta),
+                false,
+            )
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+        weighted_densities: ArrayView2<N>,
+    ) -> EosResult<Array1<N>> {
+        let p = &self.parameters;
+        // number of segments
+        let n = weighted_densities.shape()[0] / 3;
+
+        // weighted densities
+        let rho = weighted_densities.slice_axis(Axis(0), Slice::new(0, Some(n as isize), 1));
+        // negative lambdas lead to nan, therefore the absolute value is used
+        let lambda = weighted_densities
+            .slice_axis(Axis(0), Slice::new(n as isize, Some(2 * n as isize), 1))
+            .mapv(|l| if l.re() < 0.0 { -l } else { l } + N::from(f64::EPSILON));
+        let rho_bar = weighted_densities.slice_axis(Axis(0), Slice::new(2 * n as isize, None, 1));
+
+        // temperature dependent segment diameter
+        let d = p.hs_diameter(temperature);
+
+        // radial distribution function at contact
+        let mut ln_g = Array::zeros(lambda.raw_dim());
+        for (rho_bar, mut ln_g) in rho_bar.axis_iter(Axis(1)).zip(ln_g.axis_iter_mut(Axis(1))) {
+            if rho_bar.sum().re() > 0.0 {
+                let state = StateHD::new(temperature, N::one(), rho_bar.to_owned());
+                let properties = Properties::new(p, &state, &d);
+                ln_g.assign(&ln_g_mie(p, &properties, temperature));
+            }
+        }
+
+        // Helmholtz energy density
+        let mut phi = Array::zeros(rho.raw_dim().remove_axis(Axis(0)));
+        for (i, ((lambdai, ln_gi), rhoi)) in lambda
+            .outer_iter()
+            .zip(ln_g.outer_iter())
+            .zip(rho.outer_iter())
+            .enumerate()
+        {
+            phi = phi - (&ln_gi + &lambdai.mapv(|l| l.ln() - 1.0)) * rhoi * (p.m[i] - 1.0);
+        }
+        Ok(phi)
+    }
+}
+
+impl fmt::Display for ChainFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Chain functional")
+    }
+}
@@ -0,0 +1,84 @@
+use crate::hard_sphere::HardSphereProperties;
+use crate::saftvrmie::eos::dispersion::{a_disp, Properties};
+use crate::saftvrmie::parameters::SaftVRMieParameters;
+use feos_core::{EosResult, StateHD};
+use feos_dft::{FunctionalContribution, WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use ndarray::*;
+use num_dual::DualNum;
+use std::fmt;
+use std::sync::Arc;
+
+/// psi Parameter for DFT (Sauer2017)
+const PSI_DFT: f64 = 1.3862;
+/// psi Parameter for pDGT (Rehner2018)
+const PSI_PDGT: f64 = 1.3286;
+
+#[derive(Clone)]
+pub struct AttractiveFunctional {
+    parameters: Arc<SaftVRMieParameters>,
+}
+
+impl AttractiveFunctional {
+    pub fn new(parameters: Arc<SaftVRMieParameters>) -> Self {
+        Self { parameters }
+    }
+}
+
+fn att_weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+    p: &SaftVRMieParameters,
+    psi: f64,
+    temperature: N,
+) -> WeightFunctionInfo<N> {
+    let d = p.hs_diameter(temperature);
+    WeightFunctionInfo::new(Array1::from_shape_fn(d.len(), |i| i), false).add(
+        WeightFunction::new_scaled(d * psi, WeightFunctionShape::Theta),
+        false,
+    )
+}
+
+impl FunctionalContribution for AttractiveFunctional {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        att_weight_functions(&self.parameters, PSI_DFT, temperature)
+    }
+
+    fn weight_functions_pdgt<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        att_weight_functions(&self.parameters, PSI_PDGT, temperature)
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+        density: ArrayView2<N>,
+    ) -> EosResult<Array1<N>> {
+        let p = &self.parameters;
+
+        // temperature dependent segment diameter
+        let d = p.hs_diameter(temperature);
+
+        let phi = density
+            .axis_iter(Axis(1))
+            .map(|rho| {
+                if rho.sum().re() > 0.0 {
+                    let state = StateHD::new(temperature, N::one(), rho.to_owned());
+                    let properties = Properties::new(p, &state, &d);
+                    a_disp(p, &properties, &state)
+                } else {
+                    N::zero()
+                }
+            })
+            .collect();
+        Ok(phi)
+    }
+}
+
+impl fmt::Display for AttractiveFunctional {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Attractive functional")
+    }
+}
@@ -0,0 +1,154 @@
+use super::eos::association::Association;
+use super::eos::SaftVRMieOptions;
+use super::SaftVRMieParameters;
+use crate::hard_sphere::{FMTContribution, FMTVersion};
+use feos_core::parameter::Parameter;
+use feos_core::si::{MolarWeight, GRAM, MOL};
+use feos_core::{Components, EosResult};
+use feos_derive::FunctionalContribution;
+use feos_dft::adsorption::FluidParameters;
+use feos_dft::solvation::PairPotential;
+use feos_dft::{
+    FunctionalContribution, HelmholtzEnergyFunctional, MoleculeShape, WeightFunctionInfo, DFT,
+};
+use ndarray::{Array, Array1, Array2, ArrayView2, ScalarOperand};
+use num_dual::DualNum;
+use std::f64::consts::FRAC_PI_6;
+use std::sync::Arc;
+
+mod chain;
+mod dispersion;
+use chain::ChainFunctional;
+use dispersion::AttractiveFunctional;
+
+/// SAFT-VR Mie Helmholtz energy functional.
+pub struct SaftVRMieFunctional {
+    pub parameters: Arc<SaftVRMieParameters>,
+    fmt_version: FMTVersion,
+    options: SaftVRMieOptions,
+}
+
+impl SaftVRMieFunctional {
+    pub fn new(parameters: Arc<SaftVRMieParameters>) -> DFT<Self> {
+        Self::with_options(
+            parameters,
+            FMTVersion::WhiteBear,
+            SaftVRMieOptions::default(),
+        )
+    }
+
+    pub fn new_full(parameters: Arc<SaftVRMieParameters>, fmt_version: FMTVersion) -> DFT<Self> {
+        Self::with_options(parameters, fmt_version, SaftVRMieOptions::default())
+    }
+
+    pub fn with_options(
+        parameters: Arc<SaftVRMieParameters>,
+        fmt_version: FMTVersion,
+        saft_options: SaftVRMieOptions,
+    ) -> DFT<Self> {
+        DFT(Self {
+            parameters,
+            fmt_version,
+            options: saft_options,
+        })
+    }
+}
+
+impl Components for SaftVRMieFunctional {
+    fn components(&self) -> usize {
+        self.parameters.pure_records.len()
+    }
+
+    fn subset(&self, component_list: &[usize]) -> Self {
+        Self::with_options(
+            Arc::new(self.parameters.subset(component_list)),
+            self.fmt_version,
+            self.options,
+        )
+        .0
+    }
+}
+
+impl HelmholtzEnergyFunctional for SaftVRMieFunctional {
+    type Contribution = SaftVRMieFunctionalContribution;
+
+    fn compute_max_density(&self, moles: &Array1<f64>) -> f64 {
+        self.options.max_eta * moles.sum()
+            / (FRAC_PI_6 * &self.parameters.m * self.parameters.sigma.mapv(|v| v.powi(3)) * moles)
+                .sum()
+    }
+
+    fn contributions(&self) -> Box<dyn Iterator<Item = SaftVRMieFunctionalContribution>> {
+        let p = &self.parameters;
+        let mut contributions = Vec::with_capacity(4);
+
+        // Hard sphere contribution
+        let hs = FMTContribution::new(p, self.fmt_version);
+        contributions.push(hs.into());
+
+        // Chain contribution
+        if p.m.iter().any(|&m| m > 1.0) {
+            let chain = ChainFunctional::new(p.clone());
+            contributions.push(chain.into());
+        }
+
+        // Dispersion
+        let att = AttractiveFunctional::new(p.clone());
+        contributions.push(att.into());
+
+        // Association
+        if !p.association.is_empty() {
+            let assoc = Association::new(
+                p,
+                &p.association,
+                self.options.max_iter_cross_assoc,
+                self.options.tol_cross_assoc,
+            );
+            contributions.push(assoc.into());
+        }
+
+        Box::new(contributions.into_iter())
+    }
+
+    fn molar_weight(&self) -> MolarWeight<Array1<f64>> {
+        self.parameters.molarweight.clone() * GRAM / MOL
+    }
+
+    fn molecule_shape(&self) -> MoleculeShape {
+        MoleculeShape::NonSpherical(&self.parameters.m)
+    }
+}
+
+impl FluidParameters for SaftVRMieFunctional {
+    fn epsilon_k_ff(&self) -> Array1<f64> {
+        self.parameters.epsilon_k.clone()
+    }
+
+    fn sigma_ff(&self) -> &Array1<f64> {
+        &self.parameters.sigma
+    }
+}
+
+impl PairPotential for SaftVRMieFunctional {
+    fn pair_potential(&self, i: usize, r: &Array1<f64>, temperature: f64) -> Array2<f64> {
+        let p = &self.parameters;
+        let epsilon_k_ij = p
+            .k_ij_t
+            .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, temperature);
+        Array::from_shape_fn((p.m.len(), r.len()), |(j, k)| {
+            let s = p.sigma_ij[[i, j]] / r[k];
+            p.c_ij[[i, j]]
+                * epsilon_k_ij[[i, j]]
+                * (s.powf(p.lr_ij[[i, j]]) - s.powf(p.la_ij[[i, j]]))
+        })
+    }
+}
+
+/// Individual contributions for the SAFT-VR Mie Helmholtz energy functional.
+#[derive(FunctionalContribution)]
+pub enum SaftVRMieFunctionalContribution {
+    Fmt(FMTContribution<SaftVRMieParameters>),
+    Chain(ChainFunctional),
+    Attractive(AttractiveFunctional),
+    Association(Association<SaftVRMieParameters>),
+}
@@ -12,6 +12,9 @@ use std::f64::consts::PI;
 use std::fmt;
 use std::sync::Arc;
 
+#[cfg(feature = "dft")]
+mod dft;
+
 #[derive(Clone, Copy, Debug)]
 struct AssociationSite {
     assoc_comp: usize,
@@ -377,7 +380,6 @@ impl<P: HardSphereProperties> Association<P> {
         diameter: &Array1<D>,
     ) -> D {
         let p: &P = &self.parameters;
-        let a = &self.association_parameters;
 
         // auxiliary variables
         let [zeta2, n3] = p.zeta(state.temperature, &state.partial_density, [2, 3]);
@@ -388,18 +390,31 @@ impl<P: HardSphereProperties> Association<P> {
         let [delta_ab, delta_cc] =
             self.association_strength(state.temperature, diameter, n2, n3i, D::one());
 
+        self.helmholtz_energy_from_association_strength(state, &delta_ab, &delta_cc, None)
+            .unwrap_or_else(|_| D::from(std::f64::NAN))
+    }
+
+    /// Helmholtz energy for given association strengths. If provided, `x0` is
+    /// used as initial value for the fraction of non-bonded sites in the
+    /// cross association and updated with the solution.
+    fn helmholtz_energy_from_association_strength<D: DualNum<f64> + Copy>(
+        &self,
+        state: &StateHD<D>,
+        delta_ab: &Array2<D>,
+        delta_cc: &Array2<D>,
+        x0: Option<&mut Array1<f64>>,
+    ) -> EosResult<D> {
+        let a = &self.association_parameters;
         match (
             a.sites_a.len() * a.sites_b.len(),
             a.sites_c.len(),
             self.force_cross_association,
         ) {
-            (0, 0, _) => D::zero(),
-            (1, 0, false) => self.helmholtz_energy_ab_analytic(state, delta_ab[(0, 0)]),
-            (0, 1, false) => self.helmholtz_energy_cc_analytic(state, delta_cc[(0, 0)]),
-            (1, 1, false) => {
-                self.helmholtz_energy_ab_analytic(state, delta_ab[(0, 0)])
-                    + self.helmholtz_energy_cc_analytic(state, delta_cc[(0, 0)])
-            }
+            (0, 0, _) => Ok(D::zero()),
+            (1, 0, false) => Ok(self.helmholtz_energy_ab_analytic(state, delta_ab[(0, 0)])),
+            (0, 1, false) => Ok(self.helmholtz_energy_cc_analytic(state, delta_cc[(0, 0)])),
+            (1, 1, false) => Ok(self.helmholtz_energy_ab_analytic(state, delta_ab[(0, 0)])
+                + self.helmholtz_energy_cc_analytic(state, delta_cc[(0, 0)])),
             _ => {
                 // extract site densities of associating segments
                 let rho: Array1<_> = a
@@ -411,16 +426,14 @@ impl<P: HardSphereProperties> Association<P> {
                     .collect();
 
                 // Helmholtz energy
-                Self::helmholtz_energy_density_cross_association(
+                Ok(Self::helmholtz_energy_density_cross_association(
                     &rho,
-                    &delta_ab,
-                    &delta_cc,
+                    delta_ab,
+                    delta_cc,
                     self.max_iter,
                     self.tol,
-                    None,
-                )
-                .unwrap_or_else(|_| D::from(std::f64::NAN))
-                    * state.volume
+                    x0,
+                )? * state.volume)
             }
         }
     }
@@ -0,0 +1,122 @@
+use super::*;
+use feos_dft::{FunctionalContribution, WeightFunction, WeightFunctionInfo, WeightFunctionShape};
+use std::ops::MulAssign;
+
+const N0_CUTOFF: f64 = 1e-9;
+
+impl<P: HardSphereProperties + Sync + Send> FunctionalContribution for Association<P> {
+    fn weight_functions<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+    ) -> WeightFunctionInfo<N> {
+        let p = &self.parameters;
+        let r = p.hs_diameter(temperature) * 0.5;
+        let [_, _, _, c3] = p.geometry_coefficients(temperature);
+        WeightFunctionInfo::new(p.component_index().into_owned(), false)
+            .add(
+                WeightFunction::new_scaled(r.clone(), WeightFunctionShape::Delta),
+                false,
+            )
+            .add(
+                WeightFunction {
+                    prefactor: c3.clone(),
+                    kernel_radius: r.clone(),
+                    shape: WeightFunctionShape::DeltaVec,
+                },
+                false,
+            )
+            .add(
+                WeightFunction {
+                    prefactor: c3,
+                    kernel_radius: r,
+                    shape: WeightFunctionShape::Theta,
+                },
+                true,
+            )
+    }
+
+    fn helmholtz_energy_density<N: DualNum<f64> + Copy + ScalarOperand>(
+        &self,
+        temperature: N,
+        weighted_densities: ArrayView2<N>,
+    ) -> EosResult<Array1<N>> {
+        let p = &self.parameters;
+
+        // number of segments
+        let n = self.association_parameters.component_index.len();
+
+        // number of dimensions
+        let dim = (weighted_densities.shape()[0] - 1) / n - 1;
+
+        // weighted densities
+        let n0i = weighted_densities.slice_axis(Axis(0), Slice::new(0, Some(n as isize), 1));
+        let n2vi: Vec<_> = (0..dim)
+            .map(|i| {
+                weighted_densities.slice_axis(
+                    Axis(0),
+                    Slice::new((n * (i + 1)) as isize, Some((n * (i + 2)) as isize), 1),
+                )
+            })
+            .collect();
+        let n3 = weighted_densities.index_axis(Axis(0), n * (dim + 1));
+
+        // calculate rho0
+        let [_, _, c2, _] = p.geometry_coefficients(temperature);
+        let diameter = p.hs_diameter(temperature);
+        let mut n2i = n0i.to_owned();
+        for (i, mut n2i) in n2i.outer_iter_mut().enumerate() {
+            n2i.mul_assign(diameter[i].powi(2) * c2[i] * PI);
+        }
+        let mut rho0: Array2<N> = (n2vi
+            .iter()
+            .fold(Array::zeros(n0i.raw_dim()), |acc, n2vi| acc + n2vi * n2vi)
+            / -(&n2i * &n2i)
+            + 1.0)
+            * n0i;
+        rho0.iter_mut().zip(&n0i).for_each(|(rho0, &n0i)| {
+            if n0i.re() < N0_CUTOFF {
+                *rho0 = n0i;
+            }
+        });
+
+        // calculate xi
+        let n2v: Vec<_> = n2vi.iter().map(|n2vi| n2vi.sum_axis(Axis(0))).collect();
+        let n2 = n2i.sum_axis(Axis(0));
+        let mut xi = n2v
+            .iter()
+            .fold(Array::zeros(n2.raw_dim()), |acc, n2v| acc + n2v * n2v)
+            / -(&n2 * &n2)
+            + 1.0;
+        xi.iter_mut()
+            .zip(&n0i.sum_axis(Axis(0)))
+            .for_each(|(xi, &n0i)| {
+                if n0i.re() < N0_CUTOFF {
+                    *xi = N::one();
+                }
+            });
+
+        // auxiliary variables
+        let n3i = n3.mapv(|n3| (-n3 + 1.0).recip());
+
+        // the fractions of non-bonded sites of the previous grid point
+        // are used as initial values for the cross association
+        let a = &self.association_parameters;
+        let mut x0 = Array::from_elem(a.sites_a.len() + a.sites_b.len() + a.sites_c.len(), 0.2);
+        rho0.axis_iter(Axis(1))
+            .zip(n2.iter())
+            .zip(n3i.iter())
+            .zip(xi.iter())
+            .map(|(((rho0, &n2), &n3i), &xi)| {
+                let state = StateHD::new(temperature, N::one(), rho0.to_owned());
+                let [delta_ab, delta_cc] =
+                    self.association_strength(temperature, &diameter, n2, n3i, xi);
+                self.helmholtz_energy_from_association_strength(
+                    &state,
+                    &delta_ab,
+                    &delta_cc,
+                    Some(&mut x0),
+                )
+            })
+            .collect()
+    }
+}
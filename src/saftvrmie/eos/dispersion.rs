@@ -26,7 +26,7 @@ pub struct Properties<D> {
 }
 
 impl<D: DualNum<f64> + Copy + Zero + ScalarOperand> Properties<D> {
-    pub(super) fn new(
+    pub(crate) fn new(
         parameters: &SaftVRMieParameters,
         state: &StateHD<D>,
         diameter: &Array1<D>,
@@ -107,6 +107,9 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
     let n = p.sigma.len();
     let xs = &properties.segment_molefracs;
     let t_inv = state.temperature.inv();
+    let epsilon_k_ij = p
+        .k_ij_t
+        .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, state.temperature);
     let zeta_x = properties.zeta_x;
     let k_hs = (zeta_x - 1.0).powi(4)
         / ((zeta_x + zeta_x.powi(2) - zeta_x.powi(3)) * 4.0 + zeta_x.powi(4) + 1.0);
@@ -155,7 +158,7 @@ pub fn a_disp<D: DualNum<f64> + Copy + ScalarOperand>(
 
         for j in i + 1..n {
             // parameters
-            let eps_k = p.epsilon_k_ij[[i, j]];
+            let eps_k = epsilon_k_ij[[i, j]];
             let sig = p.sigma_ij[[i, j]];
             let la = p.la_ij[[i, j]];
             let lr = p.lr_ij[[i, j]];
@@ -201,26 +204,16 @@ pub fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
 ) -> D {
     let p = &parameters;
     let n = p.sigma.len();
-    let k = &properties.k0;
     let xs = &properties.segment_molefracs;
     let t_inv = state.temperature.inv();
+    let epsilon_k_ij = p
+        .k_ij_t
+        .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, state.temperature);
 
-    // wrap rho_s in Dual to calculate da1/drho_s and da2/drho_s
-    // for chain contribution on the fly.
-    let rho_s_dual = Dual::from_re(properties.segment_density).derivative();
-    let zeta_x = properties.zeta_x;
-    let zeta_x_dual = if properties.segment_density.is_zero() {
-        rho_s_dual * 0.0
-    } else {
-        Dual::from_re(zeta_x / properties.segment_density) * rho_s_dual
-    };
-    // let zeta_x_dual = Dual::from_re(zeta_x / properties.segment_density) * rho_s_dual;
-    let k_hs_dual = (zeta_x_dual - 1.0).powi(4)
-        / ((zeta_x_dual + zeta_x_dual.powi(2) - zeta_x_dual.powi(3)) * 4.0
-            + zeta_x_dual.powi(4)
-            + 1.0);
+    let (rho_s_dual, zeta_x_dual, k_hs_dual) = segment_density_dual(properties);
 
     // non-dual things
+    let zeta_x = properties.zeta_x;
     let zeta_x_bar = properties.zeta_x_bar;
     let zx5 = zeta_x_bar.powi(5);
     let zx8 = zeta_x_bar.powi(8);
@@ -235,25 +228,11 @@ pub fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
         // parameters
         let m = p.m[i];
         let eps_k = p.epsilon_k[i];
-        let sig = p.sigma[i];
-        let la = p.la[i];
-        let lr = p.lr[i];
-        let c = p.c_ij[[i, i]];
-
-        let di = properties.diameter[i];
 
-        // calculate a1 and a2 using Dual(D)
+        // calculate a1, a2 and the chain contribution using Dual(D)
         // this is only done in the outer loop
-        let d3 = Dual::from_re(di.powi(3));
-        let x0 = Dual::from_re(di.recip() * sig);
-        let pref = rho_s_dual * d3 * eps_k * 2.0 * PI * c;
-        let a1s_b_la = a1s_b_ij(zeta_x_dual, x0, la);
-        let a1s_b_lr = a1s_b_ij(zeta_x_dual, x0, lr);
-        let a1s_b_2la = a1s_b_ij(zeta_x_dual, x0, 2.0 * la);
-        let a1s_b_lalr = a1s_b_ij(zeta_x_dual, x0, la + lr);
-        let a1s_b_2lr = a1s_b_ij(zeta_x_dual, x0, 2.0 * lr);
-        let a1_ii = pref * (a1s_b_la - a1s_b_lr);
-        let a2_ii = pref * eps_k * c * k_hs_dual * 0.5 * (a1s_b_2la - a1s_b_lalr * 2.0 + a1s_b_2lr);
+        let (a1_ii, a2_ii, ln_g_mie) =
+            self_interaction(p, properties, i, t_inv, rho_s_dual, zeta_x_dual, k_hs_dual);
 
         // note indices of f(i, alpha) are shifted due to 0-indexing.
         let alpha = parameters.alpha_ij[[i, i]];
@@ -267,40 +246,20 @@ pub fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
 
         // accumulate contributions
         let xs_ii2 = xs[i] * xs[i];
-        a1 += a1_ii.re * xs_ii2;
-        a2 += a2_ii.re * xs_ii2 * (xii + 1.0);
+        a1 += a1_ii * xs_ii2;
+        a2 += a2_ii * xs_ii2 * (xii + 1.0);
         a3 += a3_ii * xs_ii2;
-
-        // calculate chain using D
-        // use dual-parts of a1_ii and a2_ii for derivatives
-        // and real-parts of a1s_b-terms.
-        let x0 = x0.re;
-        let pref = d3.re * eps_k * 2.0 * PI;
-        let g_hs = (k[0] + k[1] * x0 + k[2] * x0.powi(2) + k[3] * x0.powi(3)).exp();
-        let g1 = a1_ii.eps * 3.0 / pref - (a1s_b_la.re * la - a1s_b_lr.re * lr) * c;
-        let g2_mca = a2_ii.eps * 3.0 / pref / eps_k
-            - (a1s_b_2lr.re * lr - a1s_b_lalr.re * (la + lr) + a1s_b_2la.re * la)
-                * k_hs
-                * c.powi(2);
-        let beta_eps = t_inv * eps_k;
-        let gamma = zeta_x_bar
-            * beta_eps.exp_m1()
-            * 10.0
-            * (-(10.0 * (0.57 - alpha)).tanh() + 1.0)
-            * (-zeta_x_bar * 6.7 - zeta_x_bar.powi(2) * 8.0).exp();
-        let g2 = g2_mca * (gamma + 1.0);
-        let ln_g_mie = g_hs.ln() + (beta_eps * g1 + beta_eps.powi(2) * g2) / g_hs;
         a_chain += -state.molefracs[i] * (m - 1.0) * ln_g_mie;
 
         for j in i + 1..n {
             // parameters
-            let eps_k = p.epsilon_k_ij[[i, j]];
+            let eps_k = epsilon_k_ij[[i, j]];
             let sig = p.sigma_ij[[i, j]];
             let la = p.la_ij[[i, j]];
             let lr = p.lr_ij[[i, j]];
             let c = p.c_ij[[i, j]];
 
-            let dij = (di + properties.diameter[j]) * 0.5;
+            let dij = (properties.diameter[i] + properties.diameter[j]) * 0.5;
             let d3 = dij.powi(3);
             let x0 = dij.recip() * sig;
             let pref = properties.segment_density * d3 * eps_k * 2.0 * PI * c;
@@ -332,6 +291,109 @@ pub fn a_disp_chain<D: DualNum<f64> + Copy + ScalarOperand>(
             + a_chain)
 }
 
+/// Logarithm of the radial distribution function of the Mie fluid at contact
+/// for every component, as required for the chain contribution.
+#[cfg(feature = "dft")]
+pub(crate) fn ln_g_mie<D: DualNum<f64> + Copy + ScalarOperand>(
+    parameters: &SaftVRMieParameters,
+    properties: &Properties<D>,
+    temperature: D,
+) -> Array1<D> {
+    let t_inv = temperature.inv();
+    let (rho_s_dual, zeta_x_dual, k_hs_dual) = segment_density_dual(properties);
+    Array1::from_shape_fn(parameters.m.len(), |i| {
+        self_interaction(
+            parameters,
+            properties,
+            i,
+            t_inv,
+            rho_s_dual,
+            zeta_x_dual,
+            k_hs_dual,
+        )
+        .2
+    })
+}
+
+/// Segment density, packing fraction and isothermal compressibility of the
+/// hard-sphere fluid wrapped in Dual numbers to calculate da1/drho_s and
+/// da2/drho_s for the chain contribution on the fly.
+#[allow(clippy::type_complexity)]
+fn segment_density_dual<D: DualNum<f64> + Copy>(
+    properties: &Properties<D>,
+) -> (Dual<D, f64>, Dual<D, f64>, Dual<D, f64>) {
+    let rho_s_dual = Dual::from_re(properties.segment_density).derivative();
+    let zeta_x = properties.zeta_x;
+    let zeta_x_dual = if properties.segment_density.is_zero() {
+        rho_s_dual * 0.0
+    } else {
+        Dual::from_re(zeta_x / properties.segment_density) * rho_s_dual
+    };
+    let k_hs_dual = (zeta_x_dual - 1.0).powi(4)
+        / ((zeta_x_dual + zeta_x_dual.powi(2) - zeta_x_dual.powi(3)) * 4.0
+            + zeta_x_dual.powi(4)
+            + 1.0);
+    (rho_s_dual, zeta_x_dual, k_hs_dual)
+}
+
+/// First and second order perturbation terms of the interaction of
+/// component `i` with itself and the logarithm of the radial distribution
+/// function of the Mie fluid at contact.
+fn self_interaction<D: DualNum<f64> + Copy>(
+    parameters: &SaftVRMieParameters,
+    properties: &Properties<D>,
+    i: usize,
+    t_inv: D,
+    rho_s_dual: Dual<D, f64>,
+    zeta_x_dual: Dual<D, f64>,
+    k_hs_dual: Dual<D, f64>,
+) -> (D, D, D) {
+    // parameters
+    let p = parameters;
+    let k = &properties.k0;
+    let eps_k = p.epsilon_k[i];
+    let sig = p.sigma[i];
+    let la = p.la[i];
+    let lr = p.lr[i];
+    let c = p.c_ij[[i, i]];
+    let alpha = p.alpha_ij[[i, i]];
+    let zeta_x_bar = properties.zeta_x_bar;
+    let k_hs = k_hs_dual.re;
+
+    let di = properties.diameter[i];
+
+    // calculate a1 and a2 using Dual(D)
+    let d3 = Dual::from_re(di.powi(3));
+    let x0 = Dual::from_re(di.recip() * sig);
+    let pref = rho_s_dual * d3 * eps_k * 2.0 * PI * c;
+    let a1s_b_la = a1s_b_ij(zeta_x_dual, x0, la);
+    let a1s_b_lr = a1s_b_ij(zeta_x_dual, x0, lr);
+    let a1s_b_2la = a1s_b_ij(zeta_x_dual, x0, 2.0 * la);
+    let a1s_b_lalr = a1s_b_ij(zeta_x_dual, x0, la + lr);
+    let a1s_b_2lr = a1s_b_ij(zeta_x_dual, x0, 2.0 * lr);
+    let a1_ii = pref * (a1s_b_la - a1s_b_lr);
+    let a2_ii = pref * eps_k * c * k_hs_dual * 0.5 * (a1s_b_2la - a1s_b_lalr * 2.0 + a1s_b_2lr);
+
+    // calculate chain using D
+    // use dual-parts of a1_ii and a2_ii for derivatives
+    // and real-parts of a1s_b-terms.
+    let x0 = x0.re;
+    let pref = d3.re * eps_k * 2.0 * PI;
+    let g_hs = (k[0] + k[1] * x0 + k[2] * x0.powi(2) + k[3] * x0.powi(3)).exp();
+    let g1 = a1_ii.eps * 3.0 / pref - (a1s_b_la.re * la - a1s_b_lr.re * lr) * c;
+    let g2_mca = a2_ii.eps * 3.0 / pref / eps_k
+        - (a1s_b_2lr.re * lr - a1s_b_lalr.re * (la + lr) + a1s_b_2la.re * la) * k_hs * c.powi(2);
+    let beta_eps = t_inv * eps_k;
+    let gamma = zeta_x_bar
+        * beta_eps.exp_m1()
+        * 10.0
+        * (-(10.0 * (0.57 - alpha)).tanh() + 1.0)
+        * (-zeta_x_bar * 6.7 - zeta_x_bar.powi(2) * 8.0).exp();
+    let g2 = g2_mca * (gamma + 1.0);
+    let ln_g_mie = g_hs.ln() + (beta_eps * g1 + beta_eps.powi(2) * g2) / g_hs;
+    (a1_ii.re, a2_ii.re, ln_g_mie)
+}
+
 #[inline]
 pub(super) fn zeta_eff<D: DualNum<f64> + Copy>(zeta: D, lambda: f64) -> D {
     let li = 1. / lambda;
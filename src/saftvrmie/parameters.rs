@@ -1,8 +1,9 @@
 use std::collections::HashMap;
 
 use super::eos::association::{AssociationParameters, AssociationRecord, BinaryAssociationRecord};
+use crate::binary_interaction::{KijTemperatureDependence, TemperatureDependentKij};
 use crate::hard_sphere::{HardSphereProperties, MonomerShape};
-use feos_core::parameter::{Parameter, ParameterError, PureRecord};
+use feos_core::parameter::{FitBounds, FitRecord, Parameter, ParameterError, PureRecord};
 use ndarray::{Array, Array1, Array2};
 use num_dual::DualNum;
 use num_traits::Zero;
@@ -135,6 +136,58 @@ impl std::fmt::Display for SaftVRMieRecord {
     }
 }
 
+impl FitRecord for SaftVRMieRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["m", "sigma", "epsilon_k", "lr", "la"];
+        if self.association_record.is_some() {
+            names.extend(["rc_ab", "epsilon_k_ab"]);
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association_record.as_ref();
+        match name {
+            "m" => Some(self.m),
+            "sigma" => Some(self.sigma),
+            "epsilon_k" => Some(self.epsilon_k),
+            "lr" => Some(self.lr),
+            "la" => Some(self.la),
+            "rc_ab" => association.map(|a| a.rc_ab),
+            "epsilon_k_ab" => association.map(|a| a.epsilon_k_ab),
+            _ => None,
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association_record.as_mut();
+        let parameter = match name {
+            "m" => Some(&mut self.m),
+            "sigma" => Some(&mut self.sigma),
+            "epsilon_k" => Some(&mut self.epsilon_k),
+            "lr" => Some(&mut self.lr),
+            "la" => Some(&mut self.la),
+            "rc_ab" => association.map(|a| &mut a.rc_ab),
+            "epsilon_k_ab" => association.map(|a| &mut a.epsilon_k_ab),
+            _ => None,
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "epsilon_k" => FitBounds::positive(100.0),
+            "lr" => FitBounds::new(6.0, f64::INFINITY, 10.0),
+            "la" => FitBounds::new(3.0, f64::INFINITY, 1.0),
+            "rc_ab" => FitBounds::positive(0.1),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            _ => FitBounds::positive(1.0),
+        }
+    }
+}
+
 /// SAFT-VR Mie binary interaction parameters.
 #[derive(Serialize, Deserialize, Clone, Default)]
 pub struct SaftVRMieBinaryRecord {
@@ -146,6 +199,10 @@ pub struct SaftVRMieBinaryRecord {
     #[serde(skip_serializing_if = "f64::is_zero")]
     #[serde(default)]
     pub gamma_ij: f64,
+    /// Temperature dependence of the binary dispersion energy interaction parameter
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub k_ij_t: Option<KijTemperatureDependence>,
     /// Binary association parameters
     #[serde(flatten)]
     association: Option<BinaryAssociationRecord>,
@@ -168,9 +225,16 @@ impl SaftVRMieBinaryRecord {
         Self {
             k_ij,
             gamma_ij,
+            k_ij_t: None,
             association,
         }
     }
+
+    /// Add a temperature dependence to the binary dispersion energy interaction parameter.
+    pub fn with_k_ij_t(mut self, k_ij_t: KijTemperatureDependence) -> Self {
+        self.k_ij_t = Some(k_ij_t);
+        self
+    }
 }
 
 impl From<f64> for SaftVRMieBinaryRecord {
@@ -178,6 +242,7 @@ impl From<f64> for SaftVRMieBinaryRecord {
         Self {
             k_ij,
             gamma_ij: f64::default(),
+            k_ij_t: None,
             association: None,
         }
     }
@@ -198,6 +263,9 @@ impl std::fmt::Display for SaftVRMieBinaryRecord {
         if !self.gamma_ij.is_zero() {
             tokens.push(format!("gamma_ij={}", self.gamma_ij));
         }
+        if let Some(k_ij_t) = &self.k_ij_t {
+            tokens.push(format!("k_ij_t={}", k_ij_t));
+        }
         if let Some(association) = self.association {
             if let Some(rc_ab) = association.rc_ab {
                 tokens.push(format!("rc_ab={}", rc_ab));
@@ -210,6 +278,61 @@ impl std::fmt::Display for SaftVRMieBinaryRecord {
     }
 }
 
+impl FitRecord for SaftVRMieBinaryRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["k_ij", "gamma_ij"];
+        if let Some(k_ij_t) = &self.k_ij_t {
+            names.extend(k_ij_t.names());
+        }
+        if let Some(association) = self.association.as_ref() {
+            if association.rc_ab.is_some() {
+                names.push("rc_ab");
+            }
+            if association.epsilon_k_ab.is_some() {
+                names.push("epsilon_k_ab");
+            }
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        let association = self.association.as_ref();
+        match name {
+            "k_ij" => Some(self.k_ij),
+            "gamma_ij" => Some(self.gamma_ij),
+            "rc_ab" => association.and_then(|a| a.rc_ab),
+            "epsilon_k_ab" => association.and_then(|a| a.epsilon_k_ab),
+            _ => self.k_ij_t.as_ref().and_then(|k| k.get(name)),
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let association = self.association.as_mut();
+        let parameter = match name {
+            "k_ij" => Some(&mut self.k_ij),
+            "gamma_ij" => Some(&mut self.gamma_ij),
+            "rc_ab" => association.and_then(|a| a.rc_ab.as_mut()),
+            "epsilon_k_ab" => association.and_then(|a| a.epsilon_k_ab.as_mut()),
+            _ => self.k_ij_t.as_mut().and_then(|k| k.get_mut(name)),
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "rc_ab" => FitBounds::positive(0.1),
+            "epsilon_k_ab" => FitBounds::positive(1000.0),
+            "k_ij" | "gamma_ij" => FitBounds::new(-1.0, 1.0, 0.01),
+            _ => self
+                .k_ij_t
+                .as_ref()
+                .map_or_else(FitBounds::default, |k| k.bounds(name)),
+        }
+    }
+}
+
 /// Parameter set required for the SAFT-VR Mie equation of state.
 pub struct SaftVRMieParameters {
     pub molarweight: Array1<f64>,
@@ -222,6 +345,7 @@ pub struct SaftVRMieParameters {
     pub sigma_ij: Array2<f64>,
     pub epsilon_k_ij: Array2<f64>,
     pub e_k_ij: Array2<f64>,
+    pub k_ij_t: TemperatureDependentKij,
     pub lr_ij: Array2<f64>,
     pub la_ij: Array2<f64>,
     pub c_ij: Array2<f64>,
@@ -309,6 +433,8 @@ impl Parameter for SaftVRMieParameters {
             }
         }
 
+        let k_ij_t = TemperatureDependentKij::new(br, |br| br.k_ij_t.as_ref());
+
         let viscosity_coefficients = if viscosity.iter().any(|v| v.is_none()) {
             None
         } else {
@@ -351,6 +477,7 @@ impl Parameter for SaftVRMieParameters {
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
+            k_ij_t,
             lr_ij,
             la_ij,
             c_ij,
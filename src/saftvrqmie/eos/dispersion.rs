@@ -50,7 +50,7 @@ impl<D: DualNum<f64> + Copy> Alpha<D> {
         for i in 0..nc {
             for j in i..nc {
                 let sigma_ratio = D::one() * p.sigma_ij[[i, j]] / sigma_eff_ij[[i, j]];
-                let eps_ratio = D::one() * p.epsilon_k_ij[[i, j]] / epsilon_k_eff_ij[[i, j]];
+                let eps_ratio = p.epsilon_k_ij_t(i, j, temperature) / epsilon_k_eff_ij[[i, j]];
                 let la = p.lambda_a_ij[[i, j]];
                 let lr = p.lambda_r_ij[[i, j]];
                 let sigma_ratio_a = sigma_ratio.powf(la);
@@ -133,9 +133,32 @@ impl Dispersion {
         // alphas ....
         let alpha = Alpha::new(p, s_eff_ij, epsilon_k_eff_ij, state.temperature);
 
-        let a1 = first_order_perturbation(p, &x_s, zeta, rho_s, d_hs_ij, s_eff_ij, dq_ij);
+        // energy parameters with temperature-dependent binary interaction parameters
+        let epsilon_k_ij = p
+            .k_ij_t
+            .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, state.temperature);
+
+        let a1 = first_order_perturbation(
+            p,
+            &x_s,
+            zeta,
+            rho_s,
+            d_hs_ij,
+            s_eff_ij,
+            &epsilon_k_ij,
+            dq_ij,
+        );
         let a2 = second_order_perturbation(
-            p, &alpha, &x_s, zeta, zeta_bar, rho_s, d_hs_ij, s_eff_ij, dq_ij,
+            p,
+            &alpha,
+            &x_s,
+            zeta,
+            zeta_bar,
+            rho_s,
+            d_hs_ij,
+            s_eff_ij,
+            &epsilon_k_ij,
+            dq_ij,
         );
         let a3 = third_order_perturbation(p, &alpha, &x_s, zeta_bar, epsilon_k_eff_ij);
 
@@ -175,9 +198,32 @@ pub fn dispersion_energy_density<D: DualNum<f64> + Copy>(
     let zeta = zeta_saft_vrq_mie(&p.m, &x_s, d_hs_ij, rho_s);
     let zeta_bar = zeta_saft_vrq_mie(&p.m, &x_s, s_eff_ij, rho_s);
 
-    let a1 = first_order_perturbation(p, &x_s, zeta, rho_s, d_hs_ij, s_eff_ij, dq_ij);
+    // energy parameters with temperature-dependent binary interaction parameters
+    let epsilon_k_ij = p
+        .k_ij_t
+        .epsilon_k_ij(&p.epsilon_k_ij, &p.e_k_ij, temperature);
+
+    let a1 = first_order_perturbation(
+        p,
+        &x_s,
+        zeta,
+        rho_s,
+        d_hs_ij,
+        s_eff_ij,
+        &epsilon_k_ij,
+        dq_ij,
+    );
     let a2 = second_order_perturbation(
-        p, alpha, &x_s, zeta, zeta_bar, rho_s, d_hs_ij, s_eff_ij, dq_ij,
+        p,
+        alpha,
+        &x_s,
+        zeta,
+        zeta_bar,
+        rho_s,
+        d_hs_ij,
+        s_eff_ij,
+        &epsilon_k_ij,
+        dq_ij,
     );
     let a3 = third_order_perturbation(p, alpha, &x_s, zeta_bar, epsilon_k_eff_ij);
 
@@ -207,6 +253,7 @@ fn first_order_perturbation<D: DualNum<f64> + Copy>(
     rho_s: D,
     d_hs_ij: &Array2<D>,
     s_eff_ij: &Array2<D>,
+    epsilon_k_ij: &Array2<D>,
     dq_ij: &Array2<D>,
 ) -> D {
     let n = parameters.sigma.len();
@@ -226,7 +273,7 @@ fn first_order_perturbation<D: DualNum<f64> + Copy>(
                 * first_order_perturbation_ij(
                     parameters.lambda_a_ij[[i, j]],
                     parameters.lambda_r_ij[[i, j]],
-                    parameters.epsilon_k_ij[[i, j]],
+                    epsilon_k_ij[[i, j]],
                     zeta,
                     x0,
                     x0_eff,
@@ -242,7 +289,7 @@ fn first_order_perturbation<D: DualNum<f64> + Copy>(
 fn first_order_perturbation_ij<D: DualNum<f64> + Copy>(
     lambda_a: f64,
     lambda_r: f64,
-    epsilon_k: f64,
+    epsilon_k: D,
     zeta: D,
     x0: D,
     x0_eff: D,
@@ -287,7 +334,7 @@ fn eta_eff<D: DualNum<f64> + Copy>(lambda: f64, zeta: D) -> D {
     zeta * (zeta * (zeta * (zeta * c[3] + c[2]) + c[1]) + c[0])
 }
 
-fn sutherland<D: DualNum<f64> + Copy>(lambda: f64, epsilon_k: f64, zeta: D, x0: D) -> D {
+fn sutherland<D: DualNum<f64> + Copy>(lambda: f64, epsilon_k: D, zeta: D, x0: D) -> D {
     let ef = eta_eff(lambda, zeta);
     (-ef * 0.5 + 1.0) * -12.0 * x0.powf(lambda) * epsilon_k / (lambda - 3.0) / (-ef + 1.0).powi(3)
 }
@@ -305,7 +352,7 @@ fn jlambda<D: DualNum<f64>>(lambda: f64, x0: D) -> D {
 /// B is divided by the packing fraction
 ///
 /// \author Morten Hammer, February 2018
-fn b<D: DualNum<f64> + Copy>(lambda: f64, epsilon_k: f64, zeta: D, x0: D, x0_eff: D) -> D {
+fn b<D: DualNum<f64> + Copy>(lambda: f64, epsilon_k: D, zeta: D, x0: D, x0_eff: D) -> D {
     let ilambda = ilambda(lambda, x0_eff);
     let jlambda = jlambda(lambda, x0_eff);
     let denum = (-zeta + 1.0).powi(3);
@@ -318,7 +365,7 @@ fn b<D: DualNum<f64> + Copy>(lambda: f64, epsilon_k: f64, zeta: D, x0: D, x0_eff
 #[inline]
 fn combine_sutherland_and_b<D: DualNum<f64> + Copy>(
     lambda: f64,
-    epsilon_k: f64,
+    epsilon_k: D,
     zeta: D,
     x0: D,
     x0_eff: D,
@@ -337,6 +384,7 @@ fn second_order_perturbation<D: DualNum<f64> + Copy>(
     rho_s: D,
     d_hs_ij: &Array2<D>,
     s_eff_ij: &Array2<D>,
+    epsilon_k_ij: &Array2<D>,
     dq_ij: &Array2<D>,
 ) -> D {
     let n = parameters.sigma.len();
@@ -365,7 +413,7 @@ fn second_order_perturbation<D: DualNum<f64> + Copy>(
                 * second_order_perturbation_ij(
                     parameters.lambda_a_ij[[i, j]],
                     parameters.lambda_r_ij[[i, j]],
-                    parameters.epsilon_k_ij[[i, j]],
+                    epsilon_k_ij[[i, j]],
                     zeta,
                     x0,
                     x0_eff,
@@ -391,7 +439,7 @@ fn quantum_prefactor_second_order(lambda: f64) -> f64 {
 fn second_order_perturbation_ij<D: DualNum<f64> + Copy>(
     lambda_a: f64,
     lambda_r: f64,
-    epsilon_k: f64,
+    epsilon_k: D,
     zeta: D,
     x0: D,
     x0_eff: D,
@@ -447,7 +495,7 @@ fn second_order_perturbation_ij<D: DualNum<f64> + Copy>(
         }
         a2_ij += int_a * afac + int_ar * arfac + int_r * rfac;
     }
-    a2_ij * 0.5 * epsilon_k * c.powi(2)
+    a2_ij * epsilon_k * 0.5 * c.powi(2)
 }
 
 fn third_order_perturbation<D: DualNum<f64> + Copy>(
@@ -492,9 +540,12 @@ impl fmt::Display for Dispersion {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::binary_interaction::KijTemperatureDependence;
     use crate::saftvrqmie::parameters::utils::h2_ne_fh;
     use crate::saftvrqmie::parameters::utils::hydrogen_fh;
+    use crate::saftvrqmie::SaftVRQMieBinaryRecord;
     use approx::assert_relative_eq;
+    use feos_core::parameter::Parameter;
     use ndarray::arr1;
 
     #[test]
@@ -746,9 +797,11 @@ mod tests {
         let dq_ij =
             Array2::from_shape_fn((nc, nc), |(i, j)| p.quantum_d_ij(i, j, state.temperature));
 
-        let a1 = first_order_perturbation(&p, &x_s, zeta, rho_s, &d_hs_ij, &s_eff_ij, &dq_ij);
+        let eps_ij = &p.epsilon_k_ij;
+        let a1 =
+            first_order_perturbation(&p, &x_s, zeta, rho_s, &d_hs_ij, &s_eff_ij, eps_ij, &dq_ij);
         let a2 = second_order_perturbation(
-            &p, &alpha, &x_s, zeta, zeta_bar, rho_s, &d_hs_ij, &s_eff_ij, &dq_ij,
+            &p, &alpha, &x_s, zeta, zeta_bar, rho_s, &d_hs_ij, &s_eff_ij, eps_ij, &dq_ij,
         );
         let a3 = third_order_perturbation(&p, &alpha, &x_s, zeta_bar, &epsilon_k_eff_ij);
 
@@ -806,9 +859,11 @@ mod tests {
         let dq_ij =
             Array2::from_shape_fn((nc, nc), |(i, j)| p.quantum_d_ij(i, j, state.temperature));
 
-        let a1 = first_order_perturbation(&p, &x_s, zeta, rho_s, &d_hs_ij, &s_eff_ij, &dq_ij);
+        let eps_ij = &p.epsilon_k_ij;
+        let a1 =
+            first_order_perturbation(&p, &x_s, zeta, rho_s, &d_hs_ij, &s_eff_ij, eps_ij, &dq_ij);
         let a2 = second_order_perturbation(
-            &p, &alpha, &x_s, zeta, zeta_bar, rho_s, &d_hs_ij, &s_eff_ij, &dq_ij,
+            &p, &alpha, &x_s, zeta, zeta_bar, rho_s, &d_hs_ij, &s_eff_ij, eps_ij, &dq_ij,
         );
         let a3 = third_order_perturbation(&p, &alpha, &x_s, zeta_bar, &epsilon_k_eff_ij);
 
@@ -929,6 +984,29 @@ mod tests {
         assert_relative_eq!(a_disp, a_ref[0] * 2.0, epsilon = 5e-7);
     }
 
+    #[test]
+    fn test_temperature_dependent_k_ij() {
+        let p = h2_ne_fh("1");
+        let t = 30.0;
+        let k_ij_t = KijTemperatureDependence::polynomial(vec![1e-3], 25.0).unwrap();
+        let k_ij = 0.105 + k_ij_t.evaluate(t);
+        let binary = |record| {
+            Arc::new(
+                SaftVRQMieParameters::new_binary(p.pure_records.clone(), Some(record)).unwrap(),
+            )
+        };
+        let p_t = binary(SaftVRQMieBinaryRecord::new(0.105, 0.0).with_k_ij_t(k_ij_t));
+        let p_const = binary(SaftVRQMieBinaryRecord::new(k_ij, 0.0));
+
+        let na = 6.02214076e23;
+        let state = StateHD::new(t, 1.0e26, arr1(&[1.1 * na, 1.0 * na]));
+        let a = |parameters: Arc<SaftVRQMieParameters>| {
+            let properties = TemperatureDependentProperties::new(&parameters, t);
+            Dispersion { parameters }.helmholtz_energy(&state, &properties)
+        };
+        assert_relative_eq!(a(p_t), a(p_const), max_relative = 1e-12);
+    }
+
     #[cfg(feature = "dft")]
     #[test]
     fn test_dispersion_energy_density() {
@@ -180,6 +180,12 @@ impl SaftVRQMieParameters {
         quantum_d_mass(self.mass_ij[[i, j]], temperature)
     }
 
+    /// Dispersion energy parameter including the temperature dependence of k_ij
+    pub fn epsilon_k_ij_t<D: DualNum<f64> + Copy>(&self, i: usize, j: usize, temperature: D) -> D {
+        self.k_ij_t
+            .epsilon_k(i, j, &self.epsilon_k_ij, &self.e_k_ij, temperature)
+    }
+
     /// Feynman-Hibbs corrected potential
     pub fn qmie_potential_ij<D: DualNum<f64> + Copy>(
         &self,
@@ -191,7 +197,7 @@ impl SaftVRQMieParameters {
         let lr = self.lambda_r_ij[[i, j]];
         let la = self.lambda_a_ij[[i, j]];
         let s = self.sigma_ij[[i, j]];
-        let eps = self.epsilon_k_ij[[i, j]];
+        let eps = self.epsilon_k_ij_t(i, j, temperature);
         let c = self.c_ij[[i, j]];
 
         let q1r = lr * (lr - 1.0);
@@ -226,9 +232,9 @@ impl SaftVRQMieParameters {
                 * (r.powf(lr + 6.0).recip() * q2r * (lr + 4.0) * (lr + 5.0) * s.powf(lr)
                     - r.powf(la + 6.0).recip() * q2a * (la + 4.0) * (la + 5.0) * s.powf(la));
         }
-        u *= c * eps;
-        u_r *= c * eps;
-        u_rr *= c * eps;
+        u *= eps * c;
+        u_r *= eps * c;
+        u_rr *= eps * c;
         [u, u_r, u_rr]
     }
 }
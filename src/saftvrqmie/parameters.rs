@@ -1,3 +1,4 @@
+use crate::binary_interaction::{KijTemperatureDependence, TemperatureDependentKij};
 use crate::saftvrqmie::eos::FeynmanHibbsOrder;
 use core::cmp::max;
 use feos_core::parameter::{Parameter, ParameterError, PureRecord};
@@ -96,6 +97,26 @@ pub struct SaftVRQMieBinaryRecord {
     pub k_ij: f64,
     /// correction to diameter
     pub l_ij: f64,
+    /// temperature dependence of the correction to energy parameters
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub k_ij_t: Option<KijTemperatureDependence>,
+}
+
+impl SaftVRQMieBinaryRecord {
+    pub fn new(k_ij: f64, l_ij: f64) -> Self {
+        Self {
+            k_ij,
+            l_ij,
+            k_ij_t: None,
+        }
+    }
+
+    /// Add a temperature dependence to the binary interaction parameter.
+    pub fn with_k_ij_t(mut self, k_ij_t: KijTemperatureDependence) -> Self {
+        self.k_ij_t = Some(k_ij_t);
+        self
+    }
 }
 
 impl std::fmt::Display for SaftVRQMieBinaryRecord {
@@ -103,6 +124,9 @@ impl std::fmt::Display for SaftVRQMieBinaryRecord {
         write!(f, "SaftVRQMieBinaryParameters(")?;
         write!(f, "\n\tk_ij={}", self.k_ij)?;
         write!(f, "\n\tl_ij={}", self.l_ij)?;
+        if let Some(k_ij_t) = &self.k_ij_t {
+            write!(f, "\n\tk_ij_t={}", k_ij_t)?;
+        }
         write!(f, "\n)")
     }
 }
@@ -138,6 +162,7 @@ pub struct SaftVRQMieParameters {
     pub sigma_ij: Array2<f64>,
     pub epsilon_k_ij: Array2<f64>,
     pub e_k_ij: Array2<f64>,
+    pub k_ij_t: TemperatureDependentKij,
     pub lr: Array1<f64>,
     pub la: Array1<f64>,
     pub fh: Array1<usize>,
@@ -243,6 +268,8 @@ impl Parameter for SaftVRQMieParameters {
             }
         }
 
+        let k_ij_t = TemperatureDependentKij::new(br, |br| br.k_ij_t.as_ref());
+
         let viscosity_coefficients = if viscosity.iter().any(|v| v.is_none()) {
             None
         } else {
@@ -284,6 +311,7 @@ impl Parameter for SaftVRQMieParameters {
             sigma_ij,
             epsilon_k_ij,
             e_k_ij,
+            k_ij_t,
             lr,
             la,
             fh,
@@ -575,10 +603,7 @@ pub mod utils {
         Arc::new(
             SaftVRQMieParameters::new_binary(
                 binary_record,
-                Some(SaftVRQMieBinaryRecord {
-                    k_ij: 0.105,
-                    l_ij: 0.0,
-                }),
+                Some(SaftVRQMieBinaryRecord::new(0.105, 0.0)),
             )
             .unwrap(),
         )
@@ -133,7 +133,7 @@ impl PySaftVRQMieBinaryRecord {
     #[new]
     #[pyo3(text_signature = "(k_ij, l_ij)")]
     fn new(k_ij: f64, l_ij: f64) -> Self {
-        Self(SaftVRQMieBinaryRecord { k_ij, l_ij })
+        Self(SaftVRQMieBinaryRecord::new(k_ij, l_ij))
     }
 
     #[getter]
@@ -92,6 +92,7 @@ fn residual_virial_coefficient<D: DualNum<f64> + Copy>(
     x: &Array1<D>,
     t: D,
 ) -> D {
+    let eps_k_ij = p.eps_k_ij_t(t);
     let mut delta_b2bar = D::zero();
     for i in 0..p.ncomponents {
         let xi = x[i];
@@ -99,7 +100,7 @@ fn residual_virial_coefficient<D: DualNum<f64> + Copy>(
             delta_b2bar += xi
                 * x[j]
                 * p.sigma_ij[[i, j]].powi(3)
-                * delta_b2(t / p.eps_k_ij[[i, j]], p.rep_ij[[i, j]], p.att_ij[[i, j]]);
+                * delta_b2(t / eps_k_ij[[i, j]], p.rep_ij[[i, j]], p.att_ij[[i, j]]);
         }
     }
     delta_b2bar
@@ -143,6 +144,7 @@ fn one_fluid_properties<D: DualNum<f64> + Copy>(
     x: &Array1<D>,
     t: D,
 ) -> (D, D, D, D, D, D) {
+    let eps_k_ij = p.eps_k_ij_t(t);
     let d = diameter_bh(p, t);
     // &p.sigma;
     let mut epsilon_k = D::zero();
@@ -157,7 +159,7 @@ fn one_fluid_properties<D: DualNum<f64> + Copy>(
         for j in 0..p.ncomponents {
             let _y = xi * x[j] * p.sigma_ij[[i, j]].powi(3);
             weighted_sigma3_ij += _y;
-            epsilon_k += _y * p.eps_k_ij[[i, j]];
+            epsilon_k += _y * eps_k_ij[[i, j]];
 
             rep += xi * x[j] * p.rep_ij[[i, j]];
             att += xi * x[j] * p.att_ij[[i, j]];
@@ -128,6 +128,7 @@ fn residual_virial_coefficient<D: DualNum<f64> + Copy>(
     x: &Array1<D>,
     t: D,
 ) -> D {
+    let eps_k_ij = p.eps_k_ij_t(t);
     let mut delta_b2bar = D::zero();
 
     for i in 0..p.ncomponents {
@@ -135,7 +136,7 @@ fn residual_virial_coefficient<D: DualNum<f64> + Copy>(
 
         for j in 0..p.ncomponents {
             //let q_ij = (q[i] / p.sigma[i] + q[j] / p.sigma[j]) * 0.5;
-            let t_ij = t / p.eps_k_ij[[i, j]];
+            let t_ij = t / eps_k_ij[[i, j]];
             let rep_ij = p.rep_ij[[i, j]];
             let att_ij = p.att_ij[[i, j]];
 
@@ -178,6 +179,7 @@ pub(super) fn one_fluid_properties<D: DualNum<f64> + Copy>(
     x: &Array1<D>,
     t: D,
 ) -> (D, D, D, D, D, D) {
+    let eps_k_ij = p.eps_k_ij_t(t);
     let d = diameter_wca(p, t);
     // &p.sigma;
 
@@ -194,7 +196,7 @@ pub(super) fn one_fluid_properties<D: DualNum<f64> + Copy>(
         for j in 0..p.ncomponents {
             let _y = xi * x[j] * p.sigma_ij[[i, j]].powi(3);
             weighted_sigma3_ij += _y;
-            epsilon_k += _y * p.eps_k_ij[[i, j]];
+            epsilon_k += _y * eps_k_ij[[i, j]];
 
             rep += xi * x[j] * p.rep_ij[[i, j]];
             att += xi * x[j] * p.att_ij[[i, j]];
@@ -149,13 +149,14 @@ fn residual_virial_coefficient<D: DualNum<f64> + Copy>(
     x: &Array1<D>,
     t: D,
 ) -> D {
+    let eps_k_ij = p.eps_k_ij_t(t);
     let mut delta_b2bar = D::zero();
 
     for i in 0..p.ncomponents {
         let xi = x[i];
 
         for j in 0..p.ncomponents {
-            let t_ij = t / p.eps_k_ij[[i, j]];
+            let t_ij = t / eps_k_ij[[i, j]];
             let rep_ij = p.rep_ij[[i, j]];
             let att_ij = p.att_ij[[i, j]];
 
@@ -173,13 +174,14 @@ fn residual_third_virial_coefficient<D: DualNum<f64> + Copy>(
     t: D,
     d: &Array1<D>,
 ) -> D {
+    let eps_k_ij = p.eps_k_ij_t(t);
     let mut delta_b3bar = D::zero();
 
     for i in 0..p.ncomponents {
         let xi = x[i];
 
         for j in 0..p.ncomponents {
-            let t_ij = t / p.eps_k_ij[[i, j]];
+            let t_ij = t / eps_k_ij[[i, j]];
             let rep_ij = p.rep_ij[[i, j]];
             let att_ij = p.att_ij[[i, j]];
             let q_ij = dimensionless_diameter_q_wca(t_ij, D::from(rep_ij), D::from(att_ij));
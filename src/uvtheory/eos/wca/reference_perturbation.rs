@@ -30,6 +30,7 @@ impl ReferencePerturbation {
         let eta = packing_fraction(&state.partial_density, &d);
         let eta_a = packing_fraction_a(p, eta, state.temperature);
         let eta_b = packing_fraction_b(p, eta, state.temperature);
+        let eps_k_ij = p.eps_k_ij_t(state.temperature);
         let mut a = D::zero();
 
         for i in 0..n {
@@ -39,7 +40,7 @@ impl ReferencePerturbation {
                     * 0.5; // MIXING RULE not clear!!!
                 let d_ij = (d[i] + d[j]) * 0.5; // (d[i] * p.sigma[i] + d[j] * p.sigma[j]) * 0.5;
 
-                let t_ij = state.temperature / p.eps_k_ij[[i, j]];
+                let t_ij = state.temperature / eps_k_ij[[i, j]];
                 let rep_ij = p.rep_ij[[i, j]];
                 let att_ij = p.att_ij[[i, j]];
                 let q_ij = dimensionless_diameter_q_wca(t_ij, D::from(rep_ij), D::from(att_ij))
@@ -1,5 +1,6 @@
+use crate::binary_interaction::{KijTemperatureDependence, TemperatureDependentKij};
+use feos_core::parameter::{FitBounds, FitRecord, Parameter, PureRecord};
 use feos_core::parameter::{Identifier, ParameterError};
-use feos_core::parameter::{Parameter, PureRecord};
 use lazy_static::lazy_static;
 use ndarray::concatenate;
 use ndarray::prelude::*;
@@ -50,15 +51,62 @@ impl std::fmt::Display for UVTheoryRecord {
     }
 }
 
+impl FitRecord for UVTheoryRecord {
+    fn names(&self) -> Vec<&'static str> {
+        vec!["rep", "att", "sigma", "epsilon_k"]
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        match name {
+            "rep" => Ok(self.rep),
+            "att" => Ok(self.att),
+            "sigma" => Ok(self.sigma),
+            "epsilon_k" => Ok(self.epsilon_k),
+            _ => Err(ParameterError::UnknownParameter(name.into())),
+        }
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        match name {
+            "rep" => self.rep = value,
+            "att" => self.att = value,
+            "sigma" => self.sigma = value,
+            "epsilon_k" => self.epsilon_k = value,
+            _ => return Err(ParameterError::UnknownParameter(name.into())),
+        }
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match name {
+            "rep" => FitBounds::new(6.0, f64::INFINITY, 10.0),
+            "att" => FitBounds::new(3.0, f64::INFINITY, 1.0),
+            "epsilon_k" => FitBounds::positive(100.0),
+            _ => FitBounds::positive(1.0),
+        }
+    }
+}
+
 /// Binary interaction parameters
 #[derive(Serialize, Deserialize, Clone, Default, Debug)]
 pub struct UVTheoryBinaryRecord {
     pub k_ij: f64,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[serde(default)]
+    pub k_ij_t: Option<KijTemperatureDependence>,
+}
+
+impl UVTheoryBinaryRecord {
+    /// Add a temperature dependence to the binary interaction parameter.
+    pub fn with_k_ij_t(mut self, k_ij_t: KijTemperatureDependence) -> Self {
+        self.k_ij_t = Some(k_ij_t);
+        self
+    }
 }
 
 impl From<f64> for UVTheoryBinaryRecord {
     fn from(k_ij: f64) -> Self {
-        Self { k_ij }
+        Self { k_ij, k_ij_t: None }
     }
 }
 
@@ -70,7 +118,11 @@ impl From<UVTheoryBinaryRecord> for f64 {
 
 impl std::fmt::Display for UVTheoryBinaryRecord {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        write!(f, "UVBinaryRecord(k_ij={})", self.k_ij)
+        write!(f, "UVBinaryRecord(k_ij={}", self.k_ij)?;
+        if let Some(k_ij_t) = &self.k_ij_t {
+            write!(f, ", k_ij_t={}", k_ij_t)?;
+        }
+        write!(f, ")")
     }
 }
 
@@ -102,6 +154,40 @@ pub fn mean_field_constant<D: DualNum<f64> + Copy>(rep: D, att: D, x: D) -> D {
     mie_prefactor(rep, att) * (x.powd(-att + 3.0) / (att - 3.0) - x.powd(-rep + 3.0) / (rep - 3.0))
 }
 
+impl FitRecord for UVTheoryBinaryRecord {
+    fn names(&self) -> Vec<&'static str> {
+        let mut names = vec!["k_ij"];
+        if let Some(k_ij_t) = &self.k_ij_t {
+            names.extend(k_ij_t.names());
+        }
+        names
+    }
+
+    fn get(&self, name: &str) -> Result<f64, ParameterError> {
+        match name {
+            "k_ij" => Some(self.k_ij),
+            _ => self.k_ij_t.as_ref().and_then(|k| k.get(name)),
+        }
+        .ok_or_else(|| ParameterError::UnknownParameter(name.into()))
+    }
+
+    fn set(&mut self, name: &str, value: f64) -> Result<(), ParameterError> {
+        let parameter = match name {
+            "k_ij" => Some(&mut self.k_ij),
+            _ => self.k_ij_t.as_mut().and_then(|k| k.get_mut(name)),
+        };
+        *parameter.ok_or_else(|| ParameterError::UnknownParameter(name.into()))? = value;
+        Ok(())
+    }
+
+    fn bounds(&self, name: &str) -> FitBounds {
+        match (name, &self.k_ij_t) {
+            ("k_ij", _) | (_, None) => FitBounds::new(-1.0, 1.0, 0.01),
+            (_, Some(k)) => k.bounds(name),
+        }
+    }
+}
+
 /// Parameters for all substances for uv-theory equation of state and Helmholtz energy functional
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct UVTheoryParameters {
@@ -116,6 +202,8 @@ pub struct UVTheoryParameters {
     pub att_ij: Array2<f64>,
     pub sigma_ij: Array2<f64>,
     pub eps_k_ij: Array2<f64>,
+    #[serde(skip)]
+    pub k_ij_t: TemperatureDependentKij,
     pub cd_bh_pure: Vec<Array1<f64>>,
     pub cd_bh_binary: Array2<Array1<f64>>,
     pub pure_records: Vec<PureRecord<UVTheoryRecord>>,
@@ -174,6 +262,8 @@ impl Parameter for UVTheoryParameters {
             }
         }
 
+        let k_ij_t = TemperatureDependentKij::new(binary_records.as_ref(), |br| br.k_ij_t.as_ref());
+
         // BH temperature dependent HS diameter, eq. 21
         let cd_bh_pure: Vec<Array1<f64>> = rep.iter().map(|&mi| bh_coefficients(mi, 6.0)).collect();
         let cd_bh_binary =
@@ -191,6 +281,7 @@ impl Parameter for UVTheoryParameters {
             att_ij,
             sigma_ij,
             eps_k_ij,
+            k_ij_t,
             cd_bh_pure,
             cd_bh_binary,
             pure_records,
@@ -221,6 +312,18 @@ impl UVTheoryParameters {
         Self::new_pure(pure_record)
     }
 
+    /// Energy parameters including temperature-dependent binary interaction parameters.
+    pub fn eps_k_ij_t<D: DualNum<f64> + Copy>(&self, temperature: D) -> Array2<D> {
+        if self.k_ij_t.is_empty() {
+            return self.eps_k_ij.mapv(D::from);
+        }
+        let e_k_ij = Array2::from_shape_fn((self.ncomponents, self.ncomponents), |(i, j)| {
+            (self.epsilon_k[i] * self.epsilon_k[j]).sqrt()
+        });
+        self.k_ij_t
+            .epsilon_k_ij(&self.eps_k_ij, &e_k_ij, temperature)
+    }
+
     /// Markdown representation of parameters.
     pub fn to_markdown(&self) -> String {
         let mut output = String::new();
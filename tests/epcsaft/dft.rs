@@ -0,0 +1,95 @@
+#![cfg(feature = "dft")]
+use approx::assert_relative_eq;
+use feos::epcsaft::{ElectrolytePcSaft, ElectrolytePcSaftFunctional, ElectrolytePcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::si::*;
+use feos_core::{Contributions, DensityInitialization, State};
+use feos_dft::adsorption::{ExternalPotential, Pore1D, PoreSpecification};
+use feos_dft::{DFTSolver, Geometry};
+use ndarray::arr1;
+use std::error::Error;
+use std::sync::Arc;
+
+fn water_nacl() -> Result<Arc<ElectrolytePcSaftParameters>, Box<dyn Error>> {
+    Ok(Arc::new(ElectrolytePcSaftParameters::from_json(
+        vec!["water", "sodium ion", "chloride ion"],
+        "parameters/epcsaft/held2014_w_permittivity_added.json",
+        Some("parameters/epcsaft/held2014_binary.json"),
+        IdentifierOption::Name,
+    )?))
+}
+
+#[test]
+fn test_bulk_implementation() -> Result<(), Box<dyn Error>> {
+    let params = water_nacl()?;
+    let eos = Arc::new(ElectrolytePcSaft::new(params.clone()));
+    let func = Arc::new(ElectrolytePcSaftFunctional::new(params));
+    let t = 298.15 * KELVIN;
+    let v = 1e-5 * METER.powi::<typenum::P3>();
+    let n = arr1(&[0.5, 0.01, 0.01]) * MOL;
+    let state = State::new_nvt(&eos, t, v, &n)?;
+    let state_func = State::new_nvt(&func, t, v, &n)?;
+    assert_relative_eq!(
+        state.residual_helmholtz_energy(),
+        state_func.residual_helmholtz_energy(),
+        max_relative = 1e-10,
+    );
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        state_func.pressure(Contributions::Total),
+        max_relative = 1e-10,
+    );
+    assert_relative_eq!(
+        state.residual_chemical_potential(),
+        state_func.residual_chemical_potential(),
+        max_relative = 1e-10,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_charged_pore() -> Result<(), Box<dyn Error>> {
+    let func = Arc::new(ElectrolytePcSaftFunctional::new(water_nacl()?));
+    let bulk = State::new_npt(
+        &func,
+        298.15 * KELVIN,
+        BAR,
+        &(arr1(&[0.96, 0.02, 0.02]) * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    let surface_charge = -0.002;
+    let potential = ExternalPotential::ChargedWall {
+        potential: Box::new(ExternalPotential::HardWall { sigma_ss: 3.0 }),
+        surface_charge,
+    };
+    let solver = DFTSolver::new(None)
+        .anderson_mixing(Some(true), Some(100), Some(1e-5), None, Some(20))
+        .anderson_mixing(None, None, None, None, Some(20));
+    let pore = Pore1D::new(Geometry::Cartesian, 30.0 * ANGSTROM, potential, None, None)
+        .initialize(&bulk, None, None)?
+        .solve(Some(&solver))?;
+    let profile = &pore.profile;
+
+    // the fluid compensates the charge of the wall
+    let moles = profile.moles().to_reduced();
+    assert_relative_eq!(moles[1] - moles[2], -surface_charge, max_relative = 1e-6);
+
+    // the electrostatic potential decreases towards the negatively charged wall
+    let psi = (profile.electrostatic_potential()?.unwrap() / VOLT).into_value();
+    let n = psi.len();
+    assert!(psi[n - 1] < psi[0]);
+    assert!(pore.grand_potential.unwrap().to_reduced().is_finite());
+
+    // the potential is shifted so that it is not 0 at the center of the pore
+    assert!(psi[0].abs() > 1e-6);
+
+    // Newton's method converges despite the inexact Jacobian of the shift
+    let newton = DFTSolver::new(None).newton(None, None, None, None);
+    let pore_newton = pore.clone().solve(Some(&newton))?;
+    assert_relative_eq!(
+        pore_newton.grand_potential.unwrap(),
+        pore.grand_potential.unwrap(),
+        max_relative = 1e-5
+    );
+    Ok(())
+}
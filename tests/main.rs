@@ -1,5 +1,9 @@
+#[cfg(feature = "epcsaft")]
+mod epcsaft;
 #[cfg(feature = "gc_pcsaft")]
 mod gc_pcsaft;
+#[cfg(feature = "multiparameter")]
+mod multiparameter;
 #[cfg(feature = "pcsaft")]
 mod pcsaft;
 #[cfg(feature = "saftvrmie")]
@@ -0,0 +1,242 @@
+use approx::assert_relative_eq;
+use feos::multiparameter::{Multiparameter, MultiparameterParameters};
+use feos_core::cubic::{
+    AlphaFunction, Cubic, CubicModel, CubicOptions, CubicParameters, CubicRecord,
+};
+use feos_core::parameter::{Identifier, Parameter, PureRecord};
+use feos_core::si::*;
+use feos_core::{Components, Contributions, PhaseEquilibrium, Residual, SolverOptions, State};
+use ndarray::arr1;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+const FLUID_A: &str = "tests/multiparameter/fluid_a.json";
+const FLUID_B: &str = "tests/multiparameter/fluid_b.json";
+const BINARY: &str = "tests/multiparameter/mixture_binary_pairs.json";
+const DEPARTURE: &str = "tests/multiparameter/mixture_departure_functions.json";
+
+/// The test fluid A is the van der Waals fluid with
+/// $\alpha^\mathrm{r}=-\ln\left(1-\frac{\delta}{3}\right)-\frac{9}{8}\delta\tau$
+/// expanded in a power series.
+fn alphar_vdw(delta: f64, tau: f64) -> f64 {
+    -(1.0 - delta / 3.0).ln() - 9.0 / 8.0 * delta * tau
+}
+
+fn fluid_a() -> Result<Arc<Multiparameter>, Box<dyn Error>> {
+    let parameters = MultiparameterParameters::from_coolprop_json(&[FLUID_A], None, None)?;
+    Ok(Arc::new(Multiparameter::new(Arc::new(parameters))))
+}
+
+fn vdw() -> Result<Arc<Cubic>, Box<dyn Error>> {
+    let (tc, rhoc) = (300.0, 10000.0);
+    let pc = 3.0 / 8.0 * RGAS.convert_into(JOULE / MOL / KELVIN) * tc * rhoc;
+    let record = CubicRecord::new(tc, pc, 0.0).alpha(AlphaFunction::Soave { m: 0.0 });
+    let parameters =
+        CubicParameters::new_pure(PureRecord::new(Identifier::default(), 30.0, record))?;
+    let options = CubicOptions {
+        model: CubicModel::Generic {
+            delta1: 0.0,
+            delta2: 0.0,
+        },
+    };
+    Ok(Arc::new(Cubic::with_options(Arc::new(parameters), options)))
+}
+
+#[test]
+fn test_coolprop_pure() -> Result<(), Box<dyn Error>> {
+    let parameters = MultiparameterParameters::from_coolprop_json(&[FLUID_A], None, None)?;
+    let record = &parameters.pure_records[0];
+    assert_eq!(record.identifier.name.as_deref(), Some("FluidA"));
+    assert_eq!(record.identifier.cas.as_deref(), Some("0000-01-1"));
+    assert_relative_eq!(record.molarweight, 30.0, max_relative = 1e-14);
+    assert_relative_eq!(parameters.t_reducing[0], 300.0);
+    assert_relative_eq!(parameters.rhomolar_reducing[0], 10000.0);
+    assert_relative_eq!(parameters.rhomolar_max[0], 25000.0);
+    Ok(())
+}
+
+#[test]
+fn test_coolprop_unsupported_term() -> Result<(), Box<dyn Error>> {
+    let mut fluid: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(FLUID_A)?)?;
+    fluid["EOS"][0]["alphar"]
+        .as_array_mut()
+        .unwrap()
+        .push(serde_json::json!({"type": "ResidualHelmholtzNonAnalytic"}));
+    let path = std::env::temp_dir().join("feos_fluid_a_non_analytic.json");
+    std::fs::write(&path, fluid.to_string())?;
+    let result = MultiparameterParameters::from_coolprop_json(&[&path], None, None);
+    std::fs::remove_file(&path)?;
+    let Err(error) = result else {
+        panic!("non-analytic terms are not supported")
+    };
+    assert!(error.to_string().contains("'ResidualHelmholtzNonAnalytic'"));
+    Ok(())
+}
+
+#[test]
+fn test_pressure_vdw() -> Result<(), Box<dyn Error>> {
+    let eos = fluid_a()?;
+    let cubic = vdw()?;
+    let moles = arr1(&[1.0]) * MOL;
+    for (t, rho) in [(250.0, 1000.0), (300.0, 10000.0), (350.0, 18000.0)] {
+        let t = t * KELVIN;
+        let rho = rho * MOL / METER.powi::<P3>();
+        let s = State::new_nvt(&eos, t, moles.sum() / rho, &moles)?;
+        let s_vdw = State::new_nvt(&cubic, t, moles.sum() / rho, &moles)?;
+        assert_relative_eq!(
+            s.pressure(Contributions::Total),
+            s_vdw.pressure(Contributions::Total),
+            max_relative = 1e-10
+        );
+        assert_relative_eq!(
+            s.residual_molar_entropy(),
+            s_vdw.residual_molar_entropy(),
+            max_relative = 1e-10
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_second_virial_coefficient() -> Result<(), Box<dyn Error>> {
+    let eos = fluid_a()?;
+    let t = 250.0 * KELVIN;
+    let b = eos.second_virial_coefficient(t, None)?;
+    let tau = 300.0 / 250.0;
+    let b_vdw = (1.0 / 3.0 - 9.0 / 8.0 * tau) / 10000.0 * METER.powi::<P3>() / MOL;
+    assert_relative_eq!(b, b_vdw, max_relative = 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_critical_point() -> Result<(), Box<dyn Error>> {
+    let eos = fluid_a()?;
+    let cp = State::critical_point(&eos, None, None, SolverOptions::default())?;
+    assert_relative_eq!(cp.temperature, 300.0 * KELVIN, max_relative = 1e-8);
+    assert_relative_eq!(
+        cp.density,
+        10000.0 * MOL / METER.powi::<P3>(),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vapor_pressure() -> Result<(), Box<dyn Error>> {
+    let eos = fluid_a()?;
+    let cubic = vdw()?;
+    let t = 270.0 * KELVIN;
+    let vle = PhaseEquilibrium::pure(&eos, t, None, SolverOptions::default())?;
+    let vle_vdw = PhaseEquilibrium::pure(&cubic, t, None, SolverOptions::default())?;
+    assert_relative_eq!(
+        vle.vapor().pressure(Contributions::Total),
+        vle_vdw.vapor().pressure(Contributions::Total),
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        vle.liquid().density,
+        vle_vdw.liquid().density,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_mixture_helmholtz_energy() -> Result<(), Box<dyn Error>> {
+    let parameters = MultiparameterParameters::from_coolprop_json(
+        &[FLUID_A, FLUID_B],
+        Some(BINARY),
+        Some(DEPARTURE),
+    )?;
+    let eos = Arc::new(Multiparameter::new(Arc::new(parameters)));
+    let t = 320.0;
+    let rho = 2000.0;
+    let (xa, xb) = (0.3, 0.7);
+    let moles = arr1(&[xa, xb]) * MOL;
+    let s = State::new_nvt(
+        &eos,
+        t * KELVIN,
+        MOL / (rho * MOL / METER.powi::<P3>()),
+        &moles,
+    )?;
+    let alphar = (s.residual_helmholtz_energy() / (RGAS * s.temperature * MOL)).into_value();
+
+    // reducing functions with the binary parameters given for (B, A)
+    let (beta_t, gamma_t, beta_v, gamma_v) = (1.02, 1.05, 0.98, 1.01);
+    let (ta, tb, rhoa, rhob) = (300.0, 400.0, 10000.0, 8000.0);
+    let t_r = xa * xa * ta
+        + xb * xb * tb
+        + 2.0 * xb * xa * beta_t * gamma_t * (xb + xa) / (beta_t * beta_t * xb + xa)
+            * (ta * tb).sqrt();
+    let v_r = xa * xa / rhoa
+        + xb * xb / rhob
+        + 2.0 * xb * xa * beta_v * gamma_v * (xb + xa) / (beta_v * beta_v * xb + xa)
+            * (1.0 / rhoa.cbrt() + 1.0 / rhob.cbrt()).powi(3)
+            / 8.0;
+    let delta = rho * v_r;
+    let tau = t_r / t;
+
+    let alphar_a = alphar_vdw(delta, tau);
+    let alphar_b = alphar_vdw(delta, tau)
+        + 0.01 * delta.powi(2) * tau.powf(1.5) * (-delta.powi(2)).exp()
+        + 0.005 * delta * tau * (-(delta - 1.0).powi(2) - (tau - 1.0).powi(2)).exp();
+    let departure = 0.1 * delta * tau
+        - 0.05 * delta.powi(2) * tau.sqrt() * (-(delta - 0.5).powi(2) - 0.5 * (delta - 0.5)).exp();
+    let alphar_mix = xa * alphar_a + xb * alphar_b + xa * xb * departure;
+    assert_relative_eq!(alphar, alphar_mix, max_relative = 1e-12);
+    Ok(())
+}
+
+#[test]
+fn test_mixture_bubble_point() -> Result<(), Box<dyn Error>> {
+    let parameters = MultiparameterParameters::from_coolprop_json(
+        &[FLUID_A, FLUID_B],
+        Some(BINARY),
+        Some(DEPARTURE),
+    )?;
+    let eos = Arc::new(Multiparameter::new(Arc::new(parameters)));
+    let t = 280.0 * KELVIN;
+    let vle = PhaseEquilibrium::bubble_point(
+        &eos,
+        t,
+        &arr1(&[0.5, 0.5]),
+        None,
+        None,
+        Default::default(),
+    )?;
+    let p_sat = PhaseEquilibrium::vapor_pressure(&eos, t);
+    let p = vle.vapor().pressure(Contributions::Total);
+    assert!(p < p_sat[0].unwrap() && p > p_sat[1].unwrap());
+    let (liquid, vapor) = (vle.liquid(), vle.vapor());
+    assert_relative_eq!(
+        &liquid.molefracs.mapv(f64::ln) + &liquid.ln_phi(),
+        &vapor.molefracs.mapv(f64::ln) + &vapor.ln_phi(),
+        epsilon = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_subset() -> Result<(), Box<dyn Error>> {
+    let parameters = MultiparameterParameters::from_coolprop_json(
+        &[FLUID_A, FLUID_B],
+        Some(BINARY),
+        Some(DEPARTURE),
+    )?;
+    assert_relative_eq!(parameters.beta_t[(0, 1)], 1.0 / 1.02);
+    assert_relative_eq!(parameters.beta_t[(1, 0)], 1.02);
+    let eos = Arc::new(Multiparameter::new(Arc::new(parameters)));
+    let pure = Arc::new(eos.subset(&[0]));
+    let t = 300.0 * KELVIN;
+    let moles = arr1(&[1.0]) * MOL;
+    let volume = 1e-3 * METER.powi::<P3>();
+    let s = State::new_nvt(&pure, t, volume, &moles)?;
+    let s_pure = State::new_nvt(&fluid_a()?, t, volume, &moles)?;
+    assert_relative_eq!(
+        s.pressure(Contributions::Total),
+        s_pure.pressure(Contributions::Total),
+        max_relative = 1e-14
+    );
+    Ok(())
+}
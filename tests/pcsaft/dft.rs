@@ -6,10 +6,17 @@ use feos::ideal_gas::Joback;
 use feos::pcsaft::{PcSaft, PcSaftFunctional, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter};
 use feos_core::si::*;
-use feos_core::{Contributions, PhaseEquilibrium, State, Verbosity};
-use feos_dft::interface::PlanarInterface;
-use feos_dft::DFTSolver;
-use ndarray::{arr1, Axis};
+use feos_core::{Contributions, PhaseEquilibrium, SolverOptions, State, StateBuilder, Verbosity};
+use feos_dft::adsorption::{
+    Adsorption1D, ExternalPotential, Pore1D, PoreSpecification, Wetting, WettingDiagram,
+};
+use feos_dft::ddft::{BoundaryCondition, DDFT};
+use feos_dft::interface::{CriticalNucleus, PlanarInterface};
+use feos_dft::{
+    Axis as GridAxis, ConvolverFFT, DFTProfile, DFTSolver, Geometry, Grid,
+    HelmholtzEnergyFunctional,
+};
+use ndarray::{arr1, Array1, Array2, Axis};
 use std::error::Error;
 use std::sync::Arc;
 use typenum::P3;
@@ -385,3 +392,292 @@ fn test_entropy_bulk_values() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_critical_nucleus_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 200.0 * KELVIN;
+    let w = 80.0 * ANGSTROM;
+    let points = 1024;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let planar = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve(None)?;
+    let gamma = planar.surface_tension.unwrap();
+
+    // droplets in a supersaturated vapor
+    let droplet =
+        CriticalNucleus::from_supersaturation(&vle, 2.0, points, w, None, tc)?.solve(None)?;
+    let droplet2 =
+        CriticalNucleus::from_supersaturation(&vle, 2.01, points, w, None, tc)?.solve(None)?;
+    assert_relative_eq!(droplet.supersaturation(), 2.0, max_relative = 1e-8);
+    assert!(droplet.nucleation_barrier.unwrap() > droplet2.nucleation_barrier.unwrap());
+    assert!(droplet.equimolar_radius.unwrap() > 0.0 * ANGSTROM);
+    assert_relative_eq!(droplet.surface_tension.unwrap(), gamma, max_relative = 3e-2);
+
+    // nucleation theorem: dW/dmu = -excess number of particles
+    let excess = |n: &CriticalNucleus<_>| {
+        n.profile.total_moles() - n.profile.bulk.density * n.profile.volume()
+    };
+    let mu = |n: &CriticalNucleus<_>| {
+        n.bulk.residual_chemical_potential().get(0) + RGAS * t * n.bulk.density.to_reduced().ln()
+    };
+    let dw_dmu = (droplet2.nucleation_barrier.unwrap() - droplet.nucleation_barrier.unwrap())
+        / (mu(&droplet2) - mu(&droplet));
+    assert_relative_eq!(
+        -dw_dmu,
+        0.5 * (excess(&droplet) + excess(&droplet2)),
+        max_relative = 1e-2
+    );
+
+    // bubble in a stretched liquid
+    let bubble =
+        CriticalNucleus::from_supersaturation(&vle, -1000.0, points, w, None, tc)?.solve(None)?;
+    assert!(bubble.nucleation_barrier.unwrap() > 0.0 * JOULE);
+    assert!(bubble.interior.density < vle.vapor().density);
+
+    // the supersaturation is only defined for pure substances
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let x = arr1(&[0.5, 0.5]);
+    let vle = PhaseEquilibrium::bubble_point(&func, t, &x, None, None, Default::default())?;
+    assert!(CriticalNucleus::from_supersaturation(&vle, 2.0, points, w, None, tc).is_err());
+    Ok(())
+}
+
+#[test]
+fn test_wetting_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let potential = ExternalPotential::LJ93 {
+        sigma_ss: 3.4,
+        epsilon_k_ss: 20.0,
+        rho_s: 0.114,
+    };
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        100.0 * ANGSTROM,
+        potential,
+        Some(1024),
+        None,
+    );
+
+    // Young's equation
+    let vle = PhaseEquilibrium::pure(&func, 200.0 * KELVIN, None, Default::default())?;
+    let wetting = Wetting::new(&vle, &pore, tc)?.solve(None)?;
+    let gamma_wv = wetting.wall_vapor_tension.unwrap();
+    let gamma_wl = wetting.wall_liquid_tension.unwrap();
+    let gamma_lv = wetting.surface_tension.unwrap();
+    let theta = wetting.contact_angle.unwrap().in_radians();
+    assert!(gamma_wv > gamma_wl);
+    assert_relative_eq!(
+        theta.cos(),
+        ((gamma_wv - gamma_wl) / gamma_lv).into_value(),
+        max_relative = 1e-10
+    );
+    assert!(wetting.spreading_coefficient().unwrap() < 0.0 * NEWTON / METER);
+
+    // wetting transition along the coexistence curve
+    let dia = [200.0, 210.0, 220.0]
+        .iter()
+        .map(|&t| PhaseEquilibrium::pure(&func, t * KELVIN, None, Default::default()))
+        .collect::<Result<Vec<_>, _>>()?;
+    let diagram = WettingDiagram::new(&dia, &pore, tc, None)?;
+    assert_eq!(diagram.profiles.len(), 3);
+    let t_w = diagram.wetting_temperature().unwrap();
+    assert!(t_w > 200.0 * KELVIN && t_w < 210.0 * KELVIN);
+    let theta = diagram.contact_angle();
+    assert!(theta[0].in_radians() > 0.0);
+    assert_relative_eq!(theta[2].in_radians(), 0.0);
+    let film_thickness = diagram.film_thickness();
+    assert!(film_thickness.get(2) > film_thickness.get(0));
+    Ok(())
+}
+
+#[test]
+fn test_prewetting_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 215.0 * KELVIN;
+    let potential = ExternalPotential::LJ93 {
+        sigma_ss: 3.4,
+        epsilon_k_ss: 20.0,
+        rho_s: 0.114,
+    };
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        600.0 * ANGSTROM,
+        potential,
+        Some(4096),
+        None,
+    );
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let p_sat = vle.vapor().pressure(Contributions::Total);
+    let prewetting = Adsorption1D::prewetting_transition(
+        &func,
+        t,
+        0.98 * p_sat,
+        0.99 * p_sat,
+        &pore,
+        30.0 * ANGSTROM,
+        None,
+        SolverOptions::default(),
+    )?;
+    let p = prewetting.pressure();
+    let n = prewetting.total_adsorption();
+    let omega = prewetting.grand_potential();
+    assert_relative_eq!(p.get(0), p.get(1), max_relative = 1e-8);
+    assert!(p.get(0) > 0.98 * p_sat && p.get(0) < p_sat);
+    assert_relative_eq!(omega.get(0), omega.get(1), max_relative = 1e-6);
+    assert!(n.get(1) > 5.0 * n.get(0));
+    Ok(())
+}
+
+#[test]
+fn test_ddft_closed_pore_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let bulk = StateBuilder::new(&func)
+        .temperature(300.0 * KELVIN)
+        .pressure(5.0 * BAR)
+        .vapor()
+        .build()?;
+    let potential = ExternalPotential::LJ93 {
+        sigma_ss: 3.4,
+        epsilon_k_ss: 20.0,
+        rho_s: 0.114,
+    };
+    let pore = Pore1D::new(
+        Geometry::Cartesian,
+        30.0 * ANGSTROM,
+        potential,
+        Some(256),
+        None,
+    );
+    let profile = pore.initialize(&bulk, None, None)?.profile;
+    let diffusivity = arr1(&[1.0]) * ANGSTROM * ANGSTROM / (PICO * SECOND);
+    let mut ddft = DDFT::new(&profile, diffusivity, BoundaryCondition::Closed)?;
+    let times = Array1::linspace(0.0, 200.0, 5) * PICO * SECOND;
+    let trajectory = ddft.integrate(&times, SolverOptions::default())?;
+    assert_eq!(trajectory.profiles.len(), 5);
+
+    // the amount of fluid is conserved
+    let moles = trajectory.total_moles();
+    for i in 1..5 {
+        assert_relative_eq!(moles.get(i), moles.get(0), max_relative = 1e-10);
+    }
+
+    // the final profile has a uniform chemical potential
+    let profile = &trajectory.profiles[4];
+    let rho = profile.density.to_reduced();
+    let dfdrho = profile.functional_derivative()?;
+    let m = func.m();
+    let mu: Vec<_> = (0..256)
+        .filter(|&k| profile.external_potential[[0, k]] < 10.0)
+        .map(|k| rho[[0, k]].ln() + (dfdrho[[0, k]] + profile.external_potential[[0, k]]) / m[0])
+        .collect();
+    let mu_max = mu.iter().fold(f64::MIN, |a, &b| a.max(b));
+    let mu_min = mu.iter().fold(f64::MAX, |a, &b| a.min(b));
+    assert!(mu_max - mu_min < 1e-3);
+    Ok(())
+}
+
+#[test]
+fn test_ddft_reservoir_propane() -> Result<(), Box<dyn Error>> {
+    let params = Arc::new(PcSaftParameters::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let func = Arc::new(PcSaftFunctional::new(params));
+    let t = 300.0 * KELVIN;
+    let bulk = StateBuilder::new(&func)
+        .temperature(t)
+        .pressure(5.0 * BAR)
+        .vapor()
+        .build()?;
+
+    // uniform bulk density without external potential
+    let points = 256;
+    let grid = Grid::Cartesian1(GridAxis::new_cartesian(points, 20.0 * ANGSTROM, None));
+    let z = grid.grids()[0].clone();
+    let weight_functions = func.weight_functions(t.to_reduced());
+    let convolver = ConvolverFFT::plan(&grid, &weight_functions, None);
+    let density = Array2::from_elem((1, points), bulk.density.to_reduced());
+    let profile = DFTProfile::new(
+        grid,
+        convolver,
+        &bulk,
+        Some(Array2::zeros((1, points))),
+        Some(&Density::from_reduced(density)),
+    );
+
+    // a repulsive potential that is switched on within 10 ps
+    let barrier = z
+        .mapv(|z| 2.0 * (-(z / 5.0).powi(2)).exp())
+        .insert_axis(Axis(0));
+    let potential = barrier.clone();
+    let diffusivity = arr1(&[1.0]) * ANGSTROM * ANGSTROM / (PICO * SECOND);
+    let ddft = DDFT::new(&profile, diffusivity, BoundaryCondition::Reservoir)?;
+
+    // potentials with the wrong shape are rejected
+    let wrong_shape = Array2::zeros((2, points));
+    assert!(ddft
+        .clone()
+        .time_dependent_potential(move |_| wrong_shape.clone())
+        .is_err());
+
+    let mut ddft = ddft.time_dependent_potential(move |time: Time| {
+        &potential * (time / (10.0 * PICO * SECOND)).into_value().min(1.0)
+    })?;
+    let times = Array1::linspace(0.0, 1000.0, 11) * PICO * SECOND;
+    let trajectory = ddft.integrate(&times, SolverOptions::default())?;
+    assert_eq!(ddft.time, 1000.0 * PICO * SECOND);
+
+    // the fluid is pushed into the reservoir
+    let moles = trajectory.total_moles();
+    for i in 1..11 {
+        assert!(moles.get(i) < moles.get(i - 1));
+    }
+
+    // the final profile is the equilibrium profile
+    let profile = &trajectory.profiles[10];
+    assert_eq!(profile.external_potential, barrier);
+    let (_, _, residual) = profile.residual(false)?;
+    assert!(residual < 1e-7);
+    let mut equilibrium = profile.clone();
+    equilibrium.solve(None, false)?;
+    assert_relative_eq!(
+        profile.density.to_reduced(),
+        equilibrium.density.to_reduced(),
+        max_relative = 1e-3
+    );
+    Ok(())
+}
@@ -0,0 +1,732 @@
+use approx::assert_relative_eq;
+use feos::binary_interaction::KijTemperatureDependence;
+#[cfg(feature = "dft")]
+use feos::estimator::{
+    AdsorbedAmount, Adsorbent, AdsorptionIsotherm, IsothermBranch, SurfaceTensionMethod,
+};
+use feos::estimator::{
+    DataSet, EnthalpyOfVaporization, EquilibriumLiquidDensity, Estimator, EstimatorError,
+    ExperimentalData, GcEstimator, IsobaricHeatCapacity, IsochoricHeatCapacity,
+    JouleThomsonCoefficient, LeastSquaresOptions, Loss, MulticomponentChemicalPotential,
+    MulticomponentFlash, Phase, Property, SecondVirialCoefficient, SpeedOfSound, VaporPressure,
+};
+use feos::ideal_gas::Joback;
+#[cfg(feature = "dft")]
+use feos::pcsaft::PcSaftFunctional;
+use feos::pcsaft::{PcSaft, PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
+use feos_core::parameter::{
+    ChemicalRecord, Identifier, IdentifierOption, Parameter, ParameterError, ParameterVector,
+    PureRecord, SegmentRecord, SegmentVector,
+};
+use feos_core::si::*;
+use feos_core::{
+    Contributions, EosError, EquationOfState, PhaseEquilibrium, Residual, SolverOptions, State,
+};
+#[cfg(feature = "dft")]
+use feos_dft::adsorption::{ExternalPotential, Pore1D};
+#[cfg(feature = "dft")]
+use feos_dft::{interface::PlanarInterface, Geometry, DFT};
+use ndarray::{arr1, Array1, Array2};
+use std::error::Error;
+use std::sync::Arc;
+
+fn propane_record(parameters: &Array1<f64>) -> PureRecord<PcSaftRecord> {
+    let record = PcSaftRecord::new(
+        parameters[0],
+        parameters[1],
+        parameters[2],
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+        None,
+    );
+    PureRecord::new(Identifier::default(), 44.0962, record)
+}
+
+fn propane(parameters: &Array1<f64>) -> Result<Arc<PcSaft>, EstimatorError> {
+    let parameters =
+        PcSaftParameters::new_pure(propane_record(parameters)).map_err(EosError::from)?;
+    Ok(Arc::new(PcSaft::new(Arc::new(parameters))))
+}
+
+/// Data sets generated with `eos`, perturbed by alternating relative deviations `noise`.
+fn estimator(
+    eos: &Arc<PcSaft>,
+    loss: Loss,
+    noise: f64,
+) -> Result<Estimator<PcSaft>, Box<dyn Error>> {
+    let temperature = Array1::linspace(200.0, 340.0, 8) * KELVIN;
+    let vle: Vec<_> = temperature
+        .into_iter()
+        .map(|t| PhaseEquilibrium::pure(eos, t, None, Default::default()))
+        .collect::<Result<_, _>>()?;
+    let factor = |i: usize| 1.0 + noise * (-1.0f64).powi(i as i32);
+    let pressure = Pressure::from_shape_fn(vle.len(), |i| {
+        vle[i].vapor().pressure(Contributions::Total) * factor(i)
+    });
+    let density = MassDensity::from_shape_fn(vle.len(), |i| {
+        vle[i].liquid().mass_density() * factor(i + 1)
+    });
+    let vapor_pressure: Arc<dyn DataSet<PcSaft>> = Arc::new(VaporPressure::new(
+        pressure,
+        temperature.clone(),
+        true,
+        None,
+        None,
+    ));
+    let liquid_density: Arc<dyn DataSet<PcSaft>> =
+        Arc::new(EquilibriumLiquidDensity::new(density, temperature, None));
+    Ok(Estimator::new(
+        vec![vapor_pressure, liquid_density],
+        vec![3.0, 2.0],
+        vec![loss; 2],
+    ))
+}
+
+#[test]
+fn test_least_squares_pure() -> Result<(), Box<dyn Error>> {
+    let parameters = arr1(&[2.001829, 3.618353, 208.1101]);
+    let estimator = estimator(&propane(&parameters)?, Loss::Linear, 0.0)?;
+    let x0 = arr1(&[1.8, 3.8, 220.0]);
+    let lower = arr1(&[1.0, 2.5, 100.0]);
+    let upper = arr1(&[5.0, 5.0, 400.0]);
+    let result =
+        estimator.least_squares(propane, &x0, Some((&lower, &upper)), Default::default())?;
+    assert!(result.success());
+    assert_relative_eq!(result.parameters, parameters, max_relative = 1e-5);
+    assert!(result.cost < 1e-14);
+    Ok(())
+}
+
+#[test]
+fn test_least_squares_parameter_vector() -> Result<(), Box<dyn Error>> {
+    let parameters = arr1(&[2.001829, 3.618353, 208.1101]);
+    let estimator = estimator(&propane(&parameters)?, Loss::Linear, 0.0)?;
+    let mut vector =
+        ParameterVector::<PcSaftParameters>::new(vec![propane_record(&parameters)], None);
+    for name in ["m", "sigma", "epsilon_k"] {
+        vector.add_pure(0, name, None)?;
+    }
+    let model = |x: &Array1<f64>| {
+        let parameters = vector.parameters(x).map_err(EosError::from)?;
+        Ok(Arc::new(PcSaft::new(Arc::new(parameters))))
+    };
+    let x0 = arr1(&[1.8, 3.8, 2.2]);
+    let (lower, upper) = vector.bounds();
+    let result = estimator.least_squares(model, &x0, Some((&lower, &upper)), Default::default())?;
+    assert!(result.success());
+    vector.update(&result.parameters)?;
+    assert_relative_eq!(vector.values(), parameters, max_relative = 1e-5);
+    Ok(())
+}
+
+#[test]
+fn test_least_squares_cost() -> Result<(), Box<dyn Error>> {
+    let estimator = estimator(
+        &propane(&arr1(&[2.001829, 3.618353, 208.1101]))?,
+        Loss::Huber(0.01),
+        0.0,
+    )?;
+    let x0 = arr1(&[2.1, 3.5, 200.0]);
+    let options = LeastSquaresOptions {
+        max_iter: 0,
+        ..Default::default()
+    };
+    let result = estimator.least_squares(propane, &x0, None, options)?;
+    assert!(!result.success());
+    let cost = estimator.cost(&propane(&x0)?)?;
+    assert_relative_eq!(
+        2.0 * result.cost,
+        cost.mapv(|c| c * c).sum(),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_uncertainty() -> Result<(), Box<dyn Error>> {
+    let parameters = arr1(&[2.001829, 3.618353, 208.1101]);
+    let estimator = estimator(&propane(&parameters)?, Loss::Linear, 0.01)?;
+    let result = estimator.least_squares(propane, &parameters, None, Default::default())?;
+    assert!(result.success());
+
+    let uncertainty = result.uncertainty()?;
+    assert_eq!(uncertainty.degrees_of_freedom, 13);
+    let uncertainty_estimator = estimator.uncertainty(propane, &result.parameters)?;
+    assert_relative_eq!(
+        uncertainty.covariance,
+        uncertainty_estimator.covariance,
+        max_relative = 1e-4
+    );
+    let correlation = uncertainty.correlation();
+    for i in 0..3 {
+        assert_relative_eq!(correlation[(i, i)], 1.0, max_relative = 1e-12);
+    }
+    let sigma = uncertainty.standard_errors() / &result.parameters;
+    assert!(sigma.iter().all(|&s| s > 0.0 && s < 0.1));
+
+    // relative uncertainty of the vapor pressure
+    let temperature = Array1::linspace(220.0, 320.0, 5) * KELVIN;
+    let vapor_pressure = |x: &Array1<f64>| {
+        let eos = propane(x)?;
+        temperature
+            .into_iter()
+            .map(|t| {
+                let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default())?;
+                Ok(vle.vapor().pressure(Contributions::Total).to_reduced())
+            })
+            .collect::<Result<_, EosError>>()
+            .map_err(EstimatorError::from)
+    };
+    let (p_sat, sigma) = uncertainty.propagate(vapor_pressure)?;
+    let relative = sigma / p_sat;
+    assert!(relative.iter().all(|&r| r > 1e-4 && r < 1e-2));
+    Ok(())
+}
+
+#[test]
+fn test_caloric_data_sets() -> Result<(), Box<dyn Error>> {
+    let joback = Arc::new(Joback::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let residual = propane(&arr1(&[2.001829, 3.618353, 208.1101]))?;
+    let eos = Arc::new(EquationOfState::new(joback, residual));
+
+    let temperature = arr1(&[250.0, 300.0, 350.0]) * KELVIN;
+    let pressure = arr1(&[50.0, 50.0, 1.0]) * BAR;
+    let phase = vec![Phase::Liquid, Phase::Liquid, Phase::Vapor];
+    let moles = arr1(&[1.0]) * MOL;
+    let states: Vec<_> = temperature
+        .into_iter()
+        .zip(&pressure)
+        .zip(&phase)
+        .map(|((t, p), &phase)| State::new_npt(&eos, t, p, &moles, phase.into()))
+        .collect::<Result<_, _>>()?;
+    let n = states.len();
+    let w = Velocity::from_shape_fn(n, |i| states[i].speed_of_sound());
+    let c_p = MolarEntropy::from_shape_fn(n, |i| {
+        states[i].molar_isobaric_heat_capacity(Contributions::Total)
+    });
+    let c_v = MolarEntropy::from_shape_fn(n, |i| {
+        states[i].molar_isochoric_heat_capacity(Contributions::Total)
+    });
+    let mu_jt = Temperature::from_shape_fn(n, |i| states[i].joule_thomson() * BAR) / BAR;
+
+    let vle: Vec<_> = temperature
+        .into_iter()
+        .map(|t| PhaseEquilibrium::pure(&eos, t, None, Default::default()))
+        .collect::<Result<_, _>>()?;
+    let h_vap = MolarEnergy::from_shape_fn(n, |i| {
+        vle[i].vapor().molar_enthalpy(Contributions::Total)
+            - vle[i].liquid().molar_enthalpy(Contributions::Total)
+    });
+    let b = MolarVolume::from_shape_fn(n, |i| {
+        eos.second_virial_coefficient(temperature.get(i), None)
+            .unwrap()
+    });
+
+    type Eos = EquationOfState<Joback, PcSaft>;
+    let data: Vec<Arc<dyn DataSet<Eos>>> = vec![
+        Arc::new(SpeedOfSound::new(
+            w,
+            temperature.clone(),
+            pressure.clone(),
+            Some(&phase),
+        )),
+        Arc::new(IsobaricHeatCapacity::new(
+            c_p,
+            temperature.clone(),
+            pressure.clone(),
+            Some(&phase),
+        )),
+        Arc::new(IsochoricHeatCapacity::new(
+            c_v,
+            temperature.clone(),
+            pressure.clone(),
+            Some(&phase),
+        )),
+        Arc::new(JouleThomsonCoefficient::new(
+            mu_jt,
+            temperature.clone(),
+            pressure.clone(),
+            Some(&phase),
+        )),
+        Arc::new(EnthalpyOfVaporization::new(
+            h_vap,
+            temperature.clone(),
+            None,
+        )),
+        Arc::new(SecondVirialCoefficient::new(b, temperature)),
+    ];
+    for d in data {
+        assert_eq!(d.datapoints(), 3);
+        assert_relative_eq!(
+            d.relative_difference(&eos)?,
+            Array1::zeros(3),
+            epsilon = 1e-10
+        );
+    }
+    Ok(())
+}
+
+fn read_parameters(components: Vec<&str>) -> Result<PcSaftParameters, ParameterError> {
+    PcSaftParameters::from_json(
+        components,
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )
+}
+
+/// Compositions of both phases from Tp-flash calculations.
+fn tie_lines(
+    eos: &Arc<PcSaft>,
+    temperature: &Temperature<Array1<f64>>,
+    pressure: &Pressure<Array1<f64>>,
+    feed: &Array1<f64>,
+) -> Result<(Array2<f64>, Array2<f64>), Box<dyn Error>> {
+    let n = temperature.len();
+    let mut x1 = Array2::zeros((n, feed.len()));
+    let mut x2 = Array2::zeros((n, feed.len()));
+    for i in 0..n {
+        let vle = PhaseEquilibrium::tp_flash(
+            eos,
+            temperature.get(i),
+            pressure.get(i),
+            &(feed * MOL),
+            None,
+            SolverOptions::new().tol(1e-12),
+            None,
+        )?;
+        x1.row_mut(i).assign(&vle.liquid().molefracs);
+        x2.row_mut(i).assign(&vle.vapor().molefracs);
+    }
+    Ok((x1, x2))
+}
+
+#[test]
+fn test_multicomponent_vle() -> Result<(), Box<dyn Error>> {
+    let mut vector =
+        ParameterVector::from_parameters(&read_parameters(vec!["propane", "butane", "hexane"])?);
+    vector.add_binary(0, 2, "k_ij", None)?;
+    let model = |x: &Array1<f64>| {
+        let parameters = vector.parameters(x).map_err(EosError::from)?;
+        Ok(Arc::new(PcSaft::new(Arc::new(parameters))))
+    };
+    let eos = model(&arr1(&[2.0]))?;
+
+    let temperature = arr1(&[290.0, 300.0, 320.0]) * KELVIN;
+    let pressure = arr1(&[1.5, 2.0, 3.0]) * BAR;
+    let feed = arr1(&[0.3, 0.3, 0.4]);
+    let (x, y) = tie_lines(&eos, &temperature, &pressure, &feed)?;
+    assert!(y.iter().zip(&x).any(|(y, x)| (y - x).abs() > 0.1));
+
+    let chemical_potential: Arc<dyn DataSet<PcSaft>> =
+        Arc::new(MulticomponentChemicalPotential::new_vle(
+            temperature.clone(),
+            pressure.clone(),
+            x.clone(),
+            y.clone(),
+        )?);
+    let flash: Arc<dyn DataSet<PcSaft>> = Arc::new(MulticomponentFlash::new_vle(
+        temperature,
+        pressure,
+        x,
+        y,
+        None,
+    )?);
+    for d in [&chemical_potential, &flash] {
+        assert_eq!(d.datapoints(), d.target().len());
+        assert_relative_eq!(
+            d.relative_difference(&eos)?,
+            Array1::zeros(d.datapoints()),
+            epsilon = 1e-8
+        );
+    }
+    assert_eq!(chemical_potential.datapoints(), 9);
+    assert_eq!(flash.datapoints(), 18);
+
+    // regression of k_ij between propane and hexane
+    let estimator = Estimator::new(
+        vec![chemical_potential, flash],
+        vec![1.0, 1.0],
+        vec![Loss::Linear; 2],
+    );
+    let (lower, upper) = vector.bounds();
+    let result = estimator.least_squares(
+        model,
+        &arr1(&[0.0]),
+        Some((&lower, &upper)),
+        Default::default(),
+    )?;
+    assert!(result.success());
+    assert_relative_eq!(result.parameters[0], 2.0, max_relative = 1e-5);
+    Ok(())
+}
+
+#[test]
+fn test_multicomponent_lle() -> Result<(), Box<dyn Error>> {
+    let parameters = read_parameters(vec!["water_np", "hexane", "butane"])?;
+    let eos = Arc::new(PcSaft::new(Arc::new(parameters)));
+
+    let temperature = arr1(&[300.0, 320.0]) * KELVIN;
+    let pressure = arr1(&[20.0, 20.0]) * BAR;
+    let feed = arr1(&[0.5, 0.3, 0.2]);
+    let (x1, x2) = tie_lines(&eos, &temperature, &pressure, &feed)?;
+    assert!(x1.iter().zip(&x2).any(|(x1, x2)| (x1 - x2).abs() > 0.1));
+
+    let data: Vec<Arc<dyn DataSet<PcSaft>>> = vec![
+        Arc::new(MulticomponentChemicalPotential::new_lle(
+            temperature.clone(),
+            pressure.clone(),
+            x1.clone(),
+            x2.clone(),
+        )?),
+        // the order of the liquid phases is irrelevant for the flash
+        Arc::new(MulticomponentFlash::new_lle(
+            temperature,
+            pressure,
+            x2,
+            x1,
+            None,
+        )?),
+    ];
+    for d in data {
+        assert_relative_eq!(
+            d.relative_difference(&eos)?,
+            Array1::zeros(d.datapoints()),
+            epsilon = 1e-8
+        );
+    }
+    Ok(())
+}
+
+#[test]
+fn test_multicomponent_incompatible_input() {
+    let temperature = arr1(&[300.0, 320.0]) * KELVIN;
+    let pressure = arr1(&[20.0, 20.0]) * BAR;
+    let x = Array2::from_elem((2, 3), 1.0 / 3.0);
+    assert!(MulticomponentChemicalPotential::new_vle(
+        temperature.clone(),
+        arr1(&[20.0]) * BAR,
+        x.clone(),
+        x.clone(),
+    )
+    .is_err());
+    assert!(MulticomponentChemicalPotential::new_lle(
+        temperature.clone(),
+        pressure.clone(),
+        x.clone(),
+        Array2::from_elem((2, 2), 0.5),
+    )
+    .is_err());
+    assert!(MulticomponentFlash::new_vle(
+        arr1(&[300.0]) * KELVIN,
+        pressure.clone(),
+        x.clone(),
+        x.clone(),
+        None,
+    )
+    .is_err());
+    assert!(MulticomponentFlash::new_lle(temperature, pressure, x.clone(), x, None).is_ok());
+}
+
+#[test]
+fn test_temperature_dependent_k_ij() -> Result<(), Box<dyn Error>> {
+    let mut vector = ParameterVector::from_parameters(&read_parameters(vec!["propane", "hexane"])?);
+    let k_ij_t = KijTemperatureDependence::polynomial(vec![0.0, 0.0], 300.0)?;
+    let record = PcSaftBinaryRecord::new(None, None, None).with_k_ij_t(k_ij_t);
+    vector.set_binary_record(0, 1, record)?;
+    for name in ["k_ij", "k_ij_1", "k_ij_2"] {
+        vector.add_binary(0, 1, name, None)?;
+    }
+    assert_eq!(vector.names(), ["k_ij[0,1]", "k_ij_1[0,1]", "k_ij_2[0,1]"]);
+    let model = |x: &Array1<f64>| {
+        let parameters = vector.parameters(x).map_err(EosError::from)?;
+        Ok(Arc::new(PcSaft::new(Arc::new(parameters))))
+    };
+
+    // k_ij(T) = 0.01 + 1e-4 (T - 300 K) - 5e-7 (T - 300 K)^2
+    let x_true = arr1(&[1.0, 1.0, -0.5]);
+    assert_relative_eq!(&x_true * &vector.scaling(), arr1(&[0.01, 1e-4, -5e-7]));
+    let eos = model(&x_true)?;
+
+    // bubble points over a wide temperature range
+    let temperature = arr1(&[250.0, 300.0, 350.0, 400.0]) * KELVIN;
+    let n = temperature.len();
+    let mut pressure = Array1::zeros(n);
+    let mut x = Array2::zeros((n, 2));
+    let mut y = Array2::zeros((n, 2));
+    for i in 0..n {
+        let vle = PhaseEquilibrium::bubble_point(
+            &eos,
+            temperature.get(i),
+            &arr1(&[0.3, 0.7]),
+            None,
+            None,
+            Default::default(),
+        )?;
+        pressure[i] = vle.liquid().pressure(Contributions::Total).to_reduced();
+        x.row_mut(i).assign(&vle.liquid().molefracs);
+        y.row_mut(i).assign(&vle.vapor().molefracs);
+    }
+    let pressure = Pressure::from_reduced(pressure);
+    let data: Arc<dyn DataSet<PcSaft>> = Arc::new(MulticomponentChemicalPotential::new_vle(
+        temperature,
+        pressure,
+        x,
+        y,
+    )?);
+    assert_relative_eq!(
+        data.relative_difference(&eos)?,
+        Array1::zeros(data.datapoints()),
+        epsilon = 1e-8
+    );
+
+    // a constant k_ij cannot describe the data
+    let estimator = Estimator::new(vec![data], vec![1.0], vec![Loss::Linear]);
+    assert!(estimator.cost(&model(&arr1(&[1.0, 0.0, 0.0]))?)?[0].abs() > 1e-3);
+
+    // regression of the coefficients of k_ij(T)
+    let (lower, upper) = vector.bounds();
+    let result = estimator.least_squares(
+        model,
+        &arr1(&[0.0, 0.0, 0.0]),
+        Some((&lower, &upper)),
+        Default::default(),
+    )?;
+    assert!(result.success());
+    assert_relative_eq!(result.parameters, x_true, max_relative = 1e-5);
+    Ok(())
+}
+
+#[test]
+fn test_import_data() -> Result<(), Box<dyn Error>> {
+    let eos = propane(&arr1(&[2.001829, 3.618353, 208.1101]))?;
+    let data = ExperimentalData::from_csv("tests/pcsaft/propane_vapor_pressure.csv")?;
+    assert_eq!(data.property(), Property::VaporPressure);
+    let vapor_pressure = data.data_set::<PcSaft>()?;
+    assert_eq!(vapor_pressure.datapoints(), 5);
+    assert!(vapor_pressure.mean_absolute_relative_difference(&eos)? < 1e-6);
+
+    let data = ExperimentalData::from_thermoml("tests/pcsaft/propane_butane.xml")?;
+    assert_eq!(data.len(), 2);
+    assert_eq!(data[0].property(), Property::EquilibriumLiquidDensity);
+    let liquid_density = data[0].data_set::<PcSaft>()?;
+    assert_eq!(liquid_density.datapoints(), 3);
+    assert!(liquid_density.mean_absolute_relative_difference(&eos)? < 1e-6);
+
+    assert_eq!(data[1].property(), Property::Vle);
+    let parameters = read_parameters(vec!["propane", "butane"])?;
+    let eos = Arc::new(PcSaft::new(Arc::new(parameters)));
+    let vle = data[1].data_set::<PcSaft>()?;
+    assert_eq!(vle.datapoints(), 6);
+    assert_relative_eq!(vle.predict(&eos)?, Array1::ones(6), max_relative = 1e-4);
+
+    Ok(())
+}
+
+#[test]
+fn test_group_contribution() -> Result<(), Box<dyn Error>> {
+    let segment_records = SegmentRecord::from_json("parameters/pcsaft/sauer2014_homo.json")?;
+    let mut vector = SegmentVector::new(segment_records, None);
+    for name in ["m", "sigma", "epsilon_k"] {
+        vector.add_segment("CH2", name, None)?;
+    }
+    let model = |cr: &ChemicalRecord, x: &Array1<f64>| {
+        let parameters = vector
+            .parameters(vec![cr.clone()], x)
+            .map_err(EosError::from)?;
+        Ok(Arc::new(PcSaft::new(Arc::new(parameters))))
+    };
+
+    // data of n-alkanes generated with the original parameters of the CH2 group
+    let x = vector.to_vector();
+    let mut gc_estimator = GcEstimator::new();
+    for n in [3, 4, 6] {
+        let mut segments = vec!["CH2".to_string(); n - 2];
+        segments.insert(0, "CH3".into());
+        segments.push("CH3".into());
+        let cr = ChemicalRecord::new(Identifier::default(), segments, None);
+        let data = estimator(&model(&cr, &x)?, Loss::Linear, 0.0)?;
+        gc_estimator.add_molecule(cr, data, 1.0);
+    }
+
+    let x0 = &x * 1.1;
+    let (lower, upper) = vector.bounds();
+    let result =
+        gc_estimator.least_squares(model, &x0, Some((&lower, &upper)), Default::default())?;
+    assert!(result.success());
+    assert_relative_eq!(result.parameters, x, max_relative = 1e-5);
+    let mard = gc_estimator.mean_absolute_relative_difference(model, &result.parameters)?;
+    assert_eq!(mard.len(), 3);
+    assert!(mard.iter().all(|m| m.iter().all(|&m| m < 1e-6)));
+    vector.update(&result.parameters)?;
+    assert_relative_eq!(vector.to_vector(), x, max_relative = 1e-5);
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dft")]
+fn test_surface_tension() -> Result<(), Box<dyn Error>> {
+    let parameters = read_parameters(vec!["propane"])?;
+    let func = Arc::new(PcSaftFunctional::new(Arc::new(parameters)));
+    let temperature = arr1(&[200.0, 250.0]) * KELVIN;
+    let vle: Vec<_> = temperature
+        .into_iter()
+        .map(|t| PhaseEquilibrium::pure(&func, t, None, Default::default()))
+        .collect::<Result<_, _>>()?;
+
+    // pDGT
+    let gamma = vle
+        .iter()
+        .map(|vle| Ok(func.solve_pdgt(vle, 50, 0, None)?.1))
+        .collect::<Result<Vec<_>, EosError>>()?;
+    let gamma = SurfaceTension::from_shape_fn(2, |i| gamma[i]);
+    let pdgt = feos::estimator::SurfaceTension::new(
+        gamma,
+        temperature.clone(),
+        SurfaceTensionMethod::Pdgt,
+        None,
+        None,
+        None,
+    );
+    assert_relative_eq!(pdgt.predict(&func)?, pdgt.target, max_relative = 1e-10);
+
+    // DFT
+    let gamma = vle
+        .iter()
+        .map(|vle| {
+            let interface = PlanarInterface::from_pdgt(vle, 1024, false)?.solve(None)?;
+            Ok(interface.surface_tension.unwrap())
+        })
+        .collect::<Result<Vec<_>, EosError>>()?;
+    let gamma = SurfaceTension::from_shape_fn(2, |i| gamma[i]);
+    let dft = feos::estimator::SurfaceTension::new(
+        gamma,
+        temperature.clone(),
+        SurfaceTensionMethod::Dft,
+        None,
+        None,
+        None,
+    );
+    assert_relative_eq!(dft.predict(&func)?, dft.target, max_relative = 1e-8);
+
+    // combined with bulk data
+    let pressure = Pressure::from_shape_fn(2, |i| vle[i].vapor().pressure(Contributions::Total));
+    let vapor_pressure: Arc<dyn DataSet<DFT<PcSaftFunctional>>> =
+        Arc::new(VaporPressure::new(pressure, temperature, true, None, None));
+    let estimator = Estimator::new(
+        vec![vapor_pressure, Arc::new(pdgt), Arc::new(dft)],
+        vec![1.0; 3],
+        vec![Loss::Linear; 3],
+    );
+    let cost = estimator.cost(&func)?;
+    assert_eq!(cost.len(), 6);
+    assert!(cost.iter().all(|c| c.abs() < 1e-8));
+    Ok(())
+}
+
+#[test]
+#[cfg(feature = "dft")]
+fn test_adsorption_isotherm() -> Result<(), Box<dyn Error>> {
+    let parameters = Arc::new(read_parameters(vec!["methane"])?);
+    let func = Arc::new(PcSaftFunctional::new(parameters));
+    let adsorbent = |x: &Array1<f64>| {
+        let potential = ExternalPotential::LJ93 {
+            sigma_ss: 3.4,
+            epsilon_k_ss: x[0],
+            rho_s: 0.114,
+        };
+        let pore = Pore1D::new(
+            Geometry::Cartesian,
+            20.0 * ANGSTROM,
+            potential,
+            Some(512),
+            None,
+        );
+        let specific_pore_volume = 0.5 * CENTI * CENTI * CENTI * METER.powi::<typenum::P3>() / GRAM;
+        Ok(Arc::new(Adsorbent::new(
+            func.clone(),
+            pore,
+            specific_pore_volume,
+        )))
+    };
+    let temperature = 298.15 * KELVIN;
+    let pressure = arr1(&[1.0, 5.0, 10.0, 20.0, 50.0]) * BAR;
+    let isotherm = |target: &Array1<f64>, adsorbed_amount, branch| {
+        AdsorptionIsotherm::new(
+            target * (MILLI * MOL / GRAM),
+            temperature,
+            pressure.clone(),
+            None,
+            adsorbed_amount,
+            branch,
+            None,
+        )
+    };
+    let adsorption = IsothermBranch::Adsorption;
+
+    let parameters = arr1(&[28.0]);
+    let eos = adsorbent(&parameters)?;
+    let excess = isotherm(&Array1::ones(5), AdsorbedAmount::Excess, adsorption)?.predict(&eos)?;
+    let absolute =
+        isotherm(&Array1::ones(5), AdsorbedAmount::Absolute, adsorption)?.predict(&eos)?;
+    assert!(excess.iter().all(|n| n.is_finite() && *n > 0.0));
+    assert!(excess.iter().zip(&absolute).all(|(e, a)| e < a));
+
+    // supercritical methane shows no hysteresis, so all branches coincide
+    for branch in [IsothermBranch::Desorption, IsothermBranch::Equilibrium] {
+        let prediction = isotherm(&excess, AdsorbedAmount::Excess, branch)?.predict(&eos)?;
+        assert_relative_eq!(prediction, excess, max_relative = 1e-6);
+    }
+
+    // isotherms without data points are rejected
+    let empty = AdsorptionIsotherm::new(
+        Array1::zeros(0) * (MILLI * MOL / GRAM),
+        temperature,
+        Array1::zeros(0) * BAR,
+        None,
+        AdsorbedAmount::Excess,
+        IsothermBranch::Equilibrium,
+        None,
+    );
+    assert!(empty.is_err());
+
+    // unsorted pressures would assign data points to the wrong branch
+    let unsorted = AdsorptionIsotherm::new(
+        Array1::ones(3) * (MILLI * MOL / GRAM),
+        temperature,
+        arr1(&[1.0, 10.0, 5.0]) * BAR,
+        None,
+        AdsorbedAmount::Excess,
+        IsothermBranch::Equilibrium,
+        None,
+    );
+    assert!(unsorted.is_err());
+
+    let data: Arc<dyn DataSet<Adsorbent<PcSaftFunctional>>> =
+        Arc::new(isotherm(&excess, AdsorbedAmount::Excess, adsorption)?);
+    assert_relative_eq!(data.predict(&eos)?, excess, max_relative = 1e-10);
+
+    let estimator = Estimator::new(vec![data], vec![1.0], vec![Loss::Linear]);
+    let bounds = (arr1(&[10.0]), arr1(&[50.0]));
+    let result = estimator.least_squares(
+        adsorbent,
+        &arr1(&[35.0]),
+        Some((&bounds.0, &bounds.1)),
+        Default::default(),
+    )?;
+    assert!(result.success());
+    assert_relative_eq!(result.parameters, parameters, max_relative = 1e-5);
+    Ok(())
+}
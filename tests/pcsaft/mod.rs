@@ -1,6 +1,12 @@
 mod critical_point;
 mod dft;
+#[cfg(feature = "estimator")]
+mod estimator;
+mod ph_ps_flash;
+mod phase_envelope;
 mod properties;
+mod reaction_equilibrium;
+mod sensitivity;
 mod stability_analysis;
 mod state_creation_mixture;
 mod state_creation_pure;
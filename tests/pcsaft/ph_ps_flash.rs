@@ -0,0 +1,143 @@
+use approx::assert_relative_eq;
+use feos::ideal_gas::Joback;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::si::*;
+use feos_core::{
+    Contributions, DensityInitialization, EquationOfState, FlashResult, PhaseEquilibrium,
+    SolverOptions, State,
+};
+use ndarray::*;
+use std::error::Error;
+use std::sync::Arc;
+
+type Eos = EquationOfState<Joback, PcSaft>;
+
+fn read_eos(names: Vec<&str>) -> Result<Arc<Eos>, Box<dyn Error>> {
+    let saft = PcSaftParameters::from_json(
+        names.clone(),
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let joback = Joback::from_json(
+        names,
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    Ok(Arc::new(EquationOfState::new(
+        Arc::new(joback),
+        Arc::new(PcSaft::new(Arc::new(saft))),
+    )))
+}
+
+#[test]
+fn test_ph_flash_two_phases() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos(vec!["propane", "butane"])?;
+    let t = 250.0 * KELVIN;
+    let p = BAR;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let vle = PhaseEquilibrium::tp_flash(&eos, t, p, &feed, None, Default::default(), None)?;
+    let h = FlashResult::from(vle.clone()).molar_enthalpy(Contributions::Total);
+    let options = (Default::default(), Default::default());
+    let flash = FlashResult::ph_flash(&eos, p, h, &feed, Some(300.0 * KELVIN), options, None)?;
+    let FlashResult::TwoPhase(vle_ph) = &flash else {
+        panic!("Expected a two-phase solution.")
+    };
+    assert_relative_eq!(flash.temperature(), t, max_relative = 1e-7);
+    assert_relative_eq!(
+        flash.molar_enthalpy(Contributions::Total),
+        h,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        vle_ph.vapor().molefracs,
+        vle.vapor().molefracs,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        flash.vapor_phase_fraction().unwrap(),
+        FlashResult::from(vle).vapor_phase_fraction().unwrap(),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ps_flash_two_phases() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos(vec!["propane", "butane"])?;
+    let t = 250.0 * KELVIN;
+    let p = BAR;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let vle = PhaseEquilibrium::tp_flash(&eos, t, p, &feed, None, Default::default(), None)?;
+    let s = FlashResult::from(vle).molar_entropy(Contributions::Total);
+    let options = (Default::default(), Default::default());
+    let flash = FlashResult::ps_flash(&eos, p, s, &feed, Some(230.0 * KELVIN), options, None)?;
+    assert!(flash.vapor_phase_fraction().is_some());
+    assert_relative_eq!(flash.temperature(), t, max_relative = 1e-7);
+    assert_relative_eq!(
+        flash.molar_entropy(Contributions::Total),
+        s,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_ph_flash_single_phase() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos(vec!["propane", "butane"])?;
+    let t = 320.0 * KELVIN;
+    let p = BAR;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let state = State::new_npt(&eos, t, p, &feed, DensityInitialization::None)?;
+    let h = state.molar_enthalpy(Contributions::Total);
+    let options = (Default::default(), SolverOptions::default());
+    let flash = FlashResult::ph_flash(&eos, p, h, &feed, Some(250.0 * KELVIN), options, None)?;
+    assert!(matches!(flash, FlashResult::SinglePhase(_)));
+    assert_eq!(flash.vapor_phase_fraction(), None);
+    assert_relative_eq!(flash.temperature(), t, max_relative = 1e-7);
+    Ok(())
+}
+
+#[test]
+fn test_ph_flash_pure() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos(vec!["propane"])?;
+    let p = BAR;
+    let feed = arr1(&[2.0]) * MOL;
+    let vle = PhaseEquilibrium::pure(&eos, p, None, Default::default())?;
+    let h_v = vle.vapor().molar_enthalpy(Contributions::Total);
+    let h_l = vle.liquid().molar_enthalpy(Contributions::Total);
+    let h = 0.25 * h_v + 0.75 * h_l;
+    let options = (Default::default(), Default::default());
+    let flash = FlashResult::ph_flash(&eos, p, h, &feed, None, options, None)?;
+    assert_relative_eq!(
+        flash.vapor_phase_fraction().unwrap(),
+        0.25,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        flash.temperature(),
+        vle.vapor().temperature,
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(
+        flash.molar_enthalpy(Contributions::Total),
+        h,
+        max_relative = 1e-8
+    );
+
+    // superheated vapor
+    let h = h_v + 2.0 * KILO * JOULE / MOL;
+    let flash = FlashResult::ph_flash(&eos, p, h, &feed, None, options, None)?;
+    let FlashResult::SinglePhase(state) = &flash else {
+        panic!("Expected a single phase.")
+    };
+    assert!(state.temperature > vle.vapor().temperature);
+    assert_relative_eq!(
+        state.molar_enthalpy(Contributions::Total),
+        h,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
@@ -0,0 +1,67 @@
+use approx::assert_relative_eq;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::si::*;
+use feos_core::{Contributions, PhaseDiagram, State};
+use ndarray::arr1;
+use std::error::Error;
+use std::sync::Arc;
+
+#[test]
+fn test_phase_envelope() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["methane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.7, 0.3]) * MOL;
+    let envelope =
+        PhaseDiagram::phase_envelope(&saft, &moles, 150.0 * KELVIN, 200, Default::default())?;
+
+    let cp = envelope.critical_point.as_ref().unwrap();
+    let cp_direct = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+    assert_relative_eq!(cp.temperature, cp_direct.temperature, max_relative = 1e-8);
+    assert_relative_eq!(
+        cp.pressure(Contributions::Total),
+        cp_direct.pressure(Contributions::Total),
+        max_relative = 1e-6
+    );
+
+    let temperature = envelope.diagram.vapor().temperature();
+    let pressure = envelope.diagram.vapor().pressure();
+    let cricondenbar = envelope.cricondenbar.as_ref().unwrap();
+    let p_max = cricondenbar.vapor().pressure(Contributions::Total);
+    assert!(pressure.into_iter().all(|p| p <= p_max));
+    assert!(p_max > cp.pressure(Contributions::Total));
+    let cricondentherm = envelope.cricondentherm.as_ref().unwrap();
+    let t_max = cricondentherm.vapor().temperature;
+    assert!(temperature.into_iter().all(|t| t <= t_max));
+    assert!(t_max > cp.temperature);
+    Ok(())
+}
+
+#[test]
+fn test_phase_envelope_initial_direction() -> Result<(), Box<dyn Error>> {
+    let params = PcSaftParameters::from_json(
+        vec!["methane", "butane"],
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let saft = Arc::new(PcSaft::new(Arc::new(params)));
+    let moles = arr1(&[0.02, 0.98]) * MOL;
+    let t_min = 350.0 * KELVIN;
+    let envelope = PhaseDiagram::phase_envelope(&saft, &moles, t_min, 200, Default::default())?;
+
+    // the envelope starts at the bubble point and continues towards higher temperatures
+    let temperature = envelope.diagram.vapor().temperature();
+    assert!(temperature.get(1) > temperature.get(0));
+    assert!(temperature.into_iter().all(|t| t >= 0.999 * t_min));
+
+    let cp = envelope.critical_point.as_ref().unwrap();
+    let cp_direct = State::critical_point(&saft, Some(&moles), None, Default::default())?;
+    assert_relative_eq!(cp.temperature, cp_direct.temperature, max_relative = 1e-8);
+    Ok(())
+}
@@ -0,0 +1,187 @@
+use approx::assert_relative_eq;
+use feos::ideal_gas::Joback;
+use feos::pcsaft::{PcSaft, PcSaftParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::si::*;
+use feos_core::{
+    ChemicalReactions, Contributions, DensityInitialization, EquationOfState, FlashResult, State,
+};
+use ndarray::*;
+use std::error::Error;
+use std::sync::Arc;
+
+type Eos = EquationOfState<Joback, PcSaft>;
+
+fn read_eos() -> Result<Arc<Eos>, Box<dyn Error>> {
+    let names = vec!["propane", "butane", "methane", "hexane"];
+    let saft = PcSaftParameters::from_json(
+        names.clone(),
+        "tests/pcsaft/test_parameters.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    let joback = Joback::from_json(
+        names,
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?;
+    Ok(Arc::new(EquationOfState::new(
+        Arc::new(joback),
+        Arc::new(PcSaft::new(Arc::new(saft))),
+    )))
+}
+
+fn gibbs_energy_of_formation() -> MolarEnergy<Array1<f64>> {
+    arr1(&[-24.3, -17.0, -50.8, 0.1]) * KILO * JOULE / MOL
+}
+
+fn enthalpy_of_formation() -> MolarEnergy<Array1<f64>> {
+    arr1(&[-104.7, -125.8, -74.9, -166.9]) * KILO * JOULE / MOL
+}
+
+/// hexane + methane <-> propane + butane
+fn reactions() -> Result<ChemicalReactions, Box<dyn Error>> {
+    Ok(ChemicalReactions::new(
+        arr2(&[[1.0, 1.0, -1.0, -1.0]]),
+        gibbs_energy_of_formation(),
+        enthalpy_of_formation(),
+    )?)
+}
+
+fn ln_fugacity(state: &State<Eos>) -> Array1<f64> {
+    state.molefracs.mapv(f64::ln)
+        + state.ln_phi()
+        + (state.pressure(Contributions::Total) / BAR)
+            .into_value()
+            .ln()
+}
+
+#[test]
+fn test_equilibrium_constant() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos()?;
+    let reactions = reactions()?;
+    let t0 = 298.15 * KELVIN;
+    let nu = reactions.stoichiometric_matrix().row(0);
+    let delta_g = nu.dot(&gibbs_energy_of_formation().convert_into(JOULE / MOL));
+    let delta_h = nu.dot(&enthalpy_of_formation().convert_into(JOULE / MOL));
+    let rgas = RGAS.convert_into(JOULE / MOL / KELVIN);
+
+    let ln_k = reactions.ln_equilibrium_constant(&*eos, t0)[0];
+    assert_relative_eq!(ln_k, -delta_g / (rgas * 298.15), max_relative = 1e-12);
+
+    // van 't Hoff equation
+    let dt = 1e-3;
+    let ln_k_p = reactions.ln_equilibrium_constant(&*eos, t0 + dt * KELVIN)[0];
+    let ln_k_m = reactions.ln_equilibrium_constant(&*eos, t0 - dt * KELVIN)[0];
+    assert_relative_eq!(
+        (ln_k_p - ln_k_m) / (2.0 * dt),
+        delta_h / (rgas * 298.15 * 298.15),
+        max_relative = 1e-6
+    );
+    Ok(())
+}
+
+#[test]
+fn test_reaction_equilibrium_gas() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos()?;
+    let reactions = reactions()?;
+    let t = 600.0 * KELVIN;
+    let p = 10.0 * BAR;
+    let feed = arr1(&[1.0, 1.0, 0.0, 0.0]) * MOL;
+    let state = State::reaction_equilibrium(
+        &eos,
+        &reactions,
+        t,
+        p,
+        &feed,
+        DensityInitialization::Vapor,
+        Default::default(),
+    )?;
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        p,
+        max_relative = 1e-10
+    );
+
+    // reaction equilibrium
+    let ln_k = reactions.ln_equilibrium_constant(&*eos, t);
+    let ln_f = ln_fugacity(&state);
+    assert_relative_eq!(
+        reactions.stoichiometric_matrix().dot(&ln_f)[0],
+        ln_k[0],
+        epsilon = 1e-9
+    );
+
+    // element balances
+    let carbon = arr1(&[3.0, 4.0, 1.0, 6.0]);
+    assert_relative_eq!(state.moles.sum(), feed.sum(), max_relative = 1e-12);
+    assert_relative_eq!(
+        (state.moles.convert_into(MOL) * &carbon).sum(),
+        (feed.convert_into(MOL) * &carbon).sum(),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_element_matrix() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos()?;
+    let elements = arr2(&[[3.0, 4.0, 1.0, 6.0], [8.0, 10.0, 4.0, 14.0]]);
+    let from_elements = ChemicalReactions::from_element_matrix(
+        &elements,
+        gibbs_energy_of_formation(),
+        enthalpy_of_formation(),
+    )?;
+    assert_eq!(from_elements.reactions(), 2);
+    let nu = from_elements.stoichiometric_matrix();
+    assert_relative_eq!(
+        nu.dot(&elements.t()),
+        Array2::zeros((2, 2)),
+        epsilon = 1e-12
+    );
+
+    // hexane + methane <-> propane + butane
+    // 4 propane <-> 2 methane + butane + hexane
+    let from_stoichiometry = ChemicalReactions::new(
+        arr2(&[[1.0, 1.0, -1.0, -1.0], [-4.0, 1.0, 2.0, 1.0]]),
+        gibbs_energy_of_formation(),
+        enthalpy_of_formation(),
+    )?;
+
+    let t = 600.0 * KELVIN;
+    let p = 10.0 * BAR;
+    let feed = arr1(&[1.0, 1.0, 0.5, 0.0]) * MOL;
+    let vapor = DensityInitialization::Vapor;
+    let options = Default::default();
+    let state1 = State::reaction_equilibrium(&eos, &from_elements, t, p, &feed, vapor, options)?;
+    let state2 =
+        State::reaction_equilibrium(&eos, &from_stoichiometry, t, p, &feed, vapor, options)?;
+    assert_relative_eq!(state1.moles, state2.moles, max_relative = 1e-8);
+    Ok(())
+}
+
+#[test]
+fn test_reactive_flash() -> Result<(), Box<dyn Error>> {
+    let eos = read_eos()?;
+    let reactions = reactions()?;
+    let t = 300.0 * KELVIN;
+    let p = 5.0 * BAR;
+    let feed = arr1(&[1.0, 1.0, 0.0, 0.0]) * MOL;
+    let options = (Default::default(), Default::default());
+    let result = FlashResult::reactive_flash(&eos, &reactions, t, p, &feed, options)?;
+    let FlashResult::TwoPhase(vle) = result else {
+        panic!("Expected two phases!")
+    };
+
+    // phase and reaction equilibrium in both phases
+    let ln_k = reactions.ln_equilibrium_constant(&*eos, t)[0];
+    let nu = reactions.stoichiometric_matrix();
+    let (ln_f_v, ln_f_l) = (ln_fugacity(vle.vapor()), ln_fugacity(vle.liquid()));
+    assert_relative_eq!(ln_f_v, ln_f_l, epsilon = 1e-7);
+    assert_relative_eq!(nu.dot(&ln_f_v)[0], ln_k, epsilon = 1e-7);
+
+    let moles = &vle.vapor().moles + &vle.liquid().moles;
+    assert_relative_eq!(moles.sum(), feed.sum(), max_relative = 1e-10);
+    Ok(())
+}
@@ -0,0 +1,336 @@
+use approx::assert_relative_eq;
+use feos::binary_interaction::KijTemperatureDependence;
+use feos::ideal_gas::Joback;
+use feos::pcsaft::{PcSaft, PcSaftBinaryRecord, PcSaftParameters, PcSaftRecord};
+use feos_core::parameter::{Identifier, IdentifierOption, Parameter, PureRecord};
+use feos_core::sensitivity::{self, ParametersAD};
+use feos_core::si::*;
+use feos_core::{
+    Contributions, DensityInitialization, EquationOfState, PhaseEquilibrium, Residual, State,
+    StateHD,
+};
+use ndarray::{arr1, Array1, Array2};
+use num_dual::Dual64;
+use std::error::Error;
+use std::sync::Arc;
+
+const PROPANE: [f64; 3] = [2.001829, 3.618353, 208.1101];
+const BUTANE: [f64; 3] = [2.331586, 3.708601, 222.8774];
+const WATER: [f64; 5] = [1.065587, 3.000683, 366.5121, 0.034868, 2500.6706];
+const METHANOL: [f64; 5] = [1.5255, 3.23, 188.9, 0.035176, 2899.5];
+
+/// PC-SAFT model from a parameter vector in the order used by `ParametersAD`.
+fn pcsaft(
+    molarweight: &[f64],
+    associating: &[bool],
+    parameters: &[f64],
+) -> Result<Arc<PcSaft>, Box<dyn Error>> {
+    let n = molarweight.len();
+    let mut k = 3 * n;
+    let mut records = Vec::new();
+    for i in 0..n {
+        let p = &parameters[3 * i..3 * i + 3];
+        let (kappa_ab, epsilon_k_ab, na) = if associating[i] {
+            k += 2;
+            (Some(parameters[k - 2]), Some(parameters[k - 1]), Some(1.0))
+        } else {
+            (None, None, None)
+        };
+        let record = PcSaftRecord::new(
+            p[0],
+            p[1],
+            p[2],
+            None,
+            None,
+            kappa_ab,
+            epsilon_k_ab,
+            na,
+            na,
+            None,
+            None,
+            None,
+            None,
+        );
+        records.push(PureRecord::new(
+            Identifier::default(),
+            molarweight[i],
+            record,
+        ));
+    }
+    let binary = (n == 2).then(|| {
+        Array2::from_shape_fn((2, 2), |(i, j)| {
+            let k_ij = if i == j { 0.0 } else { parameters[k] };
+            PcSaftBinaryRecord::new(Some(k_ij), None, None)
+        })
+    });
+    let parameters = PcSaftParameters::from_records(records, binary)?;
+    Ok(Arc::new(PcSaft::new(Arc::new(parameters))))
+}
+
+/// Central finite difference of a property w.r.t. parameter `i`.
+fn finite_difference<F>(parameters: &Array1<f64>, i: usize, property: F) -> f64
+where
+    F: Fn(&[f64]) -> f64,
+{
+    let h = parameters[i].abs().max(1.0) * 1e-6;
+    let mut p_plus = parameters.to_vec();
+    let mut p_minus = parameters.to_vec();
+    p_plus[i] += h;
+    p_minus[i] -= h;
+    (property(&p_plus) - property(&p_minus)) / (2.0 * h)
+}
+
+#[test]
+fn test_helmholtz_energy_ad() -> Result<(), Box<dyn Error>> {
+    let parameters = [
+        &WATER[..3],
+        &METHANOL[..3],
+        &WATER[3..],
+        &METHANOL[3..],
+        &[0.02],
+    ]
+    .concat();
+    let eos = pcsaft(&[18.015, 32.042], &[true, true], &parameters)?;
+    assert_eq!(
+        eos.parameter_names(),
+        vec![
+            "m[0]",
+            "sigma[0]",
+            "epsilon_k[0]",
+            "m[1]",
+            "sigma[1]",
+            "epsilon_k[1]",
+            "kappa_ab[0]",
+            "epsilon_k_ab[0]",
+            "kappa_ab[1]",
+            "epsilon_k_ab[1]",
+            "k_ij[0,1]"
+        ]
+    );
+    assert_eq!(eos.parameters().to_vec(), parameters);
+
+    let state = StateHD::new(300.0, 100.0, arr1(&[1.5, 0.8]));
+    assert_relative_eq!(
+        eos.residual_helmholtz_energy_ad(&eos.parameters(), &state),
+        eos.residual_helmholtz_energy(&state),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_vapor_pressure_gradient() -> Result<(), Box<dyn Error>> {
+    let eos = pcsaft(&[18.015], &[true], &WATER)?;
+    let t = 350.0 * KELVIN;
+    let (p, grad) = sensitivity::gradient(&*eos, |parameters| {
+        sensitivity::vapor_pressure(&eos, parameters, t)
+    })?;
+    let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default())?;
+    assert_relative_eq!(
+        p,
+        vle.vapor().pressure(Contributions::Total),
+        max_relative = 1e-10
+    );
+
+    let parameters = eos.parameters();
+    for i in 0..parameters.len() {
+        let fd = finite_difference(&parameters, i, |p| {
+            let eos = pcsaft(&[18.015], &[true], p).unwrap();
+            let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default()).unwrap();
+            vle.vapor().pressure(Contributions::Total).to_reduced()
+        });
+        assert_relative_eq!(grad.get(i).to_reduced(), fd, max_relative = 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_equilibrium_liquid_density_gradient() -> Result<(), Box<dyn Error>> {
+    let eos = pcsaft(&[44.0962], &[false], &PROPANE)?;
+    let t = 250.0 * KELVIN;
+    let (rho, grad) = sensitivity::gradient(&*eos, |parameters| {
+        sensitivity::equilibrium_liquid_density(&eos, parameters, t)
+    })?;
+    let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default())?;
+    assert_relative_eq!(rho, vle.liquid().density, max_relative = 1e-10);
+
+    let parameters = eos.parameters();
+    for i in 0..parameters.len() {
+        let fd = finite_difference(&parameters, i, |p| {
+            let eos = pcsaft(&[44.0962], &[false], p).unwrap();
+            let vle = PhaseEquilibrium::pure(&eos, t, None, Default::default()).unwrap();
+            vle.liquid().density.to_reduced()
+        });
+        assert_relative_eq!(grad.get(i).to_reduced(), fd, max_relative = 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_bubble_point_pressure_gradient() -> Result<(), Box<dyn Error>> {
+    let parameters = [PROPANE.as_slice(), &BUTANE, &[0.03]].concat();
+    let molarweight = [44.0962, 58.123];
+    let eos = pcsaft(&molarweight, &[false, false], &parameters)?;
+    let t = 280.0 * KELVIN;
+    let x = arr1(&[0.3, 0.7]);
+    let (p, grad) = sensitivity::gradient(&*eos, |parameters| {
+        sensitivity::bubble_point_pressure(&eos, parameters, t, &x)
+    })?;
+    let vle = PhaseEquilibrium::bubble_point(&eos, t, &x, None, None, Default::default())?;
+    assert_relative_eq!(
+        p,
+        vle.vapor().pressure(Contributions::Total),
+        max_relative = 1e-8
+    );
+
+    let parameters = eos.parameters();
+    for i in 0..parameters.len() {
+        let fd = finite_difference(&parameters, i, |p| {
+            let eos = pcsaft(&molarweight, &[false, false], p).unwrap();
+            let vle = PhaseEquilibrium::bubble_point(&eos, t, &x, None, None, Default::default())
+                .unwrap();
+            vle.vapor().pressure(Contributions::Total).to_reduced()
+        });
+        assert_relative_eq!(grad.get(i).to_reduced(), fd, max_relative = 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_speed_of_sound_gradient() -> Result<(), Box<dyn Error>> {
+    let joback = Arc::new(Joback::from_json(
+        vec!["propane"],
+        "tests/pcsaft/test_parameters_joback.json",
+        None,
+        IdentifierOption::Name,
+    )?);
+    let eos = Arc::new(EquationOfState::new(
+        joback.clone(),
+        pcsaft(&[44.0962], &[false], &PROPANE)?,
+    ));
+    let t = 250.0 * KELVIN;
+    let p = 50.0 * BAR;
+    let (w, grad) = sensitivity::gradient(&*eos, |parameters| {
+        sensitivity::speed_of_sound(&eos, parameters, t, p, None, DensityInitialization::Liquid)
+    })?;
+    let state = State::new_npt(
+        &eos,
+        t,
+        p,
+        &(arr1(&[1.0]) * MOL),
+        DensityInitialization::Liquid,
+    )?;
+    assert_relative_eq!(w, state.speed_of_sound(), max_relative = 1e-10);
+
+    let parameters = eos.parameters();
+    for i in 0..parameters.len() {
+        let fd = finite_difference(&parameters, i, |parameters| {
+            let eos = Arc::new(EquationOfState::new(
+                joback.clone(),
+                pcsaft(&[44.0962], &[false], parameters).unwrap(),
+            ));
+            let moles = arr1(&[1.0]) * MOL;
+            State::new_npt(&eos, t, p, &moles, DensityInitialization::Liquid)
+                .unwrap()
+                .speed_of_sound()
+                .to_reduced()
+        });
+        assert_relative_eq!(grad.get(i).to_reduced(), fd, max_relative = 1e-5);
+    }
+    Ok(())
+}
+
+#[test]
+fn test_helmholtz_energy_ad_polar_mixture() -> Result<(), Box<dyn Error>> {
+    let record = |p: &[f64], mu, q, assoc: Option<&[f64]>| {
+        let na = assoc.map(|_| 1.0);
+        PcSaftRecord::new(
+            p[0],
+            p[1],
+            p[2],
+            mu,
+            q,
+            assoc.map(|a| a[0]),
+            assoc.map(|a| a[1]),
+            na,
+            na,
+            None,
+            None,
+            None,
+            None,
+        )
+    };
+    let records = vec![
+        PureRecord::new(
+            Identifier::default(),
+            18.015,
+            record(&WATER, None, None, Some(&WATER[3..])),
+        ),
+        PureRecord::new(
+            Identifier::default(),
+            32.042,
+            record(&METHANOL, None, None, Some(&METHANOL[3..])),
+        ),
+        PureRecord::new(
+            Identifier::default(),
+            58.08,
+            record(&[2.7447, 3.2742, 232.99], Some(2.88), None, None),
+        ),
+        PureRecord::new(
+            Identifier::default(),
+            44.01,
+            record(&[1.5131, 3.1869, 163.33], None, Some(4.4), None),
+        ),
+    ];
+    let binary = Array2::from_shape_fn((4, 4), |(i, j)| match (i.min(j), i.max(j)) {
+        (0, 1) => PcSaftBinaryRecord::new(Some(-0.02), None, Some(2700.0)),
+        (0, 2) => PcSaftBinaryRecord::new(Some(0.05), None, None),
+        (1, 3) => PcSaftBinaryRecord::new(Some(0.01), None, None),
+        _ => PcSaftBinaryRecord::default(),
+    });
+    let parameters = PcSaftParameters::from_records(records, Some(binary))?;
+    let eos = PcSaft::new(Arc::new(parameters));
+
+    let state = StateHD::new(320.0, 150.0, arr1(&[1.5, 0.8, 0.6, 0.3]));
+    assert_relative_eq!(
+        eos.residual_helmholtz_energy_ad(&eos.parameters(), &state),
+        eos.residual_helmholtz_energy(&state),
+        max_relative = 1e-12
+    );
+    Ok(())
+}
+
+#[test]
+fn test_helmholtz_energy_ad_temperature_dependent_k_ij() -> Result<(), Box<dyn Error>> {
+    let records = [(PROPANE, 44.0962), (BUTANE, 58.123)]
+        .map(|(p, mw)| {
+            let record = PcSaftRecord::new(
+                p[0], p[1], p[2], None, None, None, None, None, None, None, None, None, None,
+            );
+            PureRecord::new(Identifier::default(), mw, record)
+        })
+        .to_vec();
+    let k_ij_t = KijTemperatureDependence::polynomial(vec![2e-4, -1e-6], 250.0)?;
+    let binary = Array2::from_shape_fn((2, 2), |(i, j)| {
+        if i == j {
+            PcSaftBinaryRecord::default()
+        } else {
+            PcSaftBinaryRecord::new(Some(0.01), None, None).with_k_ij_t(k_ij_t.clone())
+        }
+    });
+    let parameters = PcSaftParameters::from_records(records, Some(binary))?;
+    let eos = PcSaft::new(Arc::new(parameters));
+
+    let t = Dual64::from(300.0).derivative();
+    let state = StateHD::new(
+        t,
+        Dual64::from(1000.0),
+        arr1(&[1.5, 0.8]).mapv(Dual64::from),
+    );
+    let parameters = eos.parameters().mapv(Dual64::from);
+    let a_ad = eos.residual_helmholtz_energy_ad(&parameters, &state);
+    let a = eos.residual_helmholtz_energy(&state);
+    assert_relative_eq!(a_ad.re, a.re, max_relative = 1e-12);
+    assert_relative_eq!(a_ad.eps, a.eps, max_relative = 1e-12);
+    Ok(())
+}
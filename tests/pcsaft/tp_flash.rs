@@ -2,7 +2,7 @@ use approx::assert_relative_eq;
 use feos::pcsaft::{PcSaft, PcSaftParameters};
 use feos_core::parameter::{IdentifierOption, Parameter, ParameterError};
 use feos_core::si::*;
-use feos_core::{Contributions, PhaseEquilibrium, SolverOptions};
+use feos_core::{Contributions, MultiPhaseEquilibrium, PhaseEquilibrium, SolverOptions};
 use ndarray::*;
 use std::error::Error;
 use std::sync::Arc;
@@ -60,3 +60,164 @@ fn test_tp_flash() -> Result<(), Box<dyn Error>> {
     );
     Ok(())
 }
+
+#[test]
+fn test_multiphase_flash_two_phases() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let t = 250.0 * KELVIN;
+    let p = 1.0 * BAR;
+    let feed = arr1(&[0.5, 0.5]) * MOL;
+    let options = SolverOptions::new().tol(1e-10);
+    let vle = PhaseEquilibrium::tp_flash(&mix, t, p, &feed, None, options, None)?;
+    let mpe = MultiPhaseEquilibrium::tp_flash(&mix, t, p, &feed, options, None)?;
+    assert_eq!(mpe.number_of_phases(), 2);
+    let phases = mpe.phases();
+    assert_relative_eq!(
+        phases[0].molefracs,
+        vle.vapor().molefracs,
+        max_relative = 1e-8
+    );
+    assert_relative_eq!(
+        phases[1].molefracs,
+        vle.liquid().molefracs,
+        max_relative = 1e-8
+    );
+    let vle2: PhaseEquilibrium<_, 2> = mpe.try_into()?;
+    assert_relative_eq!(
+        vle2.vapor().density,
+        vle.vapor().density,
+        max_relative = 1e-8
+    );
+    Ok(())
+}
+
+#[test]
+fn test_multiphase_flash_three_phases() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec![
+        "water_np", "hexane", "methane",
+    ])?));
+    let t = 300.0 * KELVIN;
+    let p = 10.0 * BAR;
+    let feed = arr1(&[0.4, 0.3, 0.3]) * MOL;
+    let mpe = MultiPhaseEquilibrium::tp_flash(&mix, t, p, &feed, Default::default(), None)?;
+    assert_eq!(mpe.number_of_phases(), 3);
+
+    // mass balance
+    assert_relative_eq!(mpe.total_moles(), feed, max_relative = 1e-10);
+    assert_relative_eq!(mpe.phase_fractions().sum(), 1.0, max_relative = 1e-12);
+
+    // iso-fugacity and equal pressures
+    let phases = mpe.phases();
+    let f0 = &phases[0].molefracs * &phases[0].ln_phi().mapv(f64::exp);
+    for phase in phases.iter().skip(1) {
+        assert_relative_eq!(phase.pressure(Contributions::Total), p, max_relative = 1e-8);
+        assert_relative_eq!(
+            &phase.molefracs * &phase.ln_phi().mapv(f64::exp),
+            f0,
+            max_relative = 1e-6
+        );
+    }
+
+    // the vapor phase is rich in methane, the liquid phases in water and hexane
+    assert!(phases[0].molefracs[2] > 0.9);
+    assert!(phases[1].molefracs[1] > 0.5);
+    assert!(phases[2].molefracs[0] > 0.9);
+    Ok(())
+}
+
+#[test]
+fn test_vapor_fraction_flash_temperature() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let t = 250.0 * KELVIN;
+    let feed = arr1(&[0.3, 0.7]) * MOL;
+    let options = (Default::default(), Default::default());
+    let vle = PhaseEquilibrium::vapor_fraction_flash(&mix, t, 0.4, &feed, None, options)?;
+    assert_relative_eq!(vle.vapor_phase_fraction(), 0.4, max_relative = 1e-10);
+    assert_relative_eq!(
+        &vle.vapor().moles + &vle.liquid().moles,
+        feed,
+        max_relative = 1e-10
+    );
+
+    let p = vle.vapor().pressure(Contributions::Total);
+    let flash = PhaseEquilibrium::tp_flash(&mix, t, p, &feed, None, Default::default(), None)?;
+    assert_relative_eq!(flash.vapor_phase_fraction(), 0.4, max_relative = 1e-6);
+    assert_relative_eq!(
+        flash.vapor().molefracs,
+        vle.vapor().molefracs,
+        max_relative = 1e-6
+    );
+
+    let bubble = PhaseEquilibrium::vapor_fraction_flash(&mix, t, 0.0, &feed, None, options)?;
+    let bubble_point = PhaseEquilibrium::bubble_point(
+        &mix,
+        t,
+        &arr1(&[0.3, 0.7]),
+        None,
+        None,
+        Default::default(),
+    )?;
+    assert_relative_eq!(
+        bubble.vapor().pressure(Contributions::Total),
+        bubble_point.vapor().pressure(Contributions::Total),
+        max_relative = 1e-10
+    );
+    assert_relative_eq!(bubble.vapor_phase_fraction(), 0.0, epsilon = 1e-10);
+    assert_relative_eq!(bubble.liquid().moles, feed, max_relative = 1e-10);
+
+    let dew = PhaseEquilibrium::vapor_fraction_flash(&mix, t, 1.0, &feed, None, options)?;
+    assert_relative_eq!(dew.vapor_phase_fraction(), 1.0, max_relative = 1e-10);
+    assert_relative_eq!(dew.vapor().moles, feed, max_relative = 1e-10);
+    Ok(())
+}
+
+#[test]
+fn test_vapor_fraction_flash_pressure() -> Result<(), Box<dyn Error>> {
+    let mix = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let p = BAR;
+    let feed = arr1(&[0.3, 0.7]) * MOL;
+    let options = (Default::default(), Default::default());
+    let vle = PhaseEquilibrium::vapor_fraction_flash(
+        &mix,
+        p,
+        0.75,
+        &feed,
+        Some(250.0 * KELVIN),
+        options,
+    )?;
+    assert_relative_eq!(vle.vapor_phase_fraction(), 0.75, max_relative = 1e-10);
+    assert_relative_eq!(
+        vle.liquid().pressure(Contributions::Total),
+        p,
+        max_relative = 1e-8
+    );
+
+    let t = vle.vapor().temperature;
+    let flash = PhaseEquilibrium::tp_flash(&mix, t, p, &feed, None, Default::default(), None)?;
+    assert_relative_eq!(flash.vapor_phase_fraction(), 0.75, max_relative = 1e-6);
+    Ok(())
+}
+
+#[test]
+fn test_vapor_fraction_flash_absent_component() -> Result<(), Box<dyn Error>> {
+    let binary = Arc::new(PcSaft::new(read_params(vec!["propane", "butane"])?));
+    let ternary = Arc::new(PcSaft::new(read_params(vec![
+        "propane", "butane", "hexane",
+    ])?));
+    let t = 250.0 * KELVIN;
+    let options = (Default::default(), Default::default());
+    let feed = arr1(&[0.3, 0.7]) * MOL;
+    let vle = PhaseEquilibrium::vapor_fraction_flash(&binary, t, 0.4, &feed, None, options)?;
+    let feed = arr1(&[0.3, 0.7, 0.0]) * MOL;
+    let vle_absent =
+        PhaseEquilibrium::vapor_fraction_flash(&ternary, t, 0.4, &feed, None, options)?;
+    assert_relative_eq!(vle_absent.vapor_phase_fraction(), 0.4, max_relative = 1e-10);
+    assert_eq!(vle_absent.vapor().molefracs[2], 0.0);
+    assert_eq!(vle_absent.liquid().molefracs[2], 0.0);
+    assert_relative_eq!(
+        vle_absent.vapor().pressure(Contributions::Total),
+        vle.vapor().pressure(Contributions::Total),
+        max_relative = 1e-8
+    );
+    Ok(())
+}
@@ -0,0 +1,70 @@
+#![cfg(feature = "dft")]
+use approx::assert_relative_eq;
+use feos::saftvrmie::{SaftVRMie, SaftVRMieFunctional, SaftVRMieParameters};
+use feos_core::parameter::{IdentifierOption, Parameter};
+use feos_core::si::*;
+use feos_core::{Contributions, PhaseEquilibrium, State};
+use feos_dft::interface::PlanarInterface;
+use ndarray::arr1;
+use std::error::Error;
+use std::sync::Arc;
+use typenum::P3;
+
+fn parameters(substances: Vec<&str>) -> Result<Arc<SaftVRMieParameters>, Box<dyn Error>> {
+    Ok(Arc::new(SaftVRMieParameters::from_json(
+        substances,
+        "parameters/saftvrmie/lafitte2013.json",
+        None,
+        IdentifierOption::Name,
+    )?))
+}
+
+#[test]
+fn test_bulk_implementation() -> Result<(), Box<dyn Error>> {
+    let params = parameters(vec!["ethanol", "hexane"])?;
+    let eos = Arc::new(SaftVRMie::new(params.clone()));
+    let func = Arc::new(SaftVRMieFunctional::new(params));
+    let t = 300.0 * KELVIN;
+    let v = 1e-4 * METER.powi::<P3>();
+    let n = arr1(&[0.4, 0.3]) * MOL;
+    let state = State::new_nvt(&eos, t, v, &n)?;
+    let state_func = State::new_nvt(&func, t, v, &n)?;
+    assert_relative_eq!(
+        state.residual_helmholtz_energy(),
+        state_func.residual_helmholtz_energy(),
+        max_relative = 1e-10,
+    );
+    assert_relative_eq!(
+        state.pressure(Contributions::Total),
+        state_func.pressure(Contributions::Total),
+        max_relative = 1e-10,
+    );
+    assert_relative_eq!(
+        state.residual_chemical_potential(),
+        state_func.residual_chemical_potential(),
+        max_relative = 1e-10,
+    );
+    Ok(())
+}
+
+#[test]
+fn test_dft_ethanol() -> Result<(), Box<dyn Error>> {
+    let func = Arc::new(SaftVRMieFunctional::new(parameters(vec!["ethanol"])?));
+    let t = 300.0 * KELVIN;
+    let w = 100.0 * ANGSTROM;
+    let points = 2048;
+    let tc = State::critical_point(&func, None, None, Default::default())?.temperature;
+    let vle = PhaseEquilibrium::pure(&func, t, None, Default::default())?;
+    let profile = PlanarInterface::from_tanh(&vle, points, w, tc, false).solve(None)?;
+    assert_relative_eq!(
+        profile.surface_tension.unwrap(),
+        29.6224894408557 * MILLI * NEWTON / METER,
+        max_relative = 1e-6
+    );
+    assert_relative_eq!(
+        profile.profile.bulk.density,
+        vle.vapor().density,
+        max_relative = 1e-10
+    );
+    Ok(())
+}
@@ -1 +1,2 @@
 mod critical_properties;
+mod dft;